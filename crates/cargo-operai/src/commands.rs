@@ -11,8 +11,13 @@
 //! - **`mcp`**: Run a Model Context Protocol (MCP) server for AI assistant
 //!   integration
 //! - **`call`**: Invoke a specific tool on a running server with JSON input
+//! - **`approvals`**: List, approve, or deny tool calls held for human approval
+//! - **`policy`**: Test policies offline against fixtures, or inspect what
+//!   shadow-mode policies would have done on a server
 //! - **`list`**: Display all available tools on a running server
 //! - **`describe`**: Show detailed information about a specific tool
+//! - **`search`**: Find tools by natural-language query with relevance scores
+//! - **`search-eval`**: Measure search recall@k and MRR against query fixtures
 //!
 //! # Command Structure
 //!
@@ -24,10 +29,14 @@
 //!
 //! The main CLI dispatches to these `run` functions based on user input.
 
+pub mod approvals;
 pub mod build;
 pub mod call;
 pub mod describe;
 pub mod list;
 pub mod mcp;
 pub mod new;
+pub mod policy;
+pub mod search;
+pub mod search_eval;
 pub mod serve;
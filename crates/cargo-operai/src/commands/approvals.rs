@@ -0,0 +1,183 @@
+//! Review tool calls held for human approval on a toolbox server.
+//!
+//! Policies with a `require_approval` effect park matching calls until a
+//! person decides them. This module implements `cargo operai approvals`,
+//! which lists the pending calls and approves or denies them through the
+//! server's admin service (see `cargo operai serve --admin-addr`).
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use console::style;
+use operai_runtime::proto::{
+    Approval, ApproveCallRequest, DenyCallRequest, ListApprovalsRequest,
+    toolbox_admin_client::ToolboxAdminClient,
+};
+
+use super::list::{normalize_tool_name, struct_to_json};
+
+/// Command-line arguments for the approvals command.
+#[derive(Args)]
+pub struct ApprovalsArgs {
+    /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+    #[arg(short, long, default_value = "http://127.0.0.1:50052", global = true)]
+    pub server: String,
+
+    /// The approval operation to perform.
+    #[command(subcommand)]
+    pub command: ApprovalsCommand,
+}
+
+/// Operations on pending approvals.
+#[derive(Subcommand)]
+pub enum ApprovalsCommand {
+    /// List calls waiting for approval.
+    List {
+        /// Output format: "table" for human-readable table or "json" for
+        /// machine-readable JSON
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
+    /// Approve a pending call so it runs.
+    Approve {
+        /// Approval identifier, with or without the "approvals/" prefix
+        id: String,
+    },
+
+    /// Deny a pending call so it fails.
+    Deny {
+        /// Approval identifier, with or without the "approvals/" prefix
+        id: String,
+
+        /// Reason reported to the caller
+        #[arg(short, long)]
+        reason: Option<String>,
+    },
+}
+
+/// Executes the approvals command against a toolbox admin server.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Connection to the server fails
+/// - The approval does not exist, has expired, or was already decided
+/// - JSON serialization fails (when using JSON format)
+pub async fn run(args: &ApprovalsArgs) -> Result<()> {
+    let mut client = ToolboxAdminClient::connect(args.server.clone())
+        .await
+        .context("failed to connect to server")?;
+
+    match &args.command {
+        ApprovalsCommand::List { format } => {
+            let response = client
+                .list_approvals(ListApprovalsRequest {})
+                .await
+                .context("failed to list approvals")?
+                .into_inner();
+            print_approvals(&response.approvals, format)?;
+        }
+        ApprovalsCommand::Approve { id } => {
+            let approval = client
+                .approve_call(ApproveCallRequest {
+                    name: approval_name(id),
+                })
+                .await
+                .context("failed to approve call")?
+                .into_inner();
+            println!(
+                "{} Approved {} ({})",
+                style("✓").green().bold(),
+                style(normalize_approval_name(&approval.name)).bold(),
+                normalize_tool_name(&approval.tool)
+            );
+        }
+        ApprovalsCommand::Deny { id, reason } => {
+            let approval = client
+                .deny_call(DenyCallRequest {
+                    name: approval_name(id),
+                    reason: reason.clone().unwrap_or_default(),
+                })
+                .await
+                .context("failed to deny call")?
+                .into_inner();
+            println!(
+                "{} Denied {} ({})",
+                style("✗").red().bold(),
+                style(normalize_approval_name(&approval.name)).bold(),
+                normalize_tool_name(&approval.tool)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints pending approvals as a table or JSON array.
+fn print_approvals(approvals: &[Approval], format: &str) -> Result<()> {
+    if format == "json" {
+        let approvals_json: Vec<serde_json::Value> =
+            approvals.iter().map(approval_to_json).collect();
+        println!("{}", serde_json::to_string_pretty(&approvals_json)?);
+        return Ok(());
+    }
+
+    if approvals.is_empty() {
+        println!("No pending approvals");
+        return Ok(());
+    }
+
+    println!(
+        "{:<18} {:<30} {:<20} {}",
+        style("ID").bold(),
+        style("TOOL").bold(),
+        style("SESSION").bold(),
+        style("REASON").bold()
+    );
+    println!("{}", "-".repeat(90));
+    for approval in approvals {
+        println!(
+            "{:<18} {:<30} {:<20} {}",
+            normalize_approval_name(&approval.name),
+            normalize_tool_name(&approval.tool),
+            approval.session_id,
+            approval.reason
+        );
+        if let Some(input) = &approval.input {
+            println!("  input: {}", struct_to_json(input.clone()));
+        }
+    }
+    println!(
+        "\n{} {} pending approval(s)",
+        style("…").yellow(),
+        approvals.len()
+    );
+    Ok(())
+}
+
+/// Converts an approval to JSON for machine-readable output.
+fn approval_to_json(approval: &Approval) -> serde_json::Value {
+    serde_json::json!({
+        "name": approval.name,
+        "tool": approval.tool,
+        "input": approval.input.clone().map(struct_to_json),
+        "sessionId": approval.session_id,
+        "reason": approval.reason,
+        "createTimeMs": approval.create_time_ms,
+        "expireTimeMs": approval.expire_time_ms,
+    })
+}
+
+/// Adds the "approvals/" prefix to an approval identifier if missing.
+fn approval_name(id: &str) -> String {
+    if id.starts_with("approvals/") {
+        id.to_string()
+    } else {
+        format!("approvals/{id}")
+    }
+}
+
+/// Removes the "approvals/" prefix from an approval name if present.
+fn normalize_approval_name(name: &str) -> &str {
+    name.strip_prefix("approvals/").unwrap_or(name)
+}
@@ -388,6 +388,43 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_run_writes_hashing_embedding() -> anyhow::Result<()> {
+        // Arrange
+        let temp = TestTempDir::new("operai-build")?;
+        let crate_dir = temp.path().join("crate");
+        let bin_dir = temp.path().join("bin");
+        fs::create_dir_all(crate_dir.join("src"))?;
+        fs::create_dir_all(&bin_dir)?;
+        fs::write(
+            crate_dir.join("Cargo.toml"),
+            "[package]\nname = \"test\"\nversion = \"0.1.0\"\n",
+        )?;
+        fs::write(
+            crate_dir.join("src/lib.rs"),
+            "/// Sends an email to a recipient.\npub fn send_email() {}\n",
+        )?;
+        let config: operai_core::Config =
+            toml::from_str("[embedding]\ntype = \"hashing\"\ndimensions = 64\n")?;
+
+        let cargo_path = install_fake_cargo(&bin_dir, 0)?;
+
+        let args = BuildArgs {
+            path: Some(crate_dir.clone()),
+            skip_embed: false,
+            cargo_args: Vec::new(),
+        };
+
+        // Act
+        run_with(&args, cargo_path, &config).await?;
+
+        // Assert
+        let bytes = fs::read(crate_dir.join(".brwse-embedding"))?;
+        assert_eq!(bytes.len(), 64 * std::mem::size_of::<f32>());
+
+        Ok(())
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn test_run_returns_error_when_cargo_build_fails() -> anyhow::Result<()> {
         // Arrange
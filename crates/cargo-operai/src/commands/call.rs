@@ -42,6 +42,19 @@ pub struct CallArgs {
     /// If not specified, defaults to ~/.config/operai/credentials.toml
     #[arg(long = "creds-file")]
     pub credentials_file: Option<PathBuf>,
+
+    /// Return immediately when a policy holds the call for approval, instead
+    /// of waiting for a decision
+    ///
+    /// Approve the call with `cargo operai approvals approve` and run the same
+    /// command again to execute it.
+    #[arg(long)]
+    pub defer_approval: bool,
+
+    /// Run the tool even if the server cached its output for this input,
+    /// replacing the cached output
+    #[arg(long)]
+    pub no_cache: bool,
 }
 
 /// Executes a tool call to the remote toolbox server.
@@ -98,6 +111,8 @@ pub async fn run(args: &CallArgs) -> Result<()> {
 
     let metadata = CallMetadata {
         credentials,
+        defer_approval: args.defer_approval,
+        bypass_cache: args.no_cache,
         ..Default::default()
     };
 
@@ -106,9 +121,19 @@ pub async fn run(args: &CallArgs) -> Result<()> {
         .await
         .context("failed to call tool")?;
 
+    let cached =
+        response_inner.cache() == operai_runtime::proto::call_tool_response::CacheStatus::Hit;
     match response_inner.result {
         Some(operai_runtime::proto::call_tool_response::Result::Output(output)) => {
-            println!("{} Result:", style("✓").green().bold());
+            if cached {
+                println!(
+                    "{} Result {}:",
+                    style("✓").green().bold(),
+                    style("(cached)").dim()
+                );
+            } else {
+                println!("{} Result:", style("✓").green().bold());
+            }
             // Convert struct back to JSON for printing
             let output_json = serde_json::to_string_pretty(&struct_to_json(output))?;
             println!("{output_json}");
@@ -116,6 +141,14 @@ pub async fn run(args: &CallArgs) -> Result<()> {
         Some(operai_runtime::proto::call_tool_response::Result::Error(error)) => {
             println!("{} Error: {}", style("✗").red().bold(), error);
         }
+        Some(operai_runtime::proto::call_tool_response::Result::PendingApproval(approval)) => {
+            println!(
+                "{} Awaiting approval: {} ({})",
+                style("…").yellow().bold(),
+                style(&approval.name).bold(),
+                approval.reason
+            );
+        }
         None => {
             println!("{} No result returned", style("?").yellow().bold());
         }
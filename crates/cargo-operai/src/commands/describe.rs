@@ -162,26 +162,15 @@ mod tests {
     ///
     /// Tests cover both successful tool lookup and "not found" scenarios
     /// using a mock Toolbox server.
-    use operai_runtime::proto::{
-        CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse, SearchToolsRequest,
-        SearchToolsResponse, Tool,
-        toolbox_server::{Toolbox, ToolboxServer},
-    };
-    use tonic::{Request, Response, Status};
+    use operai_runtime::proto::{ListToolsResponse, Tool};
 
     use super::*;
+    use crate::testing::MockToolbox;
 
-    /// Mock Toolbox server implementation for testing.
-    struct MockToolbox;
-
-    #[tonic::async_trait]
-    impl Toolbox for MockToolbox {
-        /// Returns a fixed list of tools for testing.
-        async fn list_tools(
-            &self,
-            _request: Request<ListToolsRequest>,
-        ) -> Result<Response<ListToolsResponse>, Status> {
-            Ok(Response::new(ListToolsResponse {
+    /// Mock toolbox that lists a fixed set of tools.
+    fn mock_toolbox() -> MockToolbox {
+        MockToolbox::default().with_list_tools(|_| {
+            Ok(ListToolsResponse {
                 tools: vec![
                     Tool {
                         name: "tools/hello-world.greet".to_string(),
@@ -200,24 +189,8 @@ mod tests {
                     },
                 ],
                 next_page_token: String::new(),
-            }))
-        }
-
-        /// Returns an unimplemented error for search operations.
-        async fn search_tools(
-            &self,
-            _request: Request<SearchToolsRequest>,
-        ) -> Result<Response<SearchToolsResponse>, Status> {
-            Err(Status::unimplemented("not implemented"))
-        }
-
-        /// Returns an unimplemented error for call operations.
-        async fn call_tool(
-            &self,
-            _request: Request<CallToolRequest>,
-        ) -> Result<Response<CallToolResponse>, Status> {
-            Err(Status::unimplemented("not implemented"))
-        }
+            })
+        })
     }
 
     /// Tests successful tool lookup and display.
@@ -225,31 +198,15 @@ mod tests {
     async fn test_run_describe_found() -> Result<()> {
         let _lock = crate::testing::test_lock_async().await;
 
-        let port = {
-            let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
-            listener.local_addr()?.port()
-        };
-        let addr_str = format!("127.0.0.1:{port}");
-        let addr: std::net::SocketAddr = addr_str.parse()?;
-
-        let server = tokio::spawn(async move {
-            tonic::transport::Server::builder()
-                .add_service(ToolboxServer::new(MockToolbox))
-                .serve(addr)
-                .await
-                .unwrap();
-        });
-
-        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+        let server = mock_toolbox().serve().await?;
 
         let args = DescribeArgs {
             tool_id: "hello-world.greet".to_owned(),
-            server: format!("http://{addr_str}"),
+            server: server.url(),
         };
 
         run(&args).await.context("run failed")?;
 
-        server.abort();
         Ok(())
     }
 
@@ -258,33 +215,17 @@ mod tests {
     async fn test_run_describe_not_found() -> Result<()> {
         let _lock = crate::testing::test_lock_async().await;
 
-        let port = {
-            let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
-            listener.local_addr()?.port()
-        };
-        let addr_str = format!("127.0.0.1:{port}");
-        let addr: std::net::SocketAddr = addr_str.parse()?;
-
-        let server = tokio::spawn(async move {
-            tonic::transport::Server::builder()
-                .add_service(ToolboxServer::new(MockToolbox))
-                .serve(addr)
-                .await
-                .unwrap();
-        });
-
-        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+        let server = mock_toolbox().serve().await?;
 
         let args = DescribeArgs {
             tool_id: "unknown.tool".to_owned(),
-            server: format!("http://{addr_str}"),
+            server: server.url(),
         };
 
         let result = run(&args).await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("tool not found"));
 
-        server.abort();
         Ok(())
     }
 }
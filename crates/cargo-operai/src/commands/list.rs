@@ -6,7 +6,7 @@
 use anyhow::{Context, Result};
 use clap::Args;
 use console::style;
-use operai_runtime::proto::toolbox_client::ToolboxClient;
+use operai_runtime::proto::{tool::CircuitState, toolbox_client::ToolboxClient};
 
 /// Removes the "tools/" prefix from a tool name if present.
 ///
@@ -18,7 +18,7 @@ use operai_runtime::proto::toolbox_client::ToolboxClient;
 ///
 /// The tool name without the "tools/" prefix, or the original name if the
 /// prefix is not present.
-fn normalize_tool_name(name: &str) -> &str {
+pub(crate) fn normalize_tool_name(name: &str) -> &str {
     name.strip_prefix("tools/").unwrap_or(name)
 }
 
@@ -36,7 +36,7 @@ fn normalize_tool_name(name: &str) -> &str {
 ///
 /// Either the original description (if ≤ 40 chars) or the truncated version
 /// with "..." appended (totaling 40 chars).
-fn truncate_description(description: &str) -> String {
+pub(crate) fn truncate_description(description: &str) -> String {
     const MAX_DESCRIPTION_CHARS: usize = 40;
     const ELLIPSIS: &str = "...";
     const TRUNCATED_CHARS: usize = MAX_DESCRIPTION_CHARS - ELLIPSIS.len();
@@ -52,6 +52,21 @@ fn truncate_description(description: &str) -> String {
     format!("{prefix}{ELLIPSIS}")
 }
 
+/// Returns the state of a tool's circuit breaker as shown to users.
+///
+/// # Returns
+///
+/// `closed`, `open` or `half-open`, or `None` if no circuit breaker watches
+/// the tool.
+pub(crate) fn circuit_state_label(tool: &operai_runtime::proto::Tool) -> Option<&'static str> {
+    match tool.circuit_state() {
+        CircuitState::Unspecified => None,
+        CircuitState::Closed => Some("closed"),
+        CircuitState::Open => Some("open"),
+        CircuitState::HalfOpen => Some("half-open"),
+    }
+}
+
 /// Command-line arguments for the list command.
 ///
 /// This struct is parsed by `clap` and configures how tools are listed from
@@ -88,8 +103,8 @@ pub struct ListArgs {
 ///
 /// # Output Formats
 ///
-/// - **table**: Prints a formatted table with columns for tool ID, name, and
-///   description
+/// - **table**: Prints a formatted table with columns for tool ID, name,
+///   circuit breaker state (`-` if none watches the tool), and description
 /// - **json**: Prints a JSON array with complete tool information including
 ///   schemas
 pub async fn run(args: &ListArgs) -> Result<()> {
@@ -118,21 +133,23 @@ pub async fn run(args: &ListArgs) -> Result<()> {
     } else if !response.tools.is_empty() {
         // ... (table output remains valid as it accesses fields directly)
         println!(
-            "{:<40} {:<20} {}",
+            "{:<40} {:<20} {:<10} {}",
             style("TOOL ID").bold(),
             style("NAME").bold(),
+            style("CIRCUIT").bold(),
             style("DESCRIPTION").bold()
         );
-        println!("{}", "-".repeat(80));
+        println!("{}", "-".repeat(91));
 
         for tool in &response.tools {
             let name = normalize_tool_name(&tool.name);
             let display_name = &tool.display_name;
             let description = &tool.description;
 
+            let circuit = circuit_state_label(tool).unwrap_or("-");
             let desc_truncated = truncate_description(description);
 
-            println!("{name:<40} {display_name:<20} {desc_truncated}");
+            println!("{name:<40} {display_name:<20} {circuit:<10} {desc_truncated}");
         }
 
         println!(
@@ -160,8 +177,10 @@ pub async fn run(args: &ListArgs) -> Result<()> {
 /// # Returns
 ///
 /// A JSON object containing the tool's data with keys: name, displayName,
-/// version, description, inputSchema, outputSchema, capabilities, and tags.
-fn tool_to_json(tool: operai_runtime::proto::Tool) -> serde_json::Value {
+/// version, description, inputSchema, outputSchema, capabilities, tags,
+/// idempotent, and circuitState (null if no circuit breaker watches the tool).
+pub(crate) fn tool_to_json(tool: operai_runtime::proto::Tool) -> serde_json::Value {
+    let circuit_state = circuit_state_label(&tool);
     serde_json::json!({
         "name": tool.name,
         "displayName": tool.display_name,
@@ -171,6 +190,8 @@ fn tool_to_json(tool: operai_runtime::proto::Tool) -> serde_json::Value {
         "outputSchema": tool.output_schema.map(struct_to_json),
         "capabilities": tool.capabilities,
         "tags": tool.tags,
+        "idempotent": tool.idempotent,
+        "circuitState": circuit_state,
     })
 }
 
@@ -184,7 +205,7 @@ fn tool_to_json(tool: operai_runtime::proto::Tool) -> serde_json::Value {
 ///
 /// A JSON `Value::Object` containing the struct's fields with values converted
 /// via `prost_value_to_json`.
-fn struct_to_json(s: prost_types::Struct) -> serde_json::Value {
+pub(crate) fn struct_to_json(s: prost_types::Struct) -> serde_json::Value {
     let map = s
         .fields
         .into_iter()
@@ -224,28 +245,15 @@ fn prost_value_to_json(v: prost_types::Value) -> serde_json::Value {
 
 #[cfg(test)]
 mod tests {
-    use operai_runtime::proto::{
-        CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse, SearchToolsRequest,
-        SearchToolsResponse, Tool,
-        toolbox_server::{Toolbox, ToolboxServer},
-    };
-    use tonic::{Request, Response, Status};
+    use operai_runtime::proto::{ListToolsResponse, Tool};
 
     use super::*;
+    use crate::testing::MockToolbox;
 
-    /// Mock toolbox implementation for testing the list command.
-    ///
-    /// Provides a simple in-memory implementation that returns a fixed set
-    /// of tools without requiring a real toolbox server.
-    struct MockToolbox;
-
-    #[tonic::async_trait]
-    impl Toolbox for MockToolbox {
-        async fn list_tools(
-            &self,
-            _request: Request<ListToolsRequest>,
-        ) -> Result<Response<ListToolsResponse>, Status> {
-            Ok(Response::new(ListToolsResponse {
+    /// Mock toolbox that lists a fixed set of tools.
+    fn mock_toolbox() -> MockToolbox {
+        MockToolbox::default().with_list_tools(|_| {
+            Ok(ListToolsResponse {
                 tools: vec![
                     Tool {
                         name: "tools/hello.greet".to_string(),
@@ -261,22 +269,8 @@ mod tests {
                     },
                 ],
                 next_page_token: String::new(),
-            }))
-        }
-
-        async fn search_tools(
-            &self,
-            _request: Request<SearchToolsRequest>,
-        ) -> Result<Response<SearchToolsResponse>, Status> {
-            Err(Status::unimplemented("not implemented"))
-        }
-
-        async fn call_tool(
-            &self,
-            _request: Request<CallToolRequest>,
-        ) -> Result<Response<CallToolResponse>, Status> {
-            Err(Status::unimplemented("not implemented"))
-        }
+            })
+        })
     }
 
     #[test]
@@ -285,6 +279,19 @@ mod tests {
         assert_eq!(normalize_tool_name("foo"), "foo");
     }
 
+    #[test]
+    fn test_circuit_state_label() {
+        let mut tool = Tool::default();
+        assert_eq!(circuit_state_label(&tool), None);
+        assert_eq!(
+            tool_to_json(tool.clone())["circuitState"],
+            serde_json::Value::Null
+        );
+        tool.set_circuit_state(CircuitState::HalfOpen);
+        assert_eq!(circuit_state_label(&tool), Some("half-open"));
+        assert_eq!(tool_to_json(tool)["circuitState"], "half-open");
+    }
+
     #[test]
     fn test_truncate_description() {
         assert_eq!(truncate_description("short"), "short");
@@ -298,34 +305,15 @@ mod tests {
     async fn test_run_list() -> Result<()> {
         let _lock = crate::testing::test_lock_async().await;
 
-        // Find a free port
-        let port = {
-            let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
-            listener.local_addr()?.port()
-        };
-        let addr_str = format!("127.0.0.1:{port}");
-        let addr: std::net::SocketAddr = addr_str.parse()?;
-
-        // Start server
-        let server = tokio::spawn(async move {
-            tonic::transport::Server::builder()
-                .add_service(ToolboxServer::new(MockToolbox))
-                .serve(addr)
-                .await
-                .unwrap();
-        });
-
-        // Give server a moment to start
-        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+        let server = mock_toolbox().serve().await?;
 
         let args = ListArgs {
-            server: format!("http://{addr_str}"),
+            server: server.url(),
             format: "table".to_owned(),
         };
 
         run(&args).await.context("run failed")?;
 
-        server.abort();
         Ok(())
     }
 }
@@ -0,0 +1,961 @@
+//! Policy tooling for `cargo operai policy`.
+//!
+//! - **`shadow-report`**: Show what shadow-mode policies would have done on a
+//!   running server, per policy and tool
+//! - **`list`**, **`get`**, **`apply`**, **`delete`**: Manage the policies
+//!   registered on a running server, without restarting it
+//! - **`context`**, **`reset-session`**, **`end-session`**: Inspect or clear a
+//!   session's policy context, or discard its policy state, on a running
+//!   server
+//! - **`test`**: Replay a fixture of tool calls through the project's policies
+//!   offline and check the expected decisions and session context
+//!
+//! Commands that talk to a running server use its admin service (see
+//! `cargo operai serve --admin-addr`).
+//!
+//! # Fixture Format
+//!
+//! Fixtures are TOML files made of independent cases. Each case starts with
+//! empty sessions and replays its calls in order:
+//!
+//! ```toml
+//! [[cases]]
+//! name = "third send is blocked"
+//! session = "s1"                  # default "test"
+//!
+//! [[cases.calls]]
+//! tool = "mailer.send"
+//! input = { to = "a@example.com" }
+//! output = { sent = true }        # simulated result (default `{}`)
+//! context = { sends = 1 }         # expected session context afterwards
+//!
+//! [[cases.calls]]
+//! tool = "mailer.send"
+//! error = "smtp unavailable"      # simulated failure instead of output
+//!
+//! [[cases.calls]]
+//! tool = "mailer.send"
+//! expect = "deny"                 # "allow" (default), "deny" or "approval"
+//! message = "Too many emails"     # expected denial or approval reason
+//!
+//! [[cases.calls]]
+//! tool = "db.drop"
+//! tags = ["destructive"]          # tool_info.tags (also `capabilities`)
+//! caller = { id = "ada", roles = ["admin"] }
+//! credentials = ["postgres"]      # supplied credential namespaces
+//! time = 2026-01-05T22:00:00Z     # `now` (default: the current time)
+//! ```
+//!
+//! Only the context keys listed in `context` are compared. A denied call or
+//! one held for approval does not run, so no `After` stage effects apply.
+
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use anyhow::{Context, Result, bail};
+use chrono::DateTime;
+use clap::{Args, Subcommand};
+use console::style;
+use operai_core::{
+    CombiningAlgorithm, Config, Policy, PolicyError, ToolInfo,
+    policy::{
+        history::HistorySettings,
+        request::{CallerIdentity, PolicyRequest},
+        session::{InMemoryPolicySessionStore, PolicySessionStore, PolicyStore},
+    },
+};
+use operai_runtime::proto::{
+    DeletePolicyRequest, EndSessionRequest, GetPolicyRequest, GetSessionContextRequest,
+    GetShadowReportRequest, ListPoliciesRequest, Policy as PolicyProto, ResetSessionRequest,
+    ShadowReportEntry, UpsertPolicyRequest, toolbox_admin_client::ToolboxAdminClient,
+};
+use serde::Deserialize;
+use serde_json::{Map, Value as JsonValue};
+use toml::value::Datetime;
+
+use super::list::{normalize_tool_name, struct_to_json};
+
+/// Command-line arguments for the policy command.
+#[derive(Args)]
+pub struct PolicyArgs {
+    /// The policy operation to perform.
+    #[command(subcommand)]
+    pub command: PolicyCommand,
+}
+
+/// Policy operations.
+#[derive(Subcommand)]
+pub enum PolicyCommand {
+    /// Show what shadow-mode policies would have done since the server
+    /// started.
+    ShadowReport {
+        /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+        #[arg(short, long, default_value = "http://127.0.0.1:50052")]
+        server: String,
+
+        /// Output format: "table" for human-readable table or "json" for
+        /// machine-readable JSON
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
+    /// List the policies registered on a server, in evaluation order.
+    List {
+        /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+        #[arg(short, long, default_value = "http://127.0.0.1:50052")]
+        server: String,
+
+        /// Output format: "table" for human-readable table or "json" for
+        /// machine-readable JSON
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
+    /// Print the definition of a policy registered on a server, as JSON.
+    Get {
+        /// Policy name, with or without the "policies/" prefix
+        name: String,
+
+        /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+        #[arg(short, long, default_value = "http://127.0.0.1:50052")]
+        server: String,
+    },
+
+    /// Register a policy file on a server, replacing any policy of the same
+    /// name. The change is not written to the project config.
+    Apply {
+        /// Path to the TOML policy file
+        file: PathBuf,
+
+        /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+        #[arg(short, long, default_value = "http://127.0.0.1:50052")]
+        server: String,
+    },
+
+    /// Unregister a policy from a server.
+    Delete {
+        /// Policy name, with or without the "policies/" prefix
+        name: String,
+
+        /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+        #[arg(short, long, default_value = "http://127.0.0.1:50052")]
+        server: String,
+    },
+
+    /// Print a session's policy context, as JSON.
+    Context {
+        /// Session identifier
+        session: String,
+
+        /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+        #[arg(short, long, default_value = "http://127.0.0.1:50052")]
+        server: String,
+    },
+
+    /// Clear a session's policy context, keeping its history.
+    ResetSession {
+        /// Session identifier
+        session: String,
+
+        /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+        #[arg(short, long, default_value = "http://127.0.0.1:50052")]
+        server: String,
+    },
+
+    /// End a session, discarding its policy context, history and
+    /// session-scope budget spend.
+    EndSession {
+        /// Session identifier
+        session: String,
+
+        /// Address of the toolbox admin server to connect to (e.g., `<http://127.0.0.1:50052>`)
+        #[arg(short, long, default_value = "http://127.0.0.1:50052")]
+        server: String,
+    },
+
+    /// Replay a fixture of tool calls through the project's policies without
+    /// running a server.
+    Test {
+        /// Path to the TOML fixture file
+        fixture: PathBuf,
+
+        /// Path to the Operai project config file (defaults to `operai.toml`)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+    },
+}
+
+/// Executes the policy command.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Connection to the server fails
+/// - The server returns an error response, such as for an unknown or invalid
+///   policy
+/// - JSON serialization fails (when using JSON format)
+/// - The config, its policies, a policy file or the fixture cannot be loaded
+/// - Any fixture case fails
+pub async fn run(args: &PolicyArgs) -> Result<()> {
+    match &args.command {
+        PolicyCommand::ShadowReport { server, format } => shadow_report(server, format).await,
+        PolicyCommand::List { server, format } => list_policies(server, format).await,
+        PolicyCommand::Get { name, server } => {
+            let policy = connect(server)
+                .await?
+                .get_policy(GetPolicyRequest {
+                    name: policy_name(name),
+                })
+                .await
+                .context("failed to get policy")?
+                .into_inner();
+            let definition: JsonValue = serde_json::from_str(&policy.definition)
+                .context("server returned an invalid policy definition")?;
+            println!("{}", serde_json::to_string_pretty(&definition)?);
+            Ok(())
+        }
+        PolicyCommand::Apply { file, server } => {
+            let policy = Config::load_policy_file(file)
+                .with_context(|| format!("failed to load policy file {}", file.display()))?;
+            let policy = connect(server)
+                .await?
+                .upsert_policy(UpsertPolicyRequest {
+                    definition: serde_json::to_string(&policy)?,
+                })
+                .await
+                .context("failed to apply policy")?
+                .into_inner();
+            println!(
+                "{} Applied {} (version {})",
+                style("✓").green().bold(),
+                style(normalize_policy_name(&policy.name)).bold(),
+                policy.version
+            );
+            Ok(())
+        }
+        PolicyCommand::Delete { name, server } => {
+            connect(server)
+                .await?
+                .delete_policy(DeletePolicyRequest {
+                    name: policy_name(name),
+                })
+                .await
+                .context("failed to delete policy")?;
+            println!(
+                "{} Deleted {}",
+                style("✓").green().bold(),
+                style(normalize_policy_name(name)).bold()
+            );
+            Ok(())
+        }
+        PolicyCommand::Context { session, server } => {
+            let response = connect(server)
+                .await?
+                .get_session_context(GetSessionContextRequest {
+                    session_id: session.clone(),
+                })
+                .await
+                .context("failed to get session context")?
+                .into_inner();
+            let context = response
+                .context
+                .map_or_else(|| JsonValue::Object(Map::new()), struct_to_json);
+            println!("{}", serde_json::to_string_pretty(&context)?);
+            Ok(())
+        }
+        PolicyCommand::ResetSession { session, server } => {
+            connect(server)
+                .await?
+                .reset_session(ResetSessionRequest {
+                    session_id: session.clone(),
+                })
+                .await
+                .context("failed to reset session")?;
+            println!(
+                "{} Reset the context of session {}",
+                style("✓").green().bold(),
+                style(session).bold()
+            );
+            Ok(())
+        }
+        PolicyCommand::EndSession { session, server } => {
+            connect(server)
+                .await?
+                .end_session(EndSessionRequest {
+                    session_id: session.clone(),
+                })
+                .await
+                .context("failed to end session")?;
+            println!(
+                "{} Ended session {}",
+                style("✓").green().bold(),
+                style(session).bold()
+            );
+            Ok(())
+        }
+        PolicyCommand::Test { fixture, config } => {
+            let config_path = config
+                .clone()
+                .unwrap_or_else(|| PathBuf::from("operai.toml"));
+            test_policies(fixture, &config_path).await
+        }
+    }
+}
+
+/// Connects to the admin service of a toolbox server.
+async fn connect(server: &str) -> Result<ToolboxAdminClient<tonic::transport::Channel>> {
+    ToolboxAdminClient::connect(server.to_string())
+        .await
+        .context("failed to connect to server")
+}
+
+/// Fetches and prints the registered policies.
+async fn list_policies(server: &str, format: &str) -> Result<()> {
+    let response = connect(server)
+        .await?
+        .list_policies(ListPoliciesRequest {})
+        .await
+        .context("failed to list policies")?
+        .into_inner();
+
+    if format == "json" {
+        let policies: Vec<JsonValue> = response.policies.iter().map(policy_to_json).collect();
+        println!("{}", serde_json::to_string_pretty(&policies)?);
+        return Ok(());
+    }
+
+    if response.policies.is_empty() {
+        println!("No policies registered");
+        return Ok(());
+    }
+
+    println!(
+        "{:<30} {:<12} {:>8} {}",
+        style("NAME").bold(),
+        style("VERSION").bold(),
+        style("PRIORITY").bold(),
+        style("MODE").bold()
+    );
+    println!("{}", "-".repeat(62));
+    for policy in &response.policies {
+        println!(
+            "{:<30} {:<12} {:>8} {}",
+            normalize_policy_name(&policy.name),
+            policy.version,
+            policy.priority,
+            policy.mode
+        );
+    }
+    Ok(())
+}
+
+/// Converts a policy summary to JSON, with its definition as an object.
+fn policy_to_json(policy: &PolicyProto) -> JsonValue {
+    serde_json::json!({
+        "name": policy.name,
+        "version": policy.version,
+        "priority": policy.priority,
+        "mode": policy.mode,
+        "definition": serde_json::from_str::<JsonValue>(&policy.definition)
+            .unwrap_or(JsonValue::Null),
+    })
+}
+
+/// Adds the "policies/" prefix to a policy name if missing.
+fn policy_name(name: &str) -> String {
+    if name.starts_with("policies/") {
+        name.to_string()
+    } else {
+        format!("policies/{name}")
+    }
+}
+
+/// Removes the "policies/" prefix from a policy name if present.
+fn normalize_policy_name(name: &str) -> &str {
+    name.strip_prefix("policies/").unwrap_or(name)
+}
+
+/// Fetches and prints the shadow-mode policy report.
+async fn shadow_report(server: &str, format: &str) -> Result<()> {
+    let response = connect(server)
+        .await?
+        .get_shadow_report(GetShadowReportRequest {})
+        .await
+        .context("failed to get shadow report")?
+        .into_inner();
+
+    if format == "json" {
+        let entries: Vec<serde_json::Value> =
+            response.entries.iter().map(shadow_entry_to_json).collect();
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    if response.entries.is_empty() {
+        println!("No shadow policy evaluations recorded");
+        return Ok(());
+    }
+
+    println!(
+        "{:<20} {:<30} {:>6} {:>6} {:>8} {:>7} {:>7} {:>7} {:>6}",
+        style("POLICY").bold(),
+        style("TOOL").bold(),
+        style("EVALS").bold(),
+        style("DENY").bold(),
+        style("APPROVE").bold(),
+        style("CONTEXT").bold(),
+        style("INPUT").bold(),
+        style("OUTPUT").bold(),
+        style("ERRORS").bold()
+    );
+    println!("{}", "-".repeat(106));
+    for entry in &response.entries {
+        println!(
+            "{:<20} {:<30} {:>6} {:>6} {:>8} {:>7} {:>7} {:>7} {:>6}",
+            entry.policy,
+            normalize_tool_name(&entry.tool),
+            entry.evaluations,
+            entry.denials,
+            entry.approvals,
+            entry.context_updates,
+            entry.input_rewrites,
+            entry.output_changes,
+            entry.errors
+        );
+    }
+    Ok(())
+}
+
+/// Converts a shadow report entry to JSON.
+fn shadow_entry_to_json(entry: &ShadowReportEntry) -> serde_json::Value {
+    serde_json::json!({
+        "policy": entry.policy,
+        "tool": entry.tool,
+        "evaluations": entry.evaluations,
+        "denials": entry.denials,
+        "approvals": entry.approvals,
+        "contextUpdates": entry.context_updates,
+        "inputRewrites": entry.input_rewrites,
+        "outputChanges": entry.output_changes,
+        "errors": entry.errors,
+    })
+}
+
+/// A policy test fixture.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+    /// Independent cases, each replayed against fresh sessions.
+    #[serde(default)]
+    cases: Vec<FixtureCase>,
+}
+
+/// A named sequence of calls.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixtureCase {
+    name: String,
+    /// Session used by calls that don't name their own.
+    #[serde(default = "default_session")]
+    session: String,
+    #[serde(default)]
+    calls: Vec<FixtureCall>,
+}
+
+/// One simulated tool call and its expected result.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixtureCall {
+    tool: String,
+    session: Option<String>,
+    #[serde(default = "empty_object")]
+    input: JsonValue,
+    /// Simulated tool output; `{}` when neither `output` nor `error` is set.
+    output: Option<JsonValue>,
+    /// Simulated tool failure.
+    error: Option<String>,
+    #[serde(default)]
+    expect: Expectation,
+    /// Expected denial or approval reason.
+    message: Option<String>,
+    /// Expected session context after the call (only listed keys).
+    context: Option<Map<String, JsonValue>>,
+    /// Tags of the simulated tool.
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Capabilities of the simulated tool.
+    #[serde(default)]
+    capabilities: Vec<String>,
+    /// Authenticated caller; unauthenticated when unset.
+    caller: Option<CallerIdentity>,
+    /// Credential namespaces supplied with the call.
+    #[serde(default)]
+    credentials: Vec<String>,
+    /// Time of the call; the current time when unset.
+    time: Option<Datetime>,
+}
+
+impl FixtureCall {
+    /// Describes the simulated tool as registered tool metadata.
+    fn tool_info(&self, tool: &str) -> ToolInfo {
+        let (crate_name, tool_id) = tool.split_once('.').unwrap_or(("", tool));
+        ToolInfo {
+            qualified_id: tool.to_string(),
+            tool_id: tool_id.to_string(),
+            crate_name: crate_name.to_string(),
+            crate_version: String::new(),
+            display_name: String::new(),
+            description: String::new(),
+            input_schema: String::new(),
+            output_schema: String::new(),
+            credential_schema: None,
+            capabilities: self.capabilities.clone(),
+            tags: self.tags.clone(),
+            embedding: None,
+            cost: None,
+            idempotent: false,
+            cache_ttl: None,
+        }
+    }
+
+    /// Returns the configured call time, or now.
+    fn time(&self) -> Result<SystemTime> {
+        let Some(time) = &self.time else {
+            return Ok(SystemTime::now());
+        };
+        let time = DateTime::parse_from_rfc3339(&time.to_string())
+            .with_context(|| format!("invalid call time {time}: expected an offset date-time"))?;
+        Ok(time.into())
+    }
+}
+
+/// The decision a call is expected to receive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Expectation {
+    #[default]
+    Allow,
+    Deny,
+    Approval,
+}
+
+impl std::fmt::Display for Expectation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Allow => "allow",
+            Self::Deny => "deny",
+            Self::Approval => "approval",
+        })
+    }
+}
+
+fn default_session() -> String {
+    "test".to_string()
+}
+
+fn empty_object() -> JsonValue {
+    JsonValue::Object(Map::new())
+}
+
+/// The outcome of replaying one fixture case.
+#[derive(Debug)]
+struct CaseReport {
+    name: String,
+    /// Human-readable mismatches; empty if the case passed.
+    failures: Vec<String>,
+}
+
+/// Loads the project's policies and replays the fixture against them.
+async fn test_policies(fixture_path: &Path, config_path: &Path) -> Result<()> {
+    let config = Config::load(config_path)
+        .with_context(|| format!("failed to load config {}", config_path.display()))?;
+    let policies = config
+        .resolve_policies(config_path)
+        .context("failed to resolve policies")?;
+    let contents = std::fs::read_to_string(fixture_path)
+        .with_context(|| format!("failed to read fixture {}", fixture_path.display()))?;
+    let fixture: Fixture = toml::from_str(&contents)
+        .with_context(|| format!("failed to parse fixture {}", fixture_path.display()))?;
+
+    println!(
+        "{} Testing {} policies against {} case(s)\n",
+        style("→").cyan(),
+        policies.len(),
+        fixture.cases.len()
+    );
+
+    let reports = run_fixture(
+        &policies,
+        config.policy_evaluation.combining,
+        config.sessions.history(),
+        &fixture,
+    )
+    .await?;
+    let mut failed = 0;
+    for report in &reports {
+        if report.failures.is_empty() {
+            println!("{} {}", style("✓").green().bold(), report.name);
+        } else {
+            failed += 1;
+            println!("{} {}", style("✗").red().bold(), style(&report.name).bold());
+            for failure in &report.failures {
+                for line in failure.lines() {
+                    println!("    {line}");
+                }
+            }
+        }
+    }
+
+    println!("\n{} passed, {} failed", reports.len() - failed, failed);
+    if failed > 0 {
+        bail!("{failed} policy test case(s) failed");
+    }
+    Ok(())
+}
+
+/// Replays every fixture case against fresh policy and session stores.
+async fn run_fixture(
+    policies: &[Policy],
+    combining: CombiningAlgorithm,
+    history: HistorySettings,
+    fixture: &Fixture,
+) -> Result<Vec<CaseReport>> {
+    let mut reports = Vec::with_capacity(fixture.cases.len());
+    for case in &fixture.cases {
+        let sessions = Arc::new(InMemoryPolicySessionStore::new());
+        let store = PolicyStore::new(sessions.clone())
+            .with_combining_algorithm(combining)
+            .with_history(history);
+        for policy in policies {
+            store
+                .register(policy.clone())
+                .with_context(|| format!("failed to register policy {}", policy.name))?;
+        }
+
+        let mut failures = Vec::new();
+        for (index, call) in case.calls.iter().enumerate() {
+            let session_id = call.session.as_deref().unwrap_or(&case.session);
+            let call_failures = replay_call(&store, sessions.as_ref(), session_id, call).await?;
+            failures.extend(call_failures.into_iter().map(|failure| {
+                format!(
+                    "call #{} ({}): {failure}",
+                    index + 1,
+                    normalize_tool_name(&call.tool)
+                )
+            }));
+        }
+        reports.push(CaseReport {
+            name: case.name.clone(),
+            failures,
+        });
+    }
+    Ok(reports)
+}
+
+/// Runs one call through the policy store and returns its mismatches.
+async fn replay_call(
+    store: &PolicyStore,
+    sessions: &dyn PolicySessionStore,
+    session_id: &str,
+    call: &FixtureCall,
+) -> Result<Vec<String>> {
+    let tool = normalize_tool_name(&call.tool);
+    let tool_info = call.tool_info(tool);
+    let request = PolicyRequest::new(session_id, tool)
+        .with_tool_info(&tool_info)
+        .with_caller(call.caller.as_ref())
+        .with_credentials(&call.credentials)
+        .with_time(call.time()?);
+    let mut blocked_by = None;
+    let (actual, message) = match store.evaluate_pre_effects(&request, &call.input).await {
+        Ok(outcome) => {
+            if let Some(reason) = outcome.approval {
+                (Expectation::Approval, Some(reason))
+            } else {
+                let output = call.output.clone().unwrap_or_else(empty_object);
+                let result = match &call.error {
+                    Some(error) => Err(error.as_str()),
+                    None => Ok(&output),
+                };
+                if let Err(e) = store
+                    .evaluate_post_effects(&request, &outcome.input, result)
+                    .await
+                {
+                    return Ok(vec![format!("after stage evaluation failed: {e}")]);
+                }
+                (Expectation::Allow, None)
+            }
+        }
+        Err(PolicyError::GuardFailed(denial)) => {
+            blocked_by = Some(format!(
+                "policy {} effect #{}",
+                denial.policy, denial.effect
+            ));
+            (Expectation::Deny, Some(denial.message))
+        }
+        Err(e) => return Ok(vec![format!("policy evaluation failed: {e}")]),
+    };
+
+    let mut failures = Vec::new();
+    if actual != call.expect {
+        let mut failure = format!("expected {}, got {actual}", call.expect);
+        if let Some(message) = &message {
+            let _ = write!(failure, " ({message})");
+        }
+        if let Some(blocked_by) = &blocked_by {
+            let _ = write!(failure, " from {blocked_by}");
+        }
+        failures.push(failure);
+    } else if let Some(expected) = &call.message
+        && message.as_deref() != Some(expected.as_str())
+    {
+        failures.push(format!(
+            "expected message {expected:?}, got {:?}",
+            message.unwrap_or_default()
+        ));
+    }
+
+    if let Some(expected) = &call.context {
+        let session = sessions
+            .load(session_id)
+            .await
+            .context("failed to load session")?;
+        let diff = context_diff(expected, &session.context);
+        if !diff.is_empty() {
+            failures.push(format!("context mismatch:\n{diff}"));
+        }
+    }
+    Ok(failures)
+}
+
+/// Describes each expected context key whose actual value differs, as
+/// `-` (expected) and `+` (actual) lines.
+fn context_diff(
+    expected: &Map<String, JsonValue>,
+    actual: &std::collections::HashMap<String, JsonValue>,
+) -> String {
+    let mut diff = String::new();
+    for (key, want) in expected {
+        match actual.get(key) {
+            Some(got) if values_match(want, got) => {}
+            Some(got) => {
+                let _ = writeln!(diff, "{}", style(format!("- {key} = {want}")).red());
+                let _ = writeln!(diff, "{}", style(format!("+ {key} = {got}")).green());
+            }
+            None => {
+                let _ = writeln!(diff, "{}", style(format!("- {key} = {want}")).red());
+                let _ = writeln!(diff, "{}", style(format!("+ {key} is not set")).green());
+            }
+        }
+    }
+    diff
+}
+
+/// Compares JSON values, treating numbers as equal when their values are
+/// equal regardless of integer or float representation.
+fn values_match(expected: &JsonValue, actual: &JsonValue) -> bool {
+    match (expected, actual) {
+        (JsonValue::Number(a), JsonValue::Number(b)) => a.as_f64() == b.as_f64(),
+        (JsonValue::Array(a), JsonValue::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| values_match(a, b))
+        }
+        (JsonValue::Object(a), JsonValue::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| values_match(a, b)))
+        }
+        _ => expected == actual,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIL_POLICY: &str = r#"name = "mail"
+version = "1"
+context = { sends = 0 }
+
+[[effects]]
+tool = "mailer.send"
+stage = "before"
+when = "context.sends < 2"
+fail_message = "Too many emails"
+
+[[effects]]
+tool = "mailer.send"
+stage = "after"
+when = "success"
+set = { sends = "context.sends + 1" }
+"#;
+
+    async fn replay(fixture: &str) -> Vec<CaseReport> {
+        let policy: Policy = toml::from_str(MAIL_POLICY).expect("policy should parse");
+        let fixture: Fixture = toml::from_str(fixture).expect("fixture should parse");
+        run_fixture(
+            &[policy],
+            CombiningAlgorithm::DenyOverrides,
+            HistorySettings::default(),
+            &fixture,
+        )
+        .await
+        .expect("fixture should run")
+    }
+
+    #[tokio::test]
+    async fn test_run_fixture_passes_matching_expectations() {
+        let reports = replay(
+            r#"[[cases]]
+name = "third send is blocked"
+
+[[cases.calls]]
+tool = "mailer.send"
+context = { sends = 1 }
+
+[[cases.calls]]
+tool = "tools/mailer.send"
+error = "smtp unavailable"
+context = { sends = 1 }
+
+[[cases.calls]]
+tool = "mailer.send"
+
+[[cases.calls]]
+tool = "mailer.send"
+expect = "deny"
+message = "Too many emails"
+context = { sends = 2 }
+
+[[cases.calls]]
+tool = "mailer.send"
+session = "other"
+"#,
+        )
+        .await;
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].failures, Vec::<String>::new());
+    }
+
+    #[tokio::test]
+    async fn test_run_fixture_reports_mismatches() {
+        let reports = replay(
+            r#"[[cases]]
+name = "wrong expectations"
+
+[[cases.calls]]
+tool = "mailer.send"
+expect = "deny"
+
+[[cases.calls]]
+tool = "mailer.send"
+context = { sends = 5, missing = true }
+
+[[cases]]
+name = "sessions start empty"
+
+[[cases.calls]]
+tool = "mailer.send"
+context = { sends = 1 }
+
+[[cases]]
+name = "unexpected denial"
+
+[[cases.calls]]
+tool = "mailer.send"
+
+[[cases.calls]]
+tool = "mailer.send"
+
+[[cases.calls]]
+tool = "mailer.send"
+"#,
+        )
+        .await;
+
+        assert_eq!(reports[0].failures.len(), 2);
+        assert_eq!(
+            reports[0].failures[0],
+            "call #1 (mailer.send): expected deny, got allow"
+        );
+        let diff = console::strip_ansi_codes(&reports[0].failures[1]).to_string();
+        assert!(diff.starts_with("call #2 (mailer.send): context mismatch:"));
+        assert!(diff.contains("- sends = 5\n+ sends = 2\n"));
+        assert!(diff.contains("- missing = true\n+ missing is not set\n"));
+        assert!(reports[1].failures.is_empty());
+        assert_eq!(
+            reports[2].failures,
+            [
+                "call #3 (mailer.send): expected allow, got deny (Too many emails) from policy \
+                 mail effect #0"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_fixture_simulates_caller_tool_tags_and_time() {
+        let policy: Policy = toml::from_str(
+            r#"name = "hours"
+version = "1"
+
+[[effects]]
+tool = "**"
+stage = "before"
+when = '!("destructive" in tool_info.tags) || (caller != null && "admin" in caller.roles) || now.getHours() < 17'
+fail_message = "admin required"
+"#,
+        )
+        .expect("policy should parse");
+        let fixture: Fixture = toml::from_str(
+            r#"[[cases]]
+name = "destructive tools after hours"
+
+[[cases.calls]]
+tool = "db.drop"
+tags = ["destructive"]
+time = 2026-01-05T22:00:00Z
+expect = "deny"
+message = "admin required"
+
+[[cases.calls]]
+tool = "db.drop"
+tags = ["destructive"]
+caller = { id = "ada", roles = ["admin"] }
+time = 2026-01-05T22:00:00Z
+
+[[cases.calls]]
+tool = "db.drop"
+tags = ["destructive"]
+time = 2026-01-05T10:00:00Z
+"#,
+        )
+        .expect("fixture should parse");
+
+        let reports = run_fixture(
+            &[policy],
+            CombiningAlgorithm::DenyOverrides,
+            HistorySettings::default(),
+            &fixture,
+        )
+        .await
+        .expect("fixture should run");
+
+        assert_eq!(reports[0].failures, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_values_match_ignores_number_representation() {
+        assert!(values_match(
+            &serde_json::json!({ "n": 1, "list": [2] }),
+            &serde_json::json!({ "n": 1.0, "list": [2.0] })
+        ));
+        assert!(!values_match(
+            &serde_json::json!({ "n": 1 }),
+            &serde_json::json!({ "n": 1, "extra": true })
+        ));
+    }
+}
@@ -0,0 +1,252 @@
+//! Search for tools by natural-language query.
+//!
+//! This module exercises the `SearchTools` RPC. By default the query text is
+//! sent to a running toolbox server, which embeds it with its configured
+//! embedder. With `--embed`, the query is embedded locally using the project's
+//! `[embedding]` config and sent as a raw vector. With `--local`, the
+//! project's tool libraries are loaded in-process and searched directly.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use console::style;
+use operai_runtime::{
+    CallMetadata, Runtime, RuntimeBuilder,
+    proto::{SearchResult, SearchToolsRequest},
+};
+
+use super::list::{normalize_tool_name, tool_to_json, truncate_description};
+use crate::embedding::EmbeddingGenerator;
+
+/// Command-line arguments for the search command.
+#[derive(Args)]
+pub struct SearchArgs {
+    /// Natural-language description of the tool to find.
+    pub query: String,
+
+    /// Address of the toolbox server to connect to (e.g., `<http://127.0.0.1:50051>`)
+    #[arg(short, long, default_value = "http://127.0.0.1:50051")]
+    pub server: String,
+
+    /// Search the project's tools in-process instead of querying a server.
+    #[arg(long)]
+    pub local: bool,
+
+    /// Embed the query locally and send the vector instead of the query text.
+    #[arg(long)]
+    pub embed: bool,
+
+    /// Path to the Operai project config file used by `--local` (defaults to
+    /// `operai.toml`).
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
+    /// Maximum number of results to return.
+    #[arg(short = 'n', long, default_value = "10")]
+    pub limit: i32,
+
+    /// Output format: "table" for human-readable table or "json" for
+    /// machine-readable JSON
+    #[arg(short, long, default_value = "table")]
+    pub format: String,
+}
+
+/// Executes the search command and prints ranked results.
+///
+/// # Arguments
+///
+/// * `args` - Query, search target, and output options
+/// * `config` - Operai project config (used for embedding settings)
+///
+/// # Errors
+///
+/// Returns an error if the runtime or embedder cannot be initialized, the
+/// search fails, or JSON serialization fails.
+pub async fn run(args: &SearchArgs, config: &operai_core::Config) -> Result<()> {
+    let runtime = if args.local {
+        let config_path = args
+            .config
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("operai.toml"));
+        Runtime::Local(
+            RuntimeBuilder::new()
+                .with_config_path(config_path)
+                .build_local()
+                .await
+                .context("failed to initialize runtime")?,
+        )
+    } else {
+        Runtime::Remote(
+            RuntimeBuilder::new()
+                .remote(args.server.clone())
+                .build_remote()
+                .await
+                .context("failed to connect to toolbox server")?,
+        )
+    };
+
+    // A local runtime has no server-side embedder, so it always needs the
+    // query embedded here.
+    let generator = if args.local || args.embed {
+        Some(
+            EmbeddingGenerator::from_config(config)
+                .context("failed to initialize search embedder")?,
+        )
+    } else {
+        None
+    };
+
+    let results = search(&runtime, generator.as_ref(), &args.query, args.limit).await?;
+
+    if args.format == "json" {
+        let results_json: Vec<serde_json::Value> = results
+            .into_iter()
+            .enumerate()
+            .map(|(index, result)| result_to_json(index + 1, result))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&results_json)?);
+    } else if !results.is_empty() {
+        println!(
+            "{:<5} {:<40} {:<8} {}",
+            style("RANK").bold(),
+            style("TOOL ID").bold(),
+            style("SCORE").bold(),
+            style("DESCRIPTION").bold()
+        );
+        println!("{}", "-".repeat(95));
+
+        for (index, result) in results.iter().enumerate() {
+            let (name, description) = result.tool.as_ref().map_or(("", ""), |tool| {
+                (normalize_tool_name(&tool.name), tool.description.as_str())
+            });
+            println!(
+                "{:<5} {name:<40} {:<8.4} {}",
+                index + 1,
+                result.relevance_score,
+                truncate_description(description)
+            );
+        }
+
+        println!("\n{} {} result(s)", style("✓").green(), results.len());
+    } else {
+        println!("No matching tools found");
+    }
+
+    Ok(())
+}
+
+/// Sends a single search request, embedding the query first when a generator
+/// is provided.
+async fn search(
+    runtime: &Runtime,
+    generator: Option<&EmbeddingGenerator>,
+    query: &str,
+    limit: i32,
+) -> Result<Vec<SearchResult>> {
+    let mut request = SearchToolsRequest {
+        page_size: limit,
+        ..Default::default()
+    };
+    if let Some(generator) = generator {
+        request.query_embedding = generator.embed(query).await?;
+    } else {
+        request.query_text = query.to_string();
+    }
+
+    let response = runtime
+        .search_tools(request, CallMetadata::default())
+        .await
+        .context("failed to search tools")?;
+    Ok(response.results)
+}
+
+/// Converts a ranked search result to a JSON value.
+///
+/// The tool is rendered with the same keys as `cargo operai list --format
+/// json`, alongside its 1-based rank and relevance score.
+fn result_to_json(rank: usize, result: SearchResult) -> serde_json::Value {
+    serde_json::json!({
+        "rank": rank,
+        "relevanceScore": result.relevance_score,
+        "tool": result.tool.map(tool_to_json),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use operai_runtime::proto::{SearchToolsResponse, Tool};
+
+    use super::*;
+    use crate::testing::MockToolbox;
+
+    /// Mock toolbox that echoes how the query was received.
+    fn mock_toolbox() -> MockToolbox {
+        MockToolbox::default().with_search_tools(|request| {
+            let description = if request.query_embedding.is_empty() {
+                format!("text:{}", request.query_text)
+            } else {
+                format!("embedding:{}", request.query_embedding.len())
+            };
+            Ok(SearchToolsResponse {
+                results: vec![SearchResult {
+                    tool: Some(Tool {
+                        name: "tools/mail.send".to_string(),
+                        description,
+                        ..Default::default()
+                    }),
+                    relevance_score: 0.75,
+                }],
+                next_page_token: String::new(),
+            })
+        })
+    }
+
+    #[test]
+    fn test_result_to_json_includes_rank_and_score() {
+        let value = result_to_json(
+            2,
+            SearchResult {
+                tool: Some(Tool {
+                    name: "tools/mail.send".to_string(),
+                    ..Default::default()
+                }),
+                relevance_score: 0.5,
+            },
+        );
+
+        assert_eq!(value["rank"], 2);
+        assert_eq!(value["relevanceScore"], 0.5);
+        assert_eq!(value["tool"]["name"], "tools/mail.send");
+    }
+
+    #[tokio::test]
+    async fn test_search_sends_text_or_embedding() -> Result<()> {
+        let _lock = crate::testing::test_lock_async().await;
+
+        let server = mock_toolbox().serve().await?;
+
+        let runtime = Runtime::Remote(
+            RuntimeBuilder::new()
+                .remote(server.url())
+                .build_remote()
+                .await?,
+        );
+
+        let results = search(&runtime, None, "send mail", 5).await?;
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].tool.as_ref().map(|t| t.description.as_str()),
+            Some("text:send mail")
+        );
+
+        let generator = EmbeddingGenerator::hashing(32);
+        let results = search(&runtime, Some(&generator), "send mail", 5).await?;
+        assert_eq!(
+            results[0].tool.as_ref().map(|t| t.description.as_str()),
+            Some("embedding:32")
+        );
+
+        Ok(())
+    }
+}
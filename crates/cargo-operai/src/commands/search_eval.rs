@@ -0,0 +1,459 @@
+//! Evaluate search quality against a fixtures file.
+//!
+//! This module runs a set of natural-language queries through tool search and
+//! scores the ranked results against the tool IDs each query is expected to
+//! find. It reports recall@k, mean reciprocal rank (MRR), and the queries that
+//! missed their expected tools, so description changes can be measured and
+//! tracked in CI.
+//!
+//! # Fixtures
+//!
+//! Fixtures are a TOML file with one `[[queries]]` entry per query:
+//!
+//! ```toml
+//! [[queries]]
+//! query = "send an email to a customer"
+//! expected = ["mailer.send_email"]
+//! ```
+//!
+//! Expected IDs are qualified tool IDs (`crate.tool`), with or without the
+//! `tools/` prefix used on the wire.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use clap::Args;
+use console::style;
+use operai_runtime::{CallMetadata, Runtime, RuntimeBuilder, proto::SearchToolsRequest};
+use serde::{Deserialize, Serialize};
+
+use super::list::normalize_tool_name;
+use crate::embedding::EmbeddingGenerator;
+
+/// Command-line arguments for the search-eval command.
+#[derive(Args)]
+pub struct SearchEvalArgs {
+    /// Path to the TOML fixtures file with queries and expected tool IDs.
+    pub fixtures: PathBuf,
+
+    /// Path to the Operai project config file (defaults to `operai.toml`).
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
+    /// Evaluate against a running toolbox server instead of loading the
+    /// project's tools locally.
+    #[arg(short, long)]
+    pub server: Option<String>,
+
+    /// Number of ranked results considered per query.
+    #[arg(short = 'k', long = "top-k", default_value = "5")]
+    pub k: usize,
+
+    /// Output format: "table" for human-readable output or "json" for
+    /// machine-readable JSON
+    #[arg(short, long, default_value = "table")]
+    pub format: String,
+
+    /// Fail when recall@k falls below this threshold (0.0 to 1.0).
+    #[arg(long)]
+    pub min_recall: Option<f64>,
+}
+
+/// Parsed fixtures file.
+#[derive(Debug, Deserialize)]
+pub struct Fixtures {
+    /// Queries to evaluate, in file order.
+    #[serde(default)]
+    pub queries: Vec<Fixture>,
+}
+
+/// A single query and the tool IDs it should retrieve.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Fixture {
+    /// Natural-language query text.
+    pub query: String,
+    /// Qualified tool IDs that count as relevant for this query.
+    pub expected: Vec<String>,
+}
+
+/// Aggregate evaluation results.
+#[derive(Debug, Serialize)]
+pub struct EvalReport {
+    /// Number of results considered per query.
+    pub k: usize,
+    /// Mean per-query recall within the top `k` results.
+    pub recall_at_k: f64,
+    /// Mean reciprocal rank of the first relevant result (0 when none is
+    /// found within the top `k`).
+    pub mrr: f64,
+    /// Per-query results, in fixture order.
+    pub queries: Vec<QueryReport>,
+}
+
+impl EvalReport {
+    /// Returns the queries that did not retrieve every expected tool.
+    pub fn misses(&self) -> impl Iterator<Item = &QueryReport> {
+        self.queries
+            .iter()
+            .filter(|query| !query.missing.is_empty())
+    }
+}
+
+/// Evaluation results for a single query.
+#[derive(Debug, Serialize)]
+pub struct QueryReport {
+    /// Query text.
+    pub query: String,
+    /// Expected tool IDs.
+    pub expected: Vec<String>,
+    /// Ranked tool IDs returned by search (at most `k`).
+    pub results: Vec<String>,
+    /// Expected tool IDs not present in `results`.
+    pub missing: Vec<String>,
+    /// Fraction of expected tools present in `results`.
+    pub recall: f64,
+    /// Reciprocal of the 1-based rank of the first expected tool, or 0.
+    pub reciprocal_rank: f64,
+}
+
+/// Executes the search-eval command.
+///
+/// # Arguments
+///
+/// * `args` - Fixtures path, search target, and output options
+/// * `config` - Operai project config (used for embedding settings)
+///
+/// # Errors
+///
+/// Returns an error if the fixtures cannot be loaded, the runtime or embedder
+/// cannot be initialized, a search fails, or recall@k is below
+/// `--min-recall`.
+pub async fn run(args: &SearchEvalArgs, config: &operai_core::Config) -> Result<()> {
+    let fixtures = load_fixtures(&args.fixtures)?;
+
+    let runtime = if let Some(server) = &args.server {
+        Runtime::Remote(
+            RuntimeBuilder::new()
+                .remote(server.clone())
+                .build_remote()
+                .await
+                .context("failed to connect to toolbox server")?,
+        )
+    } else {
+        let config_path = args
+            .config
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("operai.toml"));
+        Runtime::Local(
+            RuntimeBuilder::new()
+                .with_config_path(config_path)
+                .build_local()
+                .await
+                .context("failed to initialize runtime")?,
+        )
+    };
+
+    // Remote servers can embed `query_text` themselves; local search always
+    // needs a client-side embedder.
+    let generator = if args.server.is_none() || config.embedding.is_some() {
+        Some(
+            EmbeddingGenerator::from_config(config)
+                .context("failed to initialize search embedder")?,
+        )
+    } else {
+        None
+    };
+
+    let report = evaluate(&runtime, generator.as_ref(), &fixtures, args.k).await?;
+
+    if args.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_table(&report);
+    }
+
+    if let Some(min_recall) = args.min_recall
+        && report.recall_at_k < min_recall
+    {
+        bail!(
+            "recall@{} {:.3} is below the required minimum {min_recall:.3}",
+            report.k,
+            report.recall_at_k
+        );
+    }
+
+    Ok(())
+}
+
+/// Loads and validates a fixtures file.
+fn load_fixtures(path: &Path) -> Result<Fixtures> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read fixtures file: {}", path.display()))?;
+    let fixtures: Fixtures = toml::from_str(&content)
+        .with_context(|| format!("failed to parse fixtures file: {}", path.display()))?;
+
+    if fixtures.queries.is_empty() {
+        bail!("fixtures file contains no queries: {}", path.display());
+    }
+    if let Some(fixture) = fixtures.queries.iter().find(|f| f.expected.is_empty()) {
+        bail!("query {:?} has no expected tool IDs", fixture.query);
+    }
+
+    Ok(fixtures)
+}
+
+/// Runs every fixture query through search and scores the results.
+///
+/// When `generator` is provided, queries are embedded client-side and sent as
+/// `query_embedding`; otherwise `query_text` is sent and the server embeds it.
+async fn evaluate(
+    runtime: &Runtime,
+    generator: Option<&EmbeddingGenerator>,
+    fixtures: &Fixtures,
+    k: usize,
+) -> Result<EvalReport> {
+    let page_size = i32::try_from(k).context("k is too large")?;
+    let mut ranked = Vec::with_capacity(fixtures.queries.len());
+
+    for fixture in &fixtures.queries {
+        let mut request = SearchToolsRequest {
+            page_size,
+            ..Default::default()
+        };
+        if let Some(generator) = generator {
+            request.query_embedding = generator.embed(&fixture.query).await?;
+        } else {
+            request.query_text.clone_from(&fixture.query);
+        }
+
+        let response = runtime
+            .search_tools(request, CallMetadata::default())
+            .await
+            .with_context(|| format!("search failed for query {:?}", fixture.query))?;
+        let results = response
+            .results
+            .into_iter()
+            .filter_map(|result| result.tool)
+            .map(|tool| normalize_tool_name(&tool.name).to_string())
+            .take(k)
+            .collect();
+        ranked.push(results);
+    }
+
+    Ok(score(&fixtures.queries, ranked, k))
+}
+
+/// Computes recall@k and MRR from ranked results, one entry per fixture.
+fn score(fixtures: &[Fixture], ranked: Vec<Vec<String>>, k: usize) -> EvalReport {
+    let queries: Vec<QueryReport> = fixtures
+        .iter()
+        .zip(ranked)
+        .map(|(fixture, results)| {
+            let expected: Vec<String> = fixture
+                .expected
+                .iter()
+                .map(|id| normalize_tool_name(id).to_string())
+                .collect();
+            let missing: Vec<String> = expected
+                .iter()
+                .filter(|id| !results.contains(id))
+                .cloned()
+                .collect();
+            let recall = ratio(expected.len() - missing.len(), expected.len());
+            let reciprocal_rank = results
+                .iter()
+                .position(|id| expected.contains(id))
+                .map_or(0.0, |index| ratio(1, index + 1));
+
+            QueryReport {
+                query: fixture.query.clone(),
+                expected,
+                results,
+                missing,
+                recall,
+                reciprocal_rank,
+            }
+        })
+        .collect();
+
+    let recall_at_k = mean(queries.iter().map(|q| q.recall), queries.len());
+    let mrr = mean(queries.iter().map(|q| q.reciprocal_rank), queries.len());
+
+    EvalReport {
+        k,
+        recall_at_k,
+        mrr,
+        queries,
+    }
+}
+
+/// Prints a human-readable summary followed by the missed queries.
+fn print_table(report: &EvalReport) {
+    println!(
+        "{:<12} {:.3}",
+        style(format!("recall@{}", report.k)).bold(),
+        report.recall_at_k
+    );
+    println!("{:<12} {:.3}", style("MRR").bold(), report.mrr);
+    println!("{:<12} {}", style("queries").bold(), report.queries.len());
+
+    let misses: Vec<_> = report.misses().collect();
+    if misses.is_empty() {
+        println!(
+            "\n{} All queries found their expected tools",
+            style("✓").green()
+        );
+        return;
+    }
+
+    println!(
+        "\n{} {} query(ies) missed:",
+        style("⚠").yellow(),
+        misses.len()
+    );
+    for miss in misses {
+        println!("\n  {}", style(&miss.query).bold());
+        println!("    missing: {}", miss.missing.join(", "));
+        if miss.results.is_empty() {
+            println!("    got:     (no results)");
+        } else {
+            println!("    got:     {}", miss.results.join(", "));
+        }
+    }
+}
+
+/// Returns `numerator / denominator` as a float, or 0 for an empty
+/// denominator.
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        return 0.0;
+    }
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "query and result counts are far below f64 precision limits"
+    )]
+    let value = numerator as f64 / denominator as f64;
+    value
+}
+
+/// Returns the arithmetic mean of `values`, or 0 when `count` is zero.
+fn mean(values: impl Iterator<Item = f64>, count: usize) -> f64 {
+    if count == 0 {
+        return 0.0;
+    }
+    values.sum::<f64>() * ratio(1, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use operai_runtime::proto::{SearchResult, SearchToolsResponse, Tool};
+
+    use super::*;
+    use crate::testing::MockToolbox;
+
+    fn fixture(query: &str, expected: &[&str]) -> Fixture {
+        Fixture {
+            query: query.to_owned(),
+            expected: expected.iter().map(|id| (*id).to_owned()).collect(),
+        }
+    }
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|id| (*id).to_owned()).collect()
+    }
+
+    /// Mock toolbox that ranks the weather tool first for weather queries.
+    fn mock_toolbox() -> MockToolbox {
+        MockToolbox::default().with_search_tools(|request| {
+            let mut names = vec!["tools/mail.send", "tools/weather.get"];
+            if request.query_text.contains("weather") {
+                names.reverse();
+            }
+            Ok(SearchToolsResponse {
+                results: names
+                    .into_iter()
+                    .map(|name| SearchResult {
+                        tool: Some(Tool {
+                            name: name.to_owned(),
+                            ..Default::default()
+                        }),
+                        relevance_score: 0.5,
+                    })
+                    .collect(),
+                next_page_token: String::new(),
+            })
+        })
+    }
+
+    #[test]
+    fn test_score_computes_recall_and_mrr() {
+        let fixtures = [
+            fixture("send mail", &["mail.send"]),
+            fixture("forecast", &["tools/weather.get", "weather.alerts"]),
+            fixture("add numbers", &["calc.add"]),
+        ];
+        let ranked = vec![
+            ids(&["mail.send", "weather.get"]),
+            ids(&["mail.send", "weather.get"]),
+            ids(&["mail.send"]),
+        ];
+
+        let report = score(&fixtures, ranked, 2);
+
+        assert_eq!(report.k, 2);
+        assert!((report.queries[0].recall - 1.0).abs() < f64::EPSILON);
+        assert!((report.queries[1].recall - 0.5).abs() < f64::EPSILON);
+        assert!((report.queries[1].reciprocal_rank - 0.5).abs() < f64::EPSILON);
+        assert!(report.queries[2].reciprocal_rank.abs() < f64::EPSILON);
+        assert!((report.recall_at_k - 0.5).abs() < f64::EPSILON);
+        assert!((report.mrr - 0.5).abs() < f64::EPSILON);
+
+        let misses: Vec<_> = report.misses().map(|q| q.query.as_str()).collect();
+        assert_eq!(misses, ["forecast", "add numbers"]);
+        assert_eq!(report.queries[1].missing, ["weather.alerts"]);
+    }
+
+    #[test]
+    fn test_fixtures_parse_from_toml() {
+        let fixtures: Fixtures = toml::from_str(
+            r#"
+            [[queries]]
+            query = "send an email"
+            expected = ["mail.send"]
+            "#,
+        )
+        .expect("fixtures should parse");
+
+        assert_eq!(fixtures.queries.len(), 1);
+        assert_eq!(fixtures.queries[0].expected, ["mail.send"]);
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_against_remote_server() -> Result<()> {
+        let _lock = crate::testing::test_lock_async().await;
+
+        let server = mock_toolbox().serve().await?;
+
+        let runtime = Runtime::Remote(
+            RuntimeBuilder::new()
+                .remote(server.url())
+                .build_remote()
+                .await?,
+        );
+        let fixtures = Fixtures {
+            queries: vec![
+                fixture("send an email", &["mail.send"]),
+                fixture("weather tomorrow", &["mail.send"]),
+            ],
+        };
+
+        let report = evaluate(&runtime, None, &fixtures, 1).await?;
+
+        assert_eq!(report.queries[0].results, ["mail.send"]);
+        assert_eq!(report.queries[1].results, ["weather.get"]);
+        assert!((report.recall_at_k - 0.5).abs() < f64::EPSILON);
+        assert!((report.mrr - 0.5).abs() < f64::EPSILON);
+
+        Ok(())
+    }
+}
@@ -3,16 +3,21 @@
 //! This module provides functionality to run a local gRPC server that exposes
 //! tools defined in an Operai config. The server supports gRPC reflection,
 //! health checks, and graceful shutdown.
+//!
+//! The admin service, which decides calls held for approval and manages
+//! policies, is only served when `--admin-addr` is given, and then only on
+//! that address, so that agents calling tools cannot reach it.
 
-use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+use std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
 
 use anyhow::{Context, Result};
 use clap::Args;
 use console::style;
-use operai_runtime::{RuntimeBuilder, SearchEmbedder, proto, transports};
-use tokio::signal;
+use operai_core::circuit::{CircuitState, CircuitStatus};
+use operai_runtime::{LocalRuntime, RuntimeBuilder, SearchEmbedder, proto, transports};
+use tokio::{signal, sync::watch};
 use tonic::transport::Server;
-use tonic_health::ServingStatus;
+use tonic_health::{ServingStatus, server::HealthReporter};
 use tracing::info;
 
 use crate::embedding::EmbeddingGenerator;
@@ -26,6 +31,11 @@ pub struct ServeArgs {
     /// Port to listen on (defaults to 50051).
     #[arg(short, long, default_value = "50051")]
     pub port: u16,
+    /// Address to serve the admin service on, such as `127.0.0.1:50052`
+    /// (disabled by default). The admin service does not authenticate
+    /// callers, so only operators should be able to reach this address.
+    #[arg(long)]
+    pub admin_addr: Option<SocketAddr>,
 }
 
 /// Runs the gRPC server, listening for Ctrl+C to trigger graceful shutdown.
@@ -55,9 +65,11 @@ pub async fn run(args: &ServeArgs, config: &operai_core::Config) -> Result<()> {
 /// 1. Load tools from the config (or `operai.toml` if not specified)
 /// 2. Initialize search embedder from config if embedding is configured
 /// 3. Start a gRPC server on the specified port (default 50051)
-/// 4. Expose the toolbox service, health checks, and gRPC reflection
-/// 5. Wait for the shutdown future to complete
-/// 6. Drain in-flight requests before exiting
+/// 4. Expose the toolbox service, health checks, and gRPC reflection, with a
+///    health status per tool watched by a circuit breaker
+/// 5. Expose the admin service on the admin address, if one is given
+/// 6. Wait for the shutdown future to complete
+/// 7. Drain in-flight requests before exiting
 async fn run_with_shutdown<F>(
     args: &ServeArgs,
     shutdown: F,
@@ -116,6 +128,7 @@ where
     };
 
     let toolbox_service = transports::grpc::ToolboxService::from_runtime(local_runtime.clone());
+    let admin_service = transports::grpc::ToolboxAdminService::from_runtime(local_runtime.clone());
 
     let (health_reporter, health_service) = tonic_health::server::health_reporter();
     health_reporter
@@ -124,6 +137,12 @@ where
     health_reporter
         .set_service_status("brwse.toolbox.v1alpha1.Toolbox", ServingStatus::Serving)
         .await;
+    let circuit_health = (!local_runtime.circuit_breakers().is_empty()).then(|| {
+        tokio::spawn(report_circuit_health(
+            health_reporter.clone(),
+            local_runtime.clone(),
+        ))
+    });
 
     let addr = SocketAddr::from(([0, 0, 0, 0], args.port));
 
@@ -138,15 +157,52 @@ where
         style("✓").green().bold(),
         addr
     );
+    if let Some(admin_addr) = args.admin_addr {
+        info!(address = %admin_addr, "Starting admin gRPC server");
+        println!(
+            "{} Admin server running on http://{}",
+            style("✓").green().bold(),
+            admin_addr
+        );
+    }
     println!("Press Ctrl+C to stop\n");
 
-    Server::builder()
-        .add_service(health_service)
-        .add_service(reflection_service)
-        .add_service(proto::toolbox_server::ToolboxServer::new(toolbox_service))
-        .serve_with_shutdown(addr, shutdown)
-        .await
-        .context("server error")?;
+    // Both servers stop on the same shutdown signal.
+    let (stop_tx, stop_rx) = watch::channel(false);
+    let stopped = |mut stop_rx: watch::Receiver<bool>| async move {
+        let _ = stop_rx.wait_for(|stop| *stop).await;
+    };
+    let shutdown = async move {
+        shutdown.await;
+        let _ = stop_tx.send(true);
+        anyhow::Ok(())
+    };
+    let toolbox_server = async {
+        Server::builder()
+            .add_service(health_service)
+            .add_service(reflection_service)
+            .add_service(proto::toolbox_server::ToolboxServer::new(toolbox_service))
+            .serve_with_shutdown(addr, stopped(stop_rx.clone()))
+            .await
+            .context("server error")
+    };
+    let admin_server = async {
+        let Some(admin_addr) = args.admin_addr else {
+            return Ok(());
+        };
+        Server::builder()
+            .add_service(proto::toolbox_admin_server::ToolboxAdminServer::new(
+                admin_service,
+            ))
+            .serve_with_shutdown(admin_addr, stopped(stop_rx.clone()))
+            .await
+            .context("admin server error")
+    };
+    tokio::try_join!(toolbox_server, admin_server, shutdown)?;
+
+    if let Some(circuit_health) = circuit_health {
+        circuit_health.abort();
+    }
 
     info!("Draining inflight requests");
     local_runtime.drain().await;
@@ -155,6 +211,48 @@ where
     Ok(())
 }
 
+/// Keeps the health status of each tool watched by a circuit breaker in step
+/// with its circuit: `NOT_SERVING` while the circuit is open and `SERVING`
+/// otherwise, under the service name `tools/{tool_id}`.
+///
+/// Statuses are refreshed whenever a circuit changes state, and when an open
+/// circuit's open period ends and it turns half-open.
+async fn report_circuit_health(reporter: HealthReporter, runtime: LocalRuntime) {
+    let circuits = Arc::clone(runtime.circuit_breakers());
+    let mut changes = circuits.subscribe();
+    loop {
+        let statuses: Vec<CircuitStatus> = runtime
+            .registry()
+            .list()
+            .filter_map(|info| circuits.status(&info.qualified_id))
+            .collect();
+        for status in &statuses {
+            let serving = if status.state == CircuitState::Open {
+                ServingStatus::NotServing
+            } else {
+                ServingStatus::Serving
+            };
+            reporter
+                .set_service_status(format!("tools/{}", status.tool), serving)
+                .await;
+        }
+
+        let refresh = statuses
+            .iter()
+            .filter_map(|status| status.retry_after)
+            .min()
+            .unwrap_or(Duration::from_hours(1));
+        tokio::select! {
+            result = changes.changed() => {
+                if result.is_err() {
+                    return;
+                }
+            }
+            () = tokio::time::sleep(refresh) => {}
+        }
+    }
+}
+
 /// Wrapper that adapts [`EmbeddingGenerator`] to the [`SearchEmbedder`] trait.
 ///
 /// This struct provides thread-safe access to an embedding generator,
@@ -201,6 +299,10 @@ mod tests {
     };
 
     use clap::Parser;
+    use operai_runtime::proto::{
+        ApproveCallRequest, DeletePolicyRequest, ListApprovalsRequest, ListPoliciesRequest,
+        toolbox_admin_client::ToolboxAdminClient,
+    };
     use tokio::sync::oneshot;
 
     use super::*;
@@ -219,6 +321,17 @@ mod tests {
         let cli = ServeArgsCli::try_parse_from(["test"]).expect("args should parse");
         assert_eq!(cli.serve.port, 50051);
         assert_eq!(cli.serve.config, None);
+        assert_eq!(cli.serve.admin_addr, None);
+    }
+
+    #[test]
+    fn test_serve_args_parses_admin_addr() {
+        let cli = ServeArgsCli::try_parse_from(["test", "--admin-addr", "127.0.0.1:50052"])
+            .expect("args should parse");
+        assert_eq!(
+            cli.serve.admin_addr,
+            Some(SocketAddr::from(([127, 0, 0, 1], 50052)))
+        );
     }
 
     #[test]
@@ -401,6 +514,7 @@ mod tests {
         let args = ServeArgs {
             config: Some(config_path),
             port,
+            admin_addr: None,
         };
 
         let (tx, rx) = oneshot::channel::<()>();
@@ -438,4 +552,77 @@ mod tests {
         server_handle.await.expect("server task")?;
         Ok(())
     }
+
+    /// Integration test that verifies approvals and policy management are only
+    /// reachable through the admin service, on the admin address.
+    #[tokio::test]
+    async fn test_serve_exposes_admin_service_only_on_admin_addr() -> Result<()> {
+        let _lock = crate::testing::test_lock_async().await;
+
+        let lib_path = hello_world_cdylib_path();
+        let config_path = write_config_for_library(&lib_path);
+
+        let port = TcpListener::bind("127.0.0.1:0")?.local_addr()?.port();
+        let admin_addr = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+        let args = ServeArgs {
+            config: Some(config_path),
+            port,
+            admin_addr: Some(admin_addr),
+        };
+
+        let (tx, rx) = oneshot::channel::<()>();
+        let server_handle = tokio::spawn(async move {
+            run_with_shutdown(
+                &args,
+                async {
+                    let _ = rx.await;
+                },
+                &operai_core::Config::empty(),
+            )
+            .await
+        });
+
+        let endpoint = format!("http://127.0.0.1:{port}");
+        connect_with_retry(&endpoint).await;
+
+        // A toolbox client cannot decide approvals or change policies.
+        let mut toolbox_admin = ToolboxAdminClient::connect(endpoint).await?;
+        let status = toolbox_admin
+            .approve_call(ApproveCallRequest {
+                name: "approvals/unknown".to_string(),
+            })
+            .await
+            .expect_err("toolbox address should not serve approvals");
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+        let status = toolbox_admin
+            .delete_policy(DeletePolicyRequest {
+                name: "policies/audit".to_string(),
+            })
+            .await
+            .expect_err("toolbox address should not serve policy management");
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+
+        let mut admin = ToolboxAdminClient::connect(format!("http://{admin_addr}")).await?;
+        let approvals = admin
+            .list_approvals(ListApprovalsRequest {})
+            .await?
+            .into_inner();
+        assert!(approvals.approvals.is_empty());
+        let policies = admin
+            .list_policies(ListPoliciesRequest {})
+            .await?
+            .into_inner();
+        assert!(policies.policies.is_empty());
+        let status = admin
+            .approve_call(ApproveCallRequest {
+                name: "approvals/unknown".to_string(),
+            })
+            .await
+            .expect_err("unknown approval should not be found");
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let _ = tx.send(());
+        server_handle.await.expect("server task")?;
+        Ok(())
+    }
 }
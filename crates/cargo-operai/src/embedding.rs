@@ -1,13 +1,16 @@
 //! Embedding utilities backed by `embed_anything`.
 //!
-//! Supports both local and remote embedding providers:
+//! Supports local, remote, and offline embedding providers:
 //! - **Local**: Hugging Face models via `embed_anything` (default)
 //! - **Remote**: `OpenAI`, Gemini, Cohere cloud APIs
+//! - **Hashing**: deterministic [`HashingEmbedder`] from `operai-runtime`,
+//!   requiring no download or API key
 
 use std::{fmt::Write as _, path::Path, sync::Arc};
 
 use anyhow::{Context, Result, bail};
 use embed_anything::embeddings::embed::Embedder;
+use operai_runtime::HashingEmbedder;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Provider {
@@ -59,9 +62,15 @@ impl Provider {
     }
 }
 
+#[derive(Clone)]
+enum Backend {
+    EmbedAnything(Arc<Embedder>),
+    Hashing(HashingEmbedder),
+}
+
 #[derive(Clone)]
 pub struct EmbeddingGenerator {
-    embedder: Arc<Embedder>,
+    backend: Backend,
 }
 
 impl EmbeddingGenerator {
@@ -86,7 +95,17 @@ impl EmbeddingGenerator {
                 Provider::from_str(kind_str)?
             }
             "local" => Provider::Local,
-            _ => bail!("unknown embedding type: {embedding_type}. Supported: 'local', 'remote'"),
+            "hashing" => {
+                let dimensions = config
+                    .embedding
+                    .as_ref()
+                    .and_then(|emb| emb.dimensions)
+                    .unwrap_or(HashingEmbedder::DEFAULT_DIMENSIONS);
+                return Ok(Self::hashing(dimensions));
+            }
+            _ => bail!(
+                "unknown embedding type: {embedding_type}. Supported: 'local', 'remote', 'hashing'"
+            ),
         };
 
         match provider {
@@ -103,7 +122,7 @@ impl EmbeddingGenerator {
                     Embedder::from_pretrained_hf(&resolved_model, None, token.as_deref(), None)
                         .context("failed to initialize local embedder")?;
                 Ok(Self {
-                    embedder: Arc::new(embedder),
+                    backend: Backend::EmbedAnything(Arc::new(embedder)),
                 })
             }
             Provider::OpenAI | Provider::Gemini | Provider::Cohere => {
@@ -121,15 +140,25 @@ impl EmbeddingGenerator {
                     Embedder::from_pretrained_cloud(cloud_name, &model_id, Some(api_key))
                         .context(format!("failed to initialize {cloud_name} embedder"))?;
                 Ok(Self {
-                    embedder: Arc::new(embedder),
+                    backend: Backend::EmbedAnything(Arc::new(embedder)),
                 })
             }
         }
     }
 
+    /// Creates an offline generator using [`HashingEmbedder`].
+    pub fn hashing(dimensions: usize) -> Self {
+        Self {
+            backend: Backend::Hashing(HashingEmbedder::new(dimensions)),
+        }
+    }
+
     pub async fn embed(&self, text: &str) -> Result<Vec<f32>> {
-        let embeddings = self
-            .embedder
+        let embedder = match &self.backend {
+            Backend::EmbedAnything(embedder) => embedder,
+            Backend::Hashing(embedder) => return Ok(embedder.embed(text)),
+        };
+        let embeddings = embedder
             .embed(&[text], None, None)
             .await
             .context("failed to generate embedding")?;
@@ -6,8 +6,12 @@
 //! - Running tool servers (`serve`)
 //! - Running MCP servers (`mcp`)
 //! - Calling tools remotely (`call`)
+//! - Reviewing calls held for approval (`approvals`)
+//! - Testing and inspecting policies (`policy`)
 //! - Listing available tools (`list`)
 //! - Describing tools (`describe`)
+//! - Searching for tools (`search`)
+//! - Evaluating search quality (`search-eval`)
 //!
 //! # Command Structure
 //!
@@ -15,7 +19,8 @@
 //! parsing structure:
 //!
 //! - `cargo operai <command>` - Top-level invocation
-//! - Subcommands: `new`, `build`, `serve`, `mcp`, `call`, `list`, `describe`
+//! - Subcommands: `new`, `build`, `serve`, `mcp`, `call`, `approvals`,
+//!   `policy`, `list`, `describe`, `search`, `search-eval`
 //!
 //! # Logging
 //!
@@ -31,39 +36,7 @@ mod commands;
 mod embedding;
 
 #[cfg(test)]
-pub(crate) mod testing {
-    use std::sync::OnceLock;
-
-    use tokio::sync::Mutex;
-
-    /// Acquires a synchronous test lock.
-    ///
-    /// This provides a static mutex for synchronizing tests that need to
-    /// prevent concurrent execution (e.g., tests that modify shared state
-    /// or use exclusive resources).
-    ///
-    /// # Returns
-    ///
-    /// A mutex guard that will release the lock when dropped.
-    pub(crate) fn test_lock() -> tokio::sync::MutexGuard<'static, ()> {
-        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-        LOCK.get_or_init(|| Mutex::new(())).blocking_lock()
-    }
-
-    /// Acquires an asynchronous test lock.
-    ///
-    /// This provides a static mutex for synchronizing async tests that need to
-    /// prevent concurrent execution. Unlike [`test_lock`], this uses async
-    /// locking.
-    ///
-    /// # Returns
-    ///
-    /// A mutex guard that will release the lock when dropped.
-    pub(crate) async fn test_lock_async() -> tokio::sync::MutexGuard<'static, ()> {
-        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-        LOCK.get_or_init(|| Mutex::new(())).lock().await
-    }
-}
+mod testing;
 
 /// Top-level Cargo command parser.
 ///
@@ -109,11 +82,23 @@ enum Command {
     /// Call a tool remotely.
     Call(commands::call::CallArgs),
 
+    /// List, approve, or deny tool calls awaiting approval.
+    Approvals(commands::approvals::ApprovalsArgs),
+
+    /// Test policies offline or inspect their behavior on a server.
+    Policy(commands::policy::PolicyArgs),
+
     /// List available tools.
     List(commands::list::ListArgs),
 
     /// Describe a tool's schema.
     Describe(commands::describe::DescribeArgs),
+
+    /// Search for tools by natural-language query.
+    Search(commands::search::SearchArgs),
+
+    /// Evaluate search quality against a fixtures file.
+    SearchEval(commands::search_eval::SearchEvalArgs),
 }
 
 /// Custom Debug implementation for Command.
@@ -128,8 +113,12 @@ impl std::fmt::Debug for Command {
             Self::Serve(_) => f.debug_tuple("Serve").finish(),
             Self::Mcp(_) => f.debug_tuple("Mcp").finish(),
             Self::Call(_) => f.debug_tuple("Call").finish(),
+            Self::Approvals(_) => f.debug_tuple("Approvals").finish(),
+            Self::Policy(_) => f.debug_tuple("Policy").finish(),
             Self::List(_) => f.debug_tuple("List").finish(),
             Self::Describe(_) => f.debug_tuple("Describe").finish(),
+            Self::Search(_) => f.debug_tuple("Search").finish(),
+            Self::SearchEval(_) => f.debug_tuple("SearchEval").finish(),
         }
     }
 }
@@ -167,8 +156,12 @@ async fn main() -> Result<()> {
         Command::Serve(args) => commands::serve::run(args, &config).await,
         Command::Mcp(args) => commands::mcp::run(args, &config).await,
         Command::Call(args) => commands::call::run(args).await,
+        Command::Approvals(args) => commands::approvals::run(args).await,
+        Command::Policy(args) => commands::policy::run(args).await,
         Command::List(args) => commands::list::run(args).await,
         Command::Describe(args) => commands::describe::run(args).await,
+        Command::Search(args) => commands::search::run(args, &config).await,
+        Command::SearchEval(args) => commands::search_eval::run(args, &config).await,
     }
 }
 
@@ -293,6 +286,97 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cli_parses_approvals_deny_with_reason() -> Result<(), clap::Error> {
+        let command = parse_command(&[
+            "cargo",
+            "operai",
+            "approvals",
+            "deny",
+            "abc123",
+            "--reason",
+            "not today",
+            "--server",
+            "http://10.0.0.1:50051",
+        ])?;
+
+        let Command::Approvals(args) = command else {
+            panic!("expected Command::Approvals");
+        };
+
+        assert_eq!(args.server, "http://10.0.0.1:50051");
+        let commands::approvals::ApprovalsCommand::Deny { id, reason } = args.command else {
+            panic!("expected ApprovalsCommand::Deny");
+        };
+        assert_eq!(id, "abc123");
+        assert_eq!(reason.as_deref(), Some("not today"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cli_policy_shadow_report_defaults() -> Result<(), clap::Error> {
+        let command = parse_command(&["cargo", "operai", "policy", "shadow-report"])?;
+
+        let Command::Policy(args) = command else {
+            panic!("expected Command::Policy");
+        };
+
+        let commands::policy::PolicyCommand::ShadowReport { server, format } = args.command else {
+            panic!("expected PolicyCommand::ShadowReport");
+        };
+        assert_eq!(server, "http://127.0.0.1:50052");
+        assert_eq!(format, "table");
+        Ok(())
+    }
+
+    #[test]
+    fn test_cli_policy_apply_parses_file_and_server() -> Result<(), clap::Error> {
+        let command = parse_command(&[
+            "cargo",
+            "operai",
+            "policy",
+            "apply",
+            "policies/audit.toml",
+            "--server",
+            "http://10.0.0.1:50051",
+        ])?;
+
+        let Command::Policy(args) = command else {
+            panic!("expected Command::Policy");
+        };
+
+        let commands::policy::PolicyCommand::Apply { file, server } = args.command else {
+            panic!("expected PolicyCommand::Apply");
+        };
+        assert_eq!(file, std::path::PathBuf::from("policies/audit.toml"));
+        assert_eq!(server, "http://10.0.0.1:50051");
+        Ok(())
+    }
+
+    #[test]
+    fn test_cli_policy_test_parses_fixture_and_config() -> Result<(), clap::Error> {
+        let command = parse_command(&[
+            "cargo",
+            "operai",
+            "policy",
+            "test",
+            "policies.test.toml",
+            "--config",
+            "custom.toml",
+        ])?;
+
+        let Command::Policy(args) = command else {
+            panic!("expected Command::Policy");
+        };
+
+        let commands::policy::PolicyCommand::Test { fixture, config } = args.command else {
+            panic!("expected PolicyCommand::Test");
+        };
+        assert_eq!(fixture, std::path::PathBuf::from("policies.test.toml"));
+        assert_eq!(config, Some(std::path::PathBuf::from("custom.toml")));
+        Ok(())
+    }
+
     #[test]
     fn test_cli_list_defaults_to_table_format_and_localhost_server() -> Result<(), clap::Error> {
         let command = parse_command(&["cargo", "operai", "list"])?;
@@ -339,6 +423,54 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cli_search_defaults() -> Result<(), clap::Error> {
+        let command = parse_command(&["cargo", "operai", "search", "send an email"])?;
+
+        let Command::Search(args) = command else {
+            panic!("expected Command::Search");
+        };
+
+        assert_eq!(args.query, "send an email");
+        assert_eq!(args.server, "http://127.0.0.1:50051");
+        assert!(!args.local);
+        assert!(!args.embed);
+        assert_eq!(args.limit, 10);
+        assert_eq!(args.format, "table");
+        Ok(())
+    }
+
+    #[test]
+    fn test_cli_search_eval_defaults() -> Result<(), clap::Error> {
+        let command = parse_command(&["cargo", "operai", "search-eval", "fixtures.toml"])?;
+
+        let Command::SearchEval(args) = command else {
+            panic!("expected Command::SearchEval");
+        };
+
+        assert_eq!(args.fixtures, std::path::PathBuf::from("fixtures.toml"));
+        assert!(args.server.is_none());
+        assert_eq!(args.k, 5);
+        assert_eq!(args.format, "table");
+        assert!(args.min_recall.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cli_search_eval_parses_top_k() -> Result<(), clap::Error> {
+        for flag in ["-k", "--top-k"] {
+            let command =
+                parse_command(&["cargo", "operai", "search-eval", "fixtures.toml", flag, "3"])?;
+
+            let Command::SearchEval(args) = command else {
+                panic!("expected Command::SearchEval");
+            };
+
+            assert_eq!(args.k, 3);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_cli_build_parses_path_and_skip_embed() -> Result<(), clap::Error> {
         let command = parse_command(&[
@@ -390,6 +522,8 @@ mod tests {
             ("cargo operai call tool.id {}", "Call"),
             ("cargo operai list", "List"),
             ("cargo operai describe tool.id", "Describe"),
+            ("cargo operai search email", "Search"),
+            ("cargo operai search-eval fixtures.toml", "SearchEval"),
         ];
 
         for (argv, expected_variant) in test_cases {
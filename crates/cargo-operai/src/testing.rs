@@ -0,0 +1,149 @@
+//! Shared helpers for command tests.
+
+use std::{net::SocketAddr, sync::OnceLock};
+
+use operai_runtime::proto::{
+    CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse, SearchToolsRequest,
+    SearchToolsResponse,
+    toolbox_server::{Toolbox, ToolboxServer},
+};
+use tokio::{sync::Mutex, task::JoinHandle};
+use tonic::{Request, Response, Status};
+
+/// Acquires a synchronous test lock.
+///
+/// This provides a static mutex for synchronizing tests that need to
+/// prevent concurrent execution (e.g., tests that modify shared state
+/// or use exclusive resources).
+///
+/// # Returns
+///
+/// A mutex guard that will release the lock when dropped.
+pub(crate) fn test_lock() -> tokio::sync::MutexGuard<'static, ()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(())).blocking_lock()
+}
+
+/// Acquires an asynchronous test lock.
+///
+/// This provides a static mutex for synchronizing async tests that need to
+/// prevent concurrent execution. Unlike [`test_lock`], this uses async
+/// locking.
+///
+/// # Returns
+///
+/// A mutex guard that will release the lock when dropped.
+pub(crate) async fn test_lock_async() -> tokio::sync::MutexGuard<'static, ()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(())).lock().await
+}
+
+/// Handler answering one RPC of a [`MockToolbox`].
+type Handler<Req, Resp> = Box<dyn Fn(Req) -> Result<Resp, Status> + Send + Sync>;
+
+/// Toolbox server for command tests.
+///
+/// Every RPC fails with `unimplemented` unless a handler was set for it.
+#[derive(Default)]
+pub(crate) struct MockToolbox {
+    list_tools: Option<Handler<ListToolsRequest, ListToolsResponse>>,
+    search_tools: Option<Handler<SearchToolsRequest, SearchToolsResponse>>,
+}
+
+impl MockToolbox {
+    /// Answers `ListTools` with `handler`.
+    pub(crate) fn with_list_tools(
+        mut self,
+        handler: impl Fn(ListToolsRequest) -> Result<ListToolsResponse, Status> + Send + Sync + 'static,
+    ) -> Self {
+        self.list_tools = Some(Box::new(handler));
+        self
+    }
+
+    /// Answers `SearchTools` with `handler`.
+    pub(crate) fn with_search_tools(
+        mut self,
+        handler: impl Fn(SearchToolsRequest) -> Result<SearchToolsResponse, Status>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.search_tools = Some(Box::new(handler));
+        self
+    }
+
+    /// Serves the mock on a free local port until the returned server is
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no local port is available.
+    pub(crate) async fn serve(self) -> anyhow::Result<MockServer> {
+        let addr = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+            listener.local_addr()?
+        };
+        let task = tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(ToolboxServer::new(self))
+                .serve(addr)
+                .await
+                .unwrap();
+        });
+        // Give the server a moment to start.
+        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+        Ok(MockServer { addr, task })
+    }
+}
+
+/// Answers a request with `handler`, or `unimplemented` if there is none.
+fn answer<Req, Resp>(
+    handler: Option<&Handler<Req, Resp>>,
+    request: Request<Req>,
+) -> Result<Response<Resp>, Status> {
+    let handler = handler.ok_or_else(|| Status::unimplemented("not implemented"))?;
+    handler(request.into_inner()).map(Response::new)
+}
+
+#[tonic::async_trait]
+impl Toolbox for MockToolbox {
+    async fn list_tools(
+        &self,
+        request: Request<ListToolsRequest>,
+    ) -> Result<Response<ListToolsResponse>, Status> {
+        answer(self.list_tools.as_ref(), request)
+    }
+
+    async fn search_tools(
+        &self,
+        request: Request<SearchToolsRequest>,
+    ) -> Result<Response<SearchToolsResponse>, Status> {
+        answer(self.search_tools.as_ref(), request)
+    }
+
+    async fn call_tool(
+        &self,
+        _request: Request<CallToolRequest>,
+    ) -> Result<Response<CallToolResponse>, Status> {
+        Err(Status::unimplemented("not implemented"))
+    }
+}
+
+/// A running [`MockToolbox`], stopped when dropped.
+pub(crate) struct MockServer {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Returns the URL clients connect to.
+    pub(crate) fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
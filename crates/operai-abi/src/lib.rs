@@ -71,7 +71,7 @@ use async_ffi::FfiFuture;
 /// Tool libraries must export a [`ToolMeta`] struct with this `abi_version`
 /// to be compatible with the current runtime. Mismatches will result in
 /// load failures with [`ToolResult::AbiMismatch`].
-pub const TOOL_ABI_VERSION: u32 = 1;
+pub const TOOL_ABI_VERSION: u32 = 2;
 
 /// Result codes for tool operations.
 ///
@@ -168,6 +168,15 @@ pub struct ToolDescriptor {
     /// Embedding vector for semantic search and tool matching.
     /// Empty slice if no embedding is available.
     pub embedding: RSlice<'static, f32>,
+    /// Cost of one call, counted against the runtime's budgets.
+    /// `None` if the tool declares no static cost.
+    pub cost: ROption<f64>,
+    /// Whether calling the tool again with the same input has no further
+    /// effect, so the runtime may retry calls that failed.
+    pub idempotent: bool,
+    /// Seconds a successful result may be reused for the same input.
+    /// `None` if the tool declares no cache TTL.
+    pub cache_ttl_secs: ROption<u64>,
 }
 
 /// Context provided during tool initialization.
@@ -221,6 +230,9 @@ pub struct CallResult {
     /// On success: serialized output according to the tool's output schema.
     /// On error: UTF-8 encoded error message.
     pub output: RVec<u8>,
+    /// Cost the call reported, replacing the tool's static cost.
+    /// `None` if the call reported no cost.
+    pub cost: ROption<f64>,
 }
 
 impl CallResult {
@@ -230,6 +242,7 @@ impl CallResult {
         Self {
             result: ToolResult::Ok,
             output,
+            cost: ROption::RNone,
         }
     }
 
@@ -243,8 +256,16 @@ impl CallResult {
         Self {
             result,
             output: RVec::from_slice(message.as_bytes()),
+            cost: ROption::RNone,
         }
     }
+
+    /// Sets the cost the call reported.
+    #[must_use]
+    pub fn with_cost(mut self, cost: Option<f64>) -> Self {
+        self.cost = cost.into();
+        self
+    }
 }
 
 /// Arguments passed to the tool initialization function.
@@ -386,6 +407,15 @@ mod tests {
         assert_eq!(call_result.output.as_slice(), output_bytes.as_slice());
     }
 
+    #[test]
+    fn test_call_result_with_cost_records_reported_cost() {
+        let call_result = CallResult::ok(RVec::new());
+        assert_eq!(call_result.cost, ROption::RNone);
+
+        let call_result = call_result.with_cost(Some(0.25));
+        assert_eq!(call_result.cost, ROption::RSome(0.25));
+    }
+
     #[test]
     fn test_call_result_error_sets_result_and_encodes_message() {
         let result_code = ToolResult::InvalidInput;
@@ -558,7 +588,7 @@ mod tests {
     fn test_abi_version_is_documented_value() {
         // This test documents the current ABI version and will fail
         // if it changes unexpectedly, prompting review of compatibility
-        assert_eq!(TOOL_ABI_VERSION, 1);
+        assert_eq!(TOOL_ABI_VERSION, 2);
     }
 
     #[test]
@@ -601,6 +631,9 @@ mod tests {
             capabilities: RSlice::from_slice(&[]),
             tags: RSlice::from_slice(&[]),
             embedding: RSlice::from_slice(&[]),
+            cost: ROption::RNone,
+            idempotent: false,
+            cache_ttl_secs: ROption::RNone,
         };
 
         let cloned = descriptor.clone();
@@ -623,6 +656,9 @@ mod tests {
             capabilities: RSlice::from_slice(&[]),
             tags: RSlice::from_slice(&[]),
             embedding: RSlice::from_slice(&[]),
+            cost: ROption::RNone,
+            idempotent: false,
+            cache_ttl_secs: ROption::RNone,
         };
 
         match descriptor.credential_schema {
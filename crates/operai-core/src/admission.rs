@@ -0,0 +1,451 @@
+//! Admission control for tool calls.
+//!
+//! A tool's [`ToolLimits`] cap how many of its calls run at once, how many
+//! more may wait for a free slot, and how long a call may take. An
+//! [`AdmissionController`] enforces them together with a server-wide cap on
+//! the calls in flight: a call first takes a slot of its tool and then one of
+//! the server, waiting in each queue in arrival order while it is full. A
+//! call that finds a full queue is rejected with [`AdmissionRejected`].
+//!
+//! The controller only admits calls; enforcing the timeout, which covers the
+//! time spent queued as well as the call itself, is left to the caller. Per
+//! tool, it counts the calls that were admitted, queued, rejected and timed
+//! out, and how long they waited, in [`AdmissionStats`].
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Concurrency and timeout limits for the calls of one tool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ToolLimits {
+    /// How long a call may wait for a slot and run, if limited.
+    pub timeout: Option<Duration>,
+    /// Calls that may run at once, if limited.
+    pub max_concurrency: Option<u32>,
+    /// Calls that may wait for a slot once `max_concurrency` calls run.
+    pub max_queue: u32,
+}
+
+impl ToolLimits {
+    /// Fail calls that have not completed within `timeout`.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Run at most `max_concurrency` calls at once.
+    #[must_use]
+    pub fn with_max_concurrency(mut self, max_concurrency: u32) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Let up to `max_queue` calls wait for a slot.
+    #[must_use]
+    pub fn with_max_queue(mut self, max_queue: u32) -> Self {
+        self.max_queue = max_queue;
+        self
+    }
+}
+
+/// Which limit rejected a call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdmissionScope {
+    /// The concurrency limit of the called tool.
+    Tool,
+    /// The server-wide cap on calls in flight.
+    Server,
+}
+
+/// Why a call was rejected by admission control.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdmissionRejected {
+    /// Qualified ID of the called tool.
+    pub tool: String,
+    /// The limit whose queue was full.
+    pub scope: AdmissionScope,
+    /// Calls that may run at once under that limit.
+    pub max_concurrency: u32,
+    /// Calls that may wait under that limit.
+    pub max_queue: u32,
+}
+
+impl fmt::Display for AdmissionRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.scope {
+            AdmissionScope::Tool => write!(
+                f,
+                "Tool {} is at its limit of {} concurrent calls",
+                self.tool, self.max_concurrency
+            )?,
+            AdmissionScope::Server => write!(
+                f,
+                "Server is at its limit of {} calls in flight",
+                self.max_concurrency
+            )?,
+        }
+        write!(f, " with {} queued", self.max_queue)
+    }
+}
+
+/// Admission counts for one tool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AdmissionStats {
+    /// Calls that were admitted.
+    pub admitted: u64,
+    /// Admitted calls that had to wait for a slot.
+    pub queued: u64,
+    /// Calls rejected because a queue was full.
+    pub rejected: u64,
+    /// Calls that did not complete within the tool's timeout.
+    pub timed_out: u64,
+    /// Total time admitted calls waited for a slot.
+    pub queue_wait_total: Duration,
+    /// Longest time an admitted call waited for a slot.
+    pub queue_wait_max: Duration,
+}
+
+impl AdmissionStats {
+    /// Return the mean time queued calls waited for a slot.
+    #[must_use]
+    pub fn queue_wait_mean(&self) -> Duration {
+        if self.queued == 0 {
+            return Duration::ZERO;
+        }
+        let nanos = self.queue_wait_total.as_nanos() / u128::from(self.queued);
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+}
+
+/// Admission counts for one tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdmissionEntry {
+    /// Qualified tool ID.
+    pub tool: String,
+    /// What happened to the calls of the tool.
+    pub stats: AdmissionStats,
+}
+
+/// Permission for an admitted call to run, holding its slots until dropped.
+#[derive(Debug)]
+pub struct AdmissionPermit {
+    _tool: Option<OwnedSemaphorePermit>,
+    _server: Option<OwnedSemaphorePermit>,
+    queue_wait: Duration,
+}
+
+impl AdmissionPermit {
+    /// Return how long the call waited for its slots.
+    #[must_use]
+    pub fn queue_wait(&self) -> Duration {
+        self.queue_wait
+    }
+}
+
+/// A concurrency limit with a bounded queue.
+#[derive(Debug)]
+struct Gate {
+    slots: Arc<Semaphore>,
+    max_concurrency: u32,
+    max_queue: u32,
+    queued: AtomicU32,
+}
+
+impl Gate {
+    fn new(max_concurrency: u32, max_queue: u32) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(max_concurrency as usize)),
+            max_concurrency,
+            max_queue,
+            queued: AtomicU32::new(0),
+        }
+    }
+
+    /// Take a slot, waiting for one if the queue has room. Returns whether
+    /// the call had to wait, or `None` if the queue is full.
+    async fn acquire(&self) -> Option<(OwnedSemaphorePermit, bool)> {
+        if let Ok(permit) = Arc::clone(&self.slots).try_acquire_owned() {
+            return Some((permit, false));
+        }
+        self.queued
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |queued| {
+                (queued < self.max_queue).then_some(queued + 1)
+            })
+            .ok()?;
+        // Leave the queue even if the caller gives up waiting.
+        let _queued = QueuedGuard(&self.queued);
+        let permit = Arc::clone(&self.slots)
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+        Some((permit, true))
+    }
+
+    fn rejected(&self, tool: &str, scope: AdmissionScope) -> AdmissionRejected {
+        AdmissionRejected {
+            tool: tool.to_string(),
+            scope,
+            max_concurrency: self.max_concurrency,
+            max_queue: self.max_queue,
+        }
+    }
+}
+
+struct QueuedGuard<'a>(&'a AtomicU32);
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// The limits of one tool.
+#[derive(Debug)]
+struct ToolGate {
+    timeout: Option<Duration>,
+    gate: Option<Gate>,
+}
+
+/// Admits tool calls under per-tool and server-wide concurrency limits.
+#[derive(Debug, Default)]
+pub struct AdmissionController {
+    server: Option<Gate>,
+    tools: HashMap<String, ToolGate>,
+    stats: Mutex<BTreeMap<String, AdmissionStats>>,
+}
+
+impl AdmissionController {
+    /// Create a controller that admits every call at once.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run at most `max_inflight` calls at once across all tools, letting up
+    /// to `max_queue` more wait for a slot.
+    #[must_use]
+    pub fn with_max_inflight(mut self, max_inflight: u32, max_queue: u32) -> Self {
+        self.server = Some(Gate::new(max_inflight, max_queue));
+        self
+    }
+
+    /// Limit the calls of the tool with qualified ID `tool`.
+    #[must_use]
+    pub fn with_tool_limits(mut self, tool: impl Into<String>, limits: ToolLimits) -> Self {
+        let gate = limits
+            .max_concurrency
+            .map(|max_concurrency| Gate::new(max_concurrency, limits.max_queue));
+        if limits.timeout.is_some() || gate.is_some() {
+            self.tools.insert(
+                tool.into(),
+                ToolGate {
+                    timeout: limits.timeout,
+                    gate,
+                },
+            );
+        }
+        self
+    }
+
+    /// Return how long a call to `tool` may wait for a slot and run.
+    #[must_use]
+    pub fn timeout(&self, tool: &str) -> Option<Duration> {
+        self.tools.get(tool).and_then(|limits| limits.timeout)
+    }
+
+    /// Wait for a slot for a call to `tool`, first under the tool's limit and
+    /// then under the server's.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdmissionRejected`] if the call would have to wait in a full
+    /// queue. A call rejected by the server gives up its tool slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the statistics lock is poisoned.
+    pub async fn admit(&self, tool: &str) -> Result<AdmissionPermit, AdmissionRejected> {
+        let tool_gate = self.tools.get(tool).and_then(|limits| limits.gate.as_ref());
+        if tool_gate.is_none() && self.server.is_none() {
+            return Ok(AdmissionPermit {
+                _tool: None,
+                _server: None,
+                queue_wait: Duration::ZERO,
+            });
+        }
+
+        let start = Instant::now();
+        let mut queued = false;
+        let tool_permit = match tool_gate {
+            Some(gate) => {
+                let Some((permit, waited)) = gate.acquire().await else {
+                    self.record(tool, |stats| stats.rejected += 1);
+                    return Err(gate.rejected(tool, AdmissionScope::Tool));
+                };
+                queued |= waited;
+                Some(permit)
+            }
+            None => None,
+        };
+        let server_permit = match &self.server {
+            Some(gate) => {
+                let Some((permit, waited)) = gate.acquire().await else {
+                    self.record(tool, |stats| stats.rejected += 1);
+                    return Err(gate.rejected(tool, AdmissionScope::Server));
+                };
+                queued |= waited;
+                Some(permit)
+            }
+            None => None,
+        };
+
+        let queue_wait = if queued {
+            start.elapsed()
+        } else {
+            Duration::ZERO
+        };
+        self.record(tool, |stats| {
+            stats.admitted += 1;
+            if queued {
+                stats.queued += 1;
+                stats.queue_wait_total += queue_wait;
+                stats.queue_wait_max = stats.queue_wait_max.max(queue_wait);
+            }
+        });
+        Ok(AdmissionPermit {
+            _tool: tool_permit,
+            _server: server_permit,
+            queue_wait,
+        })
+    }
+
+    /// Count a call to `tool` that did not complete within its timeout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the statistics lock is poisoned.
+    pub fn record_timeout(&self, tool: &str) {
+        self.record(tool, |stats| stats.timed_out += 1);
+    }
+
+    /// Return the admission counts of the tools that had calls subject to a
+    /// limit, ordered by tool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the statistics lock is poisoned.
+    #[must_use]
+    pub fn stats(&self) -> Vec<AdmissionEntry> {
+        let stats = self.stats.lock().expect("lock poisoned");
+        stats
+            .iter()
+            .map(|(tool, stats)| AdmissionEntry {
+                tool: tool.clone(),
+                stats: *stats,
+            })
+            .collect()
+    }
+
+    fn record(&self, tool: &str, update: impl FnOnce(&mut AdmissionStats)) {
+        let mut stats = self.stats.lock().expect("lock poisoned");
+        update(stats.entry(tool.to_string()).or_default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tool_limit_queues_then_rejects() {
+        let controller = AdmissionController::new().with_tool_limits(
+            "t.slow",
+            ToolLimits::default()
+                .with_max_concurrency(1)
+                .with_max_queue(1),
+        );
+        let running = controller.admit("t.slow").await.expect("slot is free");
+
+        let waiting = controller.admit("t.slow");
+        tokio::pin!(waiting);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(20), &mut waiting)
+                .await
+                .is_err(),
+            "second call should wait for the slot"
+        );
+        let rejection = controller
+            .admit("t.slow")
+            .await
+            .expect_err("queue should be full");
+        assert_eq!(rejection.scope, AdmissionScope::Tool);
+        assert_eq!(
+            rejection.to_string(),
+            "Tool t.slow is at its limit of 1 concurrent calls with 1 queued"
+        );
+        // Other tools are not limited.
+        assert!(controller.admit("t.fast").await.is_ok());
+
+        drop(running);
+        let admitted = waiting.await.expect("slot should be handed over");
+        assert!(admitted.queue_wait() >= Duration::from_millis(20));
+
+        let stats = controller.stats();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].tool, "t.slow");
+        assert_eq!(stats[0].stats.admitted, 2);
+        assert_eq!(stats[0].stats.queued, 1);
+        assert_eq!(stats[0].stats.rejected, 1);
+        assert_eq!(stats[0].stats.queue_wait_max, admitted.queue_wait());
+        assert_eq!(stats[0].stats.queue_wait_mean(), admitted.queue_wait());
+    }
+
+    #[tokio::test]
+    async fn test_server_limit_applies_across_tools_and_abandoned_waits_leave_queue() {
+        let controller = AdmissionController::new()
+            .with_max_inflight(1, 1)
+            .with_tool_limits(
+                "t.a",
+                ToolLimits::default().with_timeout(Duration::from_secs(5)),
+            );
+        assert_eq!(controller.timeout("t.a"), Some(Duration::from_secs(5)));
+        assert_eq!(controller.timeout("t.b"), None);
+
+        let running = controller.admit("t.a").await.expect("slot is free");
+        // A caller that gives up waiting frees its place in the queue.
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), controller.admit("t.b"))
+                .await
+                .is_err()
+        );
+        let waiting = controller.admit("t.b");
+        tokio::pin!(waiting);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), &mut waiting)
+                .await
+                .is_err()
+        );
+        let rejection = controller
+            .admit("t.a")
+            .await
+            .expect_err("server queue should be full");
+        assert_eq!(rejection.scope, AdmissionScope::Server);
+        assert_eq!(
+            rejection.to_string(),
+            "Server is at its limit of 1 calls in flight with 1 queued"
+        );
+
+        drop(running);
+        assert!(waiting.await.is_ok());
+    }
+}
@@ -0,0 +1,543 @@
+//! Circuit breakers for repeatedly failing tools.
+//!
+//! A [`CircuitBreaker`] watches the calls of every tool it matches and keeps
+//! a separate circuit per tool; if several breakers match a tool, the first
+//! one applies. A call fails if the tool returns an error, panics or times
+//! out; calls rejected before the tool runs are not counted.
+//!
+//! A circuit starts closed and counts the calls and failures of each
+//! `window`. Once at least `min_calls` calls were made in a window and the
+//! share of failures reaches `failure_rate`, the circuit opens: calls are
+//! rejected with [`CircuitOpen`] without running the tool, for `open_for`.
+//! After that the circuit is half-open and lets up to `half_open_calls`
+//! probe calls through at a time. If that many probes succeed the circuit
+//! closes, and if any fails it opens again.
+//!
+//! Circuits are kept in memory by [`CircuitBreakers`] and are not shared
+//! between processes. Every change of state is logged and announced to the
+//! receivers returned by [`CircuitBreakers::subscribe`].
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+use crate::policy::CompiledPattern;
+
+/// When to stop calling a failing tool, and for how long.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBreaker {
+    /// Name of the breaker, unique within a set of breakers.
+    pub name: String,
+    /// Pattern of the tools the breaker watches, matched like an effect's
+    /// `tool`.
+    pub tool: String,
+    /// Share of failed calls in a window, between 0 and 1, at which the
+    /// circuit opens.
+    pub failure_rate: f64,
+    /// Calls a window needs before its failure rate counts.
+    pub min_calls: u32,
+    /// Length of a window in which calls and failures are counted.
+    pub window: Duration,
+    /// How long an open circuit rejects calls before probing.
+    pub open_for: Duration,
+    /// Probe calls let through at a time when half-open, and successful
+    /// probes needed to close the circuit.
+    pub half_open_calls: u32,
+}
+
+impl CircuitBreaker {
+    /// Create a breaker for every tool that opens when half of at least 5
+    /// calls in a minute fail, stays open for 30 seconds, and closes after
+    /// one successful probe.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tool: "**".to_string(),
+            failure_rate: 0.5,
+            min_calls: 5,
+            window: Duration::from_mins(1),
+            open_for: Duration::from_secs(30),
+            half_open_calls: 1,
+        }
+    }
+
+    /// Only watch tools matching `tool`.
+    #[must_use]
+    pub fn with_tool(mut self, tool: impl Into<String>) -> Self {
+        self.tool = tool.into();
+        self
+    }
+
+    /// Open the circuit once `failure_rate` of the calls in a window fail.
+    #[must_use]
+    pub fn with_failure_rate(mut self, failure_rate: f64) -> Self {
+        self.failure_rate = failure_rate;
+        self
+    }
+
+    /// Only open the circuit once a window has at least `min_calls` calls.
+    #[must_use]
+    pub fn with_min_calls(mut self, min_calls: u32) -> Self {
+        self.min_calls = min_calls;
+        self
+    }
+
+    /// Count calls and failures in windows of `window`.
+    #[must_use]
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Reject calls for `open_for` once the circuit opens.
+    #[must_use]
+    pub fn with_open_for(mut self, open_for: Duration) -> Self {
+        self.open_for = open_for;
+        self
+    }
+
+    /// Let `half_open_calls` probes through when half-open.
+    #[must_use]
+    pub fn with_half_open_calls(mut self, half_open_calls: u32) -> Self {
+        self.half_open_calls = half_open_calls;
+        self
+    }
+}
+
+/// The state of a tool's circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls run normally.
+    Closed,
+    /// Calls are rejected without running the tool.
+    Open,
+    /// A limited number of probe calls run to test whether the tool
+    /// recovered.
+    HalfOpen,
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half-open",
+        })
+    }
+}
+
+/// The circuit of one tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitStatus {
+    /// Qualified tool ID.
+    pub tool: String,
+    /// Name of the breaker that applies to the tool.
+    pub breaker: String,
+    /// State of the circuit.
+    pub state: CircuitState,
+    /// How long until an open circuit lets probes through.
+    pub retry_after: Option<Duration>,
+}
+
+/// Why a call was rejected by a circuit breaker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitOpen {
+    /// The circuit that rejected the call, either open or half-open with
+    /// all its probes in flight.
+    pub status: CircuitStatus,
+}
+
+impl fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Circuit of tool {} is {}",
+            self.status.tool, self.status.state
+        )?;
+        match self.status.retry_after {
+            Some(retry_after) => write!(f, ", retry after {:.3}s", retry_after.as_secs_f64()),
+            None => write!(f, " and waiting for probe calls"),
+        }
+    }
+}
+
+/// Enforces circuit breakers, keeping a circuit for each watched tool.
+#[derive(Debug)]
+pub struct CircuitBreakers {
+    breakers: Vec<(CircuitBreaker, CompiledPattern)>,
+    circuits: Mutex<HashMap<String, Circuit>>,
+    changes: watch::Sender<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Circuit {
+    Closed {
+        since: Instant,
+        calls: u32,
+        failures: u32,
+    },
+    Open {
+        until: Instant,
+    },
+    HalfOpen {
+        probing: u32,
+        succeeded: u32,
+    },
+}
+
+impl Circuit {
+    fn closed(now: Instant) -> Self {
+        Self::Closed {
+            since: now,
+            calls: 0,
+            failures: 0,
+        }
+    }
+
+    fn state(&self) -> CircuitState {
+        match self {
+            Self::Closed { .. } => CircuitState::Closed,
+            Self::Open { .. } => CircuitState::Open,
+            Self::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+}
+
+/// Permission for a call to run while its circuit is closed or half-open.
+///
+/// Record the outcome of the call with [`CircuitPermit::record`]. A probe
+/// dropped without an outcome frees its place for another probe.
+#[derive(Debug)]
+pub struct CircuitPermit<'a> {
+    breakers: &'a CircuitBreakers,
+    tool: String,
+    index: usize,
+    probe: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// Record whether the call succeeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the circuit lock is poisoned.
+    pub fn record(self, success: bool) {
+        self.breakers.record_at(self, success, Instant::now());
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe {
+            let mut circuits = self.breakers.circuits.lock().expect("lock poisoned");
+            if let Some(Circuit::HalfOpen { probing, .. }) = circuits.get_mut(&self.tool) {
+                *probing = probing.saturating_sub(1);
+            }
+        }
+    }
+}
+
+impl CircuitBreakers {
+    /// Create circuits for the tools watched by `breakers`.
+    #[must_use]
+    pub fn new(breakers: Vec<CircuitBreaker>) -> Self {
+        Self {
+            breakers: breakers
+                .into_iter()
+                .map(|breaker| {
+                    let pattern = CompiledPattern::new(&breaker.tool);
+                    (breaker, pattern)
+                })
+                .collect(),
+            circuits: Mutex::new(HashMap::new()),
+            changes: watch::Sender::new(0),
+        }
+    }
+
+    /// Return whether no breakers are configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.breakers.is_empty()
+    }
+
+    /// Return a receiver that is notified whenever a circuit changes state.
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<u64> {
+        self.changes.subscribe()
+    }
+
+    /// Return the circuit of `tool`, or `None` if no breaker watches it.
+    ///
+    /// An open circuit whose open period has passed is reported half-open.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the circuit lock is poisoned.
+    #[must_use]
+    pub fn status(&self, tool: &str) -> Option<CircuitStatus> {
+        self.status_at(tool, Instant::now())
+    }
+
+    fn status_at(&self, tool: &str, now: Instant) -> Option<CircuitStatus> {
+        let index = self.breaker_for(tool)?;
+        let circuits = self.circuits.lock().expect("lock poisoned");
+        let (state, retry_after) = match circuits.get(tool) {
+            Some(Circuit::Open { until }) if *until > now => {
+                (CircuitState::Open, Some(*until - now))
+            }
+            Some(Circuit::Open { .. }) => (CircuitState::HalfOpen, None),
+            Some(circuit) => (circuit.state(), None),
+            None => (CircuitState::Closed, None),
+        };
+        Some(CircuitStatus {
+            tool: tool.to_string(),
+            breaker: self.breakers[index].0.name.clone(),
+            state,
+            retry_after,
+        })
+    }
+
+    /// Let a call to `tool` through unless its circuit is open, or
+    /// half-open with all its probes in flight. Returns `None` if no breaker
+    /// watches the tool.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CircuitOpen`] if the call may not run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the circuit lock is poisoned.
+    pub fn check(&self, tool: &str) -> Result<Option<CircuitPermit<'_>>, CircuitOpen> {
+        self.check_at(tool, Instant::now())
+    }
+
+    fn check_at(&self, tool: &str, now: Instant) -> Result<Option<CircuitPermit<'_>>, CircuitOpen> {
+        let Some(index) = self.breaker_for(tool) else {
+            return Ok(None);
+        };
+        let breaker = &self.breakers[index].0;
+        let mut circuits = self.circuits.lock().expect("lock poisoned");
+        let circuit = circuits
+            .entry(tool.to_string())
+            .or_insert_with(|| Circuit::closed(now));
+        let rejected = |state, retry_after| CircuitOpen {
+            status: CircuitStatus {
+                tool: tool.to_string(),
+                breaker: breaker.name.clone(),
+                state,
+                retry_after,
+            },
+        };
+        let probe = match circuit {
+            Circuit::Closed { .. } => false,
+            Circuit::Open { until } if *until > now => {
+                return Err(rejected(CircuitState::Open, Some(*until - now)));
+            }
+            Circuit::Open { .. } => {
+                *circuit = Circuit::HalfOpen {
+                    probing: 1,
+                    succeeded: 0,
+                };
+                self.changed(tool, CircuitState::HalfOpen);
+                true
+            }
+            Circuit::HalfOpen { probing, succeeded } => {
+                if *probing + *succeeded >= breaker.half_open_calls {
+                    return Err(rejected(CircuitState::HalfOpen, None));
+                }
+                *probing += 1;
+                true
+            }
+        };
+        Ok(Some(CircuitPermit {
+            breakers: self,
+            tool: tool.to_string(),
+            index,
+            probe,
+        }))
+    }
+
+    fn record_at(&self, mut permit: CircuitPermit<'_>, success: bool, now: Instant) {
+        let probe = std::mem::take(&mut permit.probe);
+        let breaker = &self.breakers[permit.index].0;
+        let mut circuits = self.circuits.lock().expect("lock poisoned");
+        let Some(circuit) = circuits.get_mut(&permit.tool) else {
+            return;
+        };
+        let open = Circuit::Open {
+            until: now + breaker.open_for,
+        };
+        match circuit {
+            Circuit::Closed {
+                since,
+                calls,
+                failures,
+            } if !probe => {
+                if now.saturating_duration_since(*since) >= breaker.window {
+                    *since = now;
+                    *calls = 0;
+                    *failures = 0;
+                }
+                *calls += 1;
+                *failures += u32::from(!success);
+                if *calls >= breaker.min_calls
+                    && f64::from(*failures) >= breaker.failure_rate * f64::from(*calls)
+                {
+                    warn!(
+                        tool = %permit.tool,
+                        breaker = %breaker.name,
+                        calls = *calls,
+                        failures = *failures,
+                        "Circuit opened"
+                    );
+                    *circuit = open;
+                    self.changed(&permit.tool, CircuitState::Open);
+                }
+            }
+            Circuit::HalfOpen { probing, succeeded } if probe => {
+                *probing = probing.saturating_sub(1);
+                if success {
+                    *succeeded += 1;
+                    if *succeeded >= breaker.half_open_calls {
+                        *circuit = Circuit::closed(now);
+                        self.changed(&permit.tool, CircuitState::Closed);
+                    }
+                } else {
+                    warn!(tool = %permit.tool, breaker = %breaker.name, "Circuit reopened");
+                    *circuit = open;
+                    self.changed(&permit.tool, CircuitState::Open);
+                }
+            }
+            // Outcomes of calls let through in an earlier state do not count.
+            _ => {}
+        }
+    }
+
+    fn changed(&self, tool: &str, state: CircuitState) {
+        info!(tool = %tool, state = %state, "Circuit state changed");
+        self.changes.send_modify(|version| *version += 1);
+    }
+
+    fn breaker_for(&self, tool: &str) -> Option<usize> {
+        self.breakers
+            .iter()
+            .position(|(_, pattern)| pattern.matches(tool))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakers() -> CircuitBreakers {
+        CircuitBreakers::new(vec![
+            CircuitBreaker::new("search")
+                .with_tool("search.*")
+                .with_min_calls(4)
+                .with_failure_rate(0.5)
+                .with_window(Duration::from_secs(10))
+                .with_open_for(Duration::from_secs(30))
+                .with_half_open_calls(2),
+        ])
+    }
+
+    fn call(breakers: &CircuitBreakers, now: Instant, success: bool) {
+        let permit = breakers
+            .check_at("search.web", now)
+            .expect("circuit should let the call through")
+            .expect("breaker should watch the tool");
+        breakers.record_at(permit, success, now);
+    }
+
+    #[test]
+    fn test_circuit_opens_at_failure_rate_and_closes_after_probes() {
+        let breakers = breakers();
+        let mut changes = breakers.subscribe();
+        let start = Instant::now();
+        assert!(breakers.check_at("mail.send", start).unwrap().is_none());
+
+        // Failures of an earlier window do not count.
+        call(&breakers, start, false);
+        call(&breakers, start, false);
+        let later = start + Duration::from_secs(10);
+        call(&breakers, later, true);
+        call(&breakers, later, false);
+        call(&breakers, later, true);
+        assert_eq!(
+            breakers.status_at("search.web", later).unwrap().state,
+            CircuitState::Closed
+        );
+        call(&breakers, later, false);
+        assert!(changes.has_changed().unwrap());
+        changes.mark_unchanged();
+
+        let rejection = breakers
+            .check_at("search.web", later + Duration::from_secs(20))
+            .expect_err("circuit should be open");
+        assert_eq!(rejection.status.retry_after, Some(Duration::from_secs(10)));
+        assert_eq!(
+            rejection.to_string(),
+            "Circuit of tool search.web is open, retry after 10.000s"
+        );
+
+        let reopen = later + Duration::from_secs(30);
+        assert_eq!(
+            breakers.status_at("search.web", reopen).unwrap().state,
+            CircuitState::HalfOpen
+        );
+        let first = breakers.check_at("search.web", reopen).unwrap().unwrap();
+        let second = breakers.check_at("search.web", reopen).unwrap().unwrap();
+        let rejection = breakers
+            .check_at("search.web", reopen)
+            .expect_err("both probes are in flight");
+        assert_eq!(
+            rejection.to_string(),
+            "Circuit of tool search.web is half-open and waiting for probe calls"
+        );
+        // A probe that ends without an outcome frees its place.
+        drop(second);
+        let second = breakers.check_at("search.web", reopen).unwrap().unwrap();
+        breakers.record_at(first, true, reopen);
+        breakers.record_at(second, true, reopen);
+        assert_eq!(
+            breakers.status_at("search.web", reopen).unwrap().state,
+            CircuitState::Closed
+        );
+        assert!(changes.has_changed().unwrap());
+    }
+
+    #[test]
+    fn test_failed_probe_reopens_circuit() {
+        let breakers = breakers();
+        let start = Instant::now();
+        for _ in 0..4 {
+            call(&breakers, start, false);
+        }
+        let reopen = start + Duration::from_secs(30);
+        let probe = breakers.check_at("search.web", reopen).unwrap().unwrap();
+        // A call let through before the circuit opened does not count.
+        breakers.record_at(
+            CircuitPermit {
+                breakers: &breakers,
+                tool: "search.web".to_string(),
+                index: 0,
+                probe: false,
+            },
+            true,
+            reopen,
+        );
+        breakers.record_at(probe, false, reopen);
+        let status = breakers.status_at("search.web", reopen).unwrap();
+        assert_eq!(status.state, CircuitState::Open);
+        assert_eq!(status.breaker, "search");
+        assert_eq!(status.retry_after, Some(Duration::from_secs(30)));
+    }
+}
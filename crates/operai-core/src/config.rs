@@ -46,12 +46,23 @@ use std::{
     collections::HashMap,
     fs,
     path::{Path, PathBuf},
+    time::Duration,
 };
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
-use crate::Policy;
+use crate::{
+    Policy,
+    admission::{AdmissionController, ToolLimits},
+    circuit::CircuitBreaker,
+    policy::{
+        CombiningAlgorithm, PolicyMode, budget::Budget, history::HistorySettings,
+        scope::PolicyScope, session::SessionLimits,
+    },
+    rate_limit::{RateLimit, RateLimitKey},
+    retry::RetryPolicy,
+};
 
 /// Unified configuration file type for all Operai config files.
 ///
@@ -64,6 +75,10 @@ use crate::Policy;
 /// Most users should use `Config` directly rather than working with this enum.
 /// Use `ConfigFile::resolve()` when you need the unified resolution algorithm.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "a config file is loaded once; boxing the project config is not worth the indirection"
+)]
 pub enum ConfigFile {
     /// Project configuration from `operai.toml`.
     Project(Config),
@@ -286,12 +301,68 @@ pub struct Config {
     #[serde(default)]
     pub policies: Vec<PolicyConfig>,
 
+    /// How policy decisions are combined.
+    ///
+    /// Defaults to deny-overrides.
+    #[serde(default)]
+    pub policy_evaluation: PolicyEvaluationConfig,
+
     /// Project-specific embedding configuration.
     ///
     /// This optional section allows overriding embedding settings for this
     /// project.
     pub embedding: Option<ProjectEmbeddingConfig>,
 
+    /// Policy session storage configuration.
+    ///
+    /// Selects where policy session state (context variables and counters)
+    /// is kept. Defaults to in-memory storage.
+    #[serde(default)]
+    pub sessions: SessionsConfig,
+
+    /// Human approval configuration.
+    ///
+    /// Controls how long calls held by `require_approval` policy effects wait
+    /// for a decision.
+    #[serde(default)]
+    pub approvals: ApprovalsConfig,
+
+    /// Cost budgets for tool calls.
+    ///
+    /// Calls that would exceed a budget are rejected before they run.
+    #[serde(default)]
+    pub budgets: Vec<BudgetConfig>,
+
+    /// Token-bucket rate limits for tool calls.
+    ///
+    /// Calls over a limit are rejected before policies are evaluated.
+    #[serde(default)]
+    pub rate_limits: Vec<RateLimitConfig>,
+
+    /// Circuit breakers for repeatedly failing tools.
+    ///
+    /// Calls to a tool whose circuit is open fail right before admission,
+    /// after rate limits, approvals and the response cache were checked.
+    #[serde(default)]
+    pub circuit_breakers: Vec<CircuitBreakerConfig>,
+
+    /// Server-wide admission control.
+    ///
+    /// Caps the tool calls in flight across all tools.
+    #[serde(default)]
+    pub admission: AdmissionConfig,
+
+    /// Retries of failed calls to idempotent tools.
+    ///
+    /// Only tools with an `## Idempotent` doc heading are retried.
+    #[serde(default)]
+    pub retries: RetryConfig,
+
+    /// Cache of the results of read-only tools.
+    ///
+    /// Results are only cached if this section is present.
+    pub cache: Option<CacheConfig>,
+
     /// Arbitrary configuration data.
     ///
     /// This can contain any TOML table with project-specific configuration
@@ -352,6 +423,15 @@ impl Config {
             tools: Vec::new(),
             policies: Vec::new(),
             embedding: None,
+            policy_evaluation: PolicyEvaluationConfig::default(),
+            sessions: SessionsConfig::default(),
+            approvals: ApprovalsConfig::default(),
+            budgets: Vec::new(),
+            rate_limits: Vec::new(),
+            circuit_breakers: Vec::new(),
+            admission: AdmissionConfig::default(),
+            retries: RetryConfig::default(),
+            cache: None,
             config: None,
         }
     }
@@ -404,11 +484,7 @@ impl Config {
 
         for policy_config in &self.policies {
             if let Some(rel_path) = &policy_config.path {
-                let policy_path = root_dir.join(rel_path);
-                let content = fs::read_to_string(&policy_path)?;
-                // Assume policy file is a TOML defining a single Policy or Policy fields
-                let policy: Policy = toml::from_str(&content).map_err(ConfigError::Parse)?;
-                policies.push(policy);
+                policies.push(Self::load_policy_file(&root_dir.join(rel_path))?);
             } else {
                 let name = policy_config.name.clone().ok_or_else(|| {
                     ConfigError::Project("Inline policy must have a name".to_string())
@@ -423,7 +499,11 @@ impl Config {
                 policies.push(Policy {
                     name,
                     version,
+                    priority: policy_config.priority,
+                    mode: policy_config.mode,
                     context,
+                    global_context: policy_config.global_context.clone().unwrap_or_default(),
+                    caller_context: policy_config.caller_context.clone().unwrap_or_default(),
                     effects,
                 });
             }
@@ -431,6 +511,226 @@ impl Config {
 
         Ok(policies)
     }
+
+    /// Returns the paths of the external policy files, resolved against the
+    /// directory of `config_path`, in config order.
+    #[must_use]
+    pub fn policy_files(&self, config_path: &Path) -> Vec<PathBuf> {
+        let root_dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+        self.policies
+            .iter()
+            .filter_map(|policy_config| policy_config.path.as_ref())
+            .map(|rel_path| root_dir.join(rel_path))
+            .collect()
+    }
+
+    /// Loads a policy from an external policy file, a TOML document with the
+    /// fields of a single policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if the file cannot be read or parsed.
+    pub fn load_policy_file(path: &Path) -> Result<Policy, ConfigError> {
+        let content = fs::read_to_string(path)?;
+        toml::from_str(&content).map_err(ConfigError::Parse)
+    }
+
+    /// Returns the configured cost budgets.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError::Project)` if two budgets share a name, a
+    /// limit is negative or not finite, or a window is zero.
+    pub fn budgets(&self) -> Result<Vec<Budget>, ConfigError> {
+        let mut budgets: Vec<Budget> = Vec::with_capacity(self.budgets.len());
+        for config in &self.budgets {
+            if budgets.iter().any(|budget| budget.name == config.name) {
+                return Err(ConfigError::Project(format!(
+                    "Duplicate budget name: {}",
+                    config.name
+                )));
+            }
+            if !config.limit.is_finite() || config.limit < 0.0 {
+                return Err(ConfigError::Project(format!(
+                    "Budget {} must have a non-negative limit",
+                    config.name
+                )));
+            }
+            let mut budget = Budget::new(&config.name, config.limit)
+                .with_tool(&config.tool)
+                .with_scope(config.scope);
+            match config.window_secs {
+                Some(0) => {
+                    return Err(ConfigError::Project(format!(
+                        "Budget {} must have a non-zero window",
+                        config.name
+                    )));
+                }
+                Some(secs) => budget = budget.with_window(Duration::from_secs(secs)),
+                None => {}
+            }
+            budgets.push(budget);
+        }
+        Ok(budgets)
+    }
+
+    /// Returns the configured rate limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError::Project)` if two rate limits share a name,
+    /// or a limit allows no requests, has a zero interval, or a zero burst.
+    pub fn rate_limits(&self) -> Result<Vec<RateLimit>, ConfigError> {
+        let mut limits: Vec<RateLimit> = Vec::with_capacity(self.rate_limits.len());
+        for config in &self.rate_limits {
+            if limits.iter().any(|limit| limit.name == config.name) {
+                return Err(ConfigError::Project(format!(
+                    "Duplicate rate limit name: {}",
+                    config.name
+                )));
+            }
+            if config.requests == 0 || config.interval_secs == 0 || config.burst == Some(0) {
+                return Err(ConfigError::Project(format!(
+                    "Rate limit {} must allow at least one request per non-zero interval",
+                    config.name
+                )));
+            }
+            let limit = RateLimit::new(
+                &config.name,
+                config.requests,
+                Duration::from_secs(config.interval_secs),
+            )
+            .with_tool(&config.tool)
+            .with_key(config.key.clone());
+            limits.push(match config.burst {
+                Some(burst) => limit.with_burst(burst),
+                None => limit,
+            });
+        }
+        Ok(limits)
+    }
+
+    /// Returns the circuit breakers of the `[[circuit_breakers]]` sections.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError::Project)` if two breakers share a name, or a
+    /// breaker has a failure rate outside `(0, 1]` or a zero `min_calls`,
+    /// window, open period or `half_open_calls`.
+    pub fn circuit_breakers(&self) -> Result<Vec<CircuitBreaker>, ConfigError> {
+        let mut breakers: Vec<CircuitBreaker> = Vec::with_capacity(self.circuit_breakers.len());
+        for config in &self.circuit_breakers {
+            if breakers.iter().any(|breaker| breaker.name == config.name) {
+                return Err(ConfigError::Project(format!(
+                    "Duplicate circuit breaker name: {}",
+                    config.name
+                )));
+            }
+            if !(config.failure_rate > 0.0 && config.failure_rate <= 1.0) {
+                return Err(ConfigError::Project(format!(
+                    "Circuit breaker {} must have a failure rate above 0 and at most 1",
+                    config.name
+                )));
+            }
+            if config.min_calls == 0
+                || config.window_secs == 0
+                || config.open_secs == 0
+                || config.half_open_calls == 0
+            {
+                return Err(ConfigError::Project(format!(
+                    "Circuit breaker {} must have non-zero min_calls, window_secs, open_secs and \
+                     half_open_calls",
+                    config.name
+                )));
+            }
+            breakers.push(
+                CircuitBreaker::new(&config.name)
+                    .with_tool(&config.tool)
+                    .with_failure_rate(config.failure_rate)
+                    .with_min_calls(config.min_calls)
+                    .with_window(Duration::from_secs(config.window_secs))
+                    .with_open_for(Duration::from_secs(config.open_secs))
+                    .with_half_open_calls(config.half_open_calls),
+            );
+        }
+        Ok(breakers)
+    }
+
+    /// Returns an admission controller enforcing the server-wide in-flight
+    /// cap of the `[admission]` section.
+    ///
+    /// Per-tool limits are added once the tools are loaded; see
+    /// [`ToolConfig::tool_limits`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError::Project)` if `max_inflight` is zero.
+    pub fn admission(&self) -> Result<AdmissionController, ConfigError> {
+        let controller = AdmissionController::new();
+        match self.admission.max_inflight {
+            None => Ok(controller),
+            Some(0) => Err(ConfigError::Project(
+                "admission.max_inflight must be at least 1".to_string(),
+            )),
+            Some(max_inflight) => {
+                Ok(controller.with_max_inflight(max_inflight, self.admission.max_queue))
+            }
+        }
+    }
+
+    /// Returns the retry policy of the `[retries]` section.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError::Project)` if `max_attempts` is zero, the
+    /// multiplier is below 1, or the initial backoff exceeds the maximum.
+    pub fn retries(&self) -> Result<RetryPolicy, ConfigError> {
+        let config = &self.retries;
+        if config.max_attempts == 0 {
+            return Err(ConfigError::Project(
+                "retries.max_attempts must be at least 1".to_string(),
+            ));
+        }
+        if !(config.multiplier.is_finite() && config.multiplier >= 1.0) {
+            return Err(ConfigError::Project(
+                "retries.multiplier must be at least 1".to_string(),
+            ));
+        }
+        if config.initial_backoff_ms > config.max_backoff_ms {
+            return Err(ConfigError::Project(
+                "retries.initial_backoff_ms must not exceed retries.max_backoff_ms".to_string(),
+            ));
+        }
+        Ok(RetryPolicy::new()
+            .with_max_attempts(config.max_attempts)
+            .with_initial_backoff(Duration::from_millis(config.initial_backoff_ms))
+            .with_max_backoff(Duration::from_millis(config.max_backoff_ms))
+            .with_multiplier(config.multiplier)
+            .with_jitter(config.jitter))
+    }
+
+    /// Returns the `[cache]` section, if results are cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError::Project)` if `max_entries` or `max_bytes` is
+    /// zero.
+    pub fn cache(&self) -> Result<Option<&CacheConfig>, ConfigError> {
+        let Some(config) = &self.cache else {
+            return Ok(None);
+        };
+        if config.max_entries == 0 {
+            return Err(ConfigError::Project(
+                "cache.max_entries must be at least 1".to_string(),
+            ));
+        }
+        if config.max_bytes == 0 {
+            return Err(ConfigError::Project(
+                "cache.max_bytes must be at least 1".to_string(),
+            ));
+        }
+        Ok(Some(config))
+    }
 }
 
 /// Credentials configuration from `~/.config/operai/credentials.toml`.
@@ -489,6 +789,80 @@ pub struct ToolConfig {
     /// Optional credentials for this specific tool.
     #[serde(default)]
     pub credentials: HashMap<String, HashMap<String, String>>,
+
+    /// Limits applied to each tool of the library.
+    #[serde(flatten)]
+    pub limits: ToolLimitsConfig,
+
+    /// Limits for individual tools of the library, keyed by tool ID within
+    /// the library. Fields that are set override those of `limits`.
+    #[serde(default)]
+    pub overrides: HashMap<String, ToolLimitsConfig>,
+}
+
+impl ToolConfig {
+    /// Returns the limits of the library's tool `tool_id`, its ID without
+    /// the crate name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError::Project)` if the timeout or the concurrency
+    /// limit is zero.
+    pub fn tool_limits(&self, tool_id: &str) -> Result<ToolLimits, ConfigError> {
+        let config = match self.overrides.get(tool_id) {
+            Some(overrides) => ToolLimitsConfig {
+                timeout_secs: overrides.timeout_secs.or(self.limits.timeout_secs),
+                max_concurrency: overrides.max_concurrency.or(self.limits.max_concurrency),
+                max_queue: overrides.max_queue.or(self.limits.max_queue),
+            },
+            None => self.limits.clone(),
+        };
+        if config.timeout_secs == Some(0) || config.max_concurrency == Some(0) {
+            return Err(ConfigError::Project(format!(
+                "Tool {tool_id} must have a non-zero timeout and concurrency limit"
+            )));
+        }
+        Ok(ToolLimits {
+            timeout: config.timeout_secs.map(Duration::from_secs),
+            max_concurrency: config.max_concurrency,
+            max_queue: config.max_queue.unwrap_or_default(),
+        })
+    }
+}
+
+/// Concurrency and timeout limits for the calls of a tool.
+///
+/// # Examples
+///
+/// Run at most four calls of each tool of a library at once, letting eight
+/// more wait, and give its `crawl` tool two minutes instead of 30 seconds:
+/// ```toml
+/// [[tools]]
+/// name = "web"
+/// timeout_secs = 30
+/// max_concurrency = 4
+/// max_queue = 8
+///
+/// [tools.overrides.crawl]
+/// timeout_secs = 120
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ToolLimitsConfig {
+    /// Seconds a call may wait for a slot and run before it fails.
+    ///
+    /// Unlimited by default.
+    pub timeout_secs: Option<u64>,
+
+    /// Calls that may run at once.
+    ///
+    /// Unlimited by default.
+    pub max_concurrency: Option<u32>,
+
+    /// Calls that may wait for a slot once `max_concurrency` calls run;
+    /// further calls are rejected.
+    ///
+    /// Defaults to 0.
+    pub max_queue: Option<u32>,
 }
 
 fn default_enabled() -> bool {
@@ -506,6 +880,15 @@ pub struct PolicyConfig {
     /// Optional version for inline policies.
     pub version: Option<String>,
 
+    /// Evaluation priority for inline policies (higher runs first, default 0).
+    #[serde(default)]
+    pub priority: i32,
+
+    /// Whether an inline policy is enforced or only evaluated in shadow
+    /// (`enforce` or `shadow`, default `enforce`).
+    #[serde(default)]
+    pub mode: PolicyMode,
+
     /// Path to an external policy file (mutually exclusive with inline fields).
     pub path: Option<String>,
 
@@ -513,6 +896,14 @@ pub struct PolicyConfig {
     #[serde(default)]
     pub context: Option<HashMap<String, JsonValue>>,
 
+    /// Initial variables of the global context shared by every call.
+    #[serde(default)]
+    pub global_context: Option<HashMap<String, JsonValue>>,
+
+    /// Initial variables of each authenticated caller's context.
+    #[serde(default)]
+    pub caller_context: Option<HashMap<String, JsonValue>>,
+
     /// Policy effects (when policies execute and what they do).
     #[serde(default)]
     pub effects: Option<Vec<crate::policy::Effect>>,
@@ -526,24 +917,32 @@ pub struct PolicyConfig {
 ///
 /// Local embeddings (default):
 /// ```toml
-/// [embeddings]
+/// [embedding]
 /// type = "local"
 /// model = "nomic-embed-text-v1.5"
 /// ```
 ///
 /// Remote embeddings:
 /// ```toml
-/// [embeddings]
+/// [embedding]
 /// type = "remote"
 /// kind = "openai"
 /// model = "text-embedding-3-small"
 /// ```
+///
+/// Offline hashed embeddings (no model download or API key):
+/// ```toml
+/// [embedding]
+/// type = "hashing"
+/// dimensions = 256
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectEmbeddingConfig {
-    /// Embedding type: "local" or "remote".
+    /// Embedding type: "local", "remote", or "hashing".
     ///
     /// - "local" uses local models via `embed_anything` (default)
     /// - "remote" uses cloud API endpoints
+    /// - "hashing" uses the deterministic offline hashing embedder
     #[serde(default = "default_embedding_type")]
     pub r#type: String,
 
@@ -558,6 +957,624 @@ pub struct ProjectEmbeddingConfig {
     /// "nomic-embed-text-v1.5") For remote embeddings: Model identifier for
     /// the provider (e.g., "text-embedding-3-small")
     pub model: Option<String>,
+
+    /// Vector dimension for the hashing embedder (defaults to 256).
+    ///
+    /// Ignored by other embedding types, whose dimension is fixed by the
+    /// model.
+    pub dimensions: Option<usize>,
+}
+
+/// Policy evaluation configuration.
+///
+/// # Examples
+///
+/// Let the first effect that allows or denies a call decide:
+/// ```toml
+/// [policy_evaluation]
+/// combining = "first_applicable"
+/// ```
+///
+/// Check policy files for changes every 10 seconds, or never with `0`:
+/// ```toml
+/// [policy_evaluation]
+/// watch_interval_secs = 10
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyEvaluationConfig {
+    /// Algorithm used to combine allow and deny decisions
+    /// (`deny_overrides` or `first_applicable`).
+    #[serde(default)]
+    pub combining: CombiningAlgorithm,
+
+    /// Seconds between checks of the external policy files for changes.
+    /// A changed file is reloaded; `0` disables reloading.
+    ///
+    /// Defaults to 2.
+    #[serde(default = "default_policy_watch_interval_secs")]
+    pub watch_interval_secs: u64,
+}
+
+impl PolicyEvaluationConfig {
+    /// Returns how often policy files are checked for changes, or `None` if
+    /// they are not reloaded.
+    #[must_use]
+    pub fn watch_interval(&self) -> Option<Duration> {
+        (self.watch_interval_secs > 0).then(|| Duration::from_secs(self.watch_interval_secs))
+    }
+}
+
+impl Default for PolicyEvaluationConfig {
+    fn default() -> Self {
+        Self {
+            combining: CombiningAlgorithm::default(),
+            watch_interval_secs: default_policy_watch_interval_secs(),
+        }
+    }
+}
+
+/// Policy session storage configuration.
+///
+/// # Examples
+///
+/// In-memory sessions (default, lost on restart):
+/// ```toml
+/// [sessions]
+/// backend = "memory"
+/// ```
+///
+/// Persistent sessions shared by every process using the same database file:
+/// ```toml
+/// [sessions]
+/// backend = "sqlite"
+/// path = "data/sessions.db"
+/// ```
+///
+/// Expire sessions idle for 30 minutes and keep at most 10,000:
+/// ```toml
+/// [sessions]
+/// idle_ttl_secs = 1800
+/// max_sessions = 10000
+/// ```
+///
+/// Keep the last 500 calls of each session, with input digests:
+/// ```toml
+/// [sessions]
+/// history_limit = 500
+/// history_input_digests = true
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionsConfig {
+    /// Storage backend for policy sessions.
+    #[serde(default)]
+    pub backend: SessionBackend,
+
+    /// Database file for the `sqlite` backend.
+    ///
+    /// Relative paths are resolved against the config file's directory.
+    /// Defaults to `operai-sessions.db`.
+    pub path: Option<PathBuf>,
+
+    /// Seconds since its last save after which a session is discarded.
+    ///
+    /// Unset means sessions never expire.
+    pub idle_ttl_secs: Option<u64>,
+
+    /// Maximum number of stored sessions; the least recently saved sessions
+    /// are evicted when a new one would exceed it.
+    pub max_sessions: Option<usize>,
+
+    /// Number of recent calls kept in each session's history; 0 disables
+    /// history.
+    ///
+    /// Defaults to 100.
+    pub history_limit: Option<usize>,
+
+    /// Whether history records include a SHA-256 digest of the call input.
+    #[serde(default)]
+    pub history_input_digests: bool,
+}
+
+impl SessionsConfig {
+    /// Returns the `SQLite` database path, resolved against `config_dir`.
+    #[must_use]
+    pub fn resolve_path(&self, config_dir: &Path) -> PathBuf {
+        let path = self
+            .path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("operai-sessions.db"));
+        if path.is_absolute() {
+            path
+        } else {
+            config_dir.join(path)
+        }
+    }
+
+    /// Returns the idle TTL and session cap to apply to the session store.
+    #[must_use]
+    pub fn limits(&self) -> SessionLimits {
+        SessionLimits {
+            idle_ttl: self.idle_ttl_secs.map(Duration::from_secs),
+            max_sessions: self.max_sessions,
+        }
+    }
+
+    /// Returns how much call history to keep per session.
+    #[must_use]
+    pub fn history(&self) -> HistorySettings {
+        let defaults = HistorySettings::default();
+        HistorySettings {
+            max_entries: self.history_limit.unwrap_or(defaults.max_entries),
+            input_digests: self.history_input_digests,
+        }
+    }
+}
+
+/// Human approval configuration.
+///
+/// # Examples
+///
+/// Give approvers 15 minutes to decide a held call:
+/// ```toml
+/// [approvals]
+/// timeout_secs = 900
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalsConfig {
+    /// Seconds a held call waits for a decision before it fails; an approval
+    /// must also be claimed by a retried call within this time.
+    ///
+    /// Defaults to 300.
+    #[serde(default = "default_approval_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl ApprovalsConfig {
+    /// Returns the approval timeout.
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+}
+
+impl Default for ApprovalsConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: default_approval_timeout_secs(),
+        }
+    }
+}
+
+/// A circuit breaker for repeatedly failing tools.
+///
+/// Each tool matching `tool` gets its own circuit; if several breakers match
+/// a tool, the first one applies.
+///
+/// # Examples
+///
+/// Stop calling a search tool for a minute once 3 of its last 10 calls
+/// failed:
+/// ```toml
+/// [[circuit_breakers]]
+/// name = "search"
+/// tool = "search.*"
+/// failure_rate = 0.3
+/// min_calls = 10
+/// open_secs = 60
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Name of the circuit breaker, reported with the state of its circuits.
+    pub name: String,
+
+    /// Pattern of the tools the breaker watches.
+    ///
+    /// Defaults to `**` (every tool).
+    #[serde(default = "default_circuit_breaker_tool")]
+    pub tool: String,
+
+    /// Share of failed calls in a window at which the circuit opens.
+    ///
+    /// Defaults to 0.5.
+    #[serde(default = "default_circuit_breaker_failure_rate")]
+    pub failure_rate: f64,
+
+    /// Calls a window needs before its failure rate counts.
+    ///
+    /// Defaults to 5.
+    #[serde(default = "default_circuit_breaker_min_calls")]
+    pub min_calls: u32,
+
+    /// Length of the window in which calls and failures are counted, in
+    /// seconds.
+    ///
+    /// Defaults to 60.
+    #[serde(default = "default_circuit_breaker_window_secs")]
+    pub window_secs: u64,
+
+    /// Seconds an open circuit rejects calls before letting probes through.
+    ///
+    /// Defaults to 30.
+    #[serde(default = "default_circuit_breaker_open_secs")]
+    pub open_secs: u64,
+
+    /// Probe calls let through at a time when half-open, and successful
+    /// probes needed to close the circuit.
+    ///
+    /// Defaults to 1.
+    #[serde(default = "default_circuit_breaker_half_open_calls")]
+    pub half_open_calls: u32,
+}
+
+/// Default circuit breaker tool pattern matches every tool.
+fn default_circuit_breaker_tool() -> String {
+    "**".to_string()
+}
+
+/// Default circuit breaker failure rate is half of the calls.
+fn default_circuit_breaker_failure_rate() -> f64 {
+    0.5
+}
+
+/// Default circuit breaker minimum is 5 calls per window.
+fn default_circuit_breaker_min_calls() -> u32 {
+    5
+}
+
+/// Default circuit breaker window is a minute.
+fn default_circuit_breaker_window_secs() -> u64 {
+    60
+}
+
+/// Default circuit breaker open period is 30 seconds.
+fn default_circuit_breaker_open_secs() -> u64 {
+    30
+}
+
+/// Default circuit breaker lets one probe through.
+fn default_circuit_breaker_half_open_calls() -> u32 {
+    1
+}
+
+/// Server-wide admission control configuration.
+///
+/// # Examples
+///
+/// Run at most 64 tool calls at once, letting 128 more wait for a slot:
+/// ```toml
+/// [admission]
+/// max_inflight = 64
+/// max_queue = 128
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdmissionConfig {
+    /// Tool calls that may run at once across all tools.
+    ///
+    /// Unlimited by default.
+    pub max_inflight: Option<u32>,
+
+    /// Calls that may wait for a slot once `max_inflight` calls run;
+    /// further calls are rejected.
+    ///
+    /// Defaults to 0.
+    #[serde(default)]
+    pub max_queue: u32,
+}
+
+/// Configuration of retries of failed calls to idempotent tools.
+///
+/// # Examples
+///
+/// Make up to 5 attempts, backing off from 200 milliseconds to 10 seconds:
+/// ```toml
+/// [retries]
+/// max_attempts = 5
+/// initial_backoff_ms = 200
+/// max_backoff_ms = 10000
+/// ```
+///
+/// Never retry:
+/// ```toml
+/// [retries]
+/// max_attempts = 1
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Attempts a call may make, counting the first.
+    ///
+    /// Defaults to 3.
+    #[serde(default = "default_retry_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Milliseconds to back off before the first retry.
+    ///
+    /// Defaults to 100.
+    #[serde(default = "default_retry_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Longest backoff between two attempts, in milliseconds.
+    ///
+    /// Defaults to 5000.
+    #[serde(default = "default_retry_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+
+    /// Factor by which the backoff grows after each retry.
+    ///
+    /// Defaults to 2.
+    #[serde(default = "default_retry_multiplier")]
+    pub multiplier: f32,
+
+    /// Whether each backoff is lengthened by a random share of itself.
+    ///
+    /// Defaults to true.
+    #[serde(default = "default_retry_jitter")]
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_retry_max_attempts(),
+            initial_backoff_ms: default_retry_initial_backoff_ms(),
+            max_backoff_ms: default_retry_max_backoff_ms(),
+            multiplier: default_retry_multiplier(),
+            jitter: default_retry_jitter(),
+        }
+    }
+}
+
+/// Default retries make up to 3 attempts.
+fn default_retry_max_attempts() -> u32 {
+    3
+}
+
+/// Default initial retry backoff is 100 milliseconds.
+fn default_retry_initial_backoff_ms() -> u64 {
+    100
+}
+
+/// Default maximum retry backoff is 5 seconds.
+fn default_retry_max_backoff_ms() -> u64 {
+    5000
+}
+
+/// Default retry backoff doubles after each retry.
+fn default_retry_multiplier() -> f32 {
+    2.0
+}
+
+/// Default retry backoff has jitter.
+fn default_retry_jitter() -> bool {
+    true
+}
+
+/// Configuration of the cache of tool call results.
+///
+/// Tools declare how long their results may be reused with a `## Cache` doc
+/// section; `[[cache.tools]]` entries override that TTL for the tools they
+/// match, the first match winning, and a zero TTL disables caching.
+///
+/// # Examples
+///
+/// Cache up to 500 results, keep weather forecasts for 10 minutes and never
+/// cache search results:
+/// ```toml
+/// [cache]
+/// max_entries = 500
+///
+/// [[cache.tools]]
+/// tool = "weather.*"
+/// ttl_secs = 600
+///
+/// [[cache.tools]]
+/// tool = "search.*"
+/// ttl_secs = 0
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheConfig {
+    /// Results the cache may hold.
+    ///
+    /// Defaults to 1000.
+    #[serde(default = "default_cache_max_entries")]
+    pub max_entries: usize,
+
+    /// Serialized size of the results the cache may hold, in bytes.
+    ///
+    /// Defaults to 16 MiB.
+    #[serde(default = "default_cache_max_bytes")]
+    pub max_bytes: usize,
+
+    /// Whether results are keyed by the caller's credentials, so that
+    /// callers with different credentials never share results.
+    ///
+    /// Defaults to true.
+    #[serde(default = "default_cache_vary_by_credentials")]
+    pub vary_by_credentials: bool,
+
+    /// Per-tool TTLs overriding the ones tools declare.
+    #[serde(default)]
+    pub tools: Vec<CacheToolConfig>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: default_cache_max_entries(),
+            max_bytes: default_cache_max_bytes(),
+            vary_by_credentials: default_cache_vary_by_credentials(),
+            tools: Vec::new(),
+        }
+    }
+}
+
+/// TTL of the cached results of the tools matching a pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheToolConfig {
+    /// Tool ID pattern (`crate.tool`, `crate.*` or `*`).
+    pub tool: String,
+
+    /// Seconds a result is reused; 0 disables caching.
+    pub ttl_secs: u64,
+}
+
+impl CacheToolConfig {
+    /// Returns the TTL.
+    #[must_use]
+    pub fn ttl(&self) -> Duration {
+        Duration::from_secs(self.ttl_secs)
+    }
+}
+
+/// Default cache holds up to 1000 results.
+fn default_cache_max_entries() -> usize {
+    1000
+}
+
+/// Default cache holds up to 16 MiB of results.
+fn default_cache_max_bytes() -> usize {
+    16 * 1024 * 1024
+}
+
+/// Default cache keys results by credentials.
+fn default_cache_vary_by_credentials() -> bool {
+    true
+}
+
+/// A cost budget for tool calls.
+///
+/// A call costs what the tool reports, or else the static cost declared in
+/// its `## Cost` doc section.
+///
+/// # Examples
+///
+/// Let each caller spend at most 5.00 per day on search tools:
+/// ```toml
+/// [[budgets]]
+/// name = "search"
+/// tool = "search.*"
+/// scope = "caller"
+/// limit = 5.0
+/// window_secs = 86400
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetConfig {
+    /// Name of the budget, reported when it is exceeded.
+    pub name: String,
+
+    /// Pattern of the tools whose calls count against the budget.
+    ///
+    /// Defaults to `**` (every tool).
+    #[serde(default = "default_budget_tool")]
+    pub tool: String,
+
+    /// Where spend is shared: `session`, `caller` (per authenticated caller,
+    /// across sessions) or `global`.
+    ///
+    /// Defaults to `session`.
+    #[serde(default = "default_budget_scope")]
+    pub scope: PolicyScope,
+
+    /// Maximum total cost.
+    pub limit: f64,
+
+    /// Seconds after which spend resets, counted from the first call of a
+    /// period.
+    ///
+    /// Unset means spend never resets.
+    pub window_secs: Option<u64>,
+}
+
+/// A token-bucket rate limit for tool calls.
+///
+/// # Examples
+///
+/// Let each caller send at most 10 emails a minute, 3 at once, per session:
+/// ```toml
+/// [[rate_limits]]
+/// name = "mail"
+/// tool = "mail.*"
+/// key = ["caller", "session"]
+/// requests = 10
+/// interval_secs = 60
+/// burst = 3
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Name of the rate limit, reported when it is exceeded.
+    pub name: String,
+
+    /// Pattern of the tools whose calls are limited.
+    ///
+    /// Defaults to `**` (every tool).
+    #[serde(default = "default_rate_limit_tool")]
+    pub tool: String,
+
+    /// What separate buckets are kept for: any of `session`, `caller` and
+    /// `tool`. An empty list shares one bucket between all matching calls.
+    ///
+    /// Defaults to `["session"]`.
+    #[serde(default = "default_rate_limit_key")]
+    pub key: Vec<RateLimitKey>,
+
+    /// Calls allowed per interval.
+    pub requests: u32,
+
+    /// Length of the interval in seconds.
+    ///
+    /// Defaults to 60.
+    #[serde(default = "default_rate_limit_interval_secs")]
+    pub interval_secs: u64,
+
+    /// Calls allowed in a burst.
+    ///
+    /// Defaults to `requests`.
+    pub burst: Option<u32>,
+}
+
+/// Default rate limit tool pattern matches every tool.
+fn default_rate_limit_tool() -> String {
+    "**".to_string()
+}
+
+/// Default rate limit key is the session.
+fn default_rate_limit_key() -> Vec<RateLimitKey> {
+    vec![RateLimitKey::Session]
+}
+
+/// Default rate limit interval is a minute.
+fn default_rate_limit_interval_secs() -> u64 {
+    60
+}
+
+/// Default budget tool pattern matches every tool.
+fn default_budget_tool() -> String {
+    "**".to_string()
+}
+
+/// Default budget scope is the session.
+fn default_budget_scope() -> PolicyScope {
+    PolicyScope::Session
+}
+
+/// Default approval timeout is 5 minutes.
+fn default_approval_timeout_secs() -> u64 {
+    300
+}
+
+/// Policy files are checked for changes every 2 seconds by default.
+fn default_policy_watch_interval_secs() -> u64 {
+    2
+}
+
+/// Storage backend for policy sessions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionBackend {
+    /// Process-local storage; sessions are lost on restart.
+    #[default]
+    Memory,
+    /// `SQLite` database file; sessions persist and can be shared.
+    Sqlite,
 }
 
 /// Default embedding type is "local".
@@ -815,4 +1832,361 @@ when = "true"
         assert_eq!(config.policies.len(), 1);
         assert_eq!(config.policies[0].name.as_ref().unwrap(), "test-policy");
     }
+
+    #[test]
+    fn test_sessions_config_defaults_to_memory() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.sessions.backend, SessionBackend::Memory);
+        assert!(config.sessions.path.is_none());
+    }
+
+    #[test]
+    fn test_sessions_config_parses_sqlite_and_resolves_relative_path() {
+        let config: Config = toml::from_str(
+            r#"[sessions]
+backend = "sqlite"
+path = "data/sessions.db"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.sessions.backend, SessionBackend::Sqlite);
+        assert_eq!(
+            config.sessions.resolve_path(Path::new("/srv/project")),
+            PathBuf::from("/srv/project/data/sessions.db")
+        );
+        assert_eq!(config.sessions.limits(), SessionLimits::default());
+        assert_eq!(config.sessions.history(), HistorySettings::default());
+    }
+
+    #[test]
+    fn test_policy_evaluation_priority_and_mode_parse() {
+        let config: Config = toml::from_str(
+            r#"[policy_evaluation]
+combining = "first_applicable"
+
+[[policies]]
+name = "audit"
+priority = 10
+mode = "shadow"
+
+[[policies]]
+name = "guard"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.policy_evaluation.combining,
+            CombiningAlgorithm::FirstApplicable
+        );
+        let policies = config
+            .resolve_policies(Path::new("/srv/project/operai.toml"))
+            .unwrap();
+        assert_eq!(policies[0].priority, 10);
+        assert_eq!(policies[0].mode, PolicyMode::Shadow);
+        assert_eq!(policies[1].mode, PolicyMode::Enforce);
+
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(
+            config.policy_evaluation.combining,
+            CombiningAlgorithm::DenyOverrides
+        );
+        assert_eq!(
+            config.policy_evaluation.watch_interval(),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn test_policy_files_resolve_against_config_dir() {
+        let config: Config = toml::from_str(
+            r#"[policy_evaluation]
+watch_interval_secs = 0
+
+[[policies]]
+path = "policies/audit.toml"
+
+[[policies]]
+name = "inline"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.policy_evaluation.watch_interval(), None);
+        assert_eq!(
+            config.policy_files(Path::new("/srv/project/operai.toml")),
+            [PathBuf::from("/srv/project/policies/audit.toml")]
+        );
+    }
+
+    #[test]
+    fn test_approvals_config_timeout() {
+        let config: Config = toml::from_str("[approvals]\ntimeout_secs = 900\n").unwrap();
+        assert_eq!(config.approvals.timeout(), Duration::from_mins(15));
+
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.approvals.timeout(), Duration::from_mins(5));
+    }
+
+    #[test]
+    fn test_sessions_config_parses_limits() {
+        let config: Config = toml::from_str(
+            r"[sessions]
+idle_ttl_secs = 1800
+max_sessions = 100
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.sessions.limits(),
+            SessionLimits {
+                idle_ttl: Some(Duration::from_mins(30)),
+                max_sessions: Some(100),
+            }
+        );
+    }
+
+    #[test]
+    fn test_sessions_config_parses_history() {
+        let config: Config = toml::from_str(
+            r"[sessions]
+history_limit = 500
+history_input_digests = true
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.sessions.history(),
+            HistorySettings {
+                max_entries: 500,
+                input_digests: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_budgets_parse_with_defaults_and_are_validated() {
+        let config: Config = toml::from_str(
+            r#"[[budgets]]
+name = "search"
+tool = "search.*"
+scope = "caller"
+limit = 5.0
+window_secs = 86400
+
+[[budgets]]
+name = "total"
+limit = 20
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.budgets().unwrap(),
+            [
+                Budget::new("search", 5.0)
+                    .with_tool("search.*")
+                    .with_scope(PolicyScope::Caller)
+                    .with_window(Duration::from_hours(24)),
+                Budget::new("total", 20.0),
+            ]
+        );
+
+        for invalid in [
+            "[[budgets]]\nname = \"a\"\nlimit = -1",
+            "[[budgets]]\nname = \"a\"\nlimit = 1\nwindow_secs = 0",
+            "[[budgets]]\nname = \"a\"\nlimit = 1\n[[budgets]]\nname = \"a\"\nlimit = 2",
+        ] {
+            let config: Config = toml::from_str(invalid).unwrap();
+            assert!(matches!(config.budgets(), Err(ConfigError::Project(_))));
+        }
+    }
+
+    #[test]
+    fn test_rate_limits_parse_with_defaults_and_are_validated() {
+        let config: Config = toml::from_str(
+            r#"[[rate_limits]]
+name = "mail"
+tool = "mail.*"
+key = ["caller", "tool"]
+requests = 10
+interval_secs = 3600
+burst = 3
+
+[[rate_limits]]
+name = "session"
+requests = 100
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.rate_limits().unwrap(),
+            [
+                RateLimit::new("mail", 10, Duration::from_hours(1))
+                    .with_tool("mail.*")
+                    .with_key(vec![RateLimitKey::Caller, RateLimitKey::Tool])
+                    .with_burst(3),
+                RateLimit::new("session", 100, Duration::from_mins(1)),
+            ]
+        );
+
+        for invalid in [
+            "[[rate_limits]]\nname = \"a\"\nrequests = 0",
+            "[[rate_limits]]\nname = \"a\"\nrequests = 1\ninterval_secs = 0",
+            "[[rate_limits]]\nname = \"a\"\nrequests = 1\nburst = 0",
+            "[[rate_limits]]\nname = \"a\"\nrequests = 1\n[[rate_limits]]\nname = \"a\"\nrequests \
+             = 2",
+        ] {
+            let config: Config = toml::from_str(invalid).unwrap();
+            assert!(matches!(config.rate_limits(), Err(ConfigError::Project(_))));
+        }
+    }
+
+    #[test]
+    fn test_tool_limits_merge_overrides_and_are_validated() {
+        let config: Config = toml::from_str(
+            r#"[admission]
+max_inflight = 64
+max_queue = 128
+
+[[tools]]
+name = "web"
+timeout_secs = 30
+max_concurrency = 4
+max_queue = 8
+
+[tools.overrides.crawl]
+timeout_secs = 120
+
+[tools.overrides.fetch]
+max_concurrency = 0
+"#,
+        )
+        .unwrap();
+        assert!(config.admission().is_ok());
+
+        let tool = &config.tools[0];
+        assert_eq!(
+            tool.tool_limits("search").unwrap(),
+            ToolLimits::default()
+                .with_timeout(Duration::from_secs(30))
+                .with_max_concurrency(4)
+                .with_max_queue(8)
+        );
+        assert_eq!(
+            tool.tool_limits("crawl").unwrap(),
+            ToolLimits::default()
+                .with_timeout(Duration::from_mins(2))
+                .with_max_concurrency(4)
+                .with_max_queue(8)
+        );
+        assert!(matches!(
+            tool.tool_limits("fetch"),
+            Err(ConfigError::Project(_))
+        ));
+
+        let config: Config = toml::from_str("[admission]\nmax_inflight = 0").unwrap();
+        assert!(matches!(config.admission(), Err(ConfigError::Project(_))));
+    }
+
+    #[test]
+    fn test_circuit_breakers_parse_with_defaults_and_are_validated() {
+        let config: Config = toml::from_str(
+            r#"[[circuit_breakers]]
+name = "search"
+tool = "search.*"
+failure_rate = 0.25
+min_calls = 10
+window_secs = 120
+open_secs = 60
+half_open_calls = 2
+
+[[circuit_breakers]]
+name = "all"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.circuit_breakers().unwrap(),
+            [
+                CircuitBreaker::new("search")
+                    .with_tool("search.*")
+                    .with_failure_rate(0.25)
+                    .with_min_calls(10)
+                    .with_window(Duration::from_mins(2))
+                    .with_open_for(Duration::from_mins(1))
+                    .with_half_open_calls(2),
+                CircuitBreaker::new("all"),
+            ]
+        );
+
+        for invalid in [
+            "[[circuit_breakers]]\nname = \"a\"\nfailure_rate = 0.0",
+            "[[circuit_breakers]]\nname = \"a\"\nfailure_rate = 1.5",
+            "[[circuit_breakers]]\nname = \"a\"\nmin_calls = 0",
+            "[[circuit_breakers]]\nname = \"a\"\nopen_secs = 0",
+            "[[circuit_breakers]]\nname = \"a\"\n[[circuit_breakers]]\nname = \"a\"",
+        ] {
+            let config: Config = toml::from_str(invalid).unwrap();
+            assert!(matches!(
+                config.circuit_breakers(),
+                Err(ConfigError::Project(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_retries_parse_with_defaults_and_are_validated() {
+        assert_eq!(Config::empty().retries().unwrap(), RetryPolicy::new());
+
+        let config: Config = toml::from_str(
+            "[retries]\nmax_attempts = 5\ninitial_backoff_ms = 200\nmultiplier = 1.5\njitter = \
+             false",
+        )
+        .unwrap();
+        assert_eq!(
+            config.retries().unwrap(),
+            RetryPolicy::new()
+                .with_max_attempts(5)
+                .with_initial_backoff(Duration::from_millis(200))
+                .with_multiplier(1.5)
+                .with_jitter(false)
+        );
+
+        for invalid in [
+            "[retries]\nmax_attempts = 0",
+            "[retries]\nmultiplier = 0.5",
+            "[retries]\ninitial_backoff_ms = 6000",
+        ] {
+            let config: Config = toml::from_str(invalid).unwrap();
+            assert!(matches!(config.retries(), Err(ConfigError::Project(_))));
+        }
+    }
+
+    #[test]
+    fn test_cache_is_opt_in_and_validated() {
+        assert!(Config::empty().cache().unwrap().is_none());
+
+        let config: Config = toml::from_str(
+            "[cache]\nvary_by_credentials = false\n\n[[cache.tools]]\ntool = \
+             \"weather.*\"\nttl_secs = 600",
+        )
+        .unwrap();
+        let cache = config.cache().unwrap().unwrap();
+        assert_eq!(cache.max_entries, 1000);
+        assert_eq!(cache.max_bytes, 16 * 1024 * 1024);
+        assert!(!cache.vary_by_credentials);
+        assert_eq!(cache.tools[0].tool, "weather.*");
+        assert_eq!(cache.tools[0].ttl(), Duration::from_mins(10));
+
+        for invalid in ["[cache]\nmax_entries = 0", "[cache]\nmax_bytes = 0"] {
+            let config: Config = toml::from_str(invalid).unwrap();
+            assert!(matches!(config.cache(), Err(ConfigError::Project(_))));
+        }
+    }
 }
@@ -11,6 +11,14 @@
 //! - **Tool Registry**: Centralized tool management through [`ToolRegistry`]
 //! - **Policy System**: CEL-based policy evaluation for controlling tool
 //!   execution
+//! - **Rate Limits**: Token-bucket limits on tool calls via
+//!   [`rate_limit::RateLimiter`]
+//! - **Admission Control**: Per-tool concurrency limits and timeouts and a
+//!   server-wide in-flight cap via [`admission::AdmissionController`]
+//! - **Circuit Breakers**: Fail fast on repeatedly failing tools via
+//!   [`circuit::CircuitBreakers`]
+//! - **Retries**: Backoff retries of failed calls to idempotent tools via
+//!   [`retry::RetryPolicy`]
 //! - **Config**: TOML-based configuration for tools and policies
 //!
 //! # Example
@@ -62,7 +70,9 @@ mod tool;
 /// Most users will work with [`Config`] directly for project configuration.
 /// Use [`ConfigFile`] when you need the unified resolution algorithm.
 pub use config::{
-    Config, ConfigError, ConfigFile, ConfigKind, CredentialsConfig, PolicyConfig, ToolConfig,
+    AdmissionConfig, ApprovalsConfig, CacheConfig, CacheToolConfig, CircuitBreakerConfig, Config,
+    ConfigError, ConfigFile, ConfigKind, CredentialsConfig, PolicyConfig, PolicyEvaluationConfig,
+    RateLimitConfig, RetryConfig, SessionBackend, SessionsConfig, ToolConfig, ToolLimitsConfig,
 };
 /// Tool loading and lifecycle management.
 ///
@@ -75,17 +85,40 @@ pub use loader::{LoadError, ToolLibrary};
 /// [`ToolHandle`] for invocation, and [`ToolInfo`] for metadata.
 pub use tool::{InflightRequestGuard, RegistryError, ToolHandle, ToolInfo, ToolRegistry};
 
+/// Concurrency limits, queues and timeouts for tool calls.
+///
+/// Enforced by the runtime once policies have allowed a call.
+pub mod admission;
+
+/// Circuit breakers that stop calling repeatedly failing tools.
+///
+/// Enforced by the runtime before rate limits are checked.
+pub mod circuit;
+
 /// Policy evaluation and enforcement.
 ///
 /// CEL-based policy system for controlling tool execution with conditional
 /// effects and context management.
 pub mod policy;
 
+/// Token-bucket rate limiting of tool calls.
+///
+/// Enforced by the runtime before policies are evaluated.
+pub mod rate_limit;
+
+/// Backoff retries of failed calls to idempotent tools.
+///
+/// Applied by the runtime to each admitted call.
+pub mod retry;
+
 /// Policy-related types re-exported for convenience.
 ///
 /// These are also available via the [`policy`] module, but are re-exported
 /// at the crate root for easier access.
-pub use policy::{Effect, Policy, PolicyError, session};
+pub use policy::{
+    CombiningAlgorithm, Decision, Effect, Policy, PolicyError, PolicyMode, ToolSelector, request,
+    session, shadow, trace,
+};
 
 // All tests are in their respective submodules:
 // - loader::tests
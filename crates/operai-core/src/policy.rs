@@ -10,10 +10,12 @@
 //! - **Policy**: A collection of effects with shared context
 //! - **Effect**: A conditional action that applies to specific tools at a
 //!   specific stage
-//! - **Stage**: Before or after tool execution
+//! - **Stage**: Before or after tool execution, or when tools are listed
 //! - **Compilation**: CEL expressions are compiled to Programs for efficient
 //!   evaluation
 //! - **Session**: Maintains context and history across policy evaluations
+//! - **Scope**: Context shared beyond a session, globally or per caller, read
+//!   as `global_context` and `caller_context` (see [`scope`])
 //!
 //! # Example
 //!
@@ -21,19 +23,42 @@
 //! let policy = Policy {
 //!     name: "safety_checks".into(),
 //!     version: "1.0.0".into(),
+//!     priority: 0,
+//!     mode: PolicyMode::Enforce,
 //!     context: HashMap::new(),
+//!     global_context: HashMap::from([("safe_mode".into(), json!(false))]),
+//!     caller_context: HashMap::new(),
 //!     effects: vec![Effect {
 //!         tool: "dangerous.*".into(),
+//!         tags: None,
+//!         capabilities: None,
 //!         stage: PolicyStage::Before,
-//!         condition: "context.safe_mode == true".into(),
+//!         condition: "global_context.safe_mode == false".into(),
+//!         decision: None,
 //!         fail_message: Some("Operation blocked: safe mode enabled".into()),
 //!         updates: HashMap::new(),
+//!         global_updates: HashMap::new(),
+//!         caller_updates: HashMap::from([("calls".into(), "caller_context.calls + 1".into())]),
+//!         rewrites: HashMap::new(),
+//!         transforms: HashMap::new(),
+//!         drops: Vec::new(),
+//!         on_fail_replace_output: None,
 //!     }],
 //! };
 //!
+//! // The policy reads the global scope and updates the caller scope, so both
+//! // are loaded alongside the session when it is evaluated.
 //! let compiled = policy.compile()?;
-//! let mut session = PolicySession::default();
-//! compiled.evaluate_pre_effects(&mut session, "dangerous.nuke", &input)?;
+//! assert_eq!(compiled.shared_scopes(), [PolicyScope::Global, PolicyScope::Caller]);
+//! let mut state = ScopedState::new(PolicySession::default())
+//!     .with(PolicyScope::Global, PolicySession::default())
+//!     .with(PolicyScope::Caller, PolicySession::default());
+//! compiled.evaluate_pre_effects(
+//!     &mut state,
+//!     &PolicyRequest::new("session-1", "dangerous.nuke"),
+//!     &mut input,
+//!     CombiningAlgorithm::DenyOverrides,
+//! )?;
 //! ```
 
 use std::{collections::HashMap, sync::Arc};
@@ -47,22 +72,45 @@ use serde_json::Value as JsonValue;
 use thiserror::Error;
 use tracing::{debug, instrument};
 
+use crate::ToolInfo;
+
+pub mod budget;
+pub mod functions;
+pub mod history;
+pub mod request;
+pub mod scope;
 pub mod session;
-use session::PolicySession;
+pub mod shadow;
+pub mod trace;
+use functions::FunctionLibrary;
+use request::PolicyRequest;
+use scope::{PolicyScope, ScopedState};
+use trace::{Denial, EffectOutcome};
 
 /// When a policy effect should be evaluated.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum PolicyStage {
     /// Evaluate before tool execution.
-    /// Can block execution via `fail_message` or modify input via `updates`.
+    /// Can block execution via `fail_message`, update context via `updates`,
+    /// or replace tool input values via `rewrites`.
     Before,
     /// Evaluate after tool execution.
-    /// Can modify context based on tool output via `updates`.
+    /// Can modify context based on tool output via `updates`, reshape a
+    /// successful output via `transforms` and `drops`, or replace it with an
+    /// error via `on_fail_replace_output`.
     #[default]
     After,
+    /// Evaluate when tools are listed or searched, once per tool.
+    /// Hides the tool via `effect = "deny"` or a `fail_message` guard; cannot
+    /// update context or change input or output.
+    Visibility,
 }
 
+/// The result of a tool invocation as seen by `After` stage effects: the
+/// output JSON on success, or the error message on failure.
+pub type ToolOutcome = Result<JsonValue, String>;
+
 /// A policy definition containing conditional effects for controlling tool
 /// execution.
 ///
@@ -76,16 +124,49 @@ pub struct Policy {
     /// Version string for policy tracking and compatibility.
     pub version: String,
 
+    /// Evaluation priority; higher-priority policies are evaluated first.
+    ///
+    /// Policies with equal priority are evaluated in name order.
+    #[serde(default)]
+    pub priority: i32,
+
+    /// Whether the policy's effects are applied or only observed.
+    #[serde(default)]
+    pub mode: PolicyMode,
+
     /// Initial context variables available to all CEL expressions in this
     /// policy.
     #[serde(default)]
     pub context: HashMap<String, JsonValue>,
 
+    /// Initial variables of the global context, shared by every call (see
+    /// [`scope`]).
+    #[serde(default)]
+    pub global_context: HashMap<String, JsonValue>,
+
+    /// Initial variables of each authenticated caller's context, shared by
+    /// all of the caller's sessions (see [`scope`]).
+    #[serde(default)]
+    pub caller_context: HashMap<String, JsonValue>,
+
     /// Effects to evaluate when tools are invoked.
     #[serde(default)]
     pub effects: Vec<Effect>,
 }
 
+/// Whether a policy is enforced or evaluated in shadow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyMode {
+    /// Decisions, context updates and input/output changes are applied.
+    #[default]
+    Enforce,
+    /// The policy is evaluated against a copy of the session, input and
+    /// output; what it would have done is logged and counted in the
+    /// [`ShadowReport`](shadow::ShadowReport), but nothing is applied.
+    Shadow,
+}
+
 /// A conditional effect that can modify execution or block tool invocations.
 ///
 /// Effects are evaluated in the context of a specific tool invocation and can
@@ -96,6 +177,14 @@ pub struct Effect {
     /// Supports glob patterns: "*" (all tools), "group.*" (tools in a group).
     pub tool: String,
 
+    /// Further restricts the effect to tools whose tags match.
+    #[serde(default)]
+    pub tags: Option<ToolSelector>,
+
+    /// Further restricts the effect to tools whose capabilities match.
+    #[serde(default)]
+    pub capabilities: Option<ToolSelector>,
+
     /// When this effect should be evaluated.
     #[serde(default)]
     pub stage: PolicyStage,
@@ -105,22 +194,138 @@ pub struct Effect {
     #[serde(rename = "when")]
     pub condition: String,
 
+    /// Explicit access decision made when the condition holds (`Before`
+    /// stage, or `allow` and `deny` in the `Visibility` stage).
+    ///
+    /// Without a decision the effect is a guard: it denies only when its
+    /// condition fails and it has a `fail_message`.
+    #[serde(rename = "effect", default)]
+    pub decision: Option<Decision>,
+
     /// Error message to return if the condition fails in `Before` stage.
     /// If `None`, a failed condition simply skips the effect.
     pub fail_message: Option<String>,
 
+    /// Error that replaces a successful tool output if the condition fails
+    /// (`After` stage only).
+    #[serde(default)]
+    pub on_fail_replace_output: Option<String>,
+
     /// Context variables to update when the condition succeeds.
     /// Keys are variable names, values are CEL expressions.
     #[serde(rename = "set", default)]
     pub updates: HashMap<String, String>,
+
+    /// Global context variables to update when the condition succeeds.
+    #[serde(rename = "set_global", default)]
+    pub global_updates: HashMap<String, String>,
+
+    /// Caller context variables to update when the condition succeeds.
+    /// Skipped for unauthenticated calls.
+    #[serde(rename = "set_caller", default)]
+    pub caller_updates: HashMap<String, String>,
+
+    /// Tool input values to replace when the condition succeeds (`Before`
+    /// stage only).
+    /// Keys are dot-separated input paths (e.g. `options.limit`), values are
+    /// CEL expressions. Missing intermediate objects are created.
+    #[serde(rename = "rewrite", default)]
+    pub rewrites: HashMap<String, String>,
+
+    /// Tool output values to replace when the condition succeeds (`After`
+    /// stage only, successful results only).
+    /// Keys are dot-separated output paths, values are CEL expressions; use a
+    /// string literal such as `'***'` to mask a field.
+    #[serde(rename = "transform", default)]
+    pub transforms: HashMap<String, String>,
+
+    /// Dot-separated tool output paths to remove when the condition succeeds
+    /// (`After` stage only, successful results only).
+    #[serde(rename = "drop", default)]
+    pub drops: Vec<String>,
+}
+
+/// Selects tools by a list of their metadata values, such as tags or
+/// capabilities.
+///
+/// A tool is selected when it has at least one of the `any` values (if any
+/// are given) and all of the `all` values. Tools whose metadata is unknown
+/// have no values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolSelector {
+    /// Values of which the tool must have at least one.
+    #[serde(default)]
+    pub any: Vec<String>,
+    /// Values the tool must all have.
+    #[serde(default)]
+    pub all: Vec<String>,
+}
+
+impl ToolSelector {
+    /// Checks whether a tool with the given values is selected.
+    #[must_use]
+    pub fn matches(&self, values: &[String]) -> bool {
+        (self.any.is_empty() || self.any.iter().any(|value| values.contains(value)))
+            && self.all.iter().all(|value| values.contains(value))
+    }
+}
+
+/// An explicit access decision made by a `Before` stage effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    /// Permit the tool call.
+    Allow,
+    /// Block the tool call with the effect's `fail_message`.
+    Deny,
+    /// Hold the tool call until a person approves it; the effect's
+    /// `fail_message` is shown to the approver as the reason.
+    RequireApproval,
+}
+
+/// How access decisions from multiple effects and policies are combined.
+///
+/// Effects are visited in policy order (descending priority, then name) and
+/// declaration order within each policy. A failed guard counts as a deny.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombiningAlgorithm {
+    /// Any deny blocks the call, even after an allow; the first deny's message
+    /// is reported. Otherwise any required approval holds the call, even after
+    /// an allow.
+    #[default]
+    DenyOverrides,
+    /// The first effect to allow, deny, or require approval decides, and no
+    /// further effects are evaluated.
+    FirstApplicable,
+}
+
+/// The result of evaluating a policy's `Before` stage effects that did not
+/// deny the call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreEvaluation {
+    /// Whether session context was modified and should be persisted.
+    pub modified: bool,
+    /// The explicit decision reached, if any effect allowed the call or
+    /// required approval for it.
+    pub decision: Option<Decision>,
+    /// The reason approval is required, set when the decision is
+    /// [`Decision::RequireApproval`].
+    pub approval: Option<String>,
 }
 
 /// Errors that can occur during policy evaluation.
 #[derive(Debug, Error)]
 pub enum PolicyError {
-    /// A guard condition failed and tool execution was blocked.
-    #[error("Guard failed: {0}")]
-    GuardFailed(String),
+    /// A guard condition failed or an effect denied the call, and tool
+    /// execution was blocked.
+    #[error("Guard failed: {}", .0.message)]
+    GuardFailed(Box<Denial>),
+
+    /// The call would exceed a cost budget, and tool execution was blocked.
+    #[error("{0}")]
+    BudgetExceeded(Box<budget::BudgetExceeded>),
 
     /// CEL parsing or syntax error.
     #[error("CEL error: {0}")]
@@ -151,8 +356,53 @@ pub struct CompiledEffect {
     pub tool_pattern: CompiledPattern,
     /// Compiled CEL condition program.
     pub condition: Program,
-    /// Compiled CEL update expressions (variable name -> program).
-    pub updates: HashMap<String, Program>,
+    /// Compiled CEL update expressions with the scope they update, applied
+    /// in scope, then variable name order.
+    pub updates: Vec<(PolicyScope, String, Program)>,
+    /// Compiled input rewrites, applied in path order.
+    pub rewrites: Vec<(FieldPath, Program)>,
+    /// Compiled output transforms, applied in path order.
+    pub transforms: Vec<(FieldPath, Program)>,
+    /// Output paths to remove.
+    pub drops: Vec<FieldPath>,
+}
+
+impl CompiledEffect {
+    /// Checks whether this effect applies to the requested tool in `stage`:
+    /// the tool ID matches the pattern and its metadata matches the tag and
+    /// capability selectors.
+    fn applies_to(&self, stage: PolicyStage, request: &PolicyRequest<'_>) -> bool {
+        let selected = |selector: Option<&ToolSelector>, values: fn(&ToolInfo) -> &[String]| {
+            selector.is_none_or(|selector| {
+                selector.matches(request.tool_info.map(values).unwrap_or_default())
+            })
+        };
+        self.original.stage == stage
+            && self.tool_pattern.matches(request.tool)
+            && selected(self.original.tags.as_ref(), |info| &info.tags)
+            && selected(self.original.capabilities.as_ref(), |info| {
+                &info.capabilities
+            })
+    }
+
+    /// Whether this effect updates `scope` or one of its expressions reads
+    /// the scope's variable.
+    fn uses_scope(&self, scope: PolicyScope) -> bool {
+        let mut programs = std::iter::once(&self.condition)
+            .chain(self.updates.iter().map(|(_, _, program)| program))
+            .chain(self.rewrites.iter().map(|(_, program)| program))
+            .chain(self.transforms.iter().map(|(_, program)| program));
+        self.updates.iter().any(|(updated, _, _)| *updated == scope)
+            || programs.any(|program| program.references().has_variable(scope.variable()))
+    }
+}
+
+/// A dot-separated path into a tool's JSON input or output, such as
+/// `options.limit`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldPath {
+    parents: Vec<String>,
+    leaf: String,
 }
 
 /// A precompiled tool pattern for efficient matching.
@@ -184,6 +434,10 @@ pub struct CompiledPolicy {
     pub original: Policy,
     /// Compiled effects for evaluation.
     pub effects: Vec<CompiledEffect>,
+    /// Functions available to the policy's expressions.
+    functions: Arc<FunctionLibrary>,
+    /// Shared scopes the policy reads or updates.
+    shared_scopes: Vec<PolicyScope>,
 }
 
 impl From<ParseErrors> for PolicyError {
@@ -201,94 +455,274 @@ impl Policy {
     /// # Errors
     ///
     /// Returns `PolicyError::CompilationError` if any CEL expression fails to
-    /// parse.
+    /// parse, a rewrite, transform or drop path is invalid, or an effect
+    /// declares a decision, rewrites, or transforms and drops outside the
+    /// stage that supports them. `Visibility` stage effects may not update
+    /// context or require approval.
     pub fn compile(self) -> Result<CompiledPolicy, PolicyError> {
+        self.compile_with(FunctionLibrary::builtin())
+    }
+
+    /// Compiles this policy like [`compile`](Self::compile), making the
+    /// functions of `functions` available to its expressions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::CompilationError` under the same conditions as
+    /// [`compile`](Self::compile).
+    pub fn compile_with(
+        self,
+        functions: Arc<FunctionLibrary>,
+    ) -> Result<CompiledPolicy, PolicyError> {
         let mut compiled_effects = Vec::new();
         for effect in &self.effects {
             let condition = Program::compile(&effect.condition)
                 .map_err(|e| PolicyError::CompilationError(format!("Condition error: {e}")))?;
 
-            let mut updates = HashMap::new();
-            for (key, expr) in &effect.updates {
-                let prog = Program::compile(expr).map_err(|e| {
-                    PolicyError::CompilationError(format!("Update error for {key}: {e}"))
-                })?;
-                updates.insert(key.clone(), prog);
+            let mut updates = Vec::new();
+            for (scope, scope_updates) in effect.scoped_updates() {
+                for (key, expr) in scope_updates {
+                    let prog = Program::compile(expr).map_err(|e| {
+                        PolicyError::CompilationError(format!("Update error for {key}: {e}"))
+                    })?;
+                    updates.push((scope, key.clone(), prog));
+                }
+            }
+            updates.sort_by(|(a, a_key, _), (b, b_key, _)| (a, a_key).cmp(&(b, b_key)));
+
+            match (effect.stage, effect.decision) {
+                (PolicyStage::After, Some(_)) => {
+                    return Err(PolicyError::CompilationError(
+                        "Access decisions are only allowed in the before and visibility stages"
+                            .into(),
+                    ));
+                }
+                (PolicyStage::Visibility, Some(Decision::RequireApproval)) => {
+                    return Err(PolicyError::CompilationError(
+                        "Approval cannot be required in the visibility stage".into(),
+                    ));
+                }
+                _ => {}
+            }
+            if effect.stage == PolicyStage::Visibility && !updates.is_empty() {
+                return Err(PolicyError::CompilationError(
+                    "Context updates are not allowed in the visibility stage".into(),
+                ));
+            }
+
+            if effect.stage != PolicyStage::Before && !effect.rewrites.is_empty() {
+                return Err(PolicyError::CompilationError(
+                    "Input rewrites are only allowed in the before stage".into(),
+                ));
+            }
+            if effect.stage != PolicyStage::After
+                && !(effect.transforms.is_empty()
+                    && effect.drops.is_empty()
+                    && effect.on_fail_replace_output.is_none())
+            {
+                return Err(PolicyError::CompilationError(
+                    "Output transforms, drops and replacements are only allowed in the after stage"
+                        .into(),
+                ));
             }
+            let rewrites = compile_field_programs(&effect.rewrites, "Rewrite")?;
+            let transforms = compile_field_programs(&effect.transforms, "Transform")?;
+            let drops = effect
+                .drops
+                .iter()
+                .map(|path| {
+                    FieldPath::parse(path).ok_or_else(|| {
+                        PolicyError::CompilationError(format!("Invalid drop path: {path:?}"))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
 
             compiled_effects.push(CompiledEffect {
                 original: effect.clone(),
                 tool_pattern: CompiledPattern::new(&effect.tool),
                 condition,
                 updates,
+                rewrites,
+                transforms,
+                drops,
             });
         }
 
+        let shared_scopes = [PolicyScope::Global, PolicyScope::Caller]
+            .into_iter()
+            .filter(|scope| {
+                !self.scope_context(*scope).is_empty()
+                    || compiled_effects
+                        .iter()
+                        .any(|effect| effect.uses_scope(*scope))
+            })
+            .collect();
         Ok(CompiledPolicy {
             original: self,
             effects: compiled_effects,
+            functions,
+            shared_scopes,
         })
     }
 }
 
+impl Policy {
+    /// Return the initial context variables of `scope`.
+    #[must_use]
+    pub fn scope_context(&self, scope: PolicyScope) -> &HashMap<String, JsonValue> {
+        match scope {
+            PolicyScope::Global => &self.global_context,
+            PolicyScope::Caller => &self.caller_context,
+            PolicyScope::Session => &self.context,
+        }
+    }
+}
+
+impl Effect {
+    /// Return the context updates of each scope.
+    fn scoped_updates(&self) -> impl Iterator<Item = (PolicyScope, &HashMap<String, String>)> {
+        [
+            (PolicyScope::Global, &self.global_updates),
+            (PolicyScope::Caller, &self.caller_updates),
+            (PolicyScope::Session, &self.updates),
+        ]
+        .into_iter()
+    }
+}
+
+/// Compiles path-keyed CEL expressions, sorted so that parents precede their
+/// children and a value set at `a.b` lands on top of one set at `a`.
+fn compile_field_programs(
+    exprs: &HashMap<String, String>,
+    kind: &str,
+) -> Result<Vec<(FieldPath, Program)>, PolicyError> {
+    let mut programs = Vec::with_capacity(exprs.len());
+    for (path, expr) in exprs {
+        let field_path = FieldPath::parse(path).ok_or_else(|| {
+            PolicyError::CompilationError(format!("Invalid {} path: {path:?}", kind.to_lowercase()))
+        })?;
+        let prog = Program::compile(expr)
+            .map_err(|e| PolicyError::CompilationError(format!("{kind} error for {path}: {e}")))?;
+        programs.push((field_path, prog));
+    }
+    programs.sort_by(|(a, _), (b, _)| a.segments().cmp(b.segments()));
+    Ok(programs)
+}
+
 impl CompiledPolicy {
-    /// Initializes the session context with the policy's default values.
+    /// Initializes the context of each loaded scope with the policy's
+    /// default values.
     ///
-    /// Existing session values take precedence over policy defaults.
+    /// Existing values take precedence over policy defaults.
     /// Returns `true` if any new values were added.
-    fn initialize_context(&self, state: &mut PolicySession) -> bool {
+    fn initialize_context(&self, state: &mut ScopedState) -> bool {
         let mut modified = false;
-        for (key, value) in &self.original.context {
-            if !state.context.contains_key(key) {
-                state.context.insert(key.clone(), value.clone());
+        for scope in PolicyScope::ALL {
+            let defaults = self.original.scope_context(scope);
+            let missing = state.get(scope).is_some_and(|session| {
+                defaults
+                    .keys()
+                    .any(|key| !session.context.contains_key(key))
+            });
+            if let Some(session) = state.modify(scope).filter(|_| missing) {
+                for (key, value) in defaults {
+                    session
+                        .context
+                        .entry(key.clone())
+                        .or_insert_with(|| value.clone());
+                }
                 modified = true;
             }
         }
         modified
     }
 
+    /// Return the shared scopes the policy reads or updates.
+    #[must_use]
+    pub fn shared_scopes(&self) -> &[PolicyScope] {
+        &self.shared_scopes
+    }
+
+    /// Applies an effect's context updates to the loaded scopes.
+    fn apply_updates(
+        effect: &CompiledEffect,
+        state: &mut ScopedState,
+        cel_ctx: &mut Context<'static>,
+    ) -> Result<(), PolicyError> {
+        for (scope, key, expr_prog) in &effect.updates {
+            if state.get(*scope).is_none() {
+                continue;
+            }
+            let new_val_cel = expr_prog.execute(cel_ctx).map_err(|e| {
+                PolicyError::EvalError(format!("Effect update error for {key}: {e}"))
+            })?;
+            let Some(session) = state.modify(*scope) else {
+                continue;
+            };
+            session
+                .context
+                .insert(key.clone(), cel_to_json(new_val_cel));
+
+            // Update context for subsequent expressions
+            cel_ctx.add_variable(scope.variable(), to_cel_value(&session.context));
+        }
+        Ok(())
+    }
+
     /// Evaluates all `Before` stage effects for a tool invocation.
     ///
-    /// This is called before tool execution to enforce guards and optionally
-    /// modify the policy context. Effects are evaluated in order; if any guard
-    /// fails with a `fail_message`, execution is blocked.
+    /// This is called before tool execution to enforce guards and decisions
+    /// and optionally modify the policy context and tool input. Effects are
+    /// evaluated in order and combined with `combining`; a deny blocks
+    /// execution.
+    ///
+    /// An effect's rewrites are applied to `input` after its updates, and
+    /// later effects see the rewritten input.
     ///
     /// # CEL Context Variables
     ///
     /// - `context`: Policy session context (`HashMap`)
+    /// - `global_context`, `caller_context`: Shared contexts, or `null` when
+    ///   not loaded (see [`scope`])
     /// - `input`: Tool input JSON
-    /// - `tool`: Tool name string
+    /// - `tool`, `tool_info`, `request`, `caller`, `credentials`, `now`: Facts
+    ///   about the call (see [`request`])
+    /// - `history`: Earlier calls in the session, also queried by `called` and
+    ///   `count` (see [`history`])
     ///
     /// # Returns
     ///
-    /// - `Ok(true)`: Context was modified and should be persisted
-    /// - `Ok(false)`: No changes to context
-    /// - `Err(PolicyError::GuardFailed(msg))`: Execution blocked
+    /// - `Ok(PreEvaluation)`: The call was not denied; reports whether context
+    ///   was modified and whether an effect allowed the call
+    /// - `Err(PolicyError::GuardFailed(denial))`: Execution blocked
     ///
     /// # Errors
     ///
-    /// - Returns `PolicyError::EvalError` if CEL evaluation fails
+    /// - Returns `PolicyError::EvalError` if CEL evaluation fails or a rewrite
+    ///   path runs through a non-object input value
     /// - Returns `PolicyError::GuardFailed` if a guard condition fails with a
-    ///   fail message
-    #[instrument(skip(self, state, input), fields(policy = %self.original.name, tool = %tool_name))]
+    ///   fail message or a deny effect applies
+    #[instrument(skip_all, fields(policy = %self.original.name, tool = %request.tool))]
     pub fn evaluate_pre_effects(
         &self,
-        state: &mut PolicySession,
-        tool_name: &str,
-        input: &JsonValue,
-    ) -> Result<bool, PolicyError> {
+        state: &mut ScopedState,
+        request: &PolicyRequest<'_>,
+        input: &mut JsonValue,
+        combining: CombiningAlgorithm,
+    ) -> Result<PreEvaluation, PolicyError> {
         debug!("Evaluating pre-effects");
         // Initialize session context with policy defaults (existing values take
         // precedence)
         let initialized = self.initialize_context(state);
-        let mut cel_ctx = build_base_context(&state.context, input, tool_name);
+        let mut cel_ctx = self.build_base_context(state, input, request);
 
-        let mut modified = initialized;
+        let mut evaluation = PreEvaluation {
+            modified: initialized,
+            ..PreEvaluation::default()
+        };
 
-        for effect in &self.effects {
-            if effect.original.stage == PolicyStage::Before
-                && effect.tool_pattern.matches(tool_name)
-            {
+        for (index, effect) in self.effects.iter().enumerate() {
+            if effect.applies_to(PolicyStage::Before, request) {
                 let result = effect
                     .condition
                     .execute(&cel_ctx)
@@ -301,98 +735,328 @@ impl CompiledPolicy {
                 };
 
                 if !condition_met {
-                    if let Some(msg) = &effect.original.fail_message {
-                        return Err(PolicyError::GuardFailed(msg.clone()));
+                    if effect.original.decision.is_none()
+                        && let Some(msg) = &effect.original.fail_message
+                    {
+                        self.trace(request, index, effect, false, EffectOutcome::GuardFailed);
+                        return Err(PolicyError::GuardFailed(Box::new(Denial::new(
+                            self,
+                            index,
+                            effect,
+                            false,
+                            msg.clone(),
+                        ))));
                     }
+                    self.trace(request, index, effect, false, EffectOutcome::Skipped);
                     continue;
                 }
 
+                self.trace(
+                    request,
+                    index,
+                    effect,
+                    true,
+                    EffectOutcome::of_decision(effect.original.decision),
+                );
+                if effect.original.decision == Some(Decision::Deny) {
+                    let message = effect
+                        .original
+                        .fail_message
+                        .clone()
+                        .unwrap_or_else(|| format!("Denied by policy {}", self.original.name));
+                    return Err(PolicyError::GuardFailed(Box::new(Denial::new(
+                        self, index, effect, true, message,
+                    ))));
+                }
+
                 if !effect.updates.is_empty() {
-                    for (key, expr_prog) in &effect.updates {
+                    Self::apply_updates(effect, state, &mut cel_ctx)?;
+                    evaluation.modified = true;
+                }
+
+                if !effect.rewrites.is_empty() {
+                    // All of an effect's rewrites see the input as it was
+                    // before the effect applied.
+                    let mut values = Vec::with_capacity(effect.rewrites.len());
+                    for (path, expr_prog) in &effect.rewrites {
                         let new_val_cel = expr_prog.execute(&cel_ctx).map_err(|e| {
-                            PolicyError::EvalError(format!("Effect update error for {key}: {e}"))
+                            PolicyError::EvalError(format!("Effect rewrite error for {path}: {e}"))
                         })?;
+                        values.push((path, cel_to_json(new_val_cel)));
+                    }
+                    for (path, value) in values {
+                        path.set(input, value)?;
+                    }
+                    cel_ctx.add_variable("input", to_cel_json(input));
+                }
 
-                        let new_val_json = cel_to_json(new_val_cel);
-                        state.context.insert(key.clone(), new_val_json);
-
-                        cel_ctx.add_variable("context", to_cel_value(&state.context));
+                match effect.original.decision {
+                    Some(Decision::Allow) => {
+                        // A required approval is not lifted by a later allow.
+                        evaluation.decision.get_or_insert(Decision::Allow);
+                    }
+                    Some(Decision::RequireApproval) => {
+                        evaluation.decision = Some(Decision::RequireApproval);
+                        evaluation.approval.get_or_insert_with(|| {
+                            effect.original.fail_message.clone().unwrap_or_else(|| {
+                                format!("Approval required by policy {}", self.original.name)
+                            })
+                        });
                     }
-                    modified = true;
+                    Some(Decision::Deny) | None => continue,
+                }
+                if combining == CombiningAlgorithm::FirstApplicable {
+                    break;
                 }
             }
         }
-        Ok(modified)
+        Ok(evaluation)
     }
 
     /// Evaluates all `After` stage effects for a tool invocation.
     ///
     /// This is called after tool execution to update policy context based on
-    /// the tool's result. Always adds a history event for the invocation.
+    /// the tool's result, and to reshape what the caller sees. For each
+    /// matching effect whose condition holds, updates are applied first, then
+    /// transforms and drops on a successful `output`. An effect whose
+    /// condition fails with an `on_fail_replace_output` replaces a successful
+    /// `output` with that error. Later effects see the reshaped result.
     ///
     /// # CEL Context Variables
     ///
     /// - `context`: Policy session context (`HashMap`)
+    /// - `global_context`, `caller_context`: Shared contexts, or `null` when
+    ///   not loaded (see [`scope`])
     /// - `input`: Tool input JSON
-    /// - `tool`: Tool name string
+    /// - `tool`, `tool_info`, `request`, `caller`, `credentials`, `now`: Facts
+    ///   about the call (see [`request`])
+    /// - `history`: Earlier calls in the session, also queried by `called` and
+    ///   `count` (see [`history`])
     /// - `output`: Tool output JSON (null if error)
     /// - `error`: Error message string (only present if tool failed)
     /// - `success`: Boolean indicating if tool execution succeeded
     ///
     /// # Errors
     ///
-    /// Returns `PolicyError::EvalError` if CEL evaluation fails.
-    #[instrument(skip(self, state, input, output), fields(policy = %self.original.name, tool = %tool))]
+    /// Returns `PolicyError::EvalError` if CEL evaluation fails or a
+    /// transform path runs through a non-object output value.
+    #[instrument(skip_all, fields(policy = %self.original.name, tool = %request.tool))]
     pub fn evaluate_post_effects(
         &self,
-        state: &mut PolicySession,
-        tool: &str,
+        state: &mut ScopedState,
+        request: &PolicyRequest<'_>,
         input: &JsonValue,
-        output: Result<&JsonValue, &str>,
+        output: &mut ToolOutcome,
     ) -> Result<(), PolicyError> {
         debug!("Evaluating post-effects");
         // Initialize session context with policy defaults (existing values take
         // precedence)
         self.initialize_context(state);
-        let mut cel_ctx = build_base_context(&state.context, input, tool);
-
-        match output {
-            Ok(val) => {
-                cel_ctx.add_variable("output", to_cel_json(val));
-                cel_ctx.add_variable("success", Value::Bool(true));
-            }
-            Err(e) => {
-                cel_ctx.add_variable("output", Value::Null);
-                cel_ctx.add_variable("error", Value::String(Arc::new(e.to_string())));
-                cel_ctx.add_variable("success", Value::Bool(false));
-            }
-        }
+        let mut cel_ctx = self.build_base_context(state, input, request);
+        add_output_variables(&mut cel_ctx, output);
 
-        for effect in &self.effects {
-            if effect.original.stage == PolicyStage::After && effect.tool_pattern.matches(tool) {
+        for (index, effect) in self.effects.iter().enumerate() {
+            if effect.applies_to(PolicyStage::After, request) {
                 let result = effect
                     .condition
                     .execute(&cel_ctx)
                     .map_err(|e| PolicyError::EvalError(e.to_string()))?;
 
-                if matches!(result, Value::Bool(true)) {
-                    for (key, expr_prog) in &effect.updates {
-                        let new_val_cel = expr_prog.execute(&cel_ctx).map_err(|e| {
-                            PolicyError::EvalError(format!("Effect update error for {key}: {e}"))
-                        })?;
+                if !matches!(result, Value::Bool(true)) {
+                    if let Some(msg) = &effect.original.on_fail_replace_output
+                        && output.is_ok()
+                    {
+                        *output = Err(msg.clone());
+                        add_output_variables(&mut cel_ctx, output);
+                        self.trace(request, index, effect, false, EffectOutcome::OutputReplaced);
+                    } else {
+                        self.trace(request, index, effect, false, EffectOutcome::Skipped);
+                    }
+                    continue;
+                }
+                self.trace(request, index, effect, true, EffectOutcome::Applied);
 
-                        let new_val_json = cel_to_json(new_val_cel);
-                        state.context.insert(key.clone(), new_val_json);
+                Self::apply_updates(effect, state, &mut cel_ctx)?;
 
-                        // Update context for subsequent effects
-                        cel_ctx.add_variable("context", to_cel_value(&state.context));
+                let Ok(value) = output else {
+                    continue;
+                };
+                if effect.transforms.is_empty() && effect.drops.is_empty() {
+                    continue;
+                }
+                // All of an effect's transforms see the output as it was
+                // before the effect applied.
+                let mut values = Vec::with_capacity(effect.transforms.len());
+                for (path, expr_prog) in &effect.transforms {
+                    let new_val_cel = expr_prog.execute(&cel_ctx).map_err(|e| {
+                        PolicyError::EvalError(format!("Effect transform error for {path}: {e}"))
+                    })?;
+                    values.push((path, cel_to_json(new_val_cel)));
+                }
+                for (path, new_value) in values {
+                    path.set(value, new_value)?;
+                }
+                for path in &effect.drops {
+                    path.remove(value);
+                }
+                add_output_variables(&mut cel_ctx, output);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates all `Visibility` stage effects for a listed tool.
+    ///
+    /// This is called when tools are listed or searched, to decide whether
+    /// the session may see the tool. Effects are evaluated in order and
+    /// combined with `combining`, like `Before` stage effects: an effect
+    /// whose condition holds with `effect = "deny"`, or a guard whose
+    /// condition fails with a `fail_message`, hides the tool.
+    ///
+    /// The CEL context is the same as for `Before` stage effects, except that
+    /// `input` is `null`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(Decision::Deny))`: The tool is hidden
+    /// - `Ok(Some(Decision::Allow))`: An effect allowed the tool
+    /// - `Ok(None)`: No effect decided
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if CEL evaluation fails.
+    #[instrument(skip_all, fields(policy = %self.original.name, tool = %request.tool))]
+    pub fn evaluate_visibility(
+        &self,
+        state: &mut ScopedState,
+        request: &PolicyRequest<'_>,
+        combining: CombiningAlgorithm,
+    ) -> Result<Option<Decision>, PolicyError> {
+        debug!("Evaluating visibility");
+        self.initialize_context(state);
+        let cel_ctx = self.build_base_context(state, &JsonValue::Null, request);
+
+        let mut decision = None;
+        for (index, effect) in self.effects.iter().enumerate() {
+            if !effect.applies_to(PolicyStage::Visibility, request) {
+                continue;
+            }
+            let result = effect
+                .condition
+                .execute(&cel_ctx)
+                .map_err(|e| PolicyError::EvalError(e.to_string()))?;
+            let Value::Bool(condition_met) = result else {
+                return Err(PolicyError::EvalError(
+                    "Effect condition must return boolean".into(),
+                ));
+            };
+
+            if !condition_met {
+                if effect.original.decision.is_none() && effect.original.fail_message.is_some() {
+                    self.trace(request, index, effect, false, EffectOutcome::GuardFailed);
+                    return Ok(Some(Decision::Deny));
+                }
+                self.trace(request, index, effect, false, EffectOutcome::Skipped);
+                continue;
+            }
+            self.trace(
+                request,
+                index,
+                effect,
+                true,
+                EffectOutcome::of_decision(effect.original.decision),
+            );
+            match effect.original.decision {
+                Some(Decision::Deny) => return Ok(Some(Decision::Deny)),
+                Some(Decision::Allow) => {
+                    decision = Some(Decision::Allow);
+                    if combining == CombiningAlgorithm::FirstApplicable {
+                        break;
                     }
                 }
+                Some(Decision::RequireApproval) | None => {}
             }
         }
+        Ok(decision)
+    }
+}
+
+impl FieldPath {
+    /// Parses a dot-separated path, returning `None` if any segment is empty.
+    #[must_use]
+    pub fn parse(path: &str) -> Option<Self> {
+        let mut parents: Vec<String> = path.split('.').map(str::to_string).collect();
+        if parents.iter().any(String::is_empty) {
+            return None;
+        }
+        let leaf = parents.pop()?;
+        Some(Self { parents, leaf })
+    }
+
+    /// Returns the path segments from the root to the leaf.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.parents
+            .iter()
+            .chain(std::iter::once(&self.leaf))
+            .map(String::as_str)
+    }
 
+    /// Sets the value at this path, creating missing intermediate objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if the root or an intermediate value
+    /// exists but is not an object.
+    pub fn set(&self, root: &mut JsonValue, value: JsonValue) -> Result<(), PolicyError> {
+        let mut current = root;
+        for segment in &self.parents {
+            let JsonValue::Object(map) = current else {
+                return Err(self.not_an_object());
+            };
+            current = map
+                .entry(segment.clone())
+                .or_insert_with(|| JsonValue::Object(serde_json::Map::new()));
+        }
+        let JsonValue::Object(map) = current else {
+            return Err(self.not_an_object());
+        };
+        map.insert(self.leaf.clone(), value);
         Ok(())
     }
+
+    /// Removes the value at this path, if present.
+    ///
+    /// Returns `true` if a value was removed.
+    pub fn remove(&self, root: &mut JsonValue) -> bool {
+        let mut current = root;
+        for segment in &self.parents {
+            match current {
+                JsonValue::Object(map) => match map.get_mut(segment) {
+                    Some(next) => current = next,
+                    None => return false,
+                },
+                _ => return false,
+            }
+        }
+        match current {
+            JsonValue::Object(map) => map.remove(&self.leaf).is_some(),
+            _ => false,
+        }
+    }
+
+    fn not_an_object(&self) -> PolicyError {
+        PolicyError::EvalError(format!("Cannot set path {self}: parent is not an object"))
+    }
+}
+
+impl std::fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for segment in &self.parents {
+            write!(f, "{segment}.")?;
+        }
+        f.write_str(&self.leaf)
+    }
 }
 
 impl CompiledPattern {
@@ -498,17 +1162,58 @@ fn to_cel_value(map: &HashMap<String, JsonValue>) -> Value {
     Value::Map(CelMap { map: Arc::new(m) })
 }
 
-/// Builds the base CEL context with common variables.
-fn build_base_context(
-    context: &HashMap<String, JsonValue>,
-    input: &JsonValue,
-    tool: &str,
-) -> Context<'static> {
-    let mut cel_ctx = Context::default();
-    cel_ctx.add_variable("context", to_cel_value(context));
-    cel_ctx.add_variable("input", to_cel_json(input));
-    cel_ctx.add_variable("tool", Value::String(Arc::new(tool.to_string())));
-    cel_ctx
+impl CompiledPolicy {
+    /// Records an evaluated effect in the request's trace, if it has one.
+    fn trace(
+        &self,
+        request: &PolicyRequest<'_>,
+        index: usize,
+        effect: &CompiledEffect,
+        condition_met: bool,
+        outcome: EffectOutcome,
+    ) {
+        if let Some(trace) = request.trace {
+            trace.record(self, index, effect, condition_met, outcome);
+        }
+    }
+
+    /// Builds the base CEL context with the policy's functions and common
+    /// variables.
+    fn build_base_context(
+        &self,
+        state: &ScopedState,
+        input: &JsonValue,
+        request: &PolicyRequest<'_>,
+    ) -> Context<'static> {
+        let mut cel_ctx = self.functions.new_context();
+        for scope in PolicyScope::ALL {
+            cel_ctx.add_variable(
+                scope.variable(),
+                state
+                    .get(scope)
+                    .map_or(Value::Null, |session| to_cel_value(&session.context)),
+            );
+        }
+        cel_ctx.add_variable("input", to_cel_json(input));
+        request.add_variables(&mut cel_ctx);
+        history::add_variables(&state.session().history, request.time.into(), &mut cel_ctx);
+        cel_ctx
+    }
+}
+
+/// Sets the `output`, `success` and `error` variables for a tool result.
+fn add_output_variables(cel_ctx: &mut Context<'static>, output: &ToolOutcome) {
+    match output {
+        Ok(val) => {
+            cel_ctx.add_variable("output", to_cel_json(val));
+            cel_ctx.add_variable("success", Value::Bool(true));
+        }
+        Err(e) => {
+            cel_ctx.add_variable("output", Value::Null);
+            cel_ctx.add_variable("error", Value::String(Arc::new(e.clone())));
+            cel_ctx.add_variable("success", Value::Bool(false));
+        }
+    }
 }
 
 /// Converts a CEL `Value` back to a `serde_json::Value`.
@@ -546,32 +1251,61 @@ mod tests {
         let policy = Policy {
             name: "test".into(),
             version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
             context: HashMap::new(),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
             effects: vec![Effect {
                 tool: "dangerous.*".into(),
+                tags: None,
+                capabilities: None,
                 stage: PolicyStage::Before,
                 condition: "context.safe_mode == true".into(),
+                decision: None,
                 fail_message: Some("Safety first!".into()),
                 updates: HashMap::new(),
+                global_updates: HashMap::new(),
+                caller_updates: HashMap::new(),
+                rewrites: HashMap::new(),
+                transforms: HashMap::new(),
+                drops: Vec::new(),
+                on_fail_replace_output: None,
             }],
         };
 
-        let mut state = PolicySession::default();
-        state.context.insert("safe_mode".into(), json!(false));
+        let mut state = ScopedState::default();
+        state
+            .session_mut()
+            .context
+            .insert("safe_mode".into(), json!(false));
 
         let compiled_policy = policy.compile().expect("compilation failed");
-        let res = compiled_policy.evaluate_pre_effects(&mut state, "dangerous.nuke", &json!({}));
+        let res = compiled_policy.evaluate_pre_effects(
+            &mut state,
+            &PolicyRequest::new("test", "dangerous.nuke"),
+            &mut json!({}),
+            CombiningAlgorithm::DenyOverrides,
+        );
         assert!(res.is_err());
         match res {
-            Err(PolicyError::GuardFailed(msg)) => assert_eq!(msg, "Safety first!"),
+            Err(PolicyError::GuardFailed(denial)) => assert_eq!(denial.message, "Safety first!"),
             _ => panic!("Wrong error"),
         }
 
         // Allow
-        state.context.insert("safe_mode".into(), json!(true));
+        state
+            .session_mut()
+            .context
+            .insert("safe_mode".into(), json!(true));
         assert!(
             compiled_policy
-                .evaluate_pre_effects(&mut state, "dangerous.nuke", &json!({}))
+                .evaluate_pre_effects(
+                    &mut state,
+                    &PolicyRequest::new("test", "dangerous.nuke"),
+                    &mut json!({}),
+                    CombiningAlgorithm::DenyOverrides,
+                )
                 .is_ok()
         );
     }
@@ -581,33 +1315,440 @@ mod tests {
         let policy = Policy {
             name: "test".into(),
             version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
             context: HashMap::new(),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
             effects: vec![Effect {
                 tool: "git.commit".into(),
+                tags: None,
+                capabilities: None,
                 stage: PolicyStage::After,
                 condition: "success".into(),
+                decision: None,
                 fail_message: None,
                 updates: HashMap::from([
                     ("last_hash".into(), "output.hash".into()),
                     ("commit_count".into(), "context.commit_count + 1".into()),
                 ]),
+                global_updates: HashMap::new(),
+                caller_updates: HashMap::new(),
+                rewrites: HashMap::new(),
+                transforms: HashMap::new(),
+                drops: Vec::new(),
+                on_fail_replace_output: None,
             }],
         };
 
-        let mut state = PolicySession::default();
-        state.context.insert("commit_count".into(), json!(0));
+        let mut state = ScopedState::default();
+        state
+            .session_mut()
+            .context
+            .insert("commit_count".into(), json!(0));
 
         let input = json!({});
         let output = json!({"hash": "abc-123"});
 
         let compiled_policy = policy.compile().expect("compilation failed");
         compiled_policy
-            .evaluate_post_effects(&mut state, "git.commit", &input, Ok(&output))
+            .evaluate_post_effects(
+                &mut state,
+                &PolicyRequest::new("test", "git.commit"),
+                &input,
+                &mut Ok(output),
+            )
             .unwrap();
 
-        assert_eq!(state.context.get("last_hash"), Some(&json!("abc-123")));
-        assert_eq!(state.context.get("commit_count"), Some(&json!(1)));
+        assert_eq!(
+            state.session_mut().context.get("last_hash"),
+            Some(&json!("abc-123"))
+        );
+        assert_eq!(
+            state.session_mut().context.get("commit_count"),
+            Some(&json!(1))
+        );
     }
+    #[test]
+    fn test_rewrites_replace_input_values() {
+        let policy = Policy {
+            name: "test".into(),
+            version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
+            context: HashMap::from([("tenant".into(), json!("acme"))]),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
+            effects: vec![
+                Effect {
+                    tool: "crm.*".into(),
+                    tags: None,
+                    capabilities: None,
+                    stage: PolicyStage::Before,
+                    condition: "true".into(),
+                    decision: None,
+                    fail_message: None,
+                    updates: HashMap::new(),
+                    global_updates: HashMap::new(),
+                    caller_updates: HashMap::new(),
+                    rewrites: HashMap::from([
+                        ("dry_run".into(), "true".into()),
+                        (
+                            "options.limit".into(),
+                            "input.options.limit > 50 ? 50 : input.options.limit".into(),
+                        ),
+                        ("scope.tenant".into(), "context.tenant".into()),
+                    ]),
+                    transforms: HashMap::new(),
+                    drops: Vec::new(),
+                    on_fail_replace_output: None,
+                },
+                Effect {
+                    tool: "crm.*".into(),
+                    tags: None,
+                    capabilities: None,
+                    stage: PolicyStage::Before,
+                    condition: "input.options.limit <= 50".into(),
+                    decision: None,
+                    fail_message: Some("limit not clamped".into()),
+                    updates: HashMap::new(),
+                    global_updates: HashMap::new(),
+                    caller_updates: HashMap::new(),
+                    rewrites: HashMap::new(),
+                    transforms: HashMap::new(),
+                    drops: Vec::new(),
+                    on_fail_replace_output: None,
+                },
+            ],
+        };
+
+        let mut state = ScopedState::default();
+        let mut input = json!({"query": "x", "options": {"limit": 500}});
+
+        let compiled_policy = policy.compile().expect("compilation failed");
+        compiled_policy
+            .evaluate_pre_effects(
+                &mut state,
+                &PolicyRequest::new("test", "crm.search"),
+                &mut input,
+                CombiningAlgorithm::DenyOverrides,
+            )
+            .unwrap();
+
+        assert_eq!(
+            input,
+            json!({
+                "query": "x",
+                "dry_run": true,
+                "options": {"limit": 50},
+                "scope": {"tenant": "acme"},
+            })
+        );
+    }
+
+    #[test]
+    fn test_rewrite_through_non_object_fails() {
+        let policy = Policy {
+            name: "test".into(),
+            version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
+            context: HashMap::new(),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
+            effects: vec![Effect {
+                tool: "*".into(),
+                tags: None,
+                capabilities: None,
+                stage: PolicyStage::Before,
+                condition: "true".into(),
+                decision: None,
+                fail_message: None,
+                updates: HashMap::new(),
+                global_updates: HashMap::new(),
+                caller_updates: HashMap::new(),
+                rewrites: HashMap::from([("options.limit".into(), "10".into())]),
+                transforms: HashMap::new(),
+                drops: Vec::new(),
+                on_fail_replace_output: None,
+            }],
+        };
+
+        let compiled_policy = policy.compile().expect("compilation failed");
+        let res = compiled_policy.evaluate_pre_effects(
+            &mut ScopedState::default(),
+            &PolicyRequest::new("test", "tool"),
+            &mut json!({"options": 3}),
+            CombiningAlgorithm::DenyOverrides,
+        );
+        assert!(matches!(res, Err(PolicyError::EvalError(_))));
+    }
+
+    #[test]
+    fn test_invalid_rewrites_fail_to_compile() {
+        let effect = |stage, path: &str| Policy {
+            name: "test".into(),
+            version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
+            context: HashMap::new(),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
+            effects: vec![Effect {
+                tool: "*".into(),
+                tags: None,
+                capabilities: None,
+                stage,
+                condition: "true".into(),
+                decision: None,
+                fail_message: None,
+                updates: HashMap::new(),
+                global_updates: HashMap::new(),
+                caller_updates: HashMap::new(),
+                rewrites: HashMap::from([(path.into(), "1".into())]),
+                transforms: HashMap::new(),
+                drops: Vec::new(),
+                on_fail_replace_output: None,
+            }],
+        };
+
+        assert!(matches!(
+            effect(PolicyStage::After, "limit").compile(),
+            Err(PolicyError::CompilationError(_))
+        ));
+        assert!(matches!(
+            effect(PolicyStage::Before, "options..limit").compile(),
+            Err(PolicyError::CompilationError(_))
+        ));
+    }
+
+    fn output_effect(
+        condition: &str,
+        replace_output: Option<&str>,
+        transforms: &[(&str, &str)],
+        drops: &[&str],
+    ) -> Effect {
+        Effect {
+            tool: "crm.*".into(),
+            tags: None,
+            capabilities: None,
+            stage: PolicyStage::After,
+            condition: condition.into(),
+            decision: None,
+            fail_message: None,
+            updates: HashMap::new(),
+            global_updates: HashMap::new(),
+            caller_updates: HashMap::new(),
+            rewrites: HashMap::new(),
+            transforms: transforms
+                .iter()
+                .map(|(path, expr)| ((*path).into(), (*expr).into()))
+                .collect(),
+            drops: drops.iter().map(|path| (*path).into()).collect(),
+            on_fail_replace_output: replace_output.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn test_after_effects_transform_and_drop_output() {
+        let policy = Policy {
+            name: "pii".into(),
+            version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
+            context: HashMap::new(),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
+            effects: vec![
+                output_effect(
+                    "success",
+                    None,
+                    &[
+                        ("contact.email", "'***'"),
+                        ("contact.label", "'Contact: ' + output.contact.name"),
+                    ],
+                    &["contact.phone", "contact.missing", "internal"],
+                ),
+                // Sees the output as reshaped by the previous effect.
+                output_effect("!has(output.contact.phone)", Some("phone leaked"), &[], &[]),
+            ],
+        };
+
+        let mut output = Ok(json!({
+            "contact": {"email": "a@example.com", "phone": "555-0101234", "name": "Ada"},
+            "internal": {"score": 7},
+        }));
+        policy
+            .compile()
+            .expect("compilation failed")
+            .evaluate_post_effects(
+                &mut ScopedState::default(),
+                &PolicyRequest::new("test", "crm.lookup"),
+                &json!({}),
+                &mut output,
+            )
+            .unwrap();
+
+        assert_eq!(
+            output.unwrap(),
+            json!({"contact": {"email": "***", "label": "Contact: Ada", "name": "Ada"}})
+        );
+    }
+
+    #[test]
+    fn test_after_replacement_replaces_successful_output() {
+        let policy = Policy {
+            name: "pii".into(),
+            version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
+            context: HashMap::new(),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
+            effects: vec![
+                output_effect("!has(output.ssn)", Some("output withheld"), &[], &[]),
+                output_effect("success", None, &[("ssn", "'***'")], &[]),
+            ],
+        };
+        let compiled_policy = policy.compile().expect("compilation failed");
+
+        let mut output = Ok(json!({"ssn": "123-45-6789"}));
+        compiled_policy
+            .evaluate_post_effects(
+                &mut ScopedState::default(),
+                &PolicyRequest::new("test", "crm.get"),
+                &json!({}),
+                &mut output,
+            )
+            .unwrap();
+        assert_eq!(output, Err("output withheld".to_string()));
+
+        // Tool errors are left as they are.
+        let mut output = Err("boom".to_string());
+        compiled_policy
+            .evaluate_post_effects(
+                &mut ScopedState::default(),
+                &PolicyRequest::new("test", "crm.get"),
+                &json!({}),
+                &mut output,
+            )
+            .unwrap();
+        assert_eq!(output, Err("boom".to_string()));
+    }
+
+    #[test]
+    fn test_after_fail_message_leaves_output_unchanged() {
+        let mut effect = output_effect("false", None, &[], &[]);
+        effect.fail_message = Some("ignored".into());
+        let policy = Policy {
+            name: "test".into(),
+            version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
+            context: HashMap::new(),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
+            effects: vec![effect],
+        };
+        let compiled_policy = policy.compile().expect("compilation failed");
+
+        let mut output = Ok(json!({"ok": true}));
+        compiled_policy
+            .evaluate_post_effects(
+                &mut ScopedState::default(),
+                &PolicyRequest::new("test", "crm.get"),
+                &json!({}),
+                &mut output,
+            )
+            .unwrap();
+        assert_eq!(output, Ok(json!({"ok": true})));
+    }
+
+    #[test]
+    fn test_output_effects_fail_to_compile_in_before_stage() {
+        for mut effect in [
+            output_effect("true", None, &[], &["secret"]),
+            output_effect("true", Some("withheld"), &[], &[]),
+        ] {
+            effect.stage = PolicyStage::Before;
+            let policy = Policy {
+                name: "test".into(),
+                version: "1".into(),
+                priority: 0,
+                mode: PolicyMode::Enforce,
+                context: HashMap::new(),
+                global_context: HashMap::new(),
+                caller_context: HashMap::new(),
+                effects: vec![effect],
+            };
+
+            assert!(matches!(
+                policy.compile(),
+                Err(PolicyError::CompilationError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_visibility_effects_cannot_update_or_require_approval() {
+        let effect = |extra: &str| {
+            toml::from_str::<Policy>(&format!(
+                r#"name = "p"
+version = "1"
+
+[[effects]]
+tool = "*"
+stage = "visibility"
+when = "true"
+{extra}
+"#
+            ))
+            .expect("policy should parse")
+            .compile()
+        };
+
+        assert!(effect(r#"effect = "deny""#).is_ok());
+        assert!(effect(r#"fail_message = "hidden""#).is_ok());
+        for extra in [
+            r#"effect = "require_approval""#,
+            r#"set = { seen = "true" }"#,
+            r#"set_global = { seen = "true" }"#,
+            r#"rewrite = { limit = "1" }"#,
+            r#"drop = ["secret"]"#,
+        ] {
+            assert!(
+                matches!(effect(extra), Err(PolicyError::CompilationError(_))),
+                "{extra} should not compile"
+            );
+        }
+    }
+
+    #[test]
+    fn test_shared_scopes_come_from_variable_references() {
+        let scopes = |condition: &str, caller_updates: &str| {
+            let policy: Policy = serde_json::from_value(serde_json::json!({
+                "name": "scopes",
+                "version": "1",
+                "effects": [{
+                    "tool": "*",
+                    "stage": "before",
+                    "when": condition,
+                    "set_caller": serde_json::from_str::<JsonValue>(caller_updates).unwrap()
+                }]
+            }))
+            .unwrap();
+            policy.compile().unwrap().shared_scopes().to_vec()
+        };
+
+        assert!(scopes("input.note == 'global_context'", "{}").is_empty());
+        assert!(scopes("has(input.caller_context)", "{}").is_empty());
+        assert_eq!(
+            scopes("global_context.enabled == true", "{}"),
+            [PolicyScope::Global]
+        );
+        assert_eq!(scopes("true", r#"{"calls": "1"}"#), [PolicyScope::Caller]);
+    }
+
     #[test]
     fn test_matches_tool_pattern_glob() {
         // Exact match
@@ -639,6 +1780,63 @@ mod tests {
         assert!(matches_tool_pattern("**.bar", "foo.baz.bar"));
     }
 
+    #[test]
+    fn test_effects_select_tools_by_tags_and_capabilities() {
+        let policy: Policy = toml::from_str(
+            r#"name = "selectors"
+version = "1"
+
+[[effects]]
+tool = "**"
+tags = { any = ["destructive", "irreversible"] }
+capabilities = { all = ["write", "external"] }
+stage = "before"
+when = "false"
+fail_message = "blocked"
+"#,
+        )
+        .expect("policy should parse");
+        let policy = policy.compile().expect("policy should compile");
+        let tool = |tags: &[&str], capabilities: &[&str]| ToolInfo {
+            qualified_id: "db.drop".into(),
+            tool_id: "drop".into(),
+            crate_name: "db".into(),
+            crate_version: "1.0.0".into(),
+            display_name: "Drop".into(),
+            description: String::new(),
+            input_schema: "{}".into(),
+            output_schema: "{}".into(),
+            credential_schema: None,
+            capabilities: capabilities.iter().map(ToString::to_string).collect(),
+            tags: tags.iter().map(ToString::to_string).collect(),
+            embedding: None,
+            cost: None,
+            idempotent: false,
+            cache_ttl: None,
+        };
+        let blocked = |info: Option<&ToolInfo>| {
+            let mut request = PolicyRequest::new("s1", "db.drop");
+            request.tool_info = info;
+            policy
+                .evaluate_pre_effects(
+                    &mut ScopedState::default(),
+                    &request,
+                    &mut json!({}),
+                    CombiningAlgorithm::DenyOverrides,
+                )
+                .is_err()
+        };
+
+        assert!(blocked(Some(&tool(
+            &["irreversible"],
+            &["external", "write"]
+        ))));
+        assert!(!blocked(Some(&tool(&["readonly"], &["external", "write"]))));
+        assert!(!blocked(Some(&tool(&["destructive"], &["write"]))));
+        // Unknown metadata has no tags or capabilities.
+        assert!(!blocked(None));
+    }
+
     #[tokio::test]
     async fn test_post_effects_persistence_and_conflict() {
         use std::sync::Arc;
@@ -652,7 +1850,11 @@ mod tests {
         let policy = Policy {
             name: "test_policy".into(),
             version: "1".into(),
+            priority: 0,
+            mode: PolicyMode::Enforce,
             context: HashMap::new(),
+            global_context: HashMap::new(),
+            caller_context: HashMap::new(),
             effects: vec![],
         };
         policy_store.register(policy).expect("registration failed");
@@ -663,7 +1865,11 @@ mod tests {
         // This invokes evaluate_post_effects. Even with no policy effects,
         // it should save the history event.
         let res = policy_store
-            .evaluate_post_effects(session_id, "some.tool", &json!({}), Ok(&json!({})))
+            .evaluate_post_effects(
+                &PolicyRequest::new(session_id, "some.tool"),
+                &json!({}),
+                Ok(&json!({})),
+            )
             .await;
         assert!(res.is_ok(), "evaluate_post_effects failed: {:?}", res.err());
 
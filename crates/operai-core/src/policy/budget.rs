@@ -0,0 +1,269 @@
+//! Cost budgets for tool calls.
+//!
+//! A [`Budget`] caps how much the calls to matching tools may cost within one
+//! [scope](super::scope): a session, an authenticated caller, or everything.
+//! A call costs what it reported (`CallResult::cost`, set with
+//! `Context::report_cost`), or else the static cost the tool declares in its
+//! `## Cost` doc section. Tools with neither are free.
+//!
+//! [`PolicyStore`](super::session::PolicyStore) checks every budget that
+//! applies to a call after its `Before` stage effects allowed it, and denies
+//! it with [`PolicyError::BudgetExceeded`](super::PolicyError::BudgetExceeded)
+//! if the budget is already spent or the tool's static cost would overrun
+//! it. Once the call ran, whether it succeeded or failed, its cost is added
+//! to the spend of each applicable budget, which is kept in the scope's
+//! [`PolicySession::spend`](super::session::PolicySession::spend).
+//!
+//! A budget with a window starts a fresh period once the window has passed
+//! since the first spend of the current one.
+//!
+//! Limits are best-effort under concurrency. The check and the charge are
+//! separate session updates, so calls that run at the same time are all
+//! checked against the same spend and together may overrun a budget by up to
+//! their combined cost.
+
+use std::{fmt, time::Duration};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{CompiledPattern, scope::PolicyScope, session::PolicySession};
+
+/// A limit on the total cost of matching tool calls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Budget {
+    /// Name of the budget, unique within a store.
+    pub name: String,
+    /// Pattern of the tools whose calls count against the budget, matched
+    /// like an effect's `tool`.
+    pub tool: String,
+    /// Scope the spend is shared in.
+    pub scope: PolicyScope,
+    /// Maximum total cost.
+    pub limit: f64,
+    /// Length of a budget period, if spend resets periodically.
+    pub window: Option<Duration>,
+}
+
+impl Budget {
+    /// Create a budget of `limit` per session for every tool, which never
+    /// resets.
+    #[must_use]
+    pub fn new(name: impl Into<String>, limit: f64) -> Self {
+        Self {
+            name: name.into(),
+            tool: "**".to_string(),
+            scope: PolicyScope::Session,
+            limit,
+            window: None,
+        }
+    }
+
+    /// Only count calls to tools matching `tool`.
+    #[must_use]
+    pub fn with_tool(mut self, tool: impl Into<String>) -> Self {
+        self.tool = tool.into();
+        self
+    }
+
+    /// Share the spend in `scope`.
+    #[must_use]
+    pub fn with_scope(mut self, scope: PolicyScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Reset the spend every `window`.
+    #[must_use]
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = Some(window);
+        self
+    }
+}
+
+/// How much of a budget has been spent in one scope.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Spend {
+    /// Total cost of the calls counted in the current period.
+    pub amount: f64,
+    /// When the current period started.
+    pub since: DateTime<Utc>,
+}
+
+/// The state of a budget for one call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetStatus {
+    /// Name of the budget.
+    pub budget: String,
+    /// Scope the spend is shared in.
+    pub scope: PolicyScope,
+    /// Maximum total cost.
+    pub limit: f64,
+    /// Cost spent in the current period.
+    pub spent: f64,
+}
+
+impl BudgetStatus {
+    /// Return how much of the budget is left, never negative.
+    #[must_use]
+    pub fn remaining(&self) -> f64 {
+        (self.limit - self.spent).max(0.0)
+    }
+}
+
+/// Why a call was denied for exceeding a budget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetExceeded {
+    /// The exceeded budget, before the call.
+    pub status: BudgetStatus,
+    /// The tool's static cost, or 0 if it declares none.
+    pub cost: f64,
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Budget {} exceeded: {} of {} spent",
+            self.status.budget, self.status.spent, self.status.limit
+        )?;
+        if self.cost > 0.0 {
+            write!(f, ", call costs {}", self.cost)?;
+        }
+        Ok(())
+    }
+}
+
+/// A budget with its tool pattern compiled.
+#[derive(Debug, Clone)]
+pub(super) struct CompiledBudget {
+    budget: Budget,
+    pattern: CompiledPattern,
+}
+
+impl CompiledBudget {
+    pub(super) fn new(budget: Budget) -> Self {
+        let pattern = CompiledPattern::new(&budget.tool);
+        Self { budget, pattern }
+    }
+
+    pub(super) fn scope(&self) -> PolicyScope {
+        self.budget.scope
+    }
+
+    pub(super) fn applies_to(&self, tool: &str) -> bool {
+        self.pattern.matches(tool)
+    }
+
+    /// Return the spend of the current period in `session` at `now`.
+    fn current(&self, session: &PolicySession, now: DateTime<Utc>) -> Option<Spend> {
+        session
+            .spend
+            .get(&self.budget.name)
+            .copied()
+            .filter(|spend| {
+                // A period that starts in the future (clock skew between
+                // processes) is still current.
+                self.budget.window.is_none_or(|window| {
+                    (now - spend.since)
+                        .to_std()
+                        .ok()
+                        .is_none_or(|elapsed| elapsed < window)
+                })
+            })
+    }
+
+    /// Return the state of the budget in `session` at `now`.
+    pub(super) fn status(&self, session: &PolicySession, now: DateTime<Utc>) -> BudgetStatus {
+        BudgetStatus {
+            budget: self.budget.name.clone(),
+            scope: self.budget.scope,
+            limit: self.budget.limit,
+            spent: self.current(session, now).map_or(0.0, |spend| spend.amount),
+        }
+    }
+
+    /// Return why a call with static `cost` may not run, if the budget in
+    /// `session` is spent or would be overrun.
+    pub(super) fn check(
+        &self,
+        session: &PolicySession,
+        cost: f64,
+        now: DateTime<Utc>,
+    ) -> Option<BudgetExceeded> {
+        let status = self.status(session, now);
+        (status.spent >= status.limit || status.spent + cost > status.limit)
+            .then_some(BudgetExceeded { status, cost })
+    }
+
+    /// Add `cost` to the spend of the budget in `session` at `now`.
+    pub(super) fn charge(&self, session: &mut PolicySession, cost: f64, now: DateTime<Utc>) {
+        let spend = self.current(session, now).map_or(
+            Spend {
+                amount: cost,
+                since: now,
+            },
+            |spend| Spend {
+                amount: spend.amount + cost,
+                ..spend
+            },
+        );
+        session.spend.insert(self.budget.name.clone(), spend);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    #[test]
+    #[expect(clippy::float_cmp, reason = "the costs are exactly representable")]
+    fn test_spend_accumulates_and_resets_after_window() {
+        let budget = CompiledBudget::new(
+            Budget::new("daily", 10.0)
+                .with_tool("search.*")
+                .with_window(Duration::from_hours(1)),
+        );
+        let start = Utc::now();
+        let mut session = PolicySession::default();
+        assert!(budget.applies_to("search.web"));
+        assert!(!budget.applies_to("mail.send"));
+
+        budget.charge(&mut session, 4.0, start);
+        budget.charge(&mut session, 5.0, start + TimeDelta::minutes(30));
+        assert_eq!(budget.status(&session, start).spent, 9.0);
+        assert!(budget.check(&session, 1.0, start).is_none());
+        let exceeded = budget.check(&session, 2.0, start).expect("should exceed");
+        assert_eq!(exceeded.status.remaining(), 1.0);
+        assert_eq!(
+            exceeded.to_string(),
+            "Budget daily exceeded: 9 of 10 spent, call costs 2"
+        );
+
+        // The period started with the first charge, so it ends an hour later.
+        let later = start + TimeDelta::minutes(61);
+        assert_eq!(budget.status(&session, later).spent, 0.0);
+        budget.charge(&mut session, 1.0, later);
+        assert_eq!(session.spend["daily"].since, later);
+        assert_eq!(budget.status(&session, later).spent, 1.0);
+    }
+
+    #[test]
+    #[expect(clippy::float_cmp, reason = "the costs are exactly representable")]
+    fn test_spent_budget_denies_calls_without_static_cost() {
+        let budget = CompiledBudget::new(Budget::new("total", 1.0));
+        let now = Utc::now();
+        let mut session = PolicySession::default();
+        assert!(budget.check(&session, 0.0, now).is_none());
+
+        budget.charge(&mut session, 1.5, now);
+        let exceeded = budget.check(&session, 0.0, now).expect("should exceed");
+        assert_eq!(exceeded.status.remaining(), 0.0);
+        assert_eq!(
+            exceeded.to_string(),
+            "Budget total exceeded: 1.5 of 1 spent"
+        );
+    }
+}
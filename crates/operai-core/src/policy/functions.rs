@@ -0,0 +1,453 @@
+//! Extension functions available to policy CEL expressions.
+//!
+//! Besides the CEL built-ins (`size`, `contains`, `startsWith`, `matches`,
+//! `duration`, `timestamp`, ...), every policy expression can call:
+//!
+//! | Function                          | Result                                                  |
+//! | --------------------------------- | ------------------------------------------------------- |
+//! | `regexFind(text, pattern)`        | First match of the regex, or `null`                     |
+//! | `regexFindAll(text, pattern)`     | List of all matches                                     |
+//! | `regexReplace(text, pattern, by)` | `text` with every match replaced (`$1` refers to groups) |
+//! | `globMatch(text, pattern)`        | Whether `text` matches a glob (`*` any run, `?` one char) |
+//! | `jsonPath(value, path)`           | Value at a dot-separated path (`items.0.id`), or `null` |
+//! | `durationSeconds(duration)`       | Whole seconds in a duration                             |
+//! | `unixSeconds(timestamp)`          | Seconds since the Unix epoch                            |
+//! | `fromUnixSeconds(seconds)`        | Timestamp for seconds since the Unix epoch              |
+//! | `lower(text)`, `upper(text)`      | Case-converted string                                   |
+//! | `trim(text)`                      | String without leading and trailing whitespace          |
+//! | `normalize(text)`                 | Trimmed, lowercased string with whitespace collapsed    |
+//! | `isIp(text)`                      | Whether `text` is an IPv4 or IPv6 address               |
+//! | `inCidr(ip, cidr)`                | Whether the address is in a CIDR block (`10.0.0.0/8`)   |
+//!
+//! Every function can also be called as a method on its first argument, e.g.
+//! `input.email.normalize()` or `caller.attributes.ip.inCidr("10.0.0.0/8")`.
+//! Timestamps and durations also support arithmetic and comparison, e.g.
+//! `now - timestamp(input.created_at) > duration("24h")`.
+//!
+//! Embedding applications can add their own functions with
+//! [`FunctionLibrary::with_function`] and pass the library to
+//! [`PolicyStore::with_functions`](super::session::PolicyStore::with_functions).
+
+use std::{
+    collections::HashMap,
+    fmt,
+    net::IpAddr,
+    sync::{Arc, LazyLock, Mutex},
+};
+
+pub use cel_interpreter::Value;
+use cel_interpreter::{
+    Context, FunctionContext, ResolveResult, extractors::Arguments, objects::Key,
+};
+use chrono::DateTime;
+use regex::Regex;
+
+/// A function callable from policy expressions.
+///
+/// It receives the call's arguments, with the receiver of a method call such
+/// as `x.f(y)` as the first argument, and returns the result or an error
+/// message.
+pub type PolicyFunction = dyn Fn(&[Value]) -> Result<Value, String> + Send + Sync;
+
+/// Signature of the built-in extension functions.
+type BuiltinFunction = fn(&[Value]) -> Result<Value, String>;
+
+/// The built-in extension functions, by name.
+const BUILTINS: &[(&str, BuiltinFunction)] = &[
+    ("regexFind", regex_find),
+    ("regexFindAll", regex_find_all),
+    ("regexReplace", regex_replace),
+    ("globMatch", glob_match),
+    ("jsonPath", json_path),
+    ("durationSeconds", duration_seconds),
+    ("unixSeconds", unix_seconds),
+    ("fromUnixSeconds", from_unix_seconds),
+    ("lower", lower),
+    ("upper", upper),
+    ("trim", trim),
+    ("normalize", normalize),
+    ("isIp", is_ip),
+    ("inCidr", in_cidr),
+];
+
+/// The functions available to policy expressions: the built-in extension
+/// functions plus any registered by the embedding application.
+#[derive(Clone, Default)]
+pub struct FunctionLibrary {
+    custom: Vec<(String, Arc<PolicyFunction>)>,
+}
+
+impl fmt::Debug for FunctionLibrary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FunctionLibrary")
+            .field(
+                "custom",
+                &self.custom.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl FunctionLibrary {
+    /// Create a library with only the built-in functions.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return a shared library with only the built-in functions.
+    pub(super) fn builtin() -> Arc<Self> {
+        static BUILTIN: LazyLock<Arc<FunctionLibrary>> = LazyLock::new(Arc::default);
+        Arc::clone(&BUILTIN)
+    }
+
+    /// Register a custom function under `name`.
+    ///
+    /// A function registered under the name of a built-in or of an earlier
+    /// registration replaces it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use operai_core::policy::functions::{FunctionLibrary, Value};
+    ///
+    /// let functions = FunctionLibrary::new().with_function("isWeekendDeploy", |args| match args {
+    ///     [Value::String(day)] => Ok(Value::Bool(day.as_str() == "saturday")),
+    ///     _ => Err("expected a day name".into()),
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_function<F>(mut self, name: impl Into<String>, function: F) -> Self
+    where
+        F: Fn(&[Value]) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        let name = name.into();
+        self.custom.retain(|(existing, _)| *existing != name);
+        self.custom.push((name, Arc::new(function)));
+        self
+    }
+
+    /// Create a CEL context with the CEL built-ins and every function of this
+    /// library.
+    pub(super) fn new_context(&self) -> Context<'static> {
+        let mut cel_ctx = Context::default();
+        for (name, function) in BUILTINS {
+            add_function(&mut cel_ctx, name, Arc::new(*function));
+        }
+        for (name, function) in &self.custom {
+            add_function(&mut cel_ctx, name, Arc::clone(function));
+        }
+        cel_ctx
+    }
+}
+
+pub(super) fn add_function(
+    cel_ctx: &mut Context<'static>,
+    name: &str,
+    function: Arc<PolicyFunction>,
+) {
+    cel_ctx.add_function(
+        name,
+        move |ftx: &FunctionContext, Arguments(args): Arguments| -> ResolveResult {
+            let args: Vec<Value> = ftx.this.iter().chain(args.iter()).cloned().collect();
+            function(&args).map_err(|message| ftx.error(message))
+        },
+    );
+}
+
+/// Checks that exactly `N` arguments were passed.
+pub(super) fn arity<const N: usize>(args: &[Value]) -> Result<&[Value; N], String> {
+    args.try_into()
+        .map_err(|_| format!("expected {N} arguments, got {}", args.len()))
+}
+
+pub(super) fn string(value: &Value) -> Result<&str, String> {
+    match value {
+        Value::String(s) => Ok(s),
+        other => Err(format!("expected string, got {}", other.type_of())),
+    }
+}
+
+fn string_value(s: impl Into<String>) -> Value {
+    Value::String(Arc::new(s.into()))
+}
+
+/// Most compiled patterns kept by [`regex`]; the cache is emptied when full.
+const REGEX_CACHE_CAPACITY: usize = 256;
+
+/// Compiled regex patterns, by pattern.
+static REGEX_CACHE: LazyLock<Mutex<HashMap<String, Regex>>> = LazyLock::new(Mutex::default);
+
+/// Compiles `pattern`, reusing the compiled regex of an earlier call.
+fn regex(pattern: &Value) -> Result<Regex, String> {
+    let pattern = string(pattern)?;
+    let mut cache = REGEX_CACHE.lock().expect("lock poisoned");
+    if let Some(regex) = cache.get(pattern) {
+        return Ok(regex.clone());
+    }
+    let regex = Regex::new(pattern).map_err(|e| format!("invalid regex: {e}"))?;
+    if cache.len() >= REGEX_CACHE_CAPACITY {
+        cache.clear();
+    }
+    cache.insert(pattern.to_string(), regex.clone());
+    Ok(regex)
+}
+
+fn regex_find(args: &[Value]) -> Result<Value, String> {
+    let [text, pattern] = arity(args)?;
+    Ok(regex(pattern)?
+        .find(string(text)?)
+        .map_or(Value::Null, |m| string_value(m.as_str())))
+}
+
+fn regex_find_all(args: &[Value]) -> Result<Value, String> {
+    let [text, pattern] = arity(args)?;
+    let matches = regex(pattern)?
+        .find_iter(string(text)?)
+        .map(|m| string_value(m.as_str()))
+        .collect();
+    Ok(Value::List(Arc::new(matches)))
+}
+
+fn regex_replace(args: &[Value]) -> Result<Value, String> {
+    let [text, pattern, replacement] = arity(args)?;
+    Ok(string_value(
+        regex(pattern)?.replace_all(string(text)?, string(replacement)?),
+    ))
+}
+
+fn glob_match(args: &[Value]) -> Result<Value, String> {
+    let [text, pattern] = arity(args)?;
+    Ok(Value::Bool(matches_glob(string(pattern)?, string(text)?)))
+}
+
+/// Matches `text` against a glob where `*` matches any run of characters and
+/// `?` matches exactly one.
+fn matches_glob(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` seen, and the text position it resumes at.
+    let mut backtrack = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            p += 1;
+            backtrack = Some((p, t));
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p;
+            t = star_t + 1;
+            backtrack = Some((star_p, t));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+fn json_path(args: &[Value]) -> Result<Value, String> {
+    let [value, path] = arity(args)?;
+    let path = string(path)?;
+    let mut current = value;
+    for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+        let next = match current {
+            Value::Map(map) => map.get(&Key::String(Arc::new(segment.to_string()))),
+            Value::List(list) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| list.get(index)),
+            _ => None,
+        };
+        match next {
+            Some(next) => current = next,
+            None => return Ok(Value::Null),
+        }
+    }
+    Ok(current.clone())
+}
+
+fn duration_seconds(args: &[Value]) -> Result<Value, String> {
+    match arity(args)? {
+        [Value::Duration(duration)] => Ok(Value::Int(duration.num_seconds())),
+        [other] => Err(format!("expected duration, got {}", other.type_of())),
+    }
+}
+
+fn unix_seconds(args: &[Value]) -> Result<Value, String> {
+    match arity(args)? {
+        [Value::Timestamp(timestamp)] => Ok(Value::Int(timestamp.timestamp())),
+        [other] => Err(format!("expected timestamp, got {}", other.type_of())),
+    }
+}
+
+fn from_unix_seconds(args: &[Value]) -> Result<Value, String> {
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "out of range seconds saturate and are rejected below"
+    )]
+    let seconds = match arity(args)? {
+        [Value::Int(seconds)] => *seconds,
+        [Value::UInt(seconds)] => i64::try_from(*seconds).map_err(|e| e.to_string())?,
+        [Value::Float(seconds)] => seconds.floor() as i64,
+        [other] => return Err(format!("expected number, got {}", other.type_of())),
+    };
+    DateTime::from_timestamp(seconds, 0)
+        .map(|timestamp| Value::Timestamp(timestamp.fixed_offset()))
+        .ok_or_else(|| format!("timestamp out of range: {seconds}"))
+}
+
+fn lower(args: &[Value]) -> Result<Value, String> {
+    let [text] = arity(args)?;
+    Ok(string_value(string(text)?.to_lowercase()))
+}
+
+fn upper(args: &[Value]) -> Result<Value, String> {
+    let [text] = arity(args)?;
+    Ok(string_value(string(text)?.to_uppercase()))
+}
+
+fn trim(args: &[Value]) -> Result<Value, String> {
+    let [text] = arity(args)?;
+    Ok(string_value(string(text)?.trim()))
+}
+
+fn normalize(args: &[Value]) -> Result<Value, String> {
+    let [text] = arity(args)?;
+    let words: Vec<&str> = string(text)?.split_whitespace().collect();
+    Ok(string_value(words.join(" ").to_lowercase()))
+}
+
+fn is_ip(args: &[Value]) -> Result<Value, String> {
+    let [text] = arity(args)?;
+    Ok(Value::Bool(string(text)?.parse::<IpAddr>().is_ok()))
+}
+
+fn in_cidr(args: &[Value]) -> Result<Value, String> {
+    let [ip, cidr] = arity(args)?;
+    let ip: IpAddr = string(ip)?
+        .parse()
+        .map_err(|e| format!("invalid IP address: {e}"))?;
+    let cidr = string(cidr)?;
+    let invalid = || format!("invalid CIDR block: {cidr:?}");
+    let (network, prefix) = cidr.split_once('/').ok_or_else(invalid)?;
+    let network: IpAddr = network.parse().map_err(|_| invalid())?;
+    let prefix: u32 = prefix.parse().map_err(|_| invalid())?;
+    let (ip, network, bits) = match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => (
+            u128::from(u32::from(ip)),
+            u128::from(u32::from(network)),
+            32,
+        ),
+        (IpAddr::V6(ip), IpAddr::V6(network)) => (u128::from(ip), u128::from(network), 128),
+        _ => return Ok(Value::Bool(false)),
+    };
+    if prefix > bits {
+        return Err(invalid());
+    }
+    let mask = if prefix == 0 {
+        0
+    } else {
+        u128::MAX << (bits - prefix)
+    };
+    Ok(Value::Bool(ip & mask == network & mask))
+}
+
+#[cfg(test)]
+mod tests {
+    use cel_interpreter::{ExecutionError, Program};
+
+    use super::*;
+    use crate::policy::to_cel_json;
+
+    fn eval(library: &FunctionLibrary, expr: &str) -> Result<Value, ExecutionError> {
+        let mut cel_ctx = library.new_context();
+        cel_ctx.add_variable_from_value(
+            "input",
+            to_cel_json(&serde_json::json!({
+                "email": "  Ada.Lovelace@Example.COM ",
+                "items": [{ "id": "a1" }, { "id": "b2" }],
+                "ip": "10.1.2.3",
+            })),
+        );
+        Program::compile(expr)
+            .expect("expression should compile")
+            .execute(&cel_ctx)
+    }
+
+    fn assert_true(expr: &str) {
+        assert_eq!(
+            eval(&FunctionLibrary::new(), expr).expect("expression should evaluate"),
+            Value::Bool(true),
+            "{expr}"
+        );
+    }
+
+    #[test]
+    fn test_builtin_functions() {
+        assert_true(r#"regexFind("order-123-abc", "[0-9]+") == "123""#);
+        assert_true(r#"regexFind("abc", "[0-9]+") == null"#);
+        assert_true(r#"regexFindAll("a1b22c333", "[0-9]+") == ["1", "22", "333"]"#);
+        assert_true(
+            r#""2024-01-05".regexReplace("(\\d+)-(\\d+)-(\\d+)", "$3/$2/$1") == "05/01/2024""#,
+        );
+        assert_true(r#"globMatch("reports/2024/q1.csv", "reports/*.csv")"#);
+        assert_true(r#"!globMatch("reports/q1.txt", "reports/?.csv")"#);
+        assert_true(r#"jsonPath(input, "items.1.id") == "b2""#);
+        assert_true(r#"input.jsonPath("items.7.id") == null"#);
+        assert_true(r#"durationSeconds(duration("1h30m")) == 5400"#);
+        assert_true("unixSeconds(fromUnixSeconds(1700000000)) == 1700000000");
+        assert_true(r#"fromUnixSeconds(0) + duration("24h") == timestamp("1970-01-02T00:00:00Z")"#);
+        assert_true(r#"input.email.normalize() == "ada.lovelace@example.com""#);
+        assert_true(r#"upper(trim(" a ")) == "A" && lower("B") == "b""#);
+        assert_true(r#"isIp("::1") && !isIp("10.0.0")"#);
+        assert_true(r#"input.ip.inCidr("10.0.0.0/8") && !inCidr("11.0.0.1", "10.0.0.0/8")"#);
+        assert_true(r#"inCidr("2001:db8::1", "2001:db8::/32") && inCidr("1.2.3.4", "0.0.0.0/0")"#);
+    }
+
+    #[test]
+    fn test_regex_patterns_are_compiled_once_and_cache_stays_bounded() {
+        let pattern = string_value("cached-[0-9]+");
+        regex(&pattern).unwrap();
+        assert!(REGEX_CACHE.lock().unwrap().contains_key("cached-[0-9]+"));
+
+        for n in 0..=REGEX_CACHE_CAPACITY {
+            regex(&string_value(format!("bounded-{n}"))).unwrap();
+        }
+        assert!(REGEX_CACHE.lock().unwrap().len() <= REGEX_CACHE_CAPACITY);
+        assert!(regex(&string_value("(")).is_err());
+    }
+
+    #[test]
+    fn test_invalid_arguments_are_errors() {
+        let library = FunctionLibrary::new();
+        assert!(eval(&library, r#"regexFind("abc")"#).is_err());
+        assert!(eval(&library, r#"regexFind("abc", "(")"#).is_err());
+        assert!(eval(&library, r#"inCidr("10.0.0.1", "10.0.0.0/33")"#).is_err());
+        assert!(eval(&library, "lower(1)").is_err());
+    }
+
+    #[test]
+    fn test_custom_functions_are_registered() {
+        let library = FunctionLibrary::new()
+            .with_function("double", |_| Err("shadowed".into()))
+            .with_function("isVip", |args| match args {
+                [Value::String(id)] => Ok(Value::Bool(id.starts_with("vip-"))),
+                _ => Err("expected a caller ID".into()),
+            });
+
+        assert_eq!(
+            eval(&library, r#"isVip("vip-7")"#).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval(&library, r#""bob".isVip()"#).unwrap(),
+            Value::Bool(false)
+        );
+        let error = eval(&library, "isVip(1)").unwrap_err().to_string();
+        assert!(error.contains("expected a caller ID"), "{error}");
+        // Custom functions replace built-ins of the same name.
+        assert!(eval(&library, "double(1)").is_err());
+    }
+}
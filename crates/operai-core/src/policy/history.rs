@@ -0,0 +1,312 @@
+//! Per-session history of tool calls.
+//!
+//! [`PolicyStore`](super::session::PolicyStore) appends every call it
+//! evaluates to its session's history: a call that ran is recorded once its
+//! `After` stage effects have been evaluated, and a call denied by a `Before`
+//! stage effect when it is denied. Only the most recent
+//! [`HistorySettings::max_entries`] records are kept.
+//!
+//! Policies see the history as the `history` variable, a list of maps with
+//! `tool`, `time` (timestamp), `outcome` (`"succeeded"`, `"failed"` or
+//! `"denied"`) and `input_digest` (SHA-256 of the input JSON, or `null` when
+//! digests are not recorded), oldest first. The call being evaluated is not
+//! part of it. Two functions query it with a tool pattern, matched like an
+//! effect's `tool`:
+//!
+//! - `called(tool)`: whether a call to a matching tool succeeded
+//! - `count(tool, window)`: how many calls to matching tools ran (succeeded or
+//!   failed) within the `window` duration before `now`; `count(tool)` counts
+//!   them all
+//!
+//! For example, to only allow writes after a successful backup, and at most
+//! 100 of them per hour:
+//!
+//! ```text
+//! called("db.backup") && count("db.write", duration("1h")) < 100
+//! ```
+
+use std::{collections::HashMap, sync::Arc};
+
+use cel_interpreter::{Context, Value, objects::Map as CelMap};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use super::{
+    CompiledPattern,
+    functions::{self, arity, string},
+    request::PolicyRequest,
+};
+
+/// How much call history a [`PolicyStore`](super::session::PolicyStore)
+/// keeps per session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistorySettings {
+    /// Maximum number of records kept; older records are discarded first.
+    /// Zero disables history.
+    pub max_entries: usize,
+    /// Whether to record a SHA-256 digest of each call's input.
+    pub input_digests: bool,
+}
+
+impl Default for HistorySettings {
+    fn default() -> Self {
+        Self {
+            max_entries: 100,
+            input_digests: false,
+        }
+    }
+}
+
+/// How a recorded call ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallOutcome {
+    /// The tool ran and returned a result.
+    Succeeded,
+    /// The tool ran and returned an error.
+    Failed,
+    /// A policy denied the call before the tool ran.
+    Denied,
+}
+
+impl CallOutcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Denied => "denied",
+        }
+    }
+}
+
+/// A call in a session's history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallRecord {
+    /// Qualified ID of the called tool.
+    pub tool: String,
+    /// When the call was made.
+    pub time: DateTime<Utc>,
+    /// How the call ended.
+    pub outcome: CallOutcome,
+    /// Hex SHA-256 digest of the call's input JSON, if digests are recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_digest: Option<String>,
+}
+
+impl CallRecord {
+    /// Record `request` ending with `outcome`.
+    pub(super) fn new(
+        request: &PolicyRequest<'_>,
+        outcome: CallOutcome,
+        input: &JsonValue,
+        settings: HistorySettings,
+    ) -> Self {
+        Self {
+            tool: request.tool.to_string(),
+            time: request.time.into(),
+            outcome,
+            input_digest: settings
+                .input_digests
+                .then(|| sha256::digest(input.to_string())),
+        }
+    }
+
+    fn to_cel(&self) -> Value {
+        let map = HashMap::from([
+            ("tool".into(), Value::String(Arc::new(self.tool.clone()))),
+            ("time".into(), Value::Timestamp(self.time.fixed_offset())),
+            (
+                "outcome".into(),
+                Value::String(Arc::new(self.outcome.as_str().to_string())),
+            ),
+            (
+                "input_digest".into(),
+                self.input_digest
+                    .clone()
+                    .map_or(Value::Null, |digest| Value::String(Arc::new(digest))),
+            ),
+        ]);
+        Value::Map(CelMap { map: Arc::new(map) })
+    }
+}
+
+/// Add the `history` variable and the `called` and `count` functions for
+/// `history`, as seen at `now`, to `cel_ctx`.
+pub(super) fn add_variables(
+    history: &[CallRecord],
+    now: DateTime<Utc>,
+    cel_ctx: &mut Context<'static>,
+) {
+    cel_ctx.add_variable(
+        "history",
+        Value::List(Arc::new(history.iter().map(CallRecord::to_cel).collect())),
+    );
+
+    let records: Arc<[CallRecord]> = history.into();
+    let calls = Arc::clone(&records);
+    functions::add_function(
+        cel_ctx,
+        "called",
+        Arc::new(move |args: &[Value]| {
+            let [tool] = arity(args)?;
+            let pattern = CompiledPattern::new(string(tool)?);
+            Ok(Value::Bool(calls.iter().any(|record| {
+                record.outcome == CallOutcome::Succeeded && pattern.matches(&record.tool)
+            })))
+        }),
+    );
+    functions::add_function(
+        cel_ctx,
+        "count",
+        Arc::new(move |args: &[Value]| {
+            let (tool, window) = match args {
+                [tool] => (tool, None),
+                [tool, Value::Duration(window)] => (tool, Some(*window)),
+                [_, other] => {
+                    return Err(format!("expected duration, got {}", other.type_of()));
+                }
+                _ => return Err(format!("expected 1 or 2 arguments, got {}", args.len())),
+            };
+            let pattern = CompiledPattern::new(string(tool)?);
+            let count = records
+                .iter()
+                .filter(|record| {
+                    record.outcome != CallOutcome::Denied
+                        && pattern.matches(&record.tool)
+                        && window.is_none_or(|window| now - record.time <= window)
+                })
+                .count();
+            Ok(Value::Int(i64::try_from(count).unwrap_or(i64::MAX)))
+        }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::policy::{
+        CombiningAlgorithm, Policy, PolicyError,
+        scope::ScopedState,
+        session::{InMemoryPolicySessionStore, PolicySession, PolicySessionStore, PolicyStore},
+    };
+
+    fn policy(source: &str) -> Policy {
+        toml::from_str(source).expect("policy should parse")
+    }
+
+    #[tokio::test]
+    async fn test_history_records_calls_and_denials() {
+        let sessions = Arc::new(InMemoryPolicySessionStore::new());
+        let store = PolicyStore::new(sessions.clone()).with_history(HistorySettings {
+            max_entries: 2,
+            input_digests: true,
+        });
+        store
+            .register(policy(
+                r#"name = "backups"
+version = "1"
+
+[[effects]]
+tool = "db.write"
+stage = "before"
+when = 'called("db.backup")'
+fail_message = "back up first"
+"#,
+            ))
+            .expect("policy should register");
+
+        let write = PolicyRequest::new("s1", "db.write");
+        let denied = store.evaluate_pre_effects(&write, &json!({})).await;
+        assert!(matches!(denied, Err(PolicyError::GuardFailed(_))));
+
+        let backup = PolicyRequest::new("s1", "db.backup");
+        let outcome = store
+            .evaluate_post_effects(&backup, &json!({ "full": true }), Ok(&json!({})))
+            .await
+            .expect("post effects should evaluate");
+        assert_eq!(outcome, Ok(json!({})));
+        store
+            .evaluate_pre_effects(&write, &json!({}))
+            .await
+            .expect("write should be allowed after a backup");
+        let outcome = store
+            .evaluate_post_effects(&write, &json!({}), Err("disk full"))
+            .await
+            .expect("post effects should evaluate");
+        assert_eq!(outcome, Err("disk full".to_string()));
+
+        let session = sessions.load("s1").await.expect("session should load");
+        let history: Vec<_> = session
+            .history
+            .iter()
+            .map(|record| (record.tool.as_str(), record.outcome))
+            .collect();
+        // The denial was the oldest record and fell out of the history.
+        assert_eq!(
+            history,
+            [
+                ("db.backup", CallOutcome::Succeeded),
+                ("db.write", CallOutcome::Failed)
+            ]
+        );
+        assert_eq!(
+            session.history[0].input_digest,
+            Some(sha256::digest(json!({ "full": true }).to_string()))
+        );
+    }
+
+    #[test]
+    fn test_count_filters_by_pattern_window_and_outcome() {
+        let compiled = policy(
+            r#"name = "mail"
+version = "1"
+
+[[effects]]
+tool = "**"
+stage = "before"
+when = 'count("mail.*", duration("1h")) < 2 && count("mail.send") == 3 && size(history) == 4'
+fail_message = "too many emails"
+"#,
+        )
+        .compile()
+        .expect("policy should compile");
+        let now = SystemTime::now();
+        let record = |tool: &str, minutes_ago: u64, outcome| CallRecord {
+            tool: tool.into(),
+            time: (now - Duration::from_secs(minutes_ago * 60)).into(),
+            outcome,
+            input_digest: None,
+        };
+        let mut state = ScopedState::new(PolicySession {
+            history: vec![
+                record("mail.send", 120, CallOutcome::Succeeded),
+                record("mail.send", 30, CallOutcome::Failed),
+                record("mail.send", 10, CallOutcome::Denied),
+                record("mail.send", 5, CallOutcome::Succeeded),
+            ],
+            ..PolicySession::default()
+        });
+
+        let allowed = |state: &mut ScopedState| {
+            compiled
+                .evaluate_pre_effects(
+                    state,
+                    &PolicyRequest::new("s1", "mail.send").with_time(now),
+                    &mut json!({}),
+                    CombiningAlgorithm::DenyOverrides,
+                )
+                .is_ok()
+        };
+        // Two calls ran within the hour; the denied one does not count.
+        assert!(!allowed(&mut state));
+        let history = &mut state.session_mut().history;
+        history.remove(1);
+        history.insert(1, record("mail.send", 90, CallOutcome::Failed));
+        assert!(allowed(&mut state));
+    }
+}
@@ -0,0 +1,333 @@
+//! Facts about a tool call that policies can see besides its input.
+//!
+//! A [`PolicyRequest`] names the tool being called and carries what the
+//! runtime knows about the call: the tool's [`ToolInfo`], request and session
+//! identifiers, the authenticated [`CallerIdentity`] if any, which credential
+//! namespaces were supplied, and the time of the call. It is exposed to CEL
+//! expressions as these variables:
+//!
+//! - `tool`: Qualified tool ID string
+//! - `tool_info`: Map with `id`, `name`, `crate`, `version`, `display_name`,
+//!   `description`, `capabilities` and `tags`. Only `id` is set, with empty
+//!   lists, when the tool's metadata is unknown
+//! - `request`: Map with `id` and `session_id`
+//! - `caller`: Map with `id`, `roles` and `attributes`, or `null` for an
+//!   unauthenticated call
+//! - `credentials`: Sorted list of supplied credential namespaces (never their
+//!   values)
+//! - `now`: Timestamp of the call, in UTC
+//!
+//! For example, to require the `admin` role for destructive tools outside
+//! business hours:
+//!
+//! ```text
+//! !("destructive" in tool_info.tags)
+//!     || (caller != null && "admin" in caller.roles)
+//!     || (now.getHours() >= 9 && now.getHours() < 17)
+//! ```
+
+use std::{collections::HashMap, sync::Arc, time::SystemTime};
+
+use cel_interpreter::{Context, Value};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+
+use super::{to_cel_json, trace::PolicyTrace};
+use crate::ToolInfo;
+
+/// An authenticated caller.
+///
+/// Transports never authenticate callers themselves. An authentication layer
+/// in front of the transport inserts a `CallerIdentity` into the request's
+/// extensions, and the transport passes it on to policies.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CallerIdentity {
+    /// Stable identifier of the caller (e.g. a user or service account ID).
+    pub id: String,
+    /// Roles granted to the caller.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// Additional claims about the caller.
+    #[serde(default)]
+    pub attributes: HashMap<String, JsonValue>,
+}
+
+/// A tool call as seen by policy evaluation.
+#[derive(Debug, Clone)]
+pub struct PolicyRequest<'a> {
+    /// Qualified ID of the tool being called.
+    pub tool: &'a str,
+    /// Metadata of the tool, if it is known.
+    pub tool_info: Option<&'a ToolInfo>,
+    /// Identifier of the request.
+    pub request_id: &'a str,
+    /// Session whose policy state the call reads and updates.
+    pub session_id: &'a str,
+    /// The authenticated caller, if any.
+    pub caller: Option<&'a CallerIdentity>,
+    /// Credential namespaces supplied with the call.
+    pub credentials: Vec<String>,
+    /// When the call was made.
+    pub time: SystemTime,
+    /// Cost the call reported once it ran, replacing the tool's static cost.
+    pub cost: Option<f64>,
+    /// Where to record evaluated effects, if the call is being traced.
+    pub trace: Option<&'a PolicyTrace>,
+}
+
+impl<'a> PolicyRequest<'a> {
+    /// Create a request for `tool` in `session_id`, made now, with no other
+    /// facts known.
+    #[must_use]
+    pub fn new(session_id: &'a str, tool: &'a str) -> Self {
+        Self {
+            tool,
+            tool_info: None,
+            request_id: "",
+            session_id,
+            caller: None,
+            credentials: Vec::new(),
+            time: SystemTime::now(),
+            cost: None,
+            trace: None,
+        }
+    }
+
+    /// Set the tool's metadata.
+    #[must_use]
+    pub fn with_tool_info(mut self, tool_info: &'a ToolInfo) -> Self {
+        self.tool_info = Some(tool_info);
+        self
+    }
+
+    /// Set the request identifier.
+    #[must_use]
+    pub fn with_request_id(mut self, request_id: &'a str) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    /// Set the authenticated caller.
+    #[must_use]
+    pub fn with_caller(mut self, caller: Option<&'a CallerIdentity>) -> Self {
+        self.caller = caller;
+        self
+    }
+
+    /// Set the supplied credential namespaces.
+    #[must_use]
+    pub fn with_credentials<I, S>(mut self, namespaces: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.credentials = namespaces.into_iter().map(Into::into).collect();
+        self.credentials.sort();
+        self
+    }
+
+    /// Set the time of the call.
+    #[must_use]
+    pub fn with_time(mut self, time: SystemTime) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Set the cost the call reported.
+    #[must_use]
+    pub fn with_cost(mut self, cost: Option<f64>) -> Self {
+        self.cost = cost;
+        self
+    }
+
+    /// Return what the call costs: the reported cost once it ran, else the
+    /// tool's static cost, else 0.
+    #[must_use]
+    pub fn cost(&self) -> f64 {
+        self.cost
+            .or_else(|| self.tool_info.and_then(|info| info.cost))
+            .unwrap_or(0.0)
+    }
+
+    /// Set where to record every enforced effect evaluated for the call.
+    #[must_use]
+    pub fn with_trace(mut self, trace: Option<&'a PolicyTrace>) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// Add the request's CEL variables to `cel_ctx`.
+    pub(super) fn add_variables(&self, cel_ctx: &mut Context<'static>) {
+        cel_ctx.add_variable("tool", Value::String(Arc::new(self.tool.to_string())));
+        cel_ctx.add_variable("tool_info", to_cel_json(&self.tool_info_json()));
+        cel_ctx.add_variable(
+            "request",
+            to_cel_json(&json!({
+                "id": self.request_id,
+                "session_id": self.session_id,
+            })),
+        );
+        cel_ctx.add_variable(
+            "caller",
+            self.caller.map_or(Value::Null, |caller| {
+                to_cel_json(&serde_json::to_value(caller).unwrap_or_default())
+            }),
+        );
+        cel_ctx.add_variable("credentials", to_cel_json(&json!(self.credentials)));
+        let now: DateTime<Utc> = self.time.into();
+        cel_ctx.add_variable("now", Value::Timestamp(now.fixed_offset()));
+    }
+
+    fn tool_info_json(&self) -> JsonValue {
+        match self.tool_info {
+            Some(info) => json!({
+                "id": info.qualified_id,
+                "name": info.tool_id,
+                "crate": info.crate_name,
+                "version": info.crate_version,
+                "display_name": info.display_name,
+                "description": info.description,
+                "capabilities": info.capabilities,
+                "tags": info.tags,
+            }),
+            None => json!({
+                "id": self.tool,
+                "name": "",
+                "crate": "",
+                "version": "",
+                "display_name": "",
+                "description": "",
+                "capabilities": [],
+                "tags": [],
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::policy::{
+        CombiningAlgorithm, CompiledPolicy, Policy, PolicyError, scope::ScopedState,
+    };
+
+    fn compile(source: &str) -> CompiledPolicy {
+        let policy: Policy = toml::from_str(source).expect("policy should parse");
+        policy.compile().expect("policy should compile")
+    }
+
+    fn tool_info(tags: &[&str]) -> ToolInfo {
+        ToolInfo {
+            qualified_id: "db.drop".into(),
+            tool_id: "drop".into(),
+            crate_name: "db".into(),
+            crate_version: "1.2.0".into(),
+            display_name: "Drop".into(),
+            description: "Drops a table".into(),
+            input_schema: "{}".into(),
+            output_schema: "{}".into(),
+            credential_schema: None,
+            capabilities: vec!["write".into()],
+            tags: tags.iter().map(ToString::to_string).collect(),
+            embedding: None,
+            cost: None,
+            idempotent: false,
+            cache_ttl: None,
+        }
+    }
+
+    /// 2026-01-05 at `hour`:00 UTC.
+    fn at_hour(hour: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_767_571_200 + hour * 3600)
+    }
+
+    fn evaluate(policy: &CompiledPolicy, request: &PolicyRequest<'_>) -> Result<(), PolicyError> {
+        policy
+            .evaluate_pre_effects(
+                &mut ScopedState::default(),
+                request,
+                &mut json!({}),
+                CombiningAlgorithm::DenyOverrides,
+            )
+            .map(|_| ())
+    }
+
+    #[test]
+    fn test_destructive_tools_need_admin_outside_business_hours() {
+        let policy = compile(
+            r#"name = "hours"
+version = "1"
+
+[[effects]]
+tool = "**"
+stage = "before"
+when = '!("destructive" in tool_info.tags) || (caller != null && "admin" in caller.roles) || (now.getHours() >= 9 && now.getHours() < 17)'
+fail_message = "admin required"
+"#,
+        );
+        let destructive = tool_info(&["destructive"]);
+        let admin = CallerIdentity {
+            id: "ada".into(),
+            roles: vec!["admin".into()],
+            ..CallerIdentity::default()
+        };
+        let viewer = CallerIdentity {
+            id: "bob".into(),
+            roles: vec!["viewer".into()],
+            ..CallerIdentity::default()
+        };
+        let request = |info, caller, hour| {
+            PolicyRequest::new("s1", "db.drop")
+                .with_tool_info(info)
+                .with_caller(caller)
+                .with_time(at_hour(hour))
+        };
+
+        assert!(matches!(
+            evaluate(&policy, &request(&destructive, Some(&viewer), 22)),
+            Err(PolicyError::GuardFailed(denial)) if denial.message == "admin required"
+        ));
+        assert!(evaluate(&policy, &request(&destructive, None, 22)).is_err());
+        assert!(evaluate(&policy, &request(&destructive, Some(&admin), 22)).is_ok());
+        assert!(evaluate(&policy, &request(&destructive, Some(&viewer), 10)).is_ok());
+        assert!(evaluate(&policy, &request(&tool_info(&[]), Some(&viewer), 22)).is_ok());
+    }
+
+    #[test]
+    fn test_request_variables_are_exposed() {
+        let policy = compile(
+            r#"name = "facts"
+version = "1"
+
+[[effects]]
+tool = "**"
+stage = "before"
+when = 'request.id == "r1" && request.session_id == "s1" && credentials == ["github", "slack"] && tool_info.crate == "db" && tool_info.version == "1.2.0" && "write" in tool_info.capabilities'
+fail_message = "unexpected facts"
+"#,
+        );
+        let info = tool_info(&[]);
+        let request = PolicyRequest::new("s1", "db.drop")
+            .with_tool_info(&info)
+            .with_request_id("r1")
+            .with_credentials(["slack", "github"]);
+        assert!(evaluate(&policy, &request).is_ok());
+
+        // Unknown tools still expose the ID and empty lists.
+        let policy = compile(
+            r#"name = "unknown"
+version = "1"
+
+[[effects]]
+tool = "**"
+stage = "before"
+when = 'tool_info.id == "db.drop" && size(tool_info.tags) == 0 && caller == null'
+fail_message = "unexpected facts"
+"#,
+        );
+        assert!(evaluate(&policy, &PolicyRequest::new("s1", "db.drop")).is_ok());
+    }
+}
@@ -0,0 +1,191 @@
+//! Policy state shared beyond a single session.
+//!
+//! Besides the per-session `context`, policies can read and update two
+//! shared scopes:
+//!
+//! - **global**: one state shared by every call, exposed as `global_context`
+//!   and updated with an effect's `set_global`
+//! - **caller**: one state per authenticated caller ID, across all of the
+//!   caller's sessions, exposed as `caller_context` (`null` for unauthenticated
+//!   calls) and updated with `set_caller`
+//!
+//! Each scope is an ordinary [`PolicySession`] kept in the
+//! [`PolicySessionStore`](super::session::PolicySessionStore) under a reserved
+//! key (see [`PolicyScope::key`]), so it has the same versioning and
+//! optimistic concurrency control as sessions. Unlike sessions, scopes are
+//! exempt from the store's retention limits: they hold state such as budget
+//! spend that must not be reset when a caller goes idle or many new sessions
+//! are created. The scopes a call changed are saved together with
+//! [`save_all`](super::session::PolicySessionStore::save_all).
+//!
+//! For example, a global rate limit and a per-user daily quota:
+//!
+//! ```toml
+//! name = "limits"
+//! version = "1"
+//! global_context = { calls = 0 }
+//! caller_context = { emails = 0 }
+//!
+//! [[effects]]
+//! tool = "mail.send"
+//! stage = "before"
+//! when = "global_context.calls < 1000 && caller_context != null && caller_context.emails < 50"
+//! fail_message = "Email quota exceeded"
+//! set_global = { calls = "global_context.calls + 1" }
+//! set_caller = { emails = "caller_context.emails + 1" }
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::{request::PolicyRequest, session::PolicySession};
+
+/// Prefix of the store keys of shared scopes. Session IDs starting with it
+/// are rejected so that they cannot alias a shared scope.
+pub const RESERVED_PREFIX: char = '@';
+
+/// Return whether `key` is the store key of a shared scope rather than a
+/// session ID.
+#[must_use]
+pub fn is_scope_key(key: &str) -> bool {
+    key.starts_with(RESERVED_PREFIX)
+}
+
+/// A unit of policy state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyScope {
+    /// State shared by every call.
+    Global,
+    /// State shared by every call of one authenticated caller.
+    Caller,
+    /// State of one session.
+    Session,
+}
+
+impl PolicyScope {
+    /// All scopes, in the order they are loaded and saved.
+    pub const ALL: [Self; 3] = [Self::Global, Self::Caller, Self::Session];
+
+    /// Return the store key of this scope for `request`, or `None` for the
+    /// caller scope of an unauthenticated call.
+    #[must_use]
+    pub fn key(self, request: &PolicyRequest<'_>) -> Option<String> {
+        match self {
+            Self::Global => Some(format!("{RESERVED_PREFIX}global")),
+            Self::Caller => request
+                .caller
+                .map(|caller| format!("{RESERVED_PREFIX}caller:{}", caller.id)),
+            Self::Session => Some(request.session_id.to_string()),
+        }
+    }
+
+    /// Name of the CEL variable holding this scope's context.
+    pub(super) fn variable(self) -> &'static str {
+        match self {
+            Self::Global => "global_context",
+            Self::Caller => "caller_context",
+            Self::Session => "context",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for PolicyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Global => "global",
+            Self::Caller => "caller",
+            Self::Session => "session",
+        })
+    }
+}
+
+/// The state of every scope a call can read and update.
+///
+/// The session scope is always present. Shared scopes that were not loaded,
+/// such as the caller scope of an unauthenticated call, are absent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScopedState {
+    session: PolicySession,
+    global: Option<PolicySession>,
+    caller: Option<PolicySession>,
+    modified: [bool; 3],
+}
+
+impl ScopedState {
+    /// Create a state with only the session scope.
+    #[must_use]
+    pub fn new(session: PolicySession) -> Self {
+        Self {
+            session,
+            ..Self::default()
+        }
+    }
+
+    /// Set the state of `scope`.
+    #[must_use]
+    pub fn with(mut self, scope: PolicyScope, session: PolicySession) -> Self {
+        match scope {
+            PolicyScope::Global => self.global = Some(session),
+            PolicyScope::Caller => self.caller = Some(session),
+            PolicyScope::Session => self.session = session,
+        }
+        self
+    }
+
+    /// Return the state of `scope`, if it was loaded.
+    #[must_use]
+    pub fn get(&self, scope: PolicyScope) -> Option<&PolicySession> {
+        match scope {
+            PolicyScope::Global => self.global.as_ref(),
+            PolicyScope::Caller => self.caller.as_ref(),
+            PolicyScope::Session => Some(&self.session),
+        }
+    }
+
+    /// Return the state of the session scope.
+    #[must_use]
+    pub fn session(&self) -> &PolicySession {
+        &self.session
+    }
+
+    /// Return the state of the session scope mutably.
+    pub fn session_mut(&mut self) -> &mut PolicySession {
+        &mut self.session
+    }
+
+    /// Whether policy evaluation changed the context of `scope`.
+    #[must_use]
+    pub fn is_modified(&self, scope: PolicyScope) -> bool {
+        self.modified[scope.index()]
+    }
+
+    /// Return the state of `scope` to change it, marking it modified.
+    pub(super) fn modify(&mut self, scope: PolicyScope) -> Option<&mut PolicySession> {
+        let session = match scope {
+            PolicyScope::Global => self.global.as_mut()?,
+            PolicyScope::Caller => self.caller.as_mut()?,
+            PolicyScope::Session => &mut self.session,
+        };
+        self.modified[scope.index()] = true;
+        Some(session)
+    }
+
+    /// Return the loaded scopes and their state.
+    pub fn iter(&self) -> impl Iterator<Item = (PolicyScope, &PolicySession)> {
+        PolicyScope::ALL
+            .into_iter()
+            .filter_map(|scope| Some((scope, self.get(scope)?)))
+    }
+}
+
+impl From<PolicySession> for ScopedState {
+    fn from(session: PolicySession) -> Self {
+        Self::new(session)
+    }
+}
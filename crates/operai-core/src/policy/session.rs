@@ -12,7 +12,18 @@
 //!   modifications
 //! - **Optimistic Concurrency Control (OCC)**: Conflicts are detected via
 //!   version checks and resolved through retry loops
-//! - **History**: Chronological record of tool executions for policy evaluation
+//! - **History**: Chronological record of tool calls for policy evaluation,
+//!   bounded by [`HistorySettings`]
+//! - **Budgets**: Limits on the cost of tool calls, enforced by
+//!   [`PolicyStore`]; see [`super::budget`]
+//! - **Visibility**: `Visibility` stage effects hide tools from a session's
+//!   tool listings; see [`PolicyStore::filter_visible`]
+//! - **Policy management**: Registered policies can be listed, removed, or
+//!   swapped atomically (e.g., when a policy file changes) while calls are
+//!   being evaluated
+//! - **Lifecycle**: Stores may expire idle sessions and cap the number of live
+//!   sessions via [`SessionLimits`]; sessions can also be ended or reset
+//!   explicitly through [`PolicyStore`]
 //!
 //! # Concurrency Model
 //!
@@ -31,19 +42,24 @@
 //! // Register a policy
 //! policy_store.register(policy)?;
 //!
-//! // Evaluate pre-effects (before tool execution)
-//! policy_store.evaluate_pre_effects("session_id", "tool_name", &input).await?;
+//! // Evaluate pre-effects (before tool execution), which may rewrite the
+//! // input or require approval
+//! let request = PolicyRequest::new("session_id", "tool_name").with_caller(caller.as_ref());
+//! let pre = policy_store.evaluate_pre_effects(&request, &input).await?;
 //!
-//! // Execute tool...
+//! // Execute tool with `pre.input`...
 //!
-//! // Evaluate post-effects (after tool execution)
-//! policy_store.evaluate_post_effects("session_id", "tool_name", &input, Ok(&output)).await?;
+//! // Evaluate post-effects (after tool execution), which may reshape the
+//! // output, then charge the call to its budgets
+//! let outcome = policy_store
+//!     .evaluate_post_effects(&request.with_cost(reported_cost), &pre.input, Ok(&output))
+//!     .await?;
 //! ```
 
 use std::{
-    collections::HashMap,
-    sync::{Arc, RwLock},
-    time::Duration,
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant},
 };
 
 use async_trait::async_trait;
@@ -53,7 +69,32 @@ use serde_json::Value as JsonValue;
 use thiserror::Error;
 use tracing::{debug, instrument, warn};
 
-use super::{CompiledPolicy, Policy};
+use super::{
+    CombiningAlgorithm, CompiledPolicy, Decision, Policy, PolicyMode, PolicyStage, ToolOutcome,
+    budget::{Budget, BudgetExceeded, BudgetStatus, CompiledBudget, Spend},
+    functions::FunctionLibrary,
+    history::{CallOutcome, CallRecord, HistorySettings},
+    request::PolicyRequest,
+    scope::{PolicyScope, RESERVED_PREFIX, ScopedState, is_scope_key},
+    shadow::{ShadowReport, ShadowStats},
+    trace::PolicyTrace,
+};
+use crate::ToolInfo;
+
+mod sqlite;
+
+pub use sqlite::SqlitePolicySessionStore;
+
+/// The result of evaluating `Before` stage effects across all policies for a
+/// call that was not denied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreEffectsOutcome {
+    /// The tool input after any policy rewrites.
+    pub input: JsonValue,
+    /// The reason a person must approve the call before it runs, if any
+    /// effect requires approval.
+    pub approval: Option<String>,
+}
 
 /// A policy evaluation session that maintains state across tool executions.
 ///
@@ -65,7 +106,9 @@ use super::{CompiledPolicy, Policy};
 ///
 /// - `version`: Monotonically increasing version number for OCC
 /// - `context`: Arbitrary key-value pairs accessible in CEL expressions
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// - `history`: Most recent calls in the session, oldest first
+/// - `spend`: Spend against each cost budget
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct PolicySession {
     /// Version number for optimistic concurrency control.
     ///
@@ -78,6 +121,26 @@ pub struct PolicySession {
     /// This map is exposed as the `context` variable in CEL conditions and
     /// effect updates. Policies can read and modify these values.
     pub context: HashMap<String, JsonValue>,
+    /// Calls made in this session, oldest first.
+    ///
+    /// Exposed as the `history` variable in CEL expressions; see
+    /// [`history`](super::history).
+    #[serde(default)]
+    pub history: Vec<CallRecord>,
+    /// Spend against each [budget](super::budget) shared in this scope, by
+    /// budget name.
+    #[serde(default)]
+    pub spend: HashMap<String, Spend>,
+}
+
+impl PolicySession {
+    /// Append `record` to the history, keeping at most `max_entries` of the
+    /// most recent records.
+    pub fn record_call(&mut self, record: CallRecord, max_entries: usize) {
+        self.history.push(record);
+        let excess = self.history.len().saturating_sub(max_entries);
+        self.history.drain(..excess);
+    }
 }
 
 /// Errors that can occur during session storage operations.
@@ -104,6 +167,21 @@ pub enum SessionError {
 
 use crate::PolicyError;
 
+/// Retention limits applied by a session store.
+///
+/// Both limits are optional; the default keeps every session forever. They
+/// only apply to sessions: shared [scopes](super::scope) are never evicted
+/// and do not count towards `max_sessions`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionLimits {
+    /// Sessions not saved for longer than this are treated as absent and
+    /// removed.
+    pub idle_ttl: Option<Duration>,
+    /// Maximum number of sessions kept. Creating a session beyond this limit
+    /// evicts the least recently saved one.
+    pub max_sessions: Option<usize>,
+}
+
 /// Async storage interface for policy sessions.
 ///
 /// Implementations must be thread-safe (`Send + Sync`) to support concurrent
@@ -115,6 +193,10 @@ use crate::PolicyError;
 ///
 /// - [`Self::load`]: Retrieve a session by ID
 /// - [`Self::save`]: Persist a session with version checking
+/// - [`Self::delete`]: Remove a session
+///
+/// [`Self::save_all`] should be overridden to save several sessions
+/// atomically.
 #[async_trait]
 pub trait PolicySessionStore: std::fmt::Debug + Send + Sync {
     /// Load a session from storage.
@@ -130,15 +212,45 @@ pub trait PolicySessionStore: std::fmt::Debug + Send + Sync {
     /// versions don't match, this must return `SessionError::Conflict` to
     /// signal the caller to retry.
     async fn save(&self, session_id: &str, session: &PolicySession) -> Result<(), SessionError>;
+
+    /// Save several sessions, such as a call's session and the shared
+    /// [scopes](super::scope) it updated, all or nothing.
+    ///
+    /// Every version is checked before anything is written; if any check
+    /// fails, nothing is saved and `SessionError::Conflict` is returned.
+    ///
+    /// The default implementation saves the sessions one at a time, so a
+    /// conflict can leave earlier ones saved. Stores with concurrent writers
+    /// should override it.
+    async fn save_all(&self, sessions: &[(&str, &PolicySession)]) -> Result<(), SessionError> {
+        for (session_id, session) in sessions {
+            self.save(session_id, session).await?;
+        }
+        Ok(())
+    }
+
+    /// Remove a session from storage.
+    ///
+    /// Deleting a missing session is not an error. A concurrent writer still
+    /// holding the deleted session's version will get
+    /// `SessionError::Conflict` and reload an empty session.
+    async fn delete(&self, session_id: &str) -> Result<(), SessionError>;
+
+    /// Remove every session that has exceeded the store's idle TTL.
+    ///
+    /// Returns the number of sessions removed. Stores without a TTL return 0.
+    async fn evict_expired(&self) -> Result<usize, SessionError> {
+        Ok(0)
+    }
 }
 
 /// In-memory implementation of [`PolicySessionStore`].
 ///
 /// This implementation stores sessions in a `HashMap` protected by a `RwLock`.
 /// It's primarily useful for testing and single-process scenarios. For
-/// production use with multiple processes or persistence requirements,
-/// implement [`PolicySessionStore`] with a proper backend (database, file
-/// system, etc.).
+/// persistence across restarts or sharing between processes, use
+/// [`SqlitePolicySessionStore`] or implement [`PolicySessionStore`] with
+/// another backend.
 ///
 /// # Concurrency
 ///
@@ -147,7 +259,24 @@ pub trait PolicySessionStore: std::fmt::Debug + Send + Sync {
 /// result in `SessionError::LockPoisoned` on subsequent operations.
 #[derive(Debug, Default)]
 pub struct InMemoryPolicySessionStore {
-    sessions: RwLock<HashMap<String, PolicySession>>,
+    sessions: RwLock<HashMap<String, StoredSession>>,
+    limits: SessionLimits,
+}
+
+/// A session together with the time it was last saved.
+#[derive(Debug, Clone)]
+struct StoredSession {
+    session: PolicySession,
+    saved_at: Instant,
+}
+
+impl StoredSession {
+    fn is_expired(&self, key: &str, limits: &SessionLimits, now: Instant) -> bool {
+        !is_scope_key(key)
+            && limits
+                .idle_ttl
+                .is_some_and(|ttl| now.duration_since(self.saved_at) > ttl)
+    }
 }
 
 impl InMemoryPolicySessionStore {
@@ -155,6 +284,36 @@ impl InMemoryPolicySessionStore {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Apply idle TTL and maximum session count limits.
+    #[must_use]
+    pub fn with_limits(mut self, limits: SessionLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the number of stored sessions, including expired sessions not
+    /// yet evicted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SessionError::LockPoisoned` if the internal lock is poisoned.
+    pub fn len(&self) -> Result<usize, SessionError> {
+        Ok(self
+            .sessions
+            .read()
+            .map_err(|_| SessionError::LockPoisoned)?
+            .len())
+    }
+
+    /// Returns `true` if no sessions are stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SessionError::LockPoisoned` if the internal lock is poisoned.
+    pub fn is_empty(&self) -> Result<bool, SessionError> {
+        Ok(self.len()? == 0)
+    }
 }
 
 #[async_trait]
@@ -165,31 +324,102 @@ impl PolicySessionStore for InMemoryPolicySessionStore {
             .sessions
             .read()
             .map_err(|_| SessionError::LockPoisoned)?;
-        Ok(map.get(session_id).cloned().unwrap_or_default())
+        Ok(map
+            .get(session_id)
+            .filter(|stored| !stored.is_expired(session_id, &self.limits, Instant::now()))
+            .map(|stored| stored.session.clone())
+            .unwrap_or_default())
     }
 
     #[instrument(skip(self, session), fields(session_id = %session_id, version = session.version))]
     async fn save(&self, session_id: &str, session: &PolicySession) -> Result<(), SessionError> {
+        self.save_all(&[(session_id, session)]).await
+    }
+
+    async fn save_all(&self, sessions: &[(&str, &PolicySession)]) -> Result<(), SessionError> {
         let mut map = self
             .sessions
             .write()
             .map_err(|_| SessionError::LockPoisoned)?;
-        let current = map.entry(session_id.to_string()).or_default();
+        let now = Instant::now();
 
-        // Optimistic concurrency control: verify version before updating
-        if current.version != session.version {
-            return Err(SessionError::Conflict {
-                expected: current.version,
-                found: session.version,
-            });
+        for (session_id, session) in sessions {
+            // An expired session is indistinguishable from a missing one
+            // (version 0).
+            let current_version = map
+                .get(*session_id)
+                .filter(|stored| !stored.is_expired(session_id, &self.limits, now))
+                .map_or(0, |stored| stored.session.version);
+
+            // Optimistic concurrency control: verify version before updating
+            if current_version != session.version {
+                return Err(SessionError::Conflict {
+                    expected: current_version,
+                    found: session.version,
+                });
+            }
         }
 
-        // Increment version for the next save
-        let mut new_session = session.clone();
-        new_session.version += 1;
-        *current = new_session;
+        for (session_id, session) in sessions {
+            let session_count = |map: &HashMap<String, StoredSession>| {
+                map.keys().filter(|id| !is_scope_key(id)).count()
+            };
+            if let Some(max) = self.limits.max_sessions
+                && !is_scope_key(session_id)
+                && !map.contains_key(*session_id)
+                && session_count(&map) >= max
+            {
+                map.retain(|id, stored| !stored.is_expired(id, &self.limits, now));
+                while session_count(&map) >= max {
+                    let Some(oldest) = map
+                        .iter()
+                        .filter(|(id, _)| !is_scope_key(id))
+                        .min_by_key(|(_, stored)| stored.saved_at)
+                        .map(|(id, _)| id.clone())
+                    else {
+                        break;
+                    };
+                    debug!(evicted = %oldest, "Evicting least recently saved session");
+                    map.remove(&oldest);
+                }
+            }
+
+            // Increment version for the next save
+            let mut new_session = (*session).clone();
+            new_session.version += 1;
+            map.insert(
+                (*session_id).to_string(),
+                StoredSession {
+                    session: new_session,
+                    saved_at: now,
+                },
+            );
+        }
         Ok(())
     }
+
+    #[instrument(skip(self), fields(session_id = %session_id))]
+    async fn delete(&self, session_id: &str) -> Result<(), SessionError> {
+        self.sessions
+            .write()
+            .map_err(|_| SessionError::LockPoisoned)?
+            .remove(session_id);
+        Ok(())
+    }
+
+    async fn evict_expired(&self) -> Result<usize, SessionError> {
+        if self.limits.idle_ttl.is_none() {
+            return Ok(0);
+        }
+        let mut map = self
+            .sessions
+            .write()
+            .map_err(|_| SessionError::LockPoisoned)?;
+        let now = Instant::now();
+        let before = map.len();
+        map.retain(|id, stored| !stored.is_expired(id, &self.limits, now));
+        Ok(before - map.len())
+    }
 }
 
 /// Registry for compiled policies with session-aware evaluation.
@@ -205,31 +435,101 @@ impl PolicySessionStore for InMemoryPolicySessionStore {
 ///   context
 /// - **Post-effects**: After tool execution, can modify context
 ///
+/// Both stages visit policies in descending priority, then name order.
+/// Allow, deny, and approval decisions are combined with the store's
+/// [`CombiningAlgorithm`] (deny-overrides by default). Shadow policies are
+/// evaluated in order but never applied; see [`super::shadow`].
+///
+/// Calls the policies allow are then checked against the store's cost
+/// [budgets](super::budget), and the cost of calls that ran is added to
+/// them.
+///
 /// # Concurrency
 ///
 /// Uses optimistic concurrency control with retry loops (up to 3 attempts) to
 /// handle conflicts from concurrent policy evaluations.
 #[derive(Debug)]
 pub struct PolicyStore {
-    /// Compiled policies indexed by name.
-    policies: RwLock<HashMap<String, CompiledPolicy>>,
+    /// Compiled policies in evaluation order (descending priority, then
+    /// name).
+    policies: RwLock<Vec<CompiledPolicy>>,
+    /// How allow and deny decisions are combined.
+    combining: CombiningAlgorithm,
+    /// Functions available to policy expressions.
+    functions: Arc<FunctionLibrary>,
+    /// How much call history is kept per session.
+    history: HistorySettings,
+    /// Cost budgets enforced for every call.
+    budgets: Vec<CompiledBudget>,
     /// Session storage backend.
     store: Arc<dyn PolicySessionStore + Send + Sync>,
+    /// What shadow policies would have done.
+    shadow: ShadowReport,
 }
 
 impl PolicyStore {
     /// Create a new policy store with the given session storage backend.
     pub fn new(store: Arc<dyn PolicySessionStore + Send + Sync>) -> Self {
         Self {
-            policies: RwLock::new(HashMap::new()),
+            policies: RwLock::new(Vec::new()),
+            combining: CombiningAlgorithm::default(),
+            functions: FunctionLibrary::builtin(),
+            history: HistorySettings::default(),
+            budgets: Vec::new(),
             store,
+            shadow: ShadowReport::new(),
         }
     }
 
+    /// Return the accumulated shadow-mode decisions of shadow policies.
+    #[must_use]
+    pub fn shadow_report(&self) -> &ShadowReport {
+        &self.shadow
+    }
+
+    /// Add shadow observations from a completed evaluation to the report.
+    fn record_shadow(&self, tool: &str, observations: &Mutex<Vec<(String, ShadowStats)>>) {
+        let observations = observations.lock().expect("lock poisoned");
+        for (policy, stats) in observations.iter() {
+            self.shadow.record(policy, tool, stats);
+        }
+    }
+
+    /// Set how allow and deny decisions are combined.
+    #[must_use]
+    pub fn with_combining_algorithm(mut self, combining: CombiningAlgorithm) -> Self {
+        self.combining = combining;
+        self
+    }
+
+    /// Set the functions available to the expressions of policies registered
+    /// afterwards, in addition to the built-in ones.
+    #[must_use]
+    pub fn with_functions(mut self, functions: FunctionLibrary) -> Self {
+        self.functions = Arc::new(functions);
+        self
+    }
+
+    /// Set how much call history is kept per session.
+    #[must_use]
+    pub fn with_history(mut self, history: HistorySettings) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Set the cost budgets enforced for every call.
+    #[must_use]
+    pub fn with_budgets(mut self, budgets: Vec<Budget>) -> Self {
+        self.budgets = budgets.into_iter().map(CompiledBudget::new).collect();
+        self
+    }
+
     /// Register a policy for evaluation.
     ///
     /// The policy is compiled and stored by name. If a policy with the same
-    /// name already exists, it will be replaced.
+    /// name already exists, it will be replaced. Policies are evaluated in
+    /// descending priority, then name order, regardless of registration
+    /// order.
     ///
     /// # Errors
     ///
@@ -238,15 +538,74 @@ impl PolicyStore {
     #[instrument(skip(self, policy), fields(policy_name = %policy.name))]
     pub fn register(&self, policy: Policy) -> Result<(), PolicyError> {
         debug!("Registering policy");
-        let compiled = policy.compile()?;
-        let mut map = self
+        self.replace(&[], vec![policy])
+    }
+
+    /// Atomically remove the policies named `removed` and register
+    /// `policies`, replacing registered policies of the same names.
+    ///
+    /// Every policy is compiled before any change is made, so evaluations
+    /// see either the old or the new set, never a mix. Removing a name that
+    /// is not registered is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(PolicyError)` if a policy fails to compile, two of
+    /// `policies` share a name, or the policy lock is poisoned. No policy is
+    /// changed then.
+    #[instrument(skip_all, fields(removed = removed.len(), registered = policies.len()))]
+    pub fn replace(&self, removed: &[String], policies: Vec<Policy>) -> Result<(), PolicyError> {
+        let mut names = HashSet::new();
+        let compiled = policies
+            .into_iter()
+            .map(|policy| {
+                if !names.insert(policy.name.clone()) {
+                    return Err(PolicyError::CompilationError(format!(
+                        "Duplicate policy name: {}",
+                        policy.name
+                    )));
+                }
+                policy.compile_with(Arc::clone(&self.functions))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut registered = self
             .policies
             .write()
             .map_err(|_| PolicyError::EvalError("policy lock poisoned".into()))?;
-        map.insert(compiled.original.name.clone(), compiled);
+        registered
+            .retain(|p| !removed.contains(&p.original.name) && !names.contains(&p.original.name));
+        for compiled in compiled {
+            let index = registered.partition_point(|p| {
+                (std::cmp::Reverse(p.original.priority), &p.original.name)
+                    < (
+                        std::cmp::Reverse(compiled.original.priority),
+                        &compiled.original.name,
+                    )
+            });
+            registered.insert(index, compiled);
+        }
         Ok(())
     }
 
+    /// Unregister a policy by name.
+    ///
+    /// Returns whether the policy was registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if the policy lock is poisoned.
+    #[instrument(skip(self))]
+    pub fn remove(&self, name: &str) -> Result<bool, PolicyError> {
+        let mut policies = self
+            .policies
+            .write()
+            .map_err(|_| PolicyError::EvalError("policy lock poisoned".into()))?;
+        let before = policies.len();
+        policies.retain(|p| p.original.name != name);
+        Ok(policies.len() < before)
+    }
+
     /// Retrieve a registered policy by name.
     ///
     /// Returns `None` if the policy doesn't exist or if the lock is poisoned.
@@ -254,10 +613,118 @@ impl PolicyStore {
         self.policies
             .read()
             .ok()?
-            .get(name)
+            .iter()
+            .find(|cp| cp.original.name == name)
             .map(|cp| cp.original.clone())
     }
 
+    /// Return the registered policies in evaluation order.
+    ///
+    /// Returns an empty list if the lock is poisoned.
+    pub fn list(&self) -> Vec<Policy> {
+        self.policies.read().map_or_else(
+            |_| Vec::new(),
+            |policies| policies.iter().map(|cp| cp.original.clone()).collect(),
+        )
+    }
+
+    /// End a session, removing its state from storage.
+    ///
+    /// Call this when a client session closes so that its context does not
+    /// linger until TTL eviction. A later call with the same ID starts from a
+    /// fresh session.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if the session ID is reserved for a
+    /// shared [scope](super::scope) or the session store fails.
+    #[instrument(skip(self), fields(session_id = %session_id))]
+    pub async fn end_session(&self, session_id: &str) -> Result<(), PolicyError> {
+        debug!("Ending session");
+        check_session_id(session_id)?;
+        self.store
+            .delete(session_id)
+            .await
+            .map_err(|e| PolicyError::EvalError(format!("Failed to end session: {e}")))
+    }
+
+    /// Return a session's context, as policies last left it.
+    ///
+    /// An unknown session has an empty context.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if the session ID is reserved for a
+    /// shared [scope](super::scope) or the session store fails.
+    pub async fn session_context(
+        &self,
+        session_id: &str,
+    ) -> Result<HashMap<String, JsonValue>, PolicyError> {
+        check_session_id(session_id)?;
+        Ok(self.load_session(session_id).await?.context)
+    }
+
+    /// Reset a session's context while keeping the session alive.
+    ///
+    /// The context is cleared (policy defaults are re-applied on the next
+    /// evaluation) and the version advances, so concurrent evaluations that
+    /// loaded the old state will conflict and retry against the reset state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if the session ID is reserved for a
+    /// shared [scope](super::scope), the session store fails, or the reset
+    /// keeps conflicting after retries.
+    #[instrument(skip(self), fields(session_id = %session_id))]
+    pub async fn reset_session(&self, session_id: &str) -> Result<(), PolicyError> {
+        check_session_id(session_id)?;
+        let operation = || async {
+            let mut session = self
+                .store
+                .load(session_id)
+                .await
+                .map_err(|e| PolicyError::EvalError(format!("Failed to load session: {e}")))?;
+            session.context.clear();
+
+            self.store
+                .save(session_id, &session)
+                .await
+                .map_err(|e| save_error(&e))
+        };
+
+        operation
+            .retry(
+                ExponentialBuilder::default()
+                    .with_min_delay(Duration::from_millis(10))
+                    .with_max_delay(Duration::from_millis(100))
+                    .with_max_times(3)
+                    .with_jitter(),
+            )
+            .when(|e| matches!(e, PolicyError::SessionConflict))
+            .await
+            .map_err(|e| match e {
+                PolicyError::SessionConflict => PolicyError::EvalError(
+                    "Failed to reset session after retries due to conflicts".into(),
+                ),
+                other => other,
+            })
+    }
+
+    /// Remove sessions that have exceeded the store's idle TTL.
+    ///
+    /// Intended to be called periodically; returns the number of sessions
+    /// removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if the session store fails.
+    pub async fn evict_expired_sessions(&self) -> Result<usize, PolicyError> {
+        self.store
+            .evict_expired()
+            .await
+            .map_err(|e| PolicyError::EvalError(format!("Failed to evict sessions: {e}")))
+    }
+
     /// Evaluate pre-effects for all registered policies.
     ///
     /// This method evaluates the "before" stage of all policies that match the
@@ -267,59 +734,111 @@ impl PolicyStore {
     /// # Behavior
     ///
     /// - Loads the session from storage
-    /// - Evaluates all matching pre-effects
+    /// - Evaluates all matching pre-effects in policy order, stopping at the
+    ///   first decision under [`CombiningAlgorithm::FirstApplicable`]
+    /// - Checks the call against every budget that applies to it
     /// - Saves the session if any policies modified it
     /// - Returns early if no modifications were made (optimization)
+    /// - On a denial, discards context changes and records the call in the
+    ///   session's history instead
+    ///
+    /// Returns the tool input after any policy rewrites (unchanged when no
+    /// effect rewrites it), and the reason approval is required if an effect
+    /// holds the call for approval.
     ///
     /// # Errors
     ///
     /// Returns `PolicyError::GuardFailed` if any policy's guard condition
-    /// fails. Returns `PolicyError::EvalError` if session operations fail
-    /// after retries.
+    /// fails, and `PolicyError::BudgetExceeded` if a budget is spent or the
+    /// tool's static cost would overrun it. Returns `PolicyError::EvalError`
+    /// if the session ID is reserved for a shared [scope](super::scope) or
+    /// session operations fail after retries.
     ///
     /// # Panics
     ///
     /// Panics if the policy lock is poisoned (indicating a previous writer
     /// thread panicked while holding the read lock).
-    #[instrument(skip(self, input), fields(session_id = %session_id, tool = %tool))]
+    #[instrument(skip_all, fields(session_id = %request.session_id, tool = %request.tool))]
     pub async fn evaluate_pre_effects(
         &self,
-        session_id: &str,
-        tool: &str,
+        request: &PolicyRequest<'_>,
         input: &JsonValue,
-    ) -> Result<(), PolicyError> {
+    ) -> Result<PreEffectsOutcome, PolicyError> {
+        // Shadow observations and trace steps from the last attempt; earlier
+        // attempts lost a session conflict and are not counted.
+        let shadow = Mutex::new(Vec::new());
+        let trace_start = request.trace.map_or(0, PolicyTrace::len);
+        let caller_input = input;
         let operation = || async {
-            let mut session = self
-                .store
-                .load(session_id)
-                .await
-                .map_err(|e| PolicyError::EvalError(format!("Failed to load session: {e}")))?;
+            // Each attempt rewrites a fresh copy of the caller's input.
+            let mut input = input.clone();
+            let mut approval = None;
+            shadow.lock().expect("lock poisoned").clear();
+            if let Some(trace) = request.trace {
+                trace.truncate(trace_start);
+            }
+            let mut state = self.load_state(request).await?;
 
             let mut any_modified = false;
+            let mut denied = None;
             {
                 let policies = self.policies.read().expect("lock poisoned");
-                for policy in policies.values() {
-                    if policy.evaluate_pre_effects(&mut session, tool, input)? {
-                        any_modified = true;
+                for policy in policies.iter() {
+                    if policy.original.mode == PolicyMode::Shadow {
+                        if let Some(stats) =
+                            policy.shadow_pre_effects(&state, request, &input, self.combining)
+                        {
+                            shadow
+                                .lock()
+                                .expect("lock poisoned")
+                                .push((policy.original.name.clone(), stats));
+                        }
+                        continue;
+                    }
+                    let evaluation = match policy.evaluate_pre_effects(
+                        &mut state,
+                        request,
+                        &mut input,
+                        self.combining,
+                    ) {
+                        Err(PolicyError::GuardFailed(denial)) => {
+                            denied = Some(denial);
+                            break;
+                        }
+                        evaluation => evaluation?,
+                    };
+                    any_modified |= evaluation.modified;
+                    if approval.is_none() {
+                        approval = evaluation.approval;
+                    }
+                    if self.combining == CombiningAlgorithm::FirstApplicable
+                        && evaluation.decision.is_some()
+                    {
+                        break;
                     }
                 }
             }
 
+            if let Some(denial) = denied {
+                self.record_denial(request, caller_input).await?;
+                return Err(PolicyError::GuardFailed(denial));
+            }
+            if let Some(exceeded) = self.check_budgets(request, &state) {
+                self.record_denial(request, caller_input).await?;
+                return Err(PolicyError::BudgetExceeded(Box::new(exceeded)));
+            }
+
+            let outcome = PreEffectsOutcome { input, approval };
             if !any_modified {
                 // No changes, no need to save or check conflicts.
-                return Ok(());
+                return Ok(outcome);
             }
 
-            self.store.save(session_id, &session).await.map_err(|e| {
-                if matches!(e, SessionError::Conflict { .. }) {
-                    PolicyError::SessionConflict
-                } else {
-                    PolicyError::EvalError(format!("Failed to save session: {e}"))
-                }
-            })
+            self.save_state(request, &state, false).await?;
+            Ok(outcome)
         };
 
-        operation
+        let result = operation
             .retry(
                 ExponentialBuilder::default()
                     .with_min_delay(Duration::from_millis(10))
@@ -334,7 +853,243 @@ impl PolicyStore {
                     "Failed to reserve session after retries due to conflicts".into(),
                 ),
                 other => other,
+            });
+        self.record_shadow(request.tool, &shadow);
+        result
+    }
+
+    /// Load the session of `request`, and the shared scopes used by any
+    /// registered policy or by a budget that applies to the call.
+    async fn load_state(&self, request: &PolicyRequest<'_>) -> Result<ScopedState, PolicyError> {
+        check_session_id(request.session_id)?;
+        let shared: Vec<PolicyScope> = {
+            let policies = self.policies.read().expect("lock poisoned");
+            [PolicyScope::Global, PolicyScope::Caller]
+                .into_iter()
+                .filter(|scope| {
+                    policies
+                        .iter()
+                        .any(|policy| policy.shared_scopes().contains(scope))
+                        || self
+                            .applicable_budgets(request)
+                            .any(|budget| budget.scope() == *scope)
+                })
+                .collect()
+        };
+
+        let mut state = ScopedState::new(self.load_session(request.session_id).await?);
+        for scope in shared {
+            if let Some(key) = scope.key(request) {
+                state = state.with(scope, self.load_session(&key).await?);
+            }
+        }
+        Ok(state)
+    }
+
+    async fn load_session(&self, key: &str) -> Result<PolicySession, PolicyError> {
+        self.store
+            .load(key)
+            .await
+            .map_err(|e| PolicyError::EvalError(format!("Failed to load session: {e}")))
+    }
+
+    /// Save the scopes of `state` that were modified, and the session scope
+    /// if `session` is set, together.
+    async fn save_state(
+        &self,
+        request: &PolicyRequest<'_>,
+        state: &ScopedState,
+        session: bool,
+    ) -> Result<(), PolicyError> {
+        let changed: Vec<(String, &PolicySession)> = state
+            .iter()
+            .filter(|(scope, _)| {
+                state.is_modified(*scope) || (session && *scope == PolicyScope::Session)
             })
+            .filter_map(|(scope, scope_state)| Some((scope.key(request)?, scope_state)))
+            .collect();
+        if changed.is_empty() {
+            return Ok(());
+        }
+        let changed: Vec<(&str, &PolicySession)> = changed
+            .iter()
+            .map(|(key, scope_state)| (key.as_str(), *scope_state))
+            .collect();
+        self.store
+            .save_all(&changed)
+            .await
+            .map_err(|e| save_error(&e))
+    }
+
+    /// Return the budgets that apply to the call.
+    fn applicable_budgets<'a>(
+        &'a self,
+        request: &'a PolicyRequest<'_>,
+    ) -> impl Iterator<Item = &'a CompiledBudget> {
+        self.budgets
+            .iter()
+            .filter(|budget| budget.applies_to(request.tool))
+    }
+
+    /// Return the first budget the call may not be charged to, if any.
+    ///
+    /// Budgets shared in a scope that is not loaded, such as the caller
+    /// scope of an unauthenticated call, do not apply.
+    fn check_budgets(
+        &self,
+        request: &PolicyRequest<'_>,
+        state: &ScopedState,
+    ) -> Option<BudgetExceeded> {
+        let now = request.time.into();
+        let cost = request.cost();
+        self.applicable_budgets(request)
+            .find_map(|budget| budget.check(state.get(budget.scope())?, cost, now))
+    }
+
+    /// Add the cost of a call that ran to the budgets that apply to it.
+    fn charge_budgets(&self, request: &PolicyRequest<'_>, state: &mut ScopedState) {
+        let cost = request.cost();
+        if cost <= 0.0 {
+            return;
+        }
+        let now = request.time.into();
+        for budget in self.applicable_budgets(request) {
+            if let Some(scope_state) = state.modify(budget.scope()) {
+                budget.charge(scope_state, cost, now);
+            }
+        }
+    }
+
+    /// Return the state of every budget that applies to `request`, such as
+    /// to report what is left of them after the call.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if the session ID is reserved for a
+    /// shared [scope](super::scope) or the session store fails.
+    pub async fn budget_status(
+        &self,
+        request: &PolicyRequest<'_>,
+    ) -> Result<Vec<BudgetStatus>, PolicyError> {
+        if self.applicable_budgets(request).next().is_none() {
+            return Ok(Vec::new());
+        }
+        let state = self.load_state(request).await?;
+        let now = request.time.into();
+        Ok(self
+            .applicable_budgets(request)
+            .filter_map(|budget| Some(budget.status(state.get(budget.scope())?, now)))
+            .collect())
+    }
+
+    /// Return whether any enforced policy has `Visibility` stage effects.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the policy lock is poisoned.
+    #[must_use]
+    pub fn has_visibility_effects(&self) -> bool {
+        self.policies
+            .read()
+            .expect("lock poisoned")
+            .iter()
+            .filter(|policy| policy.original.mode == PolicyMode::Enforce)
+            .flat_map(|policy| &policy.effects)
+            .any(|effect| effect.original.stage == PolicyStage::Visibility)
+    }
+
+    /// Keep the `items` whose tool the session and caller of `request` may
+    /// see, in order.
+    ///
+    /// Each tool is evaluated against the `Visibility` stage effects of every
+    /// enforced policy, in policy order, with `request`'s tool and metadata
+    /// replaced by the tool's; decisions are combined like `Before` stage
+    /// decisions. The session and shared scopes are loaded once and never
+    /// saved. A tool whose evaluation fails is hidden. Shadow policies hide
+    /// nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError::EvalError` if the session ID is reserved for a
+    /// shared [scope](super::scope) or the session store fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the policy lock is poisoned.
+    #[instrument(skip_all, fields(session_id = %request.session_id))]
+    pub async fn filter_visible<T>(
+        &self,
+        request: &PolicyRequest<'_>,
+        mut items: Vec<T>,
+        tool_info: impl Fn(&T) -> &ToolInfo,
+    ) -> Result<Vec<T>, PolicyError> {
+        if !self.has_visibility_effects() {
+            return Ok(items);
+        }
+        let mut state = self.load_state(request).await?;
+        let policies = self.policies.read().expect("lock poisoned");
+        items.retain(|item| {
+            let info = tool_info(item);
+            let request = PolicyRequest {
+                tool: &info.qualified_id,
+                tool_info: Some(info),
+                ..request.clone()
+            };
+            let mut visible = true;
+            for policy in policies
+                .iter()
+                .filter(|policy| policy.original.mode == PolicyMode::Enforce)
+            {
+                match policy.evaluate_visibility(&mut state, &request, self.combining) {
+                    Ok(None) => {}
+                    Ok(Some(decision)) => {
+                        visible = decision != Decision::Deny;
+                        if !visible || self.combining == CombiningAlgorithm::FirstApplicable {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        warn!(
+                            policy = %policy.original.name,
+                            tool = %info.qualified_id,
+                            error = %e,
+                            "Failed to evaluate visibility, hiding tool"
+                        );
+                        visible = false;
+                        break;
+                    }
+                }
+            }
+            visible
+        });
+        Ok(items)
+    }
+
+    /// Record a denied call in its session's history.
+    ///
+    /// The session is reloaded so that context updates made by effects
+    /// evaluated before the denial are discarded.
+    async fn record_denial(
+        &self,
+        request: &PolicyRequest<'_>,
+        input: &JsonValue,
+    ) -> Result<(), PolicyError> {
+        if self.history.max_entries == 0 {
+            return Ok(());
+        }
+        let mut session = self
+            .store
+            .load(request.session_id)
+            .await
+            .map_err(|e| PolicyError::EvalError(format!("Failed to load session: {e}")))?;
+        session.record_call(
+            CallRecord::new(request, CallOutcome::Denied, input, self.history),
+            self.history.max_entries,
+        );
+        self.store
+            .save(request.session_id, &session)
+            .await
+            .map_err(|e| save_error(&e))
     }
 
     /// Evaluate post-effects for all registered policies.
@@ -346,9 +1101,14 @@ impl PolicyStore {
     ///
     /// - Loads the session from storage
     /// - Evaluates all matching post-effects
-    /// - Saves the session if context was modified
+    /// - Records the call in the session's history
+    /// - Adds the call's cost to the budgets that apply to it
+    /// - Saves the session
     /// - Retries up to 3 times on conflict
     ///
+    /// Returns the tool outcome after any policy transforms, drops, or
+    /// failures; it matches `output` when no effect reshapes it.
+    ///
     /// # Panics
     ///
     /// Panics if the policy lock is poisoned (indicating a previous writer
@@ -358,38 +1118,60 @@ impl PolicyStore {
     ///
     /// Returns `PolicyError::EvalError` if session operations fail after
     /// retries.
-    #[instrument(skip(self, input, output), fields(session_id = %session_id, tool = %tool))]
+    #[instrument(skip_all, fields(session_id = %request.session_id, tool = %request.tool))]
     pub async fn evaluate_post_effects(
         &self,
-        session_id: &str,
-        tool: &str,
+        request: &PolicyRequest<'_>,
         input: &JsonValue,
         output: Result<&JsonValue, &str>,
-    ) -> Result<(), PolicyError> {
+    ) -> Result<ToolOutcome, PolicyError> {
+        let shadow = Mutex::new(Vec::new());
+        let trace_start = request.trace.map_or(0, PolicyTrace::len);
         let operation = || async {
-            let mut session = self
-                .store
-                .load(session_id)
-                .await
-                .map_err(|e| PolicyError::EvalError(format!("Failed to load session: {e}")))?;
+            // Each attempt reshapes a fresh copy of the tool's result.
+            let mut outcome = output.cloned().map_err(str::to_string);
+            shadow.lock().expect("lock poisoned").clear();
+            if let Some(trace) = request.trace {
+                trace.truncate(trace_start);
+            }
+            let mut state = self.load_state(request).await?;
 
             {
                 let policies = self.policies.read().expect("lock poisoned");
-                for policy in policies.values() {
-                    policy.evaluate_post_effects(&mut session, tool, input, output)?;
+                for policy in policies.iter() {
+                    if policy.original.mode == PolicyMode::Shadow {
+                        if let Some(stats) =
+                            policy.shadow_post_effects(&state, request, input, &outcome)
+                        {
+                            shadow
+                                .lock()
+                                .expect("lock poisoned")
+                                .push((policy.original.name.clone(), stats));
+                        }
+                        continue;
+                    }
+                    policy.evaluate_post_effects(&mut state, request, input, &mut outcome)?;
                 }
             }
 
-            self.store.save(session_id, &session).await.map_err(|e| {
-                if matches!(e, SessionError::Conflict { .. }) {
-                    PolicyError::SessionConflict
+            if self.history.max_entries > 0 {
+                let status = if output.is_ok() {
+                    CallOutcome::Succeeded
                 } else {
-                    PolicyError::EvalError(format!("Failed to save session: {e}"))
-                }
-            })
+                    CallOutcome::Failed
+                };
+                state.session_mut().record_call(
+                    CallRecord::new(request, status, input, self.history),
+                    self.history.max_entries,
+                );
+            }
+            self.charge_budgets(request, &mut state);
+
+            self.save_state(request, &state, true).await?;
+            Ok(outcome)
         };
 
-        operation
+        let result = operation
             .retry(
                 ExponentialBuilder::default()
                     .with_min_delay(Duration::from_millis(10))
@@ -404,6 +1186,708 @@ impl PolicyStore {
                     "Failed to save session after retries due to conflicts".into(),
                 ),
                 other => other,
+            });
+        self.record_shadow(request.tool, &shadow);
+        result
+    }
+}
+
+/// Maps a failed save to `PolicyError::SessionConflict` on a version
+/// conflict, so that the evaluation is retried.
+fn save_error(e: &SessionError) -> PolicyError {
+    if matches!(e, SessionError::Conflict { .. }) {
+        PolicyError::SessionConflict
+    } else {
+        PolicyError::EvalError(format!("Failed to save session: {e}"))
+    }
+}
+
+/// Rejects session IDs that could alias the store key of a shared scope.
+fn check_session_id(session_id: &str) -> Result<(), PolicyError> {
+    if is_scope_key(session_id) {
+        return Err(PolicyError::EvalError(format!(
+            "Session IDs must not start with {RESERVED_PREFIX:?}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::policy::request::CallerIdentity;
+
+    fn session_with(key: &str, value: JsonValue) -> PolicySession {
+        let mut session = PolicySession::default();
+        session.context.insert(key.into(), value);
+        session
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_idle_ttl_expires_sessions() {
+        let store = InMemoryPolicySessionStore::new().with_limits(SessionLimits {
+            idle_ttl: Some(Duration::from_millis(20)),
+            max_sessions: None,
+        });
+        store
+            .save("s1", &session_with("k", json!(1)))
+            .await
+            .unwrap();
+        assert_eq!(store.load("s1").await.unwrap().version, 1);
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        // Expired sessions read as fresh and can be recreated at version 0.
+        let session = store.load("s1").await.unwrap();
+        assert_eq!(session.version, 0);
+        assert!(session.context.is_empty());
+        store.save("s2", &PolicySession::default()).await.unwrap();
+
+        assert_eq!(store.evict_expired().await.unwrap(), 1);
+        assert_eq!(store.len().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_max_sessions_evicts_least_recently_saved() {
+        let store = InMemoryPolicySessionStore::new().with_limits(SessionLimits {
+            idle_ttl: None,
+            max_sessions: Some(2),
+        });
+        store.save("a", &PolicySession::default()).await.unwrap();
+        store.save("b", &PolicySession::default()).await.unwrap();
+        // Touch "a" so "b" becomes the least recently saved.
+        let a = store.load("a").await.unwrap();
+        store.save("a", &a).await.unwrap();
+
+        store.save("c", &PolicySession::default()).await.unwrap();
+
+        assert_eq!(store.len().unwrap(), 2);
+        assert_eq!(store.load("a").await.unwrap().version, 2);
+        assert_eq!(store.load("b").await.unwrap().version, 0);
+        assert_eq!(store.load("c").await.unwrap().version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale_save_after_delete_conflicts() {
+        let store = InMemoryPolicySessionStore::new();
+        store.save("s1", &PolicySession::default()).await.unwrap();
+        let stale = store.load("s1").await.unwrap();
+
+        store.delete("s1").await.unwrap();
+
+        assert!(store.is_empty().unwrap());
+        assert!(matches!(
+            store.save("s1", &stale).await,
+            Err(SessionError::Conflict {
+                expected: 0,
+                found: 1
             })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_policy_store_end_and_reset_session() {
+        let store = Arc::new(InMemoryPolicySessionStore::new());
+        let policy_store = PolicyStore::new(store.clone());
+
+        store
+            .save("s1", &session_with("count", json!(3)))
+            .await
+            .unwrap();
+        assert_eq!(
+            policy_store.session_context("s1").await.unwrap(),
+            HashMap::from([("count".to_string(), json!(3))])
+        );
+        policy_store.reset_session("s1").await.unwrap();
+        assert!(policy_store.session_context("s1").await.unwrap().is_empty());
+        assert!(policy_store.session_context("@global").await.is_err());
+        let session = store.load("s1").await.unwrap();
+        assert_eq!(session.version, 2);
+        assert!(session.context.is_empty());
+
+        policy_store.end_session("s1").await.unwrap();
+        assert!(store.is_empty().unwrap());
+    }
+
+    fn policy(source: &str) -> Policy {
+        toml::from_str(source).unwrap()
+    }
+
+    /// A policy whose before effect appends its name to `context.order`.
+    fn ordered_policy(name: &str, priority: i32) -> Policy {
+        policy(&format!(
+            r#"name = "{name}"
+version = "1"
+priority = {priority}
+context = {{ order = [] }}
+
+[[effects]]
+tool = "*"
+stage = "before"
+when = "true"
+set = {{ order = "context.order + ['{name}']" }}
+"#
+        ))
+    }
+
+    fn decision_policy(name: &str, priority: i32, effect: &str) -> Policy {
+        policy(&format!(
+            r#"name = "{name}"
+version = "1"
+priority = {priority}
+
+[[effects]]
+tool = "*"
+stage = "before"
+when = "true"
+effect = "{effect}"
+fail_message = "{name} denied"
+set = {{ {name} = "true" }}
+"#
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_policies_evaluate_in_priority_then_name_order() {
+        for names in [["b", "c", "a"], ["a", "b", "c"], ["c", "a", "b"]] {
+            let store = Arc::new(InMemoryPolicySessionStore::new());
+            let policy_store = PolicyStore::new(store.clone());
+            for name in names {
+                let priority = if name == "c" { 10 } else { 0 };
+                policy_store
+                    .register(ordered_policy(name, priority))
+                    .unwrap();
+            }
+            // Re-registering replaces the policy without duplicating it.
+            policy_store.register(ordered_policy("b", 0)).unwrap();
+
+            policy_store
+                .evaluate_pre_effects(&PolicyRequest::new("s1", "tool"), &json!({}))
+                .await
+                .unwrap();
+
+            let session = store.load("s1").await.unwrap();
+            assert_eq!(session.context["order"], json!(["c", "a", "b"]));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replace_swaps_policies_atomically() {
+        let store = PolicyStore::new(Arc::new(InMemoryPolicySessionStore::new()));
+        store.register(ordered_policy("a", 0)).unwrap();
+        store.register(ordered_policy("b", 0)).unwrap();
+
+        // A policy that fails to compile leaves the registered set untouched.
+        let mut invalid = ordered_policy("c", 0);
+        invalid.effects[0].condition = "input.".to_string();
+        assert!(
+            store
+                .replace(&["a".to_string()], vec![ordered_policy("b", 5), invalid])
+                .is_err()
+        );
+        assert!(
+            store
+                .replace(&[], vec![ordered_policy("c", 0), ordered_policy("c", 1)])
+                .is_err()
+        );
+        let names = |store: &PolicyStore| -> Vec<(String, i32)> {
+            store
+                .list()
+                .into_iter()
+                .map(|policy| (policy.name, policy.priority))
+                .collect()
+        };
+        assert_eq!(names(&store), [("a".into(), 0), ("b".into(), 0)]);
+
+        store
+            .replace(
+                &["a".to_string()],
+                vec![ordered_policy("b", 5), ordered_policy("c", 0)],
+            )
+            .unwrap();
+        assert_eq!(names(&store), [("b".into(), 5), ("c".into(), 0)]);
+
+        assert!(store.remove("b").unwrap());
+        assert!(!store.remove("b").unwrap());
+        assert!(store.get("b").is_none());
+        assert_eq!(names(&store), [("c".into(), 0)]);
+    }
+
+    #[tokio::test]
+    async fn test_deny_overrides_earlier_allow() {
+        let store = Arc::new(InMemoryPolicySessionStore::new());
+        let policy_store = PolicyStore::new(store);
+        policy_store
+            .register(decision_policy("permit", 10, "allow"))
+            .unwrap();
+        policy_store
+            .register(decision_policy("low", 0, "deny"))
+            .unwrap();
+        policy_store
+            .register(decision_policy("high", 5, "deny"))
+            .unwrap();
+
+        let result = policy_store
+            .evaluate_pre_effects(&PolicyRequest::new("s1", "tool"), &json!({}))
+            .await;
+
+        assert!(
+            matches!(result, Err(PolicyError::GuardFailed(denial)) if denial.message == "high denied")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_applicable_stops_at_first_decision() {
+        let store = Arc::new(InMemoryPolicySessionStore::new());
+        let policy_store = PolicyStore::new(store.clone())
+            .with_combining_algorithm(CombiningAlgorithm::FirstApplicable);
+        policy_store
+            .register(decision_policy("permit", 10, "allow"))
+            .unwrap();
+        policy_store
+            .register(decision_policy("block", 0, "deny"))
+            .unwrap();
+        policy_store.register(ordered_policy("audit", -5)).unwrap();
+
+        policy_store
+            .evaluate_pre_effects(&PolicyRequest::new("s1", "tool"), &json!({}))
+            .await
+            .unwrap();
+        let session = store.load("s1").await.unwrap();
+        assert_eq!(session.context.get("permit"), Some(&json!(true)));
+        assert!(!session.context.contains_key("order"));
+
+        policy_store
+            .register(decision_policy("block", 20, "deny"))
+            .unwrap();
+        let result = policy_store
+            .evaluate_pre_effects(&PolicyRequest::new("s1", "tool"), &json!({}))
+            .await;
+        assert!(
+            matches!(result, Err(PolicyError::GuardFailed(denial)) if denial.message == "block denied")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_require_approval_holds_call_unless_denied() {
+        let store = Arc::new(InMemoryPolicySessionStore::new());
+        let policy_store = PolicyStore::new(store);
+        policy_store
+            .register(decision_policy("permit", 10, "allow"))
+            .unwrap();
+        policy_store
+            .register(decision_policy("review", 0, "require_approval"))
+            .unwrap();
+
+        let outcome = policy_store
+            .evaluate_pre_effects(&PolicyRequest::new("s1", "tool"), &json!({"id": 1}))
+            .await
+            .unwrap();
+        assert_eq!(outcome.input, json!({"id": 1}));
+        assert_eq!(outcome.approval.as_deref(), Some("review denied"));
+
+        policy_store
+            .register(decision_policy("block", -10, "deny"))
+            .unwrap();
+        let result = policy_store
+            .evaluate_pre_effects(&PolicyRequest::new("s1", "tool"), &json!({}))
+            .await;
+        assert!(
+            matches!(result, Err(PolicyError::GuardFailed(denial)) if denial.message == "block denied")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_applicable_allow_skips_later_approval() {
+        let store = Arc::new(InMemoryPolicySessionStore::new());
+        let policy_store =
+            PolicyStore::new(store).with_combining_algorithm(CombiningAlgorithm::FirstApplicable);
+        policy_store
+            .register(decision_policy("permit", 10, "allow"))
+            .unwrap();
+        policy_store
+            .register(decision_policy("review", 0, "require_approval"))
+            .unwrap();
+
+        let outcome = policy_store
+            .evaluate_pre_effects(&PolicyRequest::new("s1", "tool"), &json!({}))
+            .await
+            .unwrap();
+        assert_eq!(outcome.approval, None);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_policy_is_reported_but_not_applied() {
+        let store = Arc::new(InMemoryPolicySessionStore::new());
+        let policy_store = PolicyStore::new(store.clone());
+        let mut shadow = decision_policy("strict", 10, "deny");
+        shadow.mode = PolicyMode::Shadow;
+        policy_store.register(shadow).unwrap();
+        policy_store.register(ordered_policy("audit", 0)).unwrap();
+
+        let outcome = policy_store
+            .evaluate_pre_effects(&PolicyRequest::new("s1", "tool"), &json!({}))
+            .await
+            .unwrap();
+        assert_eq!(outcome.approval, None);
+        let output = policy_store
+            .evaluate_post_effects(
+                &PolicyRequest::new("s1", "tool"),
+                &json!({}),
+                Ok(&json!({})),
+            )
+            .await
+            .unwrap();
+        assert_eq!(output, Ok(json!({})));
+
+        let session = store.load("s1").await.unwrap();
+        assert!(!session.context.contains_key("strict"));
+        assert_eq!(session.context["order"], json!(["audit"]));
+
+        let entries = policy_store.shadow_report().entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].policy, "strict");
+        assert_eq!(entries[0].tool, "tool");
+        assert_eq!(entries[0].stats.evaluations, 1);
+        assert_eq!(entries[0].stats.denials, 1);
+    }
+
+    #[test]
+    fn test_decisions_fail_to_compile_in_after_stage() {
+        for effect in ["deny", "require_approval"] {
+            let mut after = decision_policy("p", 0, effect);
+            after.effects[0].stage = crate::policy::PolicyStage::After;
+
+            assert!(matches!(
+                after.compile(),
+                Err(PolicyError::CompilationError(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shared_scopes_span_sessions_and_callers() {
+        let sessions = Arc::new(InMemoryPolicySessionStore::new());
+        let store = PolicyStore::new(sessions.clone());
+        store
+            .register(policy(
+                r#"name = "limits"
+version = "1"
+global_context = { calls = 0 }
+caller_context = { calls = 0 }
+
+[[effects]]
+tool = "*"
+stage = "before"
+when = "global_context.calls < 3"
+fail_message = "global limit"
+set_global = { calls = "global_context.calls + 1" }
+set_caller = { calls = "caller_context.calls + 1" }
+"#,
+            ))
+            .unwrap();
+
+        let alice = CallerIdentity {
+            id: "alice".into(),
+            ..CallerIdentity::default()
+        };
+        let bob = CallerIdentity {
+            id: "bob".into(),
+            ..CallerIdentity::default()
+        };
+        for (session_id, caller) in [("s1", Some(&alice)), ("s2", Some(&alice)), ("s3", None)] {
+            let request = PolicyRequest::new(session_id, "t").with_caller(caller);
+            store
+                .evaluate_pre_effects(&request, &json!({}))
+                .await
+                .unwrap();
+        }
+
+        let global = sessions.load("@global").await.unwrap();
+        assert_eq!(global.context["calls"], json!(3));
+        let caller = sessions.load("@caller:alice").await.unwrap();
+        assert_eq!(caller.context["calls"], json!(2));
+        // Unauthenticated calls have no caller scope to update.
+        assert!(
+            !sessions
+                .load("@caller:")
+                .await
+                .unwrap()
+                .context
+                .contains_key("calls")
+        );
+
+        let request = PolicyRequest::new("s4", "t").with_caller(Some(&bob));
+        assert!(matches!(
+            store.evaluate_pre_effects(&request, &json!({})).await,
+            Err(PolicyError::GuardFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reserved_session_ids_are_rejected() {
+        let store = PolicyStore::new(Arc::new(InMemoryPolicySessionStore::new()));
+        let request = PolicyRequest::new("@global", "t");
+
+        assert!(matches!(
+            store.evaluate_pre_effects(&request, &json!({})).await,
+            Err(PolicyError::EvalError(_))
+        ));
+        assert!(matches!(
+            store.reset_session("@global").await,
+            Err(PolicyError::EvalError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_save_all_is_atomic() {
+        let store = InMemoryPolicySessionStore::new();
+        store.save("b", &PolicySession::default()).await.unwrap();
+
+        // "b" is stale, so neither session is written.
+        let fresh = session_with("k", json!(1));
+        let result = store
+            .save_all(&[("a", &fresh), ("b", &PolicySession::default())])
+            .await;
+        assert!(matches!(result, Err(SessionError::Conflict { .. })));
+        assert_eq!(store.load("a").await.unwrap().version, 0);
+
+        let current = store.load("b").await.unwrap();
+        store
+            .save_all(&[("a", &fresh), ("b", &current)])
+            .await
+            .unwrap();
+        assert_eq!(store.load("a").await.unwrap().version, 1);
+        assert_eq!(store.load("b").await.unwrap().version, 2);
+    }
+
+    #[tokio::test]
+    #[expect(clippy::float_cmp, reason = "the costs are exactly representable")]
+    async fn test_budgets_deny_calls_once_spent() {
+        let sessions = Arc::new(InMemoryPolicySessionStore::new());
+        let store = PolicyStore::new(sessions.clone()).with_budgets(vec![
+            Budget::new("search", 2.0)
+                .with_tool("search.*")
+                .with_scope(PolicyScope::Caller),
+        ]);
+        let alice = CallerIdentity {
+            id: "alice".into(),
+            ..CallerIdentity::default()
+        };
+        let info = ToolInfo {
+            qualified_id: "search.web".into(),
+            tool_id: "web".into(),
+            crate_name: "search".into(),
+            crate_version: "1.0.0".into(),
+            display_name: "Web search".into(),
+            description: String::new(),
+            input_schema: "{}".into(),
+            output_schema: "{}".into(),
+            credential_schema: None,
+            capabilities: Vec::new(),
+            tags: Vec::new(),
+            embedding: None,
+            cost: Some(0.5),
+            idempotent: false,
+            cache_ttl: None,
+        };
+        let call = |session_id| {
+            PolicyRequest::new(session_id, "search.web")
+                .with_tool_info(&info)
+                .with_caller(Some(&alice))
+        };
+
+        // The first call reports its own cost; the second costs the static 0.5.
+        for (session_id, cost) in [("s1", Some(1.25)), ("s2", None)] {
+            let request = call(session_id).with_cost(cost);
+            store
+                .evaluate_pre_effects(&request, &json!({}))
+                .await
+                .unwrap();
+            let _outcome = store
+                .evaluate_post_effects(&request, &json!({}), Ok(&json!({})))
+                .await
+                .unwrap();
+        }
+        let status = store.budget_status(&call("s3")).await.unwrap();
+        assert_eq!(status.len(), 1);
+        assert_eq!(status[0].spent, 1.75);
+        assert_eq!(status[0].remaining(), 0.25);
+
+        let Err(PolicyError::BudgetExceeded(exceeded)) =
+            store.evaluate_pre_effects(&call("s3"), &json!({})).await
+        else {
+            panic!("expected the budget to be exceeded");
+        };
+        assert_eq!(exceeded.cost, 0.5);
+        let history = sessions.load("s3").await.unwrap().history;
+        assert_eq!(history[0].outcome, CallOutcome::Denied);
+
+        // Other tools and unauthenticated calls are not counted.
+        for request in [
+            PolicyRequest::new("s3", "mail.send").with_caller(Some(&alice)),
+            PolicyRequest::new("s3", "search.web").with_tool_info(&info),
+        ] {
+            store
+                .evaluate_pre_effects(&request, &json!({}))
+                .await
+                .unwrap();
+            assert!(store.budget_status(&request).await.unwrap().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    #[expect(clippy::float_cmp, reason = "the cost is exactly representable")]
+    async fn test_caller_budget_survives_session_eviction() {
+        let sessions = Arc::new(
+            InMemoryPolicySessionStore::new().with_limits(SessionLimits {
+                idle_ttl: Some(Duration::from_millis(20)),
+                max_sessions: Some(1),
+            }),
+        );
+        let store = PolicyStore::new(sessions.clone()).with_budgets(vec![
+            Budget::new("search", 2.0)
+                .with_tool("search.*")
+                .with_scope(PolicyScope::Caller),
+        ]);
+        let alice = CallerIdentity {
+            id: "alice".into(),
+            ..CallerIdentity::default()
+        };
+        let call = |session_id| {
+            PolicyRequest::new(session_id, "search.web")
+                .with_caller(Some(&alice))
+                .with_cost(Some(1.5))
+        };
+        store
+            .evaluate_pre_effects(&call("s1"), &json!({}))
+            .await
+            .unwrap();
+        let _outcome = store
+            .evaluate_post_effects(&call("s1"), &json!({}), Ok(&json!({})))
+            .await
+            .unwrap();
+
+        // Idle past the TTL, then crowded out by new sessions.
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert_eq!(store.evict_expired_sessions().await.unwrap(), 1);
+        for session_id in ["s2", "s3", "s4"] {
+            sessions
+                .save(session_id, &PolicySession::default())
+                .await
+                .unwrap();
+        }
+
+        let status = store.budget_status(&call("s5")).await.unwrap();
+        assert_eq!(status[0].spent, 1.5);
+        assert!(matches!(
+            store.evaluate_pre_effects(&call("s5"), &json!({})).await,
+            Err(PolicyError::BudgetExceeded(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_visibility_effects_hide_tools_per_caller_and_session() {
+        let sessions = Arc::new(InMemoryPolicySessionStore::new());
+        let store = PolicyStore::new(sessions.clone());
+        store
+            .register(policy(
+                r#"name = "visibility"
+version = "1"
+context = { beta = false }
+
+[[effects]]
+tool = "admin.*"
+stage = "visibility"
+when = 'caller != null && "admin" in caller.roles'
+fail_message = "admins only"
+
+[[effects]]
+tool = "**"
+tags = { any = ["beta"] }
+stage = "visibility"
+when = "!context.beta"
+effect = "deny"
+"#,
+            ))
+            .unwrap();
+        let mut shadow = policy(
+            r#"name = "shadow"
+version = "1"
+mode = "shadow"
+
+[[effects]]
+tool = "**"
+stage = "visibility"
+when = "true"
+effect = "deny"
+"#,
+        );
+        store.register(shadow.clone()).unwrap();
+        assert!(store.has_visibility_effects());
+
+        let tool = |id: &str, tags: &[&str]| ToolInfo {
+            qualified_id: id.into(),
+            tool_id: id.into(),
+            crate_name: String::new(),
+            crate_version: String::new(),
+            display_name: String::new(),
+            description: String::new(),
+            input_schema: "{}".into(),
+            output_schema: "{}".into(),
+            credential_schema: None,
+            capabilities: Vec::new(),
+            tags: tags.iter().map(ToString::to_string).collect(),
+            embedding: None,
+            cost: None,
+            idempotent: false,
+            cache_ttl: None,
+        };
+        let tools = [
+            tool("admin.reset", &[]),
+            tool("mail.send", &[]),
+            tool("mail.draft", &["beta"]),
+        ];
+        let admin = CallerIdentity {
+            id: "root".into(),
+            roles: vec!["admin".into()],
+            ..CallerIdentity::default()
+        };
+        let visible = async |request: PolicyRequest<'_>| {
+            store
+                .filter_visible(&request, tools.iter().collect(), |info| info)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|info| info.qualified_id.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(visible(PolicyRequest::new("s1", "")).await, ["mail.send"]);
+        assert_eq!(
+            visible(PolicyRequest::new("s1", "").with_caller(Some(&admin))).await,
+            ["admin.reset", "mail.send"]
+        );
+        sessions
+            .save("s2", &session_with("beta", json!(true)))
+            .await
+            .unwrap();
+        assert_eq!(
+            visible(PolicyRequest::new("s2", "")).await,
+            ["mail.send", "mail.draft"]
+        );
+        // Listing tools never creates or changes sessions.
+        assert_eq!(sessions.len().unwrap(), 1);
+
+        // An enforced deny hides everything.
+        shadow.mode = PolicyMode::Enforce;
+        store.register(shadow).unwrap();
+        assert!(
+            visible(PolicyRequest::new("s1", "").with_caller(Some(&admin)))
+                .await
+                .is_empty()
+        );
     }
 }
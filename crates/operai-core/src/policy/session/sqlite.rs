@@ -0,0 +1,623 @@
+//! `SQLite`-backed [`PolicySessionStore`].
+//!
+//! Sessions are stored one row per session ID, with the context, call
+//! history and budget spend serialized as JSON. The optimistic concurrency
+//! `version` is enforced inside the `UPDATE` statement itself, so concurrent
+//! writers in different processes sharing the same database file observe the
+//! same conflict semantics as
+//! [`InMemoryPolicySessionStore`](super::InMemoryPolicySessionStore).
+//!
+//! The schema is versioned with `PRAGMA user_version`; pending migrations are
+//! applied in order when the store is opened.
+//!
+//! [`SessionLimits`] are enforced against the `updated_at` column (Unix
+//! milliseconds of the last save), so every process sharing the database
+//! applies the same idle TTL and session cap. Rows of shared
+//! [scopes](crate::policy::scope) are exempt from both.
+
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use rusqlite::{Connection, OptionalExtension, params};
+use tracing::instrument;
+
+use super::{PolicySession, PolicySessionStore, SessionError, SessionLimits};
+use crate::policy::scope::{RESERVED_PREFIX, is_scope_key};
+
+/// Schema migrations, applied in order. The index of a migration plus one is
+/// the `user_version` recorded after it runs; never edit or reorder existing
+/// entries, only append.
+const MIGRATIONS: &[&str] = &[
+    // Initial schema.
+    "CREATE TABLE policy_sessions (
+        session_id TEXT PRIMARY KEY NOT NULL,
+        version INTEGER NOT NULL,
+        context TEXT NOT NULL,
+        updated_at INTEGER NOT NULL,
+        history TEXT NOT NULL DEFAULT '[]',
+        spend TEXT NOT NULL DEFAULT '{}'
+    );
+    CREATE INDEX policy_sessions_updated_at ON policy_sessions (updated_at);",
+];
+
+/// How long a writer waits for another connection's lock before failing.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// `SQLite` implementation of [`PolicySessionStore`].
+///
+/// Suitable for persisting session state across restarts and for sharing it
+/// between processes on the same host. All database access runs on Tokio's
+/// blocking thread pool.
+#[derive(Debug, Clone)]
+pub struct SqlitePolicySessionStore {
+    conn: Arc<Mutex<Connection>>,
+    limits: SessionLimits,
+}
+
+impl SqlitePolicySessionStore {
+    /// Opens (creating if necessary) the database at `path` and applies any
+    /// pending migrations.
+    ///
+    /// Parent directories are created if they do not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SessionError::Storage` if the file cannot be opened or a
+    /// migration fails.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SessionError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent).map_err(storage_error)?;
+        }
+        let conn = Connection::open(path).map_err(storage_error)?;
+        // WAL lets readers proceed while another process writes.
+        conn.pragma_update(None, "journal_mode", "WAL")
+            .map_err(storage_error)?;
+        Self::from_connection(conn)
+    }
+
+    /// Opens a private in-memory database, mainly for tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SessionError::Storage` if the database cannot be created.
+    pub fn open_in_memory() -> Result<Self, SessionError> {
+        Self::from_connection(Connection::open_in_memory().map_err(storage_error)?)
+    }
+
+    fn from_connection(mut conn: Connection) -> Result<Self, SessionError> {
+        conn.busy_timeout(BUSY_TIMEOUT).map_err(storage_error)?;
+        migrate(&mut conn)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+            limits: SessionLimits::default(),
+        })
+    }
+
+    /// Apply idle TTL and maximum session count limits.
+    #[must_use]
+    pub fn with_limits(mut self, limits: SessionLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the `updated_at` value below which sessions are expired, or
+    /// `i64::MIN` when no idle TTL is configured.
+    fn expiry_cutoff(&self, now: i64) -> i64 {
+        self.limits.idle_ttl.map_or(i64::MIN, |ttl| {
+            now.saturating_sub(i64::try_from(ttl.as_millis()).unwrap_or(i64::MAX))
+        })
+    }
+
+    /// Runs `f` with exclusive access to the connection on the blocking pool.
+    async fn with_conn<T, F>(&self, f: F) -> Result<T, SessionError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<T, SessionError> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        tokio::task::spawn_blocking(move || {
+            let mut conn = conn.lock().map_err(|_| SessionError::LockPoisoned)?;
+            f(&mut conn)
+        })
+        .await
+        .map_err(storage_error)?
+    }
+}
+
+#[async_trait]
+impl PolicySessionStore for SqlitePolicySessionStore {
+    #[instrument(skip(self), fields(session_id = %session_id))]
+    async fn load(&self, session_id: &str) -> Result<PolicySession, SessionError> {
+        let session_id = session_id.to_string();
+        let cutoff = key_cutoff(&session_id, self.expiry_cutoff(unix_millis()));
+        self.with_conn(move |conn| {
+            let row = conn
+                .query_row(
+                    "SELECT version, context, history, spend FROM policy_sessions
+                     WHERE session_id = ?1 AND updated_at >= ?2",
+                    params![session_id, cutoff],
+                    |row| {
+                        Ok((
+                            row.get::<_, i64>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, String>(2)?,
+                            row.get::<_, String>(3)?,
+                        ))
+                    },
+                )
+                .optional()
+                .map_err(storage_error)?;
+
+            let Some((version, context, history, spend)) = row else {
+                return Ok(PolicySession::default());
+            };
+            Ok(PolicySession {
+                version: u64::try_from(version).map_err(storage_error)?,
+                context: serde_json::from_str(&context).map_err(storage_error)?,
+                history: serde_json::from_str(&history).map_err(storage_error)?,
+                spend: serde_json::from_str(&spend).map_err(storage_error)?,
+            })
+        })
+        .await
+    }
+
+    #[instrument(skip(self, session), fields(session_id = %session_id, version = session.version))]
+    async fn save(&self, session_id: &str, session: &PolicySession) -> Result<(), SessionError> {
+        self.save_all(&[(session_id, session)]).await
+    }
+
+    async fn save_all(&self, sessions: &[(&str, &PolicySession)]) -> Result<(), SessionError> {
+        let rows = sessions
+            .iter()
+            .map(|(session_id, session)| {
+                Ok((
+                    (*session_id).to_string(),
+                    session.version,
+                    serde_json::to_string(&session.context).map_err(storage_error)?,
+                    serde_json::to_string(&session.history).map_err(storage_error)?,
+                    serde_json::to_string(&session.spend).map_err(storage_error)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, SessionError>>()?;
+        let now = unix_millis();
+        let cutoff = self.expiry_cutoff(now);
+        let scope_pattern = scope_pattern();
+        let max_sessions = self
+            .limits
+            .max_sessions
+            .map(|max| i64::try_from(max).unwrap_or(i64::MAX));
+
+        self.with_conn(move |conn| {
+            // A conflict returns before the commit, rolling back every row
+            // already written.
+            let tx = conn.transaction().map_err(storage_error)?;
+            for (session_id, expected, context, history, spend) in rows {
+                let version = i64::try_from(expected).map_err(storage_error)?;
+                let cutoff = key_cutoff(&session_id, cutoff);
+
+                let changed = if expected == 0 {
+                    // A missing or expired row is version 0; the first save
+                    // (re)creates it.
+                    tx.execute(
+                        "INSERT INTO policy_sessions
+                             (session_id, version, context, history, spend, updated_at)
+                         VALUES (?1, 1, ?2, ?3, ?4, ?5)
+                         ON CONFLICT (session_id) DO UPDATE
+                         SET version = 1, context = excluded.context, history = excluded.history,
+                             spend = excluded.spend, updated_at = excluded.updated_at
+                         WHERE policy_sessions.updated_at < ?6",
+                        params![session_id, context, history, spend, now, cutoff],
+                    )
+                } else {
+                    tx.execute(
+                        "UPDATE policy_sessions
+                         SET version = version + 1, context = ?2, history = ?3, spend = ?4,
+                             updated_at = ?5
+                         WHERE session_id = ?1 AND version = ?6 AND updated_at >= ?7",
+                        params![session_id, context, history, spend, now, version, cutoff],
+                    )
+                }
+                .map_err(storage_error)?;
+
+                if changed == 0 {
+                    let current: Option<i64> = tx
+                        .query_row(
+                            "SELECT version FROM policy_sessions
+                             WHERE session_id = ?1 AND updated_at >= ?2",
+                            params![session_id, cutoff],
+                            |row| row.get(0),
+                        )
+                        .optional()
+                        .map_err(storage_error)?;
+                    return Err(SessionError::Conflict {
+                        expected: current
+                            .map_or(Ok(0), u64::try_from)
+                            .map_err(storage_error)?,
+                        found: expected,
+                    });
+                }
+
+                if let Some(max) = max_sessions
+                    && expected == 0
+                    && !is_scope_key(&session_id)
+                {
+                    // Evict expired sessions first, then the least recently saved
+                    // ones, never the session just written nor a shared scope.
+                    tx.execute(
+                        "DELETE FROM policy_sessions WHERE updated_at < ?1 AND session_id NOT \
+                         LIKE ?2",
+                        params![cutoff, scope_pattern],
+                    )
+                    .map_err(storage_error)?;
+                    tx.execute(
+                        "DELETE FROM policy_sessions WHERE session_id IN (
+                             SELECT session_id FROM policy_sessions
+                             WHERE session_id != ?1 AND session_id NOT LIKE ?3
+                             ORDER BY updated_at ASC, session_id ASC
+                             LIMIT MAX((SELECT COUNT(*) FROM policy_sessions
+                                        WHERE session_id NOT LIKE ?3) - ?2, 0)
+                         )",
+                        params![session_id, max, scope_pattern],
+                    )
+                    .map_err(storage_error)?;
+                }
+            }
+
+            tx.commit().map_err(storage_error)
+        })
+        .await
+    }
+
+    #[instrument(skip(self), fields(session_id = %session_id))]
+    async fn delete(&self, session_id: &str) -> Result<(), SessionError> {
+        let session_id = session_id.to_string();
+        self.with_conn(move |conn| {
+            conn.execute(
+                "DELETE FROM policy_sessions WHERE session_id = ?1",
+                params![session_id],
+            )
+            .map_err(storage_error)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn evict_expired(&self) -> Result<usize, SessionError> {
+        if self.limits.idle_ttl.is_none() {
+            return Ok(0);
+        }
+        let cutoff = self.expiry_cutoff(unix_millis());
+        let scope_pattern = scope_pattern();
+        self.with_conn(move |conn| {
+            conn.execute(
+                "DELETE FROM policy_sessions WHERE updated_at < ?1 AND session_id NOT LIKE ?2",
+                params![cutoff, scope_pattern],
+            )
+            .map_err(storage_error)
+        })
+        .await
+    }
+}
+
+/// Applies every migration newer than the database's `user_version`.
+fn migrate(conn: &mut Connection) -> Result<(), SessionError> {
+    let tx = conn.transaction().map_err(storage_error)?;
+    let current: usize = tx
+        .pragma_query_value(None, "user_version", |row| row.get(0))
+        .map_err(storage_error)?;
+
+    if current > MIGRATIONS.len() {
+        return Err(SessionError::Storage(format!(
+            "session database schema version {current} is newer than supported version {}",
+            MIGRATIONS.len()
+        )));
+    }
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(current) {
+        tx.execute_batch(migration).map_err(storage_error)?;
+        tx.pragma_update(None, "user_version", index + 1)
+            .map_err(storage_error)?;
+    }
+    tx.commit().map_err(storage_error)
+}
+
+/// Returns the expiry cutoff of the row `key`: shared scopes never expire.
+fn key_cutoff(key: &str, cutoff: i64) -> i64 {
+    if is_scope_key(key) { i64::MIN } else { cutoff }
+}
+
+/// `LIKE` pattern matching the keys of shared scopes.
+fn scope_pattern() -> String {
+    format!("{RESERVED_PREFIX}%")
+}
+
+fn unix_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX)
+        })
+}
+
+fn storage_error(err: impl std::fmt::Display) -> SessionError {
+    SessionError::Storage(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::policy::{
+        budget::Spend,
+        history::{CallOutcome, CallRecord},
+    };
+
+    #[tokio::test]
+    async fn test_load_missing_session_returns_default() {
+        let store = SqlitePolicySessionStore::open_in_memory().unwrap();
+        let session = store.load("missing").await.unwrap();
+
+        assert_eq!(session.version, 0);
+        assert!(session.context.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_save_increments_version_and_round_trips_context() {
+        let store = SqlitePolicySessionStore::open_in_memory().unwrap();
+
+        let mut session = store.load("s1").await.unwrap();
+        session.context.insert("count".into(), json!(1));
+        store.save("s1", &session).await.unwrap();
+
+        let mut session = store.load("s1").await.unwrap();
+        assert_eq!(session.version, 1);
+        assert_eq!(session.context["count"], json!(1));
+
+        session.context.insert("count".into(), json!(2));
+        store.save("s1", &session).await.unwrap();
+
+        let session = store.load("s1").await.unwrap();
+        assert_eq!(session.version, 2);
+        assert_eq!(session.context["count"], json!(2));
+    }
+
+    #[tokio::test]
+    async fn test_stale_save_returns_conflict() {
+        let store = SqlitePolicySessionStore::open_in_memory().unwrap();
+
+        let first = store.load("s1").await.unwrap();
+        let second = store.load("s1").await.unwrap();
+        store.save("s1", &first).await.unwrap();
+
+        let err = store.save("s1", &second).await.unwrap_err();
+        assert!(matches!(
+            err,
+            SessionError::Conflict {
+                expected: 1,
+                found: 0
+            }
+        ));
+
+        let mut current = store.load("s1").await.unwrap();
+        let stale = current.clone();
+        current.context.insert("k".into(), json!("v"));
+        store.save("s1", &current).await.unwrap();
+
+        let err = store.save("s1", &stale).await.unwrap_err();
+        assert!(matches!(
+            err,
+            SessionError::Conflict {
+                expected: 2,
+                found: 1
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_save_all_rolls_back_on_conflict() {
+        let store = SqlitePolicySessionStore::open_in_memory().unwrap();
+        let stale = store.load("b").await.unwrap();
+        store.save("b", &stale).await.unwrap();
+
+        let fresh = store.load("a").await.unwrap();
+        let err = store
+            .save_all(&[("a", &fresh), ("b", &stale)])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SessionError::Conflict { .. }));
+        assert_eq!(store.load("a").await.unwrap().version, 0);
+    }
+
+    #[tokio::test]
+    async fn test_save_with_nonzero_version_for_missing_session_conflicts() {
+        let store = SqlitePolicySessionStore::open_in_memory().unwrap();
+        let session = PolicySession {
+            version: 3,
+            ..PolicySession::default()
+        };
+
+        let err = store.save("s1", &session).await.unwrap_err();
+        assert!(matches!(
+            err,
+            SessionError::Conflict {
+                expected: 0,
+                found: 3
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sessions_persist_across_reopen_and_are_shared() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("nested/sessions.db");
+
+        let writer = SqlitePolicySessionStore::open(&path).unwrap();
+        let reader = SqlitePolicySessionStore::open(&path).unwrap();
+
+        let mut session = writer.load("s1").await.unwrap();
+        session.context.insert("user".into(), json!("alice"));
+        writer.save("s1", &session).await.unwrap();
+
+        assert_eq!(reader.load("s1").await.unwrap().context["user"], "alice");
+        drop((writer, reader));
+
+        let reopened = SqlitePolicySessionStore::open(&path).unwrap();
+        let session = reopened.load("s1").await.unwrap();
+        assert_eq!(session.version, 1);
+        assert_eq!(session.context["user"], "alice");
+    }
+
+    #[tokio::test]
+    async fn test_idle_ttl_expires_and_evicts_sessions() {
+        let store = SqlitePolicySessionStore::open_in_memory()
+            .unwrap()
+            .with_limits(SessionLimits {
+                idle_ttl: Some(Duration::from_millis(50)),
+                max_sessions: None,
+            });
+        let mut session = store.load("s1").await.unwrap();
+        session.context.insert("k".into(), json!(1));
+        store.save("s1", &session).await.unwrap();
+        let stale = store.load("s1").await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+
+        let session = store.load("s1").await.unwrap();
+        assert_eq!(session.version, 0);
+        assert!(session.context.is_empty());
+        assert!(matches!(
+            store.save("s1", &stale).await,
+            Err(SessionError::Conflict {
+                expected: 0,
+                found: 1
+            })
+        ));
+
+        store.save("s2", &PolicySession::default()).await.unwrap();
+        assert_eq!(store.evict_expired().await.unwrap(), 1);
+        // The expired row was replaced rather than conflicting.
+        store.save("s1", &PolicySession::default()).await.unwrap();
+        assert_eq!(store.load("s1").await.unwrap().version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_max_sessions_evicts_least_recently_saved() {
+        let store = SqlitePolicySessionStore::open_in_memory()
+            .unwrap()
+            .with_limits(SessionLimits {
+                idle_ttl: None,
+                max_sessions: Some(2),
+            });
+        store.save("a", &PolicySession::default()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        store.save("b", &PolicySession::default()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let a = store.load("a").await.unwrap();
+        store.save("a", &a).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        store.save("c", &PolicySession::default()).await.unwrap();
+
+        assert_eq!(store.load("a").await.unwrap().version, 2);
+        assert_eq!(store.load("b").await.unwrap().version, 0);
+        assert_eq!(store.load("c").await.unwrap().version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_limits_do_not_evict_shared_scopes() {
+        let store = SqlitePolicySessionStore::open_in_memory()
+            .unwrap()
+            .with_limits(SessionLimits {
+                idle_ttl: Some(Duration::from_millis(50)),
+                max_sessions: Some(1),
+            });
+        store
+            .save("@caller:alice", &PolicySession::default())
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(store.evict_expired().await.unwrap(), 0);
+        store.save("a", &PolicySession::default()).await.unwrap();
+        store.save("b", &PolicySession::default()).await.unwrap();
+
+        assert_eq!(store.load("@caller:alice").await.unwrap().version, 1);
+        assert_eq!(store.load("a").await.unwrap().version, 0);
+        assert_eq!(store.load("b").await.unwrap().version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_session() {
+        let store = SqlitePolicySessionStore::open_in_memory().unwrap();
+        store.save("s1", &PolicySession::default()).await.unwrap();
+
+        store.delete("s1").await.unwrap();
+        store.delete("missing").await.unwrap();
+
+        assert_eq!(store.load("s1").await.unwrap().version, 0);
+    }
+
+    #[tokio::test]
+    async fn test_history_round_trips() {
+        let store = SqlitePolicySessionStore::open_in_memory().unwrap();
+
+        let mut session = store.load("s1").await.unwrap();
+        assert!(session.history.is_empty());
+
+        let record = CallRecord {
+            tool: "db.backup".into(),
+            time: UNIX_EPOCH.into(),
+            outcome: CallOutcome::Succeeded,
+            input_digest: Some("abc".into()),
+        };
+        session.record_call(record.clone(), 10);
+        store.save("s1", &session).await.unwrap();
+        assert_eq!(store.load("s1").await.unwrap().history, [record]);
+    }
+
+    #[tokio::test]
+    async fn test_spend_round_trips() {
+        let store = SqlitePolicySessionStore::open_in_memory().unwrap();
+
+        let mut session = store.load("s1").await.unwrap();
+        assert!(session.spend.is_empty());
+
+        let spend = Spend {
+            amount: 2.5,
+            since: UNIX_EPOCH.into(),
+        };
+        session.spend.insert("daily".into(), spend);
+        store.save("s1", &session).await.unwrap();
+        assert_eq!(store.load("s1").await.unwrap().spend["daily"], spend);
+    }
+
+    #[test]
+    fn test_migrations_record_schema_version_and_are_idempotent() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate(&mut conn).unwrap();
+        migrate(&mut conn).unwrap();
+
+        let version: usize = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, MIGRATIONS.len());
+    }
+
+    #[test]
+    fn test_newer_schema_version_is_rejected() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", MIGRATIONS.len() + 1)
+            .unwrap();
+
+        assert!(matches!(migrate(&mut conn), Err(SessionError::Storage(_))));
+    }
+}
@@ -0,0 +1,418 @@
+//! Shadow-mode policy evaluation and reporting.
+//!
+//! A policy with [`PolicyMode::Shadow`](super::PolicyMode::Shadow) is evaluated
+//! at its usual position in the policy order, but against copies of the
+//! session, input and output. Whatever it would have done (deny the call,
+//! require approval, update context, rewrite input, reshape output) is logged
+//! and counted per policy and tool in a [`ShadowReport`], and then discarded.
+//! Evaluation errors in shadow policies are counted rather than failing the
+//! call.
+//!
+//! This makes it possible to roll out a new guard, watch what it would block,
+//! and only then switch it to `enforce`.
+
+use std::{collections::BTreeMap, sync::Mutex};
+
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use tracing::{info, warn};
+
+use super::{
+    CombiningAlgorithm, CompiledPolicy, Decision, PolicyError, PolicyStage, ToolOutcome,
+    request::PolicyRequest, scope::ScopedState,
+};
+
+/// Counts of what a shadow policy would have done for one tool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ShadowStats {
+    /// Evaluations in which the policy had an effect matching the tool.
+    pub evaluations: u64,
+    /// Calls the policy would have denied.
+    pub denials: u64,
+    /// Calls the policy would have held for approval.
+    pub approvals: u64,
+    /// Evaluations that would have changed session context.
+    pub context_updates: u64,
+    /// Calls whose input the policy would have rewritten.
+    pub input_rewrites: u64,
+    /// Results the policy would have transformed, dropped fields from, or
+    /// replaced with an error.
+    pub output_changes: u64,
+    /// Evaluations that failed with a CEL or rewrite error.
+    pub errors: u64,
+}
+
+impl ShadowStats {
+    fn add(&mut self, other: &Self) {
+        self.evaluations += other.evaluations;
+        self.denials += other.denials;
+        self.approvals += other.approvals;
+        self.context_updates += other.context_updates;
+        self.input_rewrites += other.input_rewrites;
+        self.output_changes += other.output_changes;
+        self.errors += other.errors;
+    }
+}
+
+/// Shadow statistics for one policy and tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShadowEntry {
+    /// Name of the shadow policy.
+    pub policy: String,
+    /// Qualified tool ID.
+    pub tool: String,
+    /// What the policy would have done.
+    pub stats: ShadowStats,
+}
+
+/// Accumulated shadow decisions, keyed by policy and tool.
+#[derive(Debug, Default)]
+pub struct ShadowReport {
+    stats: Mutex<BTreeMap<(String, String), ShadowStats>>,
+}
+
+impl ShadowReport {
+    /// Create an empty report.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an observation for a policy and tool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the report lock is poisoned.
+    pub fn record(&self, policy: &str, tool: &str, observation: &ShadowStats) {
+        let mut stats = self.stats.lock().expect("lock poisoned");
+        stats
+            .entry((policy.to_string(), tool.to_string()))
+            .or_default()
+            .add(observation);
+    }
+
+    /// Return the accumulated statistics, ordered by policy then tool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the report lock is poisoned.
+    #[must_use]
+    pub fn entries(&self) -> Vec<ShadowEntry> {
+        let stats = self.stats.lock().expect("lock poisoned");
+        stats
+            .iter()
+            .map(|((policy, tool), stats)| ShadowEntry {
+                policy: policy.clone(),
+                tool: tool.clone(),
+                stats: *stats,
+            })
+            .collect()
+    }
+
+    /// Discard all accumulated statistics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the report lock is poisoned.
+    pub fn clear(&self) {
+        self.stats.lock().expect("lock poisoned").clear();
+    }
+}
+
+impl CompiledPolicy {
+    /// Whether any effect of this policy applies to the requested tool in
+    /// `stage`.
+    fn has_effects_for(&self, request: &PolicyRequest<'_>, stage: PolicyStage) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| effect.applies_to(stage, request))
+    }
+
+    /// Evaluate `Before` stage effects against copies of `state` and `input`,
+    /// logging what the policy would have done.
+    ///
+    /// Returns `None` if no effect applies to the tool.
+    pub(super) fn shadow_pre_effects(
+        &self,
+        state: &ScopedState,
+        request: &PolicyRequest<'_>,
+        input: &JsonValue,
+        combining: CombiningAlgorithm,
+    ) -> Option<ShadowStats> {
+        if !self.has_effects_for(request, PolicyStage::Before) {
+            return None;
+        }
+        // What a shadow policy would have done is not part of the call's
+        // trace.
+        let request = &PolicyRequest {
+            trace: None,
+            ..request.clone()
+        };
+
+        let (mut state, baseline) = self.shadow_session(state);
+        let mut shadow_input = input.clone();
+        let mut observed = ShadowStats {
+            evaluations: 1,
+            ..ShadowStats::default()
+        };
+        match self.evaluate_pre_effects(&mut state, request, &mut shadow_input, combining) {
+            Ok(evaluation) => {
+                if evaluation.decision == Some(Decision::RequireApproval) {
+                    observed.approvals = 1;
+                    info!(
+                        policy = %self.original.name,
+                        tool = %request.tool,
+                        reason = evaluation.approval.as_deref().unwrap_or_default(),
+                        "Shadow policy would require approval"
+                    );
+                }
+                if shadow_input != *input {
+                    observed.input_rewrites = 1;
+                    info!(
+                        policy = %self.original.name,
+                        tool = %request.tool,
+                        input = %shadow_input,
+                        "Shadow policy would rewrite input"
+                    );
+                }
+            }
+            Err(PolicyError::GuardFailed(denial)) => {
+                observed.denials = 1;
+                info!(
+                    policy = %self.original.name,
+                    tool = %request.tool,
+                    effect = denial.effect,
+                    reason = %denial.message,
+                    "Shadow policy would deny call"
+                );
+            }
+            Err(e) => {
+                observed.errors = 1;
+                warn!(policy = %self.original.name, tool = %request.tool, error = %e, "Shadow policy failed");
+            }
+        }
+        self.log_context_update(&state, &baseline, request.tool, &mut observed);
+        Some(observed)
+    }
+
+    /// Evaluate `After` stage effects against copies of `state` and
+    /// `outcome`, logging what the policy would have done.
+    ///
+    /// Returns `None` if no effect applies to the tool.
+    pub(super) fn shadow_post_effects(
+        &self,
+        state: &ScopedState,
+        request: &PolicyRequest<'_>,
+        input: &JsonValue,
+        outcome: &ToolOutcome,
+    ) -> Option<ShadowStats> {
+        if !self.has_effects_for(request, PolicyStage::After) {
+            return None;
+        }
+        let request = &PolicyRequest {
+            trace: None,
+            ..request.clone()
+        };
+
+        let (mut state, baseline) = self.shadow_session(state);
+        let mut shadow_outcome = outcome.clone();
+        let mut observed = ShadowStats {
+            evaluations: 1,
+            ..ShadowStats::default()
+        };
+        match self.evaluate_post_effects(&mut state, request, input, &mut shadow_outcome) {
+            Ok(()) if shadow_outcome != *outcome => {
+                observed.output_changes = 1;
+                info!(
+                    policy = %self.original.name,
+                    tool = %request.tool,
+                    outcome = ?shadow_outcome,
+                    "Shadow policy would change output"
+                );
+            }
+            Ok(()) => {}
+            Err(e) => {
+                observed.errors = 1;
+                warn!(policy = %self.original.name, tool = %request.tool, error = %e, "Shadow policy failed");
+            }
+        }
+        self.log_context_update(&state, &baseline, request.tool, &mut observed);
+        Some(observed)
+    }
+
+    /// Copy `state` with this policy's context defaults applied, returning
+    /// the copy and a second copy to compare it to after evaluation.
+    ///
+    /// Filling in defaults is not counted as a context update.
+    fn shadow_session(&self, state: &ScopedState) -> (ScopedState, ScopedState) {
+        let mut state = state.clone();
+        self.initialize_context(&mut state);
+        let baseline = state.clone();
+        (state, baseline)
+    }
+
+    fn log_context_update(
+        &self,
+        state: &ScopedState,
+        baseline: &ScopedState,
+        tool: &str,
+        observed: &mut ShadowStats,
+    ) {
+        for ((scope, session), (_, before)) in state.iter().zip(baseline.iter()) {
+            if session.context != before.context {
+                observed.context_updates = 1;
+                info!(
+                    policy = %self.original.name,
+                    tool = %tool,
+                    scope = %scope,
+                    context = ?session.context,
+                    "Shadow policy would update context"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::policy::Policy;
+
+    fn shadow_policy(source: &str) -> CompiledPolicy {
+        let policy: Policy = toml::from_str(source).expect("policy should parse");
+        policy.compile().expect("policy should compile")
+    }
+
+    #[test]
+    fn test_shadow_pre_effects_report_without_applying() {
+        let policy = shadow_policy(
+            r#"name = "limits"
+version = "1"
+mode = "shadow"
+context = { calls = 0 }
+
+[[effects]]
+tool = "db.*"
+stage = "before"
+when = "true"
+set = { calls = "context.calls + 1" }
+rewrite = { limit = "10" }
+
+[[effects]]
+tool = "db.drop"
+stage = "before"
+when = "false"
+fail_message = "no drops"
+"#,
+        );
+        let session = ScopedState::default();
+        let input = json!({ "limit": 500 });
+
+        let stats = policy
+            .shadow_pre_effects(
+                &session,
+                &PolicyRequest::new("test", "db.drop"),
+                &input,
+                CombiningAlgorithm::DenyOverrides,
+            )
+            .expect("policy should apply to db.drop");
+
+        assert_eq!(
+            stats,
+            ShadowStats {
+                evaluations: 1,
+                denials: 1,
+                context_updates: 1,
+                ..ShadowStats::default()
+            }
+        );
+        assert!(session.session().context.is_empty());
+
+        let stats = policy
+            .shadow_pre_effects(
+                &session,
+                &PolicyRequest::new("test", "db.query"),
+                &input,
+                CombiningAlgorithm::DenyOverrides,
+            )
+            .expect("policy should apply to db.query");
+        assert_eq!(stats.input_rewrites, 1);
+        assert_eq!(input, json!({ "limit": 500 }));
+
+        assert!(
+            policy
+                .shadow_pre_effects(
+                    &session,
+                    &PolicyRequest::new("test", "mail.send"),
+                    &input,
+                    CombiningAlgorithm::DenyOverrides
+                )
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_shadow_post_effects_count_output_changes_and_errors() {
+        let policy = shadow_policy(
+            r#"name = "pii"
+version = "1"
+mode = "shadow"
+
+[[effects]]
+tool = "users.*"
+stage = "after"
+when = "success"
+drop = ["ssn"]
+
+[[effects]]
+tool = "users.fail"
+stage = "after"
+when = "output.missing.field"
+"#,
+        );
+        let session = ScopedState::default();
+
+        let stats = policy
+            .shadow_post_effects(
+                &session,
+                &PolicyRequest::new("test", "users.get"),
+                &json!({}),
+                &Ok(json!({ "ssn": "1" })),
+            )
+            .expect("policy should apply");
+        assert_eq!(stats.output_changes, 1);
+
+        let stats = policy
+            .shadow_post_effects(
+                &session,
+                &PolicyRequest::new("test", "users.fail"),
+                &json!({}),
+                &Ok(json!({})),
+            )
+            .expect("policy should apply");
+        assert_eq!(stats.errors, 1);
+    }
+
+    #[test]
+    fn test_shadow_report_accumulates_per_policy_and_tool() {
+        let report = ShadowReport::new();
+        let denial = ShadowStats {
+            evaluations: 1,
+            denials: 1,
+            ..ShadowStats::default()
+        };
+        report.record("limits", "db.drop", &denial);
+        report.record("limits", "db.drop", &denial);
+        report.record("limits", "db.query", &ShadowStats::default());
+
+        let entries = report.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].tool, "db.drop");
+        assert_eq!(entries[0].stats.denials, 2);
+
+        report.clear();
+        assert!(report.entries().is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+//! Explanations of policy decisions.
+//!
+//! When an effect blocks a call, [`PolicyError::GuardFailed`] carries a
+//! [`Denial`] naming the policy and effect responsible and the condition it
+//! evaluated. For debugging, a [`PolicyTrace`] attached to a
+//! [`PolicyRequest`](super::request::PolicyRequest) with
+//! [`with_trace`](super::request::PolicyRequest::with_trace) additionally
+//! records every enforced effect that matched the call, in evaluation order.
+//! Shadow policies are not traced.
+//!
+//! [`PolicyError::GuardFailed`]: super::PolicyError::GuardFailed
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+use super::{CompiledEffect, CompiledPolicy, Decision, PolicyStage};
+
+/// The policy effect that blocked a call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Denial {
+    /// Message shown to the caller: the effect's `fail_message`, or a default
+    /// naming the policy.
+    pub message: String,
+    /// Name of the policy.
+    pub policy: String,
+    /// Version of the policy.
+    pub version: String,
+    /// Position of the effect in the policy's `effects`, starting at 0.
+    pub effect: usize,
+    /// Tool pattern of the effect.
+    pub tool_pattern: String,
+    /// The effect's condition expression.
+    pub condition: String,
+    /// Whether the condition held: `false` for a failed guard, `true` for a
+    /// deny decision.
+    pub condition_met: bool,
+}
+
+impl Denial {
+    pub(super) fn new(
+        policy: &CompiledPolicy,
+        index: usize,
+        effect: &CompiledEffect,
+        condition_met: bool,
+        message: String,
+    ) -> Self {
+        Self {
+            message,
+            policy: policy.original.name.clone(),
+            version: policy.original.version.clone(),
+            effect: index,
+            tool_pattern: effect.original.tool.clone(),
+            condition: effect.original.condition.clone(),
+            condition_met,
+        }
+    }
+}
+
+/// What an evaluated effect did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectOutcome {
+    /// The condition did not hold and the effect did nothing.
+    Skipped,
+    /// The condition held and the effect made no decision: a guard passed,
+    /// and any updates, rewrites or transforms were applied.
+    Applied,
+    /// The effect allowed the call.
+    Allowed,
+    /// The effect required approval for the call.
+    ApprovalRequired,
+    /// The effect denied the call.
+    Denied,
+    /// The condition did not hold and the guard blocked the call.
+    GuardFailed,
+    /// The condition did not hold and the effect replaced the tool's output
+    /// with its `on_fail_replace_output`.
+    OutputReplaced,
+}
+
+impl EffectOutcome {
+    /// Outcome of a `Before` or `Visibility` stage effect whose condition held.
+    pub(super) fn of_decision(decision: Option<Decision>) -> Self {
+        match decision {
+            None => Self::Applied,
+            Some(Decision::Allow) => Self::Allowed,
+            Some(Decision::RequireApproval) => Self::ApprovalRequired,
+            Some(Decision::Deny) => Self::Denied,
+        }
+    }
+}
+
+/// One evaluated effect in a [`PolicyTrace`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TraceStep {
+    /// Name of the policy.
+    pub policy: String,
+    /// Version of the policy.
+    pub version: String,
+    /// Position of the effect in the policy's `effects`, starting at 0.
+    pub effect: usize,
+    /// Stage the effect was evaluated in.
+    pub stage: PolicyStage,
+    /// Tool pattern of the effect.
+    pub tool_pattern: String,
+    /// The effect's condition expression.
+    pub condition: String,
+    /// Whether the condition held.
+    pub condition_met: bool,
+    /// What the effect did.
+    pub outcome: EffectOutcome,
+}
+
+/// Records every enforced effect evaluated for a call.
+#[derive(Debug, Default)]
+pub struct PolicyTrace {
+    steps: Mutex<Vec<TraceStep>>,
+}
+
+impl PolicyTrace {
+    /// Create an empty trace.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the recorded steps in evaluation order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the trace lock is poisoned.
+    #[must_use]
+    pub fn steps(&self) -> Vec<TraceStep> {
+        self.steps.lock().expect("lock poisoned").clone()
+    }
+
+    pub(super) fn record(
+        &self,
+        policy: &CompiledPolicy,
+        index: usize,
+        effect: &CompiledEffect,
+        condition_met: bool,
+        outcome: EffectOutcome,
+    ) {
+        self.steps.lock().expect("lock poisoned").push(TraceStep {
+            policy: policy.original.name.clone(),
+            version: policy.original.version.clone(),
+            effect: index,
+            stage: effect.original.stage,
+            tool_pattern: effect.original.tool.clone(),
+            condition: effect.original.condition.clone(),
+            condition_met,
+            outcome,
+        });
+    }
+
+    /// Number of recorded steps, to roll back to with
+    /// [`truncate`](Self::truncate).
+    pub(super) fn len(&self) -> usize {
+        self.steps.lock().expect("lock poisoned").len()
+    }
+
+    /// Discard steps recorded after the first `len`, such as those of an
+    /// evaluation attempt that lost a session conflict and is retried.
+    pub(super) fn truncate(&self, len: usize) {
+        self.steps.lock().expect("lock poisoned").truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::policy::{
+        CombiningAlgorithm, Policy, PolicyError, request::PolicyRequest, scope::ScopedState,
+    };
+
+    fn compile(source: &str) -> CompiledPolicy {
+        let policy: Policy = toml::from_str(source).expect("policy should parse");
+        policy.compile().expect("policy should compile")
+    }
+
+    #[test]
+    fn test_deny_effect_is_explained_and_traced() {
+        let policy = compile(
+            r#"name = "deploys"
+version = "3"
+
+[[effects]]
+tool = "deploy.*"
+stage = "before"
+when = "input.env == 'staging'"
+effect = "allow"
+
+[[effects]]
+tool = "deploy.*"
+stage = "before"
+when = "input.env == 'prod'"
+effect = "deny"
+"#,
+        );
+        let trace = PolicyTrace::new();
+        let request = PolicyRequest::new("s1", "deploy.run").with_trace(Some(&trace));
+
+        let result = policy.evaluate_pre_effects(
+            &mut ScopedState::default(),
+            &request,
+            &mut json!({ "env": "prod" }),
+            CombiningAlgorithm::DenyOverrides,
+        );
+
+        let Err(PolicyError::GuardFailed(denial)) = result else {
+            panic!("expected a denial, got {result:?}");
+        };
+        assert_eq!(
+            *denial,
+            Denial {
+                message: "Denied by policy deploys".into(),
+                policy: "deploys".into(),
+                version: "3".into(),
+                effect: 1,
+                tool_pattern: "deploy.*".into(),
+                condition: "input.env == 'prod'".into(),
+                condition_met: true,
+            }
+        );
+        let steps: Vec<_> = trace
+            .steps()
+            .iter()
+            .map(|step| (step.effect, step.condition_met, step.outcome))
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                (0, false, EffectOutcome::Skipped),
+                (1, true, EffectOutcome::Denied)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_after_effects_are_traced() {
+        let policy = compile(
+            r#"name = "redact"
+version = "1"
+
+[[effects]]
+tool = "**"
+stage = "after"
+when = "!has(output.ssn)"
+on_fail_replace_output = "output withheld"
+
+[[effects]]
+tool = "other.*"
+stage = "after"
+when = "true"
+"#,
+        );
+        let trace = PolicyTrace::new();
+        let request = PolicyRequest::new("s1", "crm.lookup").with_trace(Some(&trace));
+        let mut output = Ok(json!({ "ssn": "123" }));
+
+        policy
+            .evaluate_post_effects(
+                &mut ScopedState::default(),
+                &request,
+                &json!({}),
+                &mut output,
+            )
+            .expect("post effects should evaluate");
+
+        assert_eq!(output, Err("output withheld".to_string()));
+        // Effects for other tools are not part of the trace.
+        let steps = trace.steps();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].stage, PolicyStage::After);
+        assert_eq!(steps[0].outcome, EffectOutcome::OutputReplaced);
+    }
+}
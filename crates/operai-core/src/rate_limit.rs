@@ -0,0 +1,380 @@
+//! Token-bucket rate limits for tool calls.
+//!
+//! A [`RateLimit`] allows `requests` calls to matching tools per `interval`,
+//! with bursts of up to `burst` calls. Calls are counted in buckets keyed by
+//! any combination of the session, the authenticated caller and the tool
+//! (see [`RateLimitKey`]); with no key, all matching calls share one bucket.
+//! Unauthenticated calls share the caller bucket of the anonymous caller.
+//!
+//! Each bucket holds up to `burst` tokens and starts full. A call takes one
+//! token from the bucket of every limit that applies to it, and tokens are
+//! refilled continuously at `requests / interval`. A call that finds any of
+//! its buckets empty is rejected with [`RateLimited`], which says how long
+//! until a token is available, and takes no tokens.
+//!
+//! Buckets are kept in memory by the [`RateLimiter`] and are not shared
+//! between processes. Buckets that have refilled completely are forgotten.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{CompiledPattern, request::CallerIdentity};
+
+/// What a rate limit keeps separate buckets for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitKey {
+    /// One bucket per session.
+    Session,
+    /// One bucket per authenticated caller, across sessions.
+    Caller,
+    /// One bucket per tool.
+    Tool,
+}
+
+/// A limit on the rate of matching tool calls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    /// Name of the rate limit, unique within a limiter.
+    pub name: String,
+    /// Pattern of the tools whose calls are limited, matched like an
+    /// effect's `tool`.
+    pub tool: String,
+    /// What separate buckets are kept for; empty for one shared bucket.
+    pub key: Vec<RateLimitKey>,
+    /// Calls allowed per interval.
+    pub requests: u32,
+    /// Interval in which `requests` calls are allowed.
+    pub interval: Duration,
+    /// Calls allowed in a burst, the capacity of each bucket.
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// Create a limit of `requests` calls per `interval` per session for
+    /// every tool, with bursts of up to `requests` calls.
+    #[must_use]
+    pub fn new(name: impl Into<String>, requests: u32, interval: Duration) -> Self {
+        Self {
+            name: name.into(),
+            tool: "**".to_string(),
+            key: vec![RateLimitKey::Session],
+            requests,
+            interval,
+            burst: requests,
+        }
+    }
+
+    /// Only limit calls to tools matching `tool`.
+    #[must_use]
+    pub fn with_tool(mut self, tool: impl Into<String>) -> Self {
+        self.tool = tool.into();
+        self
+    }
+
+    /// Keep separate buckets for each combination of `key`.
+    #[must_use]
+    pub fn with_key(mut self, key: Vec<RateLimitKey>) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Allow bursts of up to `burst` calls.
+    #[must_use]
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+
+    /// Tokens refilled per second.
+    fn rate(&self) -> f64 {
+        f64::from(self.requests) / self.interval.as_secs_f64()
+    }
+}
+
+/// Why a call was rejected by a rate limit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimited {
+    /// Name of the exceeded rate limit.
+    pub rate_limit: String,
+    /// The bucket that is empty, such as `session=s1,tool=mail.send`, or
+    /// empty for a shared bucket.
+    pub key: String,
+    /// How long until the call would be allowed.
+    pub retry_after: Duration,
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Rate limit {} exceeded, retry after {:.3}s",
+            self.rate_limit,
+            self.retry_after.as_secs_f64()
+        )
+    }
+}
+
+/// Number of buckets at which full buckets are first pruned.
+const MIN_PRUNE_AT: usize = 1024;
+
+/// Enforces rate limits, keeping a token bucket for each limit and key.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limits: Vec<(RateLimit, CompiledPattern)>,
+    state: Mutex<Buckets>,
+}
+
+#[derive(Debug)]
+struct Buckets {
+    /// Buckets by limit index and key.
+    buckets: HashMap<(usize, String), Bucket>,
+    /// Number of buckets at which full ones are pruned next.
+    prune_at: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter enforcing `limits`.
+    #[must_use]
+    pub fn new(limits: Vec<RateLimit>) -> Self {
+        Self {
+            limits: limits
+                .into_iter()
+                .map(|limit| {
+                    let pattern = CompiledPattern::new(&limit.tool);
+                    (limit, pattern)
+                })
+                .collect(),
+            state: Mutex::new(Buckets {
+                buckets: HashMap::new(),
+                prune_at: MIN_PRUNE_AT,
+            }),
+        }
+    }
+
+    /// Return whether the limiter enforces no limits.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.limits.is_empty()
+    }
+
+    /// Take a token for a call to `tool` in `session_id` by `caller` from
+    /// the bucket of every limit that applies to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RateLimited`] for the limit whose bucket is empty, or the
+    /// one that takes longest to refill if several are; no tokens are taken.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bucket lock is poisoned.
+    pub fn check(
+        &self,
+        tool: &str,
+        session_id: &str,
+        caller: Option<&CallerIdentity>,
+    ) -> Result<(), RateLimited> {
+        self.check_at(tool, session_id, caller, Instant::now())
+    }
+
+    fn check_at(
+        &self,
+        tool: &str,
+        session_id: &str,
+        caller: Option<&CallerIdentity>,
+        now: Instant,
+    ) -> Result<(), RateLimited> {
+        let applicable: Vec<(usize, &RateLimit, String)> = self
+            .limits
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, pattern))| pattern.matches(tool))
+            .map(|(index, (limit, _))| (index, limit, bucket_key(limit, tool, session_id, caller)))
+            .collect();
+        if applicable.is_empty() {
+            return Ok(());
+        }
+
+        let mut state = self.state.lock().expect("lock poisoned");
+        let mut refilled = Vec::with_capacity(applicable.len());
+        let mut exceeded: Option<RateLimited> = None;
+        for (index, limit, key) in applicable {
+            let bucket = state
+                .buckets
+                .get(&(index, key.clone()))
+                .map_or(Bucket::full(limit, now), |bucket| bucket.refill(limit, now));
+            if bucket.tokens < 1.0 {
+                let retry_after = Duration::try_from_secs_f64((1.0 - bucket.tokens) / limit.rate())
+                    .unwrap_or(Duration::MAX);
+                if exceeded
+                    .as_ref()
+                    .is_none_or(|exceeded| retry_after > exceeded.retry_after)
+                {
+                    exceeded = Some(RateLimited {
+                        rate_limit: limit.name.clone(),
+                        key,
+                        retry_after,
+                    });
+                }
+                continue;
+            }
+            refilled.push(((index, key), bucket));
+        }
+        if let Some(exceeded) = exceeded {
+            return Err(exceeded);
+        }
+
+        for (key, mut bucket) in refilled {
+            bucket.tokens -= 1.0;
+            state.buckets.insert(key, bucket);
+        }
+        if state.buckets.len() >= state.prune_at {
+            self.prune(&mut state, now);
+        }
+        Ok(())
+    }
+
+    /// Forget the buckets that have refilled completely, which behave like
+    /// new ones.
+    fn prune(&self, state: &mut Buckets, now: Instant) {
+        state.buckets.retain(|(index, _), bucket| {
+            let limit = &self.limits[*index].0;
+            bucket.refill(limit, now).tokens < f64::from(limit.burst)
+        });
+        state.prune_at = (state.buckets.len() * 2).max(MIN_PRUNE_AT);
+    }
+}
+
+impl Bucket {
+    fn full(limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(limit.burst),
+            updated: now,
+        }
+    }
+
+    fn refill(self, limit: &RateLimit, now: Instant) -> Self {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        Self {
+            tokens: (self.tokens + elapsed * limit.rate()).min(f64::from(limit.burst)),
+            updated: now.max(self.updated),
+        }
+    }
+}
+
+/// Return the key of the bucket a call counts against.
+fn bucket_key(
+    limit: &RateLimit,
+    tool: &str,
+    session_id: &str,
+    caller: Option<&CallerIdentity>,
+) -> String {
+    limit
+        .key
+        .iter()
+        .map(|key| match key {
+            RateLimitKey::Session => format!("session={session_id}"),
+            RateLimitKey::Caller => {
+                format!("caller={}", caller.map_or("", |caller| caller.id.as_str()))
+            }
+            RateLimitKey::Tool => format!("tool={tool}"),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_allows_burst_then_refills_at_rate() {
+        let limiter = RateLimiter::new(vec![
+            RateLimit::new("mail", 2, Duration::from_secs(10))
+                .with_tool("mail.*")
+                .with_burst(3),
+        ]);
+        let start = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.check_at("mail.send", "s1", None, start).is_ok());
+        }
+        let rejection = limiter
+            .check_at("mail.send", "s1", None, start)
+            .expect_err("burst should be spent");
+        assert_eq!(rejection.rate_limit, "mail");
+        assert_eq!(rejection.key, "session=s1");
+        assert_eq!(rejection.retry_after, Duration::from_secs(5));
+        assert_eq!(
+            rejection.to_string(),
+            "Rate limit mail exceeded, retry after 5.000s"
+        );
+
+        // Other sessions and unmatched tools have their own budget.
+        assert!(limiter.check_at("mail.send", "s2", None, start).is_ok());
+        assert!(limiter.check_at("search.web", "s1", None, start).is_ok());
+
+        let later = start + Duration::from_secs(5);
+        assert!(limiter.check_at("mail.send", "s1", None, later).is_ok());
+        assert!(limiter.check_at("mail.send", "s1", None, later).is_err());
+    }
+
+    #[test]
+    fn test_rejected_calls_take_no_tokens_from_other_limits() {
+        let limiter = RateLimiter::new(vec![
+            RateLimit::new("per-caller-tool", 1, Duration::from_mins(1))
+                .with_key(vec![RateLimitKey::Caller, RateLimitKey::Tool]),
+            RateLimit::new("global", 3, Duration::from_mins(1)).with_key(Vec::new()),
+        ]);
+        let now = Instant::now();
+        let ada = CallerIdentity {
+            id: "ada".into(),
+            ..CallerIdentity::default()
+        };
+
+        assert!(limiter.check_at("a", "s1", Some(&ada), now).is_ok());
+        // Another session of the same caller shares the bucket.
+        let rejection = limiter
+            .check_at("a", "s2", Some(&ada), now)
+            .expect_err("caller bucket should be empty");
+        assert_eq!(rejection.key, "caller=ada,tool=a");
+        // The rejected call left two global tokens.
+        assert!(limiter.check_at("b", "s1", Some(&ada), now).is_ok());
+        assert!(limiter.check_at("a", "s1", None, now).is_ok());
+        let rejection = limiter
+            .check_at("b", "s1", None, now)
+            .expect_err("global bucket should be empty");
+        assert_eq!(rejection.rate_limit, "global");
+        assert_eq!(rejection.key, "");
+    }
+
+    #[test]
+    fn test_full_buckets_are_pruned() {
+        let limiter = RateLimiter::new(vec![RateLimit::new("s", 1, Duration::from_secs(1))]);
+        let start = Instant::now();
+        for session in 0..MIN_PRUNE_AT - 1 {
+            assert!(
+                limiter
+                    .check_at("t", &session.to_string(), None, start)
+                    .is_ok()
+            );
+        }
+        let later = start + Duration::from_secs(1);
+        assert!(limiter.check_at("t", "last", None, later).is_ok());
+        let state = limiter.state.lock().unwrap();
+        assert_eq!(state.buckets.len(), 1);
+        assert_eq!(state.prune_at, MIN_PRUNE_AT);
+    }
+}
@@ -0,0 +1,168 @@
+//! Retries of failed calls to idempotent tools.
+//!
+//! A tool declares itself idempotent with an `## Idempotent` doc heading,
+//! promising that calling it again with the same input has no further
+//! effect. The runtime retries calls to such tools that fail with a
+//! [retryable](RetryPolicy::is_retryable) result, waiting an exponentially
+//! growing backoff between attempts, until an attempt succeeds or
+//! `max_attempts` were made. Calls to other tools are never retried.
+//!
+//! All attempts of a call share its timeout and concurrency slot, and
+//! policies, budgets and circuit breakers see the call once, with the outcome
+//! of its last attempt.
+
+use std::time::Duration;
+
+use backon::ExponentialBuilder;
+use operai_abi::ToolResult;
+
+use crate::ToolInfo;
+
+/// How often and how patiently failed calls to idempotent tools are
+/// retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Attempts a call may make, counting the first; 1 disables retries.
+    pub max_attempts: u32,
+    /// Backoff before the first retry.
+    pub initial_backoff: Duration,
+    /// Longest backoff between two attempts.
+    pub max_backoff: Duration,
+    /// Factor by which the backoff grows after each retry.
+    pub multiplier: f32,
+    /// Whether each backoff is lengthened by a random share of itself, so
+    /// that calls failing together do not retry together.
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Create a policy that makes up to 3 attempts, backing off 100
+    /// milliseconds before the first retry and doubling the backoff up to 5
+    /// seconds, with jitter.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: true,
+        }
+    }
+
+    /// Make up to `max_attempts` attempts per call.
+    #[must_use]
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Back off `initial_backoff` before the first retry.
+    #[must_use]
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Never back off longer than `max_backoff`.
+    #[must_use]
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Grow the backoff by `multiplier` after each retry.
+    #[must_use]
+    pub fn with_multiplier(mut self, multiplier: f32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Lengthen each backoff by a random share of itself, or not.
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Whether calls to the tool are retried.
+    #[must_use]
+    pub fn applies_to(&self, info: &ToolInfo) -> bool {
+        info.idempotent && self.max_attempts > 1
+    }
+
+    /// Whether a call that ended with `result` may succeed if made again.
+    ///
+    /// Only errors raised by the tool itself are retried; unknown tools,
+    /// rejected input and missing credentials fail the same way every time.
+    #[must_use]
+    pub fn is_retryable(result: ToolResult) -> bool {
+        result == ToolResult::Error
+    }
+
+    /// Returns the backoffs between the attempts of a call.
+    #[must_use]
+    pub fn backoff(&self) -> ExponentialBuilder {
+        let backoff = ExponentialBuilder::default()
+            .with_min_delay(self.initial_backoff)
+            .with_max_delay(self.max_backoff)
+            .with_factor(self.multiplier)
+            .with_max_times(self.max_attempts.saturating_sub(1) as usize);
+        if self.jitter {
+            backoff.with_jitter()
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backon::BackoffBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_up_to_max_and_stops_after_max_attempts() {
+        let policy = RetryPolicy::new()
+            .with_max_attempts(5)
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(300))
+            .with_jitter(false);
+
+        let backoffs: Vec<u128> = policy
+            .backoff()
+            .build()
+            .map(|backoff| backoff.as_millis())
+            .collect();
+
+        assert_eq!(backoffs, [100, 200, 300, 300]);
+        assert_eq!(
+            RetryPolicy::new()
+                .with_max_attempts(1)
+                .backoff()
+                .build()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_only_tool_errors_are_retryable() {
+        assert!(RetryPolicy::is_retryable(ToolResult::Error));
+        for result in [
+            ToolResult::Ok,
+            ToolResult::NotFound,
+            ToolResult::InvalidInput,
+            ToolResult::CredentialError,
+        ] {
+            assert!(!RetryPolicy::is_retryable(result));
+        }
+    }
+}
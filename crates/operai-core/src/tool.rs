@@ -52,6 +52,7 @@ use std::{
         Arc,
         atomic::{AtomicU64, Ordering},
     },
+    time::Duration,
 };
 
 use abi_stable::std_types::{RSlice, RStr};
@@ -115,6 +116,9 @@ pub enum RegistryError {
 ///   "async"]`)
 /// - `tags`: Optional tags for categorization and search
 /// - `embedding`: Optional vector embedding for semantic search
+/// - `cost`: Optional static cost of one call, counted against budgets
+/// - `idempotent`: Whether the runtime may retry failed calls
+/// - `cache_ttl`: Optional time a successful result may be reused
 #[derive(Debug, Clone)]
 pub struct ToolInfo {
     /// Full qualified identifier (format: `crate-name.tool-name`)
@@ -141,6 +145,14 @@ pub struct ToolInfo {
     pub tags: Vec<String>,
     /// Optional vector embedding for semantic search
     pub embedding: Option<Vec<f32>>,
+    /// Optional static cost of one call, counted against budgets
+    pub cost: Option<f64>,
+    /// Whether calling the tool again with the same input has no further
+    /// effect, letting the runtime retry failed calls
+    pub idempotent: bool,
+    /// Optional time a successful result may be reused for the same input,
+    /// when the runtime caches results
+    pub cache_ttl: Option<Duration>,
 }
 
 /// Runtime handle for invoking a specific tool.
@@ -469,6 +481,12 @@ impl ToolRegistry {
                         Some(slice.to_vec())
                     }
                 },
+                cost: descriptor.cost.into_option(),
+                idempotent: descriptor.idempotent,
+                cache_ttl: descriptor
+                    .cache_ttl_secs
+                    .into_option()
+                    .map(Duration::from_secs),
             };
 
             if let Some(ref embedding) = info.embedding {
@@ -595,11 +613,11 @@ impl ToolRegistry {
 
                 if heap.len() < limit {
                     heap.push(entry);
-                } else if let Some(min) = heap.peek() {
-                    if score > min.0.score {
-                        heap.pop();
-                        heap.push(entry);
-                    }
+                } else if let Some(min) = heap.peek()
+                    && score > min.0.score
+                {
+                    heap.pop();
+                    heap.push(entry);
                 }
             }
         }
@@ -730,7 +748,7 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
     }
 }
 
-/// Helper struct for ordered comparison in BinaryHeap.
+/// Helper struct for ordered comparison in `BinaryHeap`.
 ///
 /// Wraps a score and tool info reference for use in the top-K heap.
 /// Comparison is based solely on the score for heap ordering.
@@ -841,6 +859,9 @@ mod tests {
             capabilities: Vec::new(),
             tags: Vec::new(),
             embedding,
+            cost: None,
+            idempotent: false,
+            cache_ttl: None,
         }
     }
 
@@ -30,12 +30,23 @@
 //! /// ## Tags
 //! /// - utility
 //! /// - greeting
+//! ///
+//! /// ## Cost
+//! /// - 0.001
+//! ///
+//! /// ## Idempotent
 //! #[tool]
 //! async fn greet(ctx: Context, input: GreetInput) -> Result<GreetOutput, Error> {
 //!     // ...
 //! }
 //! ```
 //!
+//! The optional `## Cost` is the static cost of one call, which the runtime
+//! counts against its budgets. An `## Idempotent` heading declares that
+//! calling the tool again with the same input has no further effect, so the
+//! runtime may retry calls that failed. The optional `## Cache` gives the
+//! seconds for which a successful result may be reused for the same input.
+//!
 //! The macro generates:
 //! - A wrapper function for JSON serialization/deserialization
 //! - Schema generation for input/output types
@@ -93,6 +104,12 @@ struct DocCommentMetadata {
     capabilities: Vec<String>,
     /// List of tags (from ## Tags section)
     tags: Vec<String>,
+    /// Static cost of one call (from ## Cost section)
+    cost: Option<String>,
+    /// Whether failed calls may be retried (from ## Idempotent heading)
+    idempotent: bool,
+    /// Seconds a result may be reused (from ## Cache section)
+    cache_ttl: Option<String>,
 }
 
 /// Extracts tool metadata from a function's doc comments.
@@ -102,6 +119,9 @@ struct DocCommentMetadata {
 /// - Description text following H1
 /// - `## Capabilities` section with list items
 /// - `## Tags` section with list items
+/// - `## Cost` section with a single list item
+/// - `## Idempotent` heading without items
+/// - `## Cache` section with a single list item
 ///
 /// Returns `None` if no doc comments are present.
 fn extract_doc_metadata(attrs: &[Attribute]) -> Option<DocCommentMetadata> {
@@ -129,6 +149,9 @@ fn extract_doc_metadata(attrs: &[Attribute]) -> Option<DocCommentMetadata> {
         name: None,
         capabilities: Vec::new(),
         tags: Vec::new(),
+        cost: None,
+        idempotent: false,
+        cache_ttl: None,
     };
 
     let mut current_section: Option<String> = None;
@@ -169,7 +192,11 @@ fn extract_doc_metadata(attrs: &[Attribute]) -> Option<DocCommentMetadata> {
 
         // Check for H2 heading: ## Section Name
         if let Some(rest) = line.strip_prefix("## ") {
-            current_section = Some(rest.trim().to_string());
+            let section = rest.trim();
+            if section == "Idempotent" {
+                metadata.idempotent = true;
+            }
+            current_section = Some(section.to_string());
             continue;
         }
 
@@ -186,6 +213,14 @@ fn extract_doc_metadata(attrs: &[Attribute]) -> Option<DocCommentMetadata> {
                     metadata.tags.push(value);
                     handled = true;
                 }
+                Some("Cost") => {
+                    metadata.cost = Some(value);
+                    handled = true;
+                }
+                Some("Cache") => {
+                    metadata.cache_ttl = Some(value);
+                    handled = true;
+                }
                 _ => {}
             }
             if handled {
@@ -221,6 +256,12 @@ fn extract_doc_metadata(attrs: &[Attribute]) -> Option<DocCommentMetadata> {
 /// - Description text after H1
 /// - `## Capabilities` section with `-` list items
 /// - `## Tags` section with `-` list items
+/// - `## Cost` section with one `-` list item: the cost of a call, counted
+///   against the runtime's budgets
+/// - `## Idempotent` heading: calling the tool again with the same input has no
+///   further effect, so the runtime may retry failed calls
+/// - `## Cache` section with one `-` list item: the seconds for which a
+///   successful result may be reused for the same input
 ///
 /// # Errors
 ///
@@ -262,6 +303,8 @@ pub fn tool(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// Returns an error if:
 /// - Doc metadata is missing required fields
+/// - The declared cost is not a non-negative number
+/// - The declared cache TTL is not a positive number of seconds
 /// - Function is not async
 /// - Function doesn't have exactly 2 parameters
 /// - Return type is not `Result<T, E>`
@@ -303,6 +346,33 @@ fn expand_tool(func: &ItemFn) -> Result<proc_macro2::TokenStream> {
 
     let capabilities = &metadata.capabilities;
     let tags = &metadata.tags;
+    let idempotent = metadata.idempotent;
+    let cost = match metadata.cost.as_deref().map(str::parse::<f64>) {
+        Some(Ok(cost)) if cost.is_finite() && cost >= 0.0 => {
+            let cost = proc_macro2::Literal::f64_suffixed(cost);
+            quote!(::std::option::Option::Some(#cost))
+        }
+        Some(_) => {
+            return Err(Error::new(
+                func.sig.ident.span(),
+                "invalid tool cost in doc comments (must be a non-negative number)",
+            ));
+        }
+        None => quote!(::std::option::Option::None),
+    };
+    let cache_ttl_secs = match metadata.cache_ttl.as_deref().map(str::parse::<u64>) {
+        Some(Ok(secs)) if secs > 0 => {
+            let secs = proc_macro2::Literal::u64_suffixed(secs);
+            quote!(::std::option::Option::Some(#secs))
+        }
+        Some(_) => {
+            return Err(Error::new(
+                func.sig.ident.span(),
+                "invalid tool cache TTL in doc comments (must be a positive number of seconds)",
+            ));
+        }
+        None => quote!(::std::option::Option::None),
+    };
     let sig = &func.sig;
 
     if sig.asyncness.is_none() {
@@ -363,6 +433,9 @@ fn expand_tool(func: &ItemFn) -> Result<proc_macro2::TokenStream> {
                 description: #description,
                 capabilities: &[#(#capabilities),*],
                 tags: &[#(#tags),*],
+                cost: #cost,
+                idempotent: #idempotent,
+                cache_ttl_secs: #cache_ttl_secs,
                 input_schema_fn: || {
                     let schema = ::operai::__private::schemars::schema_for!(#input_type);
                     ::operai::__private::serde_json::to_string(&schema)
@@ -919,6 +992,9 @@ mod tests {
         assert!(expanded.contains("description:\"Thisisadescription.\""));
         assert!(expanded.contains("capabilities:&[\"read\"]"));
         assert!(expanded.contains("tags:&[\"tag1\",\"tag2\"]"));
+        assert!(expanded.contains("cost:::std::option::Option::None"));
+        assert!(expanded.contains("idempotent:false"));
+        assert!(expanded.contains("cache_ttl_secs:::std::option::Option::None"));
     }
 
     #[test]
@@ -1121,6 +1197,120 @@ mod tests {
         assert!(expanded.contains("pubfn__operai_wrapper_greet"));
     }
 
+    #[test]
+    fn test_expand_tool_with_cost_emits_static_cost() {
+        // Arrange
+        let func = parse_item_fn(quote!(
+            /// # Search
+            /// desc
+            ///
+            /// ## Cost
+            /// - 0.25
+            async fn search(ctx: Context, input: Input) -> Result<Output, Error> {}
+        ));
+
+        // Act
+        let expanded = expand_tool(&func).expect("expected expansion to succeed");
+
+        // Assert
+        let expanded = strip_whitespace(&expanded.to_string());
+        assert!(expanded.contains("cost:::std::option::Option::Some(0.25f64)"));
+    }
+
+    #[test]
+    fn test_expand_tool_with_idempotent_heading_marks_tool_idempotent() {
+        // Arrange
+        let func = parse_item_fn(quote!(
+            /// # Lookup
+            /// desc
+            ///
+            /// ## Idempotent
+            ///
+            /// ## Tags
+            /// - read
+            async fn lookup(ctx: Context, input: Input) -> Result<Output, Error> {}
+        ));
+
+        // Act
+        let expanded = expand_tool(&func).expect("expected expansion to succeed");
+
+        // Assert
+        let expanded = strip_whitespace(&expanded.to_string());
+        assert!(expanded.contains("idempotent:true"));
+        assert!(expanded.contains("tags:&[\"read\"]"));
+        assert!(expanded.contains("description:\"desc\""));
+    }
+
+    #[test]
+    fn test_expand_tool_with_cache_section_emits_cache_ttl() {
+        // Arrange
+        let func = parse_item_fn(quote!(
+            /// # Lookup
+            /// desc
+            ///
+            /// ## Cache
+            /// - 300
+            async fn lookup(ctx: Context, input: Input) -> Result<Output, Error> {}
+        ));
+
+        // Act
+        let expanded = expand_tool(&func).expect("expected expansion to succeed");
+
+        // Assert
+        let expanded = strip_whitespace(&expanded.to_string());
+        assert!(expanded.contains("cache_ttl_secs:::std::option::Option::Some(300u64)"));
+    }
+
+    #[test]
+    fn test_expand_tool_rejects_invalid_cache_ttl() {
+        for ttl in ["forever", "0", "-5", "1.5"] {
+            // Arrange
+            let doc = format!(" - {ttl}");
+            let func = parse_item_fn(quote!(
+                /// # Lookup
+                /// desc
+                ///
+                /// ## Cache
+                #[doc = #doc]
+                async fn lookup(ctx: Context, input: Input) -> Result<Output, Error> {}
+            ));
+
+            // Act
+            let err = expand_tool(&func).expect_err("expected cache TTL error");
+
+            // Assert
+            assert_eq!(
+                err.to_string(),
+                "invalid tool cache TTL in doc comments (must be a positive number of seconds)"
+            );
+        }
+    }
+
+    #[test]
+    fn test_expand_tool_rejects_invalid_cost() {
+        for cost in ["cheap", "-1", "inf"] {
+            // Arrange
+            let doc = format!(" - {cost}");
+            let func = parse_item_fn(quote!(
+                /// # Search
+                /// desc
+                ///
+                /// ## Cost
+                #[doc = #doc]
+                async fn search(ctx: Context, input: Input) -> Result<Output, Error> {}
+            ));
+
+            // Act
+            let err = expand_tool(&func).expect_err("expected cost error");
+
+            // Assert
+            assert_eq!(
+                err.to_string(),
+                "invalid tool cost in doc comments (must be a non-negative number)"
+            );
+        }
+    }
+
     #[test]
     fn test_expand_tool_rejects_no_return_type() {
         // Arrange
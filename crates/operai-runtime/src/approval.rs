@@ -0,0 +1,597 @@
+//! Human approval of tool calls held by policy.
+//!
+//! When a `require_approval` policy effect applies, the call is parked in an
+//! [`ApprovalQueue`] until a person approves or denies it, or it expires.
+//! Pending calls are keyed by session, caller, tool and the caller's input, so
+//! a caller that retries the same call joins the existing approval instead of
+//! creating a new one.
+//!
+//! Every call waiting on an entry gets its decision, and the entry is used up
+//! once they have it. A decision is only kept for a caller that deferred the
+//! call instead of waiting: it is a single-use grant or denial for that
+//! caller's retry, and is discarded once claimed. Decided entries that nobody
+//! claims expire after the queue's timeout, like pending ones.
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hasher, RandomState},
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use operai_core::policy::request::CallerIdentity;
+use thiserror::Error;
+use tokio::sync::watch;
+
+use crate::{
+    proto::{Approval, approval::State},
+    runtime::json_value_to_struct,
+};
+
+/// Default time a call waits for a decision.
+pub(crate) const DEFAULT_APPROVAL_TIMEOUT: Duration = Duration::from_mins(5);
+
+/// Errors returned when deciding an approval.
+#[derive(Debug, Error)]
+pub(crate) enum ApprovalError {
+    /// No approval exists with the given name.
+    #[error("approval not found: {0}")]
+    NotFound(String),
+    /// The approval was already approved, denied, or expired.
+    #[error("approval {0} is not pending")]
+    NotPending(String),
+}
+
+impl From<ApprovalError> for tonic::Status {
+    fn from(error: ApprovalError) -> Self {
+        match error {
+            ApprovalError::NotFound(_) => Self::not_found(error.to_string()),
+            ApprovalError::NotPending(_) => Self::failed_precondition(error.to_string()),
+        }
+    }
+}
+
+/// The outcome of submitting a call that requires approval.
+#[derive(Debug)]
+pub(crate) enum Admission {
+    /// A matching approval was granted; the call may run.
+    Granted,
+    /// A matching approval was denied, with the approver's reason.
+    Denied(String),
+    /// The call is waiting for a decision.
+    Pending(Approval, watch::Receiver<Approval>),
+}
+
+impl Admission {
+    /// Converts a decided approval into an admission.
+    ///
+    /// Returns `None` for approvals that are pending or expired.
+    fn decided(approval: &Approval) -> Option<Self> {
+        match approval.state() {
+            State::Approved => Some(Self::Granted),
+            State::Denied if approval.deny_reason.is_empty() => Some(Self::Denied(format!(
+                "Call denied by approver ({})",
+                approval.name
+            ))),
+            State::Denied => Some(Self::Denied(approval.deny_reason.clone())),
+            State::Pending | State::Expired | State::Unspecified => None,
+        }
+    }
+}
+
+/// Identifies the call an approval applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CallKey {
+    session_id: String,
+    caller_id: Option<String>,
+    tool_id: String,
+    input: String,
+}
+
+impl CallKey {
+    /// Creates the key of a call by `caller` in a session, with the caller's
+    /// input before any policy rewrites it.
+    pub(crate) fn new(
+        session_id: &str,
+        caller: Option<&CallerIdentity>,
+        tool_id: &str,
+        input: &serde_json::Value,
+    ) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            caller_id: caller.map(|caller| caller.id.clone()),
+            tool_id: tool_id.to_string(),
+            input: input.to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    key: CallKey,
+    /// Whether a caller deferred the call instead of waiting for its
+    /// decision, and so may still claim it.
+    deferred: bool,
+    /// The approval, watched by the calls waiting for its decision.
+    approval: watch::Sender<Approval>,
+}
+
+impl Entry {
+    fn state(&self) -> State {
+        self.approval.borrow().state()
+    }
+}
+
+/// Pending and decided approvals for a runtime.
+#[derive(Debug)]
+pub(crate) struct ApprovalQueue {
+    /// Entries keyed by approval ID.
+    entries: Mutex<HashMap<String, Entry>>,
+    /// How long an entry stays pending, and how long a decision stays
+    /// claimable.
+    timeout: Duration,
+    /// Sequence mixed into generated approval IDs.
+    next_seq: AtomicU64,
+}
+
+impl ApprovalQueue {
+    /// Creates an empty queue whose entries expire after `timeout`.
+    pub(crate) fn new(timeout: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            timeout,
+            next_seq: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the pending approval of a call that was held before, for a
+    /// caller that defers rather than waits and is checking on it.
+    ///
+    /// Returns `None` if no pending entry matches; a decided entry is left to
+    /// be claimed by [`submit`](Self::submit).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the queue lock is poisoned.
+    pub(crate) fn pending(&self, key: &CallKey) -> Option<Approval> {
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        Self::purge_expired(&mut entries);
+        let entry = entries
+            .values_mut()
+            .find(|entry| entry.key == *key && entry.state() == State::Pending)?;
+        entry.deferred = true;
+        Some(entry.approval.borrow().clone())
+    }
+
+    /// Submits a call that requires approval, with the `input` it runs with
+    /// once policies rewrote it.
+    ///
+    /// Claims a matching decided entry if there is one; otherwise joins the
+    /// matching pending entry or creates a new one. `deferred` is set when
+    /// the caller will not wait for the decision but retry the call later.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the queue lock is poisoned.
+    pub(crate) fn submit(
+        &self,
+        key: &CallKey,
+        input: &serde_json::Value,
+        reason: &str,
+        deferred: bool,
+    ) -> Admission {
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        Self::purge_expired(&mut entries);
+
+        if let Some(admission) = Self::claim_matching(&mut entries, key, deferred) {
+            return admission;
+        }
+
+        let id = loop {
+            let id = self.next_id();
+            if !entries.contains_key(&id) {
+                break id;
+            }
+        };
+        let now = unix_millis();
+        let approval = Approval {
+            name: format!("approvals/{id}"),
+            tool: format!("tools/{}", key.tool_id),
+            input: json_value_to_struct(input),
+            session_id: key.session_id.clone(),
+            reason: reason.to_string(),
+            state: State::Pending.into(),
+            create_time_ms: now,
+            expire_time_ms: now.saturating_add(self.timeout_millis()),
+            deny_reason: String::new(),
+        };
+        let (sender, receiver) = watch::channel(approval.clone());
+        entries.insert(
+            id,
+            Entry {
+                key: key.clone(),
+                deferred,
+                approval: sender,
+            },
+        );
+        Admission::Pending(approval, receiver)
+    }
+
+    /// Waits until a pending approval is decided or expires.
+    ///
+    /// Every call waiting on the approval gets its decision, and the entry is
+    /// used up once they have it. Returns `None` if the approval expired.
+    pub(crate) async fn wait(
+        &self,
+        approval: &Approval,
+        mut receiver: watch::Receiver<Approval>,
+    ) -> Option<Admission> {
+        let remaining = u64::try_from(approval.expire_time_ms.saturating_sub(unix_millis()))
+            .map_or(Duration::ZERO, Duration::from_millis);
+        // The entry may be removed once decided, which closes the channel;
+        // the receiver still holds the decision either way.
+        let _ = tokio::time::timeout(
+            remaining,
+            receiver.wait_for(|approval| approval.state() != State::Pending),
+        )
+        .await;
+
+        // The waiters have run the call, so the decision is not kept for a
+        // retry; an entry still pending after the wait has expired.
+        let id = extract_approval_id(&approval.name).unwrap_or_default();
+        let removed = self.entries.lock().expect("lock poisoned").remove(id);
+        if let Some(entry) = removed
+            && entry.state() == State::Pending
+        {
+            entry.approval.send_modify(|approval| {
+                approval.state = State::Expired.into();
+            });
+        }
+        Admission::decided(&receiver.borrow())
+    }
+
+    /// Lists pending approvals, oldest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the queue lock is poisoned.
+    pub(crate) fn list(&self) -> Vec<Approval> {
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        Self::purge_expired(&mut entries);
+        let mut approvals: Vec<Approval> = entries
+            .values()
+            .filter(|entry| entry.state() == State::Pending)
+            .map(|entry| entry.approval.borrow().clone())
+            .collect();
+        approvals.sort_by(|a, b| {
+            a.create_time_ms
+                .cmp(&b.create_time_ms)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        approvals
+    }
+
+    /// Approves a pending call, letting the calls waiting on it, or else the
+    /// deferred caller's retry, run once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the approval does not exist or is not pending.
+    pub(crate) fn approve(&self, name: &str) -> Result<Approval, ApprovalError> {
+        self.decide(name, State::Approved, "")
+    }
+
+    /// Denies a pending call with an optional reason.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the approval does not exist or is not pending.
+    pub(crate) fn deny(&self, name: &str, reason: &str) -> Result<Approval, ApprovalError> {
+        self.decide(name, State::Denied, reason)
+    }
+
+    fn decide(&self, name: &str, state: State, reason: &str) -> Result<Approval, ApprovalError> {
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        Self::purge_expired(&mut entries);
+        let id = extract_approval_id(name)
+            .filter(|id| entries.contains_key(*id))
+            .ok_or_else(|| ApprovalError::NotFound(name.to_string()))?;
+        let entry = &entries[id];
+        if entry.state() != State::Pending {
+            return Err(ApprovalError::NotPending(name.to_string()));
+        }
+
+        let timeout = self.timeout_millis();
+        entry.approval.send_modify(|approval| {
+            approval.state = state.into();
+            approval.deny_reason = reason.to_string();
+            // A deferred caller can claim the decision for another timeout
+            // period.
+            approval.expire_time_ms = unix_millis().saturating_add(timeout);
+        });
+        let approval = entry.approval.borrow().clone();
+        if !entry.deferred {
+            // Waiters already hold the decision, and nobody else may claim it.
+            entries.remove(id);
+        }
+        Ok(approval)
+    }
+
+    /// Claims the entry matching `key`, preferring a decision over a pending
+    /// entry, and returns its admission.
+    ///
+    /// Decided entries are removed; pending ones are left in place, and
+    /// marked deferred if the claiming caller defers.
+    fn claim_matching(
+        entries: &mut HashMap<String, Entry>,
+        key: &CallKey,
+        deferred: bool,
+    ) -> Option<Admission> {
+        let id = entries
+            .iter()
+            .filter(|(_, entry)| entry.key == *key)
+            .min_by_key(|(_, entry)| entry.state() == State::Pending)
+            .map(|(id, _)| id.clone())?;
+        let entry = entries.get_mut(&id)?;
+        if entry.state() == State::Pending {
+            entry.deferred |= deferred;
+            return Some(Admission::Pending(
+                entry.approval.borrow().clone(),
+                entry.approval.subscribe(),
+            ));
+        }
+        let entry = entries.remove(&id)?;
+        Admission::decided(&entry.approval.borrow())
+    }
+
+    /// Drops entries past their expiry time, waking any waiters.
+    fn purge_expired(entries: &mut HashMap<String, Entry>) {
+        let now = unix_millis();
+        entries.retain(|_, entry| {
+            let live = entry.approval.borrow().expire_time_ms > now;
+            if !live {
+                entry.approval.send_modify(|approval| {
+                    approval.state = State::Expired.into();
+                });
+            }
+            live
+        });
+    }
+
+    fn next_id(&self) -> String {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(self.next_seq.fetch_add(1, Ordering::Relaxed));
+        hasher.write_i64(unix_millis());
+        format!("{:016x}", hasher.finish())
+    }
+
+    fn timeout_millis(&self) -> i64 {
+        i64::try_from(self.timeout.as_millis()).unwrap_or(i64::MAX)
+    }
+}
+
+impl Default for ApprovalQueue {
+    fn default() -> Self {
+        Self::new(DEFAULT_APPROVAL_TIMEOUT)
+    }
+}
+
+/// Extracts the approval ID from a resource name by removing the
+/// "approvals/" prefix.
+///
+/// Returns `None` if the name doesn't start with "approvals/".
+pub(crate) fn extract_approval_id(name: &str) -> Option<&str> {
+    name.strip_prefix("approvals/")
+}
+
+fn unix_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| i64::try_from(d.as_millis()).unwrap_or(i64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn key(session_id: &str, tool_id: &str, input: &serde_json::Value) -> CallKey {
+        CallKey::new(session_id, None, tool_id, input)
+    }
+
+    fn pending(admission: Admission) -> (Approval, watch::Receiver<Approval>) {
+        match admission {
+            Admission::Pending(approval, receiver) => (approval, receiver),
+            other => panic!("expected pending admission, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resubmitting_same_call_joins_pending_approval() {
+        let queue = ApprovalQueue::default();
+        let input = json!({"instance": "i-1"});
+        let call = key("s1", "aws-ec2.terminate", &input);
+        let caller = CallerIdentity {
+            id: "alice".to_string(),
+            ..Default::default()
+        };
+
+        let (first, _) = pending(queue.submit(&call, &input, "review", true));
+        let (second, _) = pending(queue.submit(&call, &input, "review", true));
+        let (other_session, _) = pending(queue.submit(
+            &key("s2", "aws-ec2.terminate", &input),
+            &input,
+            "review",
+            true,
+        ));
+        let (other_caller, _) = pending(queue.submit(
+            &CallKey::new("s1", Some(&caller), "aws-ec2.terminate", &input),
+            &input,
+            "review",
+            true,
+        ));
+
+        assert_eq!(first.name, second.name);
+        assert_ne!(first.name, other_session.name);
+        assert_ne!(first.name, other_caller.name);
+        assert_eq!(first.tool, "tools/aws-ec2.terminate");
+        assert_eq!(first.state, i32::from(State::Pending));
+        assert_eq!(queue.list().len(), 3);
+    }
+
+    #[test]
+    fn test_approval_grants_deferred_caller_one_call() {
+        let queue = ApprovalQueue::default();
+        let input = json!({"amount": 10});
+        let call = key("s1", "pay", &input);
+        let (approval, _) = pending(queue.submit(&call, &input, "review", true));
+
+        let approved = queue.approve(&approval.name).unwrap();
+        assert_eq!(approved.state, i32::from(State::Approved));
+        assert!(queue.list().is_empty());
+        assert!(matches!(
+            queue.approve(&approval.name),
+            Err(ApprovalError::NotPending(_))
+        ));
+
+        assert!(matches!(
+            queue.submit(&call, &input, "review", true),
+            Admission::Granted
+        ));
+        pending(queue.submit(&call, &input, "review", true));
+    }
+
+    #[test]
+    fn test_denial_fails_deferred_callers_next_call_with_reason() {
+        let queue = ApprovalQueue::default();
+        let call = key("s1", "pay", &json!({}));
+        let (approval, _) = pending(queue.submit(&call, &json!({}), "review", true));
+
+        queue.deny(&approval.name, "too expensive").unwrap();
+
+        assert!(matches!(
+            queue.submit(&call, &json!({}), "review", true),
+            Admission::Denied(reason) if reason == "too expensive"
+        ));
+        assert!(matches!(
+            queue.deny("approvals/missing", ""),
+            Err(ApprovalError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_returns_decision() {
+        let queue = Arc::new(ApprovalQueue::default());
+        let call = key("s1", "pay", &json!({}));
+        let (approval, receiver) = pending(queue.submit(&call, &json!({}), "review", false));
+
+        let approver = {
+            let queue = queue.clone();
+            let name = approval.name.clone();
+            tokio::spawn(async move { queue.approve(&name).unwrap() })
+        };
+
+        assert!(matches!(
+            queue.wait(&approval, receiver).await,
+            Some(Admission::Granted)
+        ));
+        approver.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_second_identical_call_needs_new_approval() {
+        // A deferred caller joining the approval does not keep the grant
+        // claimable once a waiting call has run with it.
+        for deferred in [false, true] {
+            let queue = ApprovalQueue::default();
+            let call = key("s1", "pay", &json!({}));
+            let (approval, receiver) = pending(queue.submit(&call, &json!({}), "review", false));
+            if deferred {
+                assert_eq!(queue.pending(&call), Some(approval.clone()));
+            }
+
+            queue.approve(&approval.name).unwrap();
+            assert!(matches!(
+                queue.wait(&approval, receiver).await,
+                Some(Admission::Granted)
+            ));
+
+            let (next, _) = pending(queue.submit(&call, &json!({}), "review", false));
+            assert_ne!(next.name, approval.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_every_waiter_gets_decision() {
+        let queue = Arc::new(ApprovalQueue::default());
+        for approve in [true, false] {
+            let input = json!({ "approve": approve });
+            let call = key("s1", "pay", &input);
+            let (approval, first) = pending(queue.submit(&call, &input, "review", false));
+            let (_, second) = pending(queue.submit(&call, &input, "review", false));
+            let waiters = [first, second].map(|receiver| {
+                let queue = queue.clone();
+                let approval = approval.clone();
+                tokio::spawn(async move { queue.wait(&approval, receiver).await })
+            });
+
+            if approve {
+                queue.approve(&approval.name).unwrap();
+            } else {
+                queue.deny(&approval.name, "too expensive").unwrap();
+            }
+            for waiter in waiters {
+                let admission = waiter.await.unwrap();
+                if approve {
+                    assert!(matches!(admission, Some(Admission::Granted)));
+                } else {
+                    assert!(
+                        matches!(admission, Some(Admission::Denied(reason)) if reason == "too expensive")
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pending_returns_held_call_until_decided() {
+        let queue = ApprovalQueue::default();
+        let input = json!({"amount": 10});
+        let rewritten = json!({"amount": 10, "currency": "EUR"});
+        let call = key("s1", "pay", &input);
+        assert!(queue.pending(&call).is_none());
+
+        let (approval, _) = pending(queue.submit(&call, &rewritten, "review", false));
+        assert_eq!(approval.input, json_value_to_struct(&rewritten));
+        assert_eq!(queue.pending(&call), Some(approval.clone()));
+
+        // Checking on the call made it deferred, so the grant is kept for its
+        // retry.
+        queue.approve(&approval.name).unwrap();
+        assert!(queue.pending(&call).is_none());
+        assert!(matches!(
+            queue.submit(&call, &rewritten, "review", true),
+            Admission::Granted
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_expires_without_decision() {
+        let queue = ApprovalQueue::new(Duration::from_millis(20));
+        let call = key("s1", "pay", &json!({}));
+        let (approval, receiver) = pending(queue.submit(&call, &json!({}), "review", false));
+
+        assert!(queue.wait(&approval, receiver).await.is_none());
+        assert!(queue.list().is_empty());
+        assert!(matches!(
+            queue.approve(&approval.name),
+            Err(ApprovalError::NotFound(_))
+        ));
+    }
+}
@@ -7,7 +7,10 @@
 //! - **Project Config Loading**: Parsing tool configurations from `operai.toml`
 //! - **Tool Registration**: Loading dynamic tool libraries and static tool
 //!   modules
-//! - **Policy Setup**: Resolving and registering policy enforcement rules
+//! - **Policy Setup**: Resolving and registering policy enforcement rules, and
+//!   reloading policy files when they change
+//! - **Result Caching**: Enabling the result cache if the config has a
+//!   `[cache]` section
 //! - **Runtime Mode**: Choosing between local execution or remote gRPC
 //!   connections
 //!
@@ -33,21 +36,32 @@
 //! ```
 
 use std::{
-    fmt,
+    collections::HashSet,
+    fmt, fs,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, Weak},
+    time::{Duration, SystemTime},
 };
 
 use operai_abi::RuntimeContext;
 #[cfg(feature = "static-link")]
 use operai_abi::ToolModuleRef;
 use operai_core::{
-    Config, ToolRegistry,
-    policy::session::{InMemoryPolicySessionStore, PolicyStore},
+    CacheConfig, Config, SessionBackend, ToolRegistry,
+    policy::{
+        functions::FunctionLibrary,
+        session::{
+            InMemoryPolicySessionStore, PolicySessionStore, PolicyStore, SessionError,
+            SqlitePolicySessionStore,
+        },
+    },
 };
 use tracing::{error, info, warn};
 
-use crate::runtime::{LocalRuntime, RemoteRuntime, Runtime};
+use crate::{
+    cache::ResultCache,
+    runtime::{LocalRuntime, RemoteRuntime, Runtime},
+};
 
 /// Errors that can occur during runtime construction.
 #[derive(Debug, thiserror::Error)]
@@ -63,6 +77,10 @@ pub enum RuntimeBuildError {
     /// Failed to establish a connection to a remote runtime.
     #[error("failed to connect to remote runtime: {0}")]
     Transport(#[from] tonic::transport::Error),
+
+    /// Failed to open the configured policy session store.
+    #[error("failed to open session store: {0}")]
+    SessionStore(#[from] SessionError),
 }
 
 #[derive(Debug, Clone)]
@@ -106,6 +124,7 @@ pub struct RuntimeBuilder {
     config_path: PathBuf,
     runtime_ctx: RuntimeContext,
     mode: RuntimeMode,
+    policy_functions: FunctionLibrary,
     #[cfg(feature = "static-link")]
     static_tools: Vec<ToolModuleRef>,
 }
@@ -116,7 +135,8 @@ impl fmt::Debug for RuntimeBuilder {
         debug_struct
             .field("config_path", &self.config_path)
             .field("runtime_ctx", &self.runtime_ctx)
-            .field("mode", &self.mode);
+            .field("mode", &self.mode)
+            .field("policy_functions", &self.policy_functions);
         #[cfg(feature = "static-link")]
         {
             debug_struct.field("static_tools", &self.static_tools.len());
@@ -140,6 +160,7 @@ impl RuntimeBuilder {
             config_path: PathBuf::from("operai.toml"),
             runtime_ctx: RuntimeContext::new(),
             mode: RuntimeMode::Local,
+            policy_functions: FunctionLibrary::new(),
             #[cfg(feature = "static-link")]
             static_tools: Vec::new(),
         }
@@ -175,6 +196,20 @@ impl RuntimeBuilder {
         self
     }
 
+    /// Sets custom functions available to policy expressions.
+    ///
+    /// The functions are added to the built-in policy functions when the
+    /// policies from the config are registered. Only used in local mode.
+    ///
+    /// # Parameters
+    ///
+    /// - `functions`: The custom functions to register
+    #[must_use]
+    pub fn with_policy_functions(mut self, functions: FunctionLibrary) -> Self {
+        self.policy_functions = functions;
+        self
+    }
+
     /// Builds a [`LocalRuntime`] instance.
     ///
     /// This method loads tools from the configured config, initializes
@@ -185,7 +220,8 @@ impl RuntimeBuilder {
     /// # Errors
     ///
     /// Returns [`RuntimeBuildError::Config`] if the config file cannot be
-    /// loaded or parsed.
+    /// loaded or parsed, or a budget, rate limit, circuit breaker or
+    /// admission limit in it is invalid.
     pub async fn build_local(self) -> Result<LocalRuntime, RuntimeBuildError> {
         build_local_runtime(self).await
     }
@@ -289,10 +325,14 @@ impl Default for RuntimeBuilder {
 ///
 /// 1. Loads the project config from the configured path (or uses unified
 ///    resolution if not set)
-/// 2. Creates a tool registry and loads all enabled tool libraries
+/// 2. Creates a tool registry and loads all enabled tool libraries, taking note
+///    of the concurrency limits and timeouts of their tools
 /// 3. Registers any static tool modules
-/// 4. Initializes the policy store and registers policies from the project
+/// 4. Opens the session store selected by the `[sessions]` config section
+/// 5. Initializes the policy store and registers policies from the project
 ///    config
+/// 6. Watches the external policy files for changes, unless disabled with
+///    `[policy_evaluation] watch_interval_secs = 0`
 ///
 /// Tool loading failures are logged but do not prevent runtime construction.
 /// Policy registration failures are similarly logged and skipped.
@@ -304,6 +344,7 @@ async fn build_local_runtime(builder: RuntimeBuilder) -> Result<LocalRuntime, Ru
     let config_dir = config_path.parent().unwrap_or_else(|| Path::new("."));
 
     let mut registry = ToolRegistry::new();
+    let mut admission = config.admission()?;
 
     for tool_config in config.enabled_tools() {
         let Some(path) = resolve_tool_path(tool_config, config_dir) else {
@@ -313,6 +354,10 @@ async fn build_local_runtime(builder: RuntimeBuilder) -> Result<LocalRuntime, Ru
 
         info!(path = %path.display(), "Loading tool library");
 
+        let loaded: HashSet<String> = registry
+            .list()
+            .map(|info| info.qualified_id.clone())
+            .collect();
         if let Err(e) = registry
             .load_library(
                 &path,
@@ -325,6 +370,20 @@ async fn build_local_runtime(builder: RuntimeBuilder) -> Result<LocalRuntime, Ru
             error!(path = %path.display(), error = %e, "Failed to load tool library");
         } else {
             info!(path = %path.display(), "Loaded tool library");
+            let mut tool_ids = HashSet::new();
+            for info in registry
+                .list()
+                .filter(|info| !loaded.contains(&info.qualified_id))
+            {
+                admission = admission
+                    .with_tool_limits(&info.qualified_id, tool_config.tool_limits(&info.tool_id)?);
+                tool_ids.insert(info.tool_id.as_str());
+            }
+            for tool_id in tool_config.overrides.keys() {
+                if !tool_ids.contains(tool_id.as_str()) {
+                    warn!(path = %path.display(), tool_id = %tool_id, "Limits configured for unknown tool");
+                }
+            }
         }
     }
 
@@ -339,8 +398,25 @@ async fn build_local_runtime(builder: RuntimeBuilder) -> Result<LocalRuntime, Ru
 
     let registry = Arc::new(registry);
 
-    let session_store = Arc::new(InMemoryPolicySessionStore::new());
-    let policy_store = Arc::new(PolicyStore::new(session_store));
+    let session_store = open_session_store(&config, config_dir)?;
+    let policy_store = Arc::new(
+        PolicyStore::new(session_store)
+            .with_combining_algorithm(config.policy_evaluation.combining)
+            .with_functions(builder.policy_functions)
+            .with_history(config.sessions.history())
+            .with_budgets(config.budgets()?),
+    );
+    if let Some(ttl) = config.sessions.limits().idle_ttl {
+        spawn_session_sweeper(Arc::downgrade(&policy_store), ttl);
+    }
+
+    // Take note of the policy files before loading them, so that a change
+    // made while they load is picked up by the first check.
+    let policy_files: Vec<WatchedPolicyFile> = config
+        .policy_files(&config_path)
+        .into_iter()
+        .map(WatchedPolicyFile::new)
+        .collect();
 
     match config.resolve_policies(&config_path) {
         Ok(policies) => {
@@ -361,12 +437,176 @@ async fn build_local_runtime(builder: RuntimeBuilder) -> Result<LocalRuntime, Ru
             warn!(error = %e, "Failed to resolve policies from config");
         }
     }
+    if let Some(interval) = config.policy_evaluation.watch_interval()
+        && !policy_files.is_empty()
+    {
+        spawn_policy_watcher(Arc::downgrade(&policy_store), policy_files, interval);
+    }
+
+    let runtime = LocalRuntime::with_context(registry, policy_store, runtime_ctx)
+        .with_approval_timeout(config.approvals.timeout())
+        .with_circuit_breakers(config.circuit_breakers()?)
+        .with_retry_policy(config.retries()?)
+        .with_rate_limits(config.rate_limits()?)
+        .with_admission(admission);
+    Ok(match config.cache()? {
+        Some(cache) => runtime.with_result_cache(result_cache(cache)),
+        None => runtime,
+    })
+}
+
+/// Builds the result cache described by the `[cache]` config section.
+fn result_cache(config: &CacheConfig) -> ResultCache {
+    config.tools.iter().fold(
+        ResultCache::new()
+            .with_max_entries(config.max_entries)
+            .with_max_bytes(config.max_bytes)
+            .with_credential_keys(config.vary_by_credentials),
+        |cache, rule| cache.with_ttl(&rule.tool, rule.ttl()),
+    )
+}
 
-    Ok(LocalRuntime::with_context(
-        registry,
-        policy_store,
-        runtime_ctx,
-    ))
+/// Opens the policy session store selected by the `[sessions]` config section.
+///
+/// Unlike tool and policy loading, failures here are fatal: silently falling
+/// back to in-memory sessions would discard state the operator asked to keep.
+fn open_session_store(
+    config: &Config,
+    config_dir: &Path,
+) -> Result<Arc<dyn PolicySessionStore>, RuntimeBuildError> {
+    let limits = config.sessions.limits();
+    match config.sessions.backend {
+        SessionBackend::Memory => Ok(Arc::new(
+            InMemoryPolicySessionStore::new().with_limits(limits),
+        )),
+        SessionBackend::Sqlite => {
+            let path = config.sessions.resolve_path(config_dir);
+            info!(path = %path.display(), "Opening SQLite session store");
+            Ok(Arc::new(
+                SqlitePolicySessionStore::open(path)?.with_limits(limits),
+            ))
+        }
+    }
+}
+
+/// Periodically removes expired sessions so idle ones don't accumulate
+/// until the next save.
+///
+/// The task holds only a weak reference and exits once the policy store is
+/// dropped.
+fn spawn_session_sweeper(policy_store: Weak<PolicyStore>, idle_ttl: Duration) {
+    let period = (idle_ttl / 2).max(Duration::from_secs(1));
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let Some(policy_store) = policy_store.upgrade() else {
+                break;
+            };
+            match policy_store.evict_expired_sessions().await {
+                Ok(0) => {}
+                Ok(evicted) => info!(evicted, "Evicted expired policy sessions"),
+                Err(e) => warn!(error = %e, "Failed to evict expired policy sessions"),
+            }
+        }
+    });
+}
+
+/// An external policy file checked for changes.
+#[derive(Debug)]
+struct WatchedPolicyFile {
+    path: PathBuf,
+    /// Modification time and length of the file when it was last loaded,
+    /// or `None` if it could not be read.
+    stamp: Option<(SystemTime, u64)>,
+    /// Name of the policy the file defines, if it could be parsed.
+    policy: Option<String>,
+}
+
+impl WatchedPolicyFile {
+    fn new(path: PathBuf) -> Self {
+        let stamp = file_stamp(&path);
+        let policy = Config::load_policy_file(&path)
+            .ok()
+            .map(|policy| policy.name);
+        Self {
+            path,
+            stamp,
+            policy,
+        }
+    }
+
+    /// Reloads the policy if the file changed since it was last loaded.
+    ///
+    /// The new policy replaces the old one, also when it was renamed, only
+    /// if it parses and compiles; otherwise the old one stays registered.
+    fn reload_if_changed(&mut self, policy_store: &PolicyStore) {
+        let stamp = file_stamp(&self.path);
+        if stamp == self.stamp {
+            return;
+        }
+        self.stamp = stamp;
+        if stamp.is_none() {
+            warn!(path = %self.path.display(), "Policy file is unreadable, keeping the registered policy");
+            return;
+        }
+
+        let policy = match Config::load_policy_file(&self.path) {
+            Ok(policy) => policy,
+            Err(e) => {
+                warn!(path = %self.path.display(), error = %e, "Failed to reload policy file, keeping the registered policy");
+                return;
+            }
+        };
+        let name = policy.name.clone();
+        let version = policy.version.clone();
+        let renamed: Vec<String> = self
+            .policy
+            .iter()
+            .filter(|old| **old != name)
+            .cloned()
+            .collect();
+        match policy_store.replace(&renamed, vec![policy]) {
+            Ok(()) => {
+                info!(path = %self.path.display(), name = %name, version = %version, "Reloaded policy");
+                self.policy = Some(name);
+            }
+            Err(e) => {
+                warn!(path = %self.path.display(), name = %name, error = %e, "Invalid policy file, keeping the registered policy");
+            }
+        }
+    }
+}
+
+/// Returns the modification time and length of a file, if it exists.
+fn file_stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Periodically reloads the policy files that changed.
+///
+/// The task holds only a weak reference and exits once the policy store is
+/// dropped.
+fn spawn_policy_watcher(
+    policy_store: Weak<PolicyStore>,
+    mut files: Vec<WatchedPolicyFile>,
+    period: Duration,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let Some(policy_store) = policy_store.upgrade() else {
+                break;
+            };
+            for file in &mut files {
+                file.reload_if_changed(&policy_store);
+            }
+        }
+    });
 }
 
 /// Loads a config from the given path, returning an empty config if not found.
@@ -658,10 +898,13 @@ mod tests {
             .expect("runtime should build");
 
         let list_response = runtime
-            .list_tools(ListToolsRequest {
-                page_size: 1000,
-                page_token: String::new(),
-            })
+            .list_tools(
+                ListToolsRequest {
+                    page_size: 1000,
+                    page_token: String::new(),
+                },
+                CallMetadata::default(),
+            )
             .await
             .expect("list_tools should succeed");
         assert!(
@@ -723,10 +966,13 @@ mod tests {
             .expect("runtime should build with name-based resolution");
 
         let list_response = runtime
-            .list_tools(ListToolsRequest {
-                page_size: 1000,
-                page_token: String::new(),
-            })
+            .list_tools(
+                ListToolsRequest {
+                    page_size: 1000,
+                    page_token: String::new(),
+                },
+                CallMetadata::default(),
+            )
             .await
             .expect("list_tools should succeed");
         assert!(
@@ -741,4 +987,180 @@ mod tests {
         let _ = std::fs::remove_file(&test_lib_dest);
         let _ = std::fs::remove_dir_all(target_dir);
     }
+
+    #[tokio::test]
+    async fn test_runtime_builder_registers_policy_functions() {
+        let lib_path = hello_world_cdylib_path();
+        let config_path = write_config_for_library(&lib_path);
+        let mut contents = std::fs::read_to_string(&config_path).expect("read config");
+        contents.push_str(
+            r#"
+[[policies]]
+name = "polite"
+[[policies.effects]]
+tool = "hello-world.echo"
+stage = "before"
+when = "isPolite(input.message)"
+fail_message = "say please"
+"#,
+        );
+        std::fs::write(&config_path, contents).expect("write config");
+
+        let runtime = RuntimeBuilder::new()
+            .with_config_path(&config_path)
+            .with_policy_functions(FunctionLibrary::new().with_function("isPolite", |args| {
+                match args {
+                    [operai_core::policy::functions::Value::String(message)] => Ok(
+                        operai_core::policy::functions::Value::Bool(message.contains("please")),
+                    ),
+                    _ => Err("expected a message".into()),
+                }
+            }))
+            .build_local()
+            .await
+            .expect("runtime should build");
+        let call = |message: &str| {
+            runtime.call_tool(
+                CallToolRequest {
+                    name: "tools/hello-world.echo".to_string(),
+                    input: Some(prost_types::Struct {
+                        fields: [("message".to_string(), make_string_value(message))]
+                            .into_iter()
+                            .collect(),
+                    }),
+                },
+                CallMetadata::default(),
+            )
+        };
+
+        let allowed = call("hi please").await.expect("call_tool should succeed");
+        assert!(matches!(
+            allowed.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+        let denied = call("hi")
+            .await
+            .expect_err("impolite call should be denied");
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+        assert_eq!(denied.message(), "say please");
+
+        let _ = std::fs::remove_file(&config_path);
+    }
+
+    #[tokio::test]
+    async fn test_runtime_builder_persists_sessions_in_sqlite() {
+        let lib_path = hello_world_cdylib_path();
+        let config_path = temp_config_path();
+        let db_path = config_path.with_extension("sessions.db");
+        let mut lib_str = lib_path.display().to_string();
+        let mut db_str = db_path.display().to_string();
+        if std::path::MAIN_SEPARATOR == '\\' {
+            lib_str = lib_str.replace('\\', "\\\\");
+            db_str = db_str.replace('\\', "\\\\");
+        }
+        let contents = format!(
+            r#"[sessions]
+backend = "sqlite"
+path = "{db_str}"
+
+[[tools]]
+path = "{lib_str}"
+
+[[policies]]
+name = "counter"
+[policies.context]
+calls = 0
+[[policies.effects]]
+tool = "hello-world.echo"
+stage = "after"
+when = "true"
+[policies.effects.set]
+calls = "context.calls + 1"
+"#
+        );
+        std::fs::write(&config_path, contents).expect("write config");
+
+        // Each runtime opens the database afresh, as a restarted server would.
+        for _ in 0..2 {
+            let runtime = RuntimeBuilder::new()
+                .with_config_path(&config_path)
+                .build_local()
+                .await
+                .expect("runtime should build");
+            let input = prost_types::Struct {
+                fields: [("message".to_string(), make_string_value("hi"))]
+                    .into_iter()
+                    .collect(),
+            };
+            runtime
+                .call_tool(
+                    CallToolRequest {
+                        name: "tools/hello-world.echo".to_string(),
+                        input: Some(input),
+                    },
+                    CallMetadata {
+                        session_id: "s1".to_string(),
+                        ..CallMetadata::default()
+                    },
+                )
+                .await
+                .expect("call_tool should succeed");
+        }
+
+        let store = SqlitePolicySessionStore::open(&db_path).expect("open session store");
+        let session = store.load("s1").await.expect("load session");
+        assert_eq!(session.context.get("calls"), Some(&serde_json::json!(2)));
+
+        let _ = std::fs::remove_file(&config_path);
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_watched_policy_file_reloads_valid_changes_only() {
+        let policy_path = temp_config_path();
+        let write = |name: &str, when: &str| {
+            std::fs::write(
+                &policy_path,
+                format!(
+                    "name = \"{name}\"\nversion = \"1\"\n\n[[effects]]\ntool = \"*\"\nstage = \
+                     \"before\"\nwhen = \"{when}\"\n"
+                ),
+            )
+            .expect("write policy file");
+        };
+        write("audit", "true");
+        let policy_store = PolicyStore::new(Arc::new(InMemoryPolicySessionStore::new()));
+        policy_store
+            .register(Config::load_policy_file(&policy_path).expect("load policy file"))
+            .expect("policy should register");
+        let mut watched = WatchedPolicyFile::new(policy_path.clone());
+        let names = || -> Vec<String> {
+            policy_store
+                .list()
+                .into_iter()
+                .map(|policy| policy.name)
+                .collect()
+        };
+
+        // Unchanged files are not reloaded.
+        watched.reload_if_changed(&policy_store);
+        assert_eq!(names(), ["audit"]);
+
+        // A policy that fails to compile keeps the old one registered.
+        write("audit", "input.");
+        watched.reload_if_changed(&policy_store);
+        assert_eq!(
+            policy_store.get("audit").expect("policy kept").effects[0].condition,
+            "true"
+        );
+
+        // A renamed policy replaces the one the file defined before.
+        write("audit-v2", "false");
+        watched.reload_if_changed(&policy_store);
+        assert_eq!(names(), ["audit-v2"]);
+
+        let _ = std::fs::remove_file(&policy_path);
+        watched.reload_if_changed(&policy_store);
+        assert_eq!(names(), ["audit-v2"]);
+    }
 }
@@ -0,0 +1,538 @@
+//! Cache of tool call results.
+//!
+//! A [`ResultCache`] keeps the output of successful calls to tools that have
+//! a time to live: either from the first TTL rule added with
+//! [`ResultCache::with_ttl`] that matches the tool, or else from the
+//! `## Cache` section of the tool's doc comments. A zero TTL disables caching
+//! of the tools a rule matches. Results of tools with a `write` or
+//! `mutating` capability are never cached.
+//!
+//! Results are keyed by tool, a digest of the input, the session and
+//! authenticated caller, which tools can both read, and, unless
+//! [disabled](ResultCache::with_credential_keys), a digest of the caller's
+//! credentials. Different callers, sessions, or credentials never share
+//! results. Once the cache holds `max_entries` results or `max_bytes` of
+//! output, expired results are dropped and then the least recently used ones,
+//! to make room.
+//!
+//! Only the output of the tool is cached: policies are still evaluated for
+//! every call, and their post-call effects apply to cached output as they do
+//! to fresh output.
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use operai_core::{ToolInfo, policy::CompiledPattern};
+use tracing::debug;
+
+use crate::runtime::CallMetadata;
+
+/// Capabilities of tools whose calls change state, so their results are
+/// never reused.
+const MUTATING_CAPABILITIES: &[&str] = &["write", "mutating"];
+
+/// Key of a cached result.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey(String);
+
+/// Hit and miss counts and size of a [`ResultCache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Calls answered from the cache.
+    pub hits: u64,
+    /// Calls that found no unexpired result and ran the tool.
+    pub misses: u64,
+    /// Calls that skipped the cache because the caller asked to.
+    pub bypasses: u64,
+    /// Results stored.
+    pub insertions: u64,
+    /// Unexpired results dropped to make room for others.
+    pub evictions: u64,
+    /// Results currently held, including expired ones not yet dropped.
+    pub entries: usize,
+    /// Serialized size of the results currently held, in bytes.
+    pub bytes: usize,
+}
+
+impl CacheStats {
+    /// Share of lookups answered from the cache, or 0 before any lookup.
+    #[must_use]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "counts stay far below 2^52 in practice"
+    )]
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// A cached result.
+#[derive(Debug)]
+struct CacheEntry {
+    output: serde_json::Value,
+    size: usize,
+    expires_at: Instant,
+    last_used: Instant,
+}
+
+/// Results and counts, guarded together.
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<CacheKey, CacheEntry>,
+    stats: CacheStats,
+}
+
+/// Size-limited cache of the results of successful tool calls.
+#[derive(Debug)]
+pub struct ResultCache {
+    rules: Vec<(CompiledPattern, Duration)>,
+    max_entries: usize,
+    max_bytes: usize,
+    credential_keys: bool,
+    state: Mutex<CacheState>,
+}
+
+impl ResultCache {
+    /// Create a cache of at most 1000 results and 16 MiB of output, keyed by
+    /// credentials, that only caches tools declaring a TTL.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            max_entries: 1000,
+            max_bytes: 16 * 1024 * 1024,
+            credential_keys: true,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Hold at most `max_entries` results.
+    #[must_use]
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Hold at most `max_bytes` of serialized output; larger results are not
+    /// cached.
+    #[must_use]
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Include a digest of the caller's credentials in the key, or key
+    /// results only by tool, input, session and caller.
+    #[must_use]
+    pub fn with_credential_keys(mut self, credential_keys: bool) -> Self {
+        self.credential_keys = credential_keys;
+        self
+    }
+
+    /// Keep the results of tools matching `tool` for `ttl`, overriding the
+    /// TTL they declare; a zero `ttl` disables caching of their results.
+    ///
+    /// Rules are tried in the order they were added.
+    #[must_use]
+    pub fn with_ttl(mut self, tool: &str, ttl: Duration) -> Self {
+        self.rules.push((CompiledPattern::new(tool), ttl));
+        self
+    }
+
+    /// Returns how long results of the tool are kept, or `None` if they are
+    /// not cached, as for tools with a `write` or `mutating` capability.
+    #[must_use]
+    pub fn ttl(&self, info: &ToolInfo) -> Option<Duration> {
+        if info
+            .capabilities
+            .iter()
+            .any(|capability| MUTATING_CAPABILITIES.contains(&capability.as_str()))
+        {
+            return None;
+        }
+        self.rules
+            .iter()
+            .find(|(pattern, _)| pattern.matches(&info.qualified_id))
+            .map_or(info.cache_ttl, |(_, ttl)| Some(*ttl))
+            .filter(|ttl| !ttl.is_zero())
+    }
+
+    /// Returns the key of a call to `tool_id` with `input`, made in the
+    /// session, by the caller and with the credentials of `metadata`.
+    #[must_use]
+    pub fn key(
+        &self,
+        tool_id: &str,
+        input: &serde_json::Value,
+        metadata: &CallMetadata,
+    ) -> CacheKey {
+        // JSON objects serialize with sorted keys, so equal inputs and
+        // credentials digest equally. The session and caller are digested
+        // together so that no choice of IDs makes two keys collide.
+        let input = sha256::digest(input.to_string());
+        let caller = metadata.caller.as_ref().map(|caller| caller.id.as_str());
+        let scope = sha256::digest(serde_json::json!([metadata.session_id, caller]).to_string());
+        if !self.credential_keys {
+            return CacheKey(format!("{tool_id}:{input}:{scope}"));
+        }
+        let credentials: serde_json::Map<_, _> = metadata
+            .credentials
+            .iter()
+            .map(|(provider, values)| {
+                let values: serde_json::Map<_, _> = values
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.as_str().into()))
+                    .collect();
+                (provider.clone(), values.into())
+            })
+            .collect();
+        let credentials = sha256::digest(serde_json::Value::Object(credentials).to_string());
+        CacheKey(format!("{tool_id}:{input}:{scope}:{credentials}"))
+    }
+
+    /// Returns the unexpired result stored under `key`, counting a hit or a
+    /// miss.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cache lock is poisoned.
+    pub fn get(&self, key: &CacheKey) -> Option<serde_json::Value> {
+        self.get_at(key, Instant::now())
+    }
+
+    /// Counts a call that skipped the cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cache lock is poisoned.
+    pub fn record_bypass(&self) {
+        self.state.lock().expect("lock poisoned").stats.bypasses += 1;
+    }
+
+    /// Stores the result of a successful call under `key` for `ttl`,
+    /// replacing any result already stored and making room if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cache lock is poisoned.
+    pub fn insert(&self, key: CacheKey, output: &serde_json::Value, ttl: Duration) {
+        self.insert_at(key, output, ttl, Instant::now());
+    }
+
+    /// Returns the hit and miss counts and the current size of the cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cache lock is poisoned.
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        let state = self.state.lock().expect("lock poisoned");
+        CacheStats {
+            entries: state.entries.len(),
+            bytes: state.entries.values().map(|entry| entry.size).sum(),
+            ..state.stats
+        }
+    }
+
+    fn get_at(&self, key: &CacheKey, now: Instant) -> Option<serde_json::Value> {
+        let mut state = self.state.lock().expect("lock poisoned");
+        let output = match state.entries.get_mut(key) {
+            Some(entry) if entry.expires_at > now => {
+                entry.last_used = now;
+                Some(entry.output.clone())
+            }
+            Some(_) => {
+                state.entries.remove(key);
+                None
+            }
+            None => None,
+        };
+        if output.is_some() {
+            state.stats.hits += 1;
+        } else {
+            state.stats.misses += 1;
+        }
+        output
+    }
+
+    fn insert_at(&self, key: CacheKey, output: &serde_json::Value, ttl: Duration, now: Instant) {
+        let size = output.to_string().len();
+        let mut state = self.state.lock().expect("lock poisoned");
+        state.entries.remove(&key);
+        if size > self.max_bytes || self.max_entries == 0 {
+            debug!(size, "Result too large to cache");
+            return;
+        }
+
+        let mut bytes: usize = state.entries.values().map(|entry| entry.size).sum();
+        if state.entries.len() >= self.max_entries || bytes + size > self.max_bytes {
+            state.entries.retain(|_, entry| entry.expires_at > now);
+            bytes = state.entries.values().map(|entry| entry.size).sum();
+        }
+        while state.entries.len() >= self.max_entries || bytes + size > self.max_bytes {
+            let Some(oldest) = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            if let Some(evicted) = state.entries.remove(&oldest) {
+                bytes -= evicted.size;
+                state.stats.evictions += 1;
+            }
+        }
+
+        state.entries.insert(
+            key,
+            CacheEntry {
+                output: output.clone(),
+                size,
+                expires_at: now + ttl,
+                last_used: now,
+            },
+        );
+        state.stats.insertions += 1;
+    }
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use operai_core::policy::request::CallerIdentity;
+    use serde_json::json;
+
+    use super::*;
+
+    fn tool_info(qualified_id: &str, cache_ttl: Option<Duration>) -> ToolInfo {
+        let (crate_name, tool_id) = qualified_id.split_once('.').unwrap_or(("", qualified_id));
+        ToolInfo {
+            qualified_id: qualified_id.to_string(),
+            tool_id: tool_id.to_string(),
+            crate_name: crate_name.to_string(),
+            crate_version: "0.1.0".to_string(),
+            display_name: String::new(),
+            description: String::new(),
+            input_schema: "{}".to_string(),
+            output_schema: "{}".to_string(),
+            credential_schema: None,
+            capabilities: Vec::new(),
+            tags: Vec::new(),
+            embedding: None,
+            cost: None,
+            idempotent: false,
+            cache_ttl,
+        }
+    }
+
+    #[test]
+    fn test_ttl_prefers_first_matching_rule_over_declared_ttl() {
+        let cache = ResultCache::new()
+            .with_ttl("weather.*", Duration::from_mins(5))
+            .with_ttl("search.*", Duration::ZERO);
+
+        assert_eq!(
+            cache.ttl(&tool_info(
+                "weather.forecast",
+                Some(Duration::from_secs(10))
+            )),
+            Some(Duration::from_mins(5))
+        );
+        assert_eq!(
+            cache.ttl(&tool_info("search.web", Some(Duration::from_secs(10)))),
+            None
+        );
+        assert_eq!(
+            cache.ttl(&tool_info("files.list", Some(Duration::from_secs(10)))),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(cache.ttl(&tool_info("files.write", None)), None);
+    }
+
+    #[test]
+    fn test_ttl_is_none_for_mutating_tools() {
+        let cache = ResultCache::new().with_ttl("**", Duration::from_mins(5));
+
+        for capability in ["write", "mutating"] {
+            let mut info = tool_info("db.update", Some(Duration::from_secs(10)));
+            info.capabilities = vec!["read".to_string(), capability.to_string()];
+            assert_eq!(cache.ttl(&info), None, "{capability} should not be cached");
+        }
+        let mut info = tool_info("db.query", None);
+        info.capabilities = vec!["read".to_string()];
+        assert_eq!(cache.ttl(&info), Some(Duration::from_mins(5)));
+    }
+
+    fn with_credentials(token: &str) -> CallMetadata {
+        CallMetadata {
+            credentials: HashMap::from([(
+                "github".to_string(),
+                HashMap::from([("token".to_string(), token.to_string())]),
+            )]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_key_depends_on_input_and_credentials() {
+        let cache = ResultCache::new();
+        let alice = with_credentials("alice");
+        let bob = with_credentials("bob");
+        let input = json!({ "city": "Paris", "days": 3 });
+
+        assert_eq!(
+            cache.key("weather.forecast", &input, &alice),
+            cache.key(
+                "weather.forecast",
+                &json!({ "days": 3, "city": "Paris" }),
+                &alice
+            )
+        );
+        assert_ne!(
+            cache.key("weather.forecast", &input, &alice),
+            cache.key("weather.forecast", &json!({ "city": "Rome" }), &alice)
+        );
+        assert_ne!(
+            cache.key("weather.forecast", &input, &alice),
+            cache.key("weather.forecast", &input, &bob)
+        );
+
+        let shared = ResultCache::new().with_credential_keys(false);
+        assert_eq!(
+            shared.key("weather.forecast", &input, &alice),
+            shared.key("weather.forecast", &input, &bob)
+        );
+    }
+
+    #[test]
+    fn test_callers_and_sessions_without_credentials_do_not_share_results() {
+        let cache = ResultCache::new().with_credential_keys(false);
+        let caller = |id: &str| CallMetadata {
+            session_id: "s1".to_string(),
+            caller: Some(CallerIdentity {
+                id: id.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let input = json!({ "account": "main" });
+        let now = Instant::now();
+
+        cache.insert_at(
+            cache.key("bank.balance", &input, &caller("alice")),
+            &json!({ "balance": 100 }),
+            Duration::from_mins(1),
+            now,
+        );
+
+        assert!(
+            cache
+                .get_at(&cache.key("bank.balance", &input, &caller("alice")), now)
+                .is_some()
+        );
+        assert!(
+            cache
+                .get_at(&cache.key("bank.balance", &input, &caller("bob")), now)
+                .is_none()
+        );
+        let other_session = CallMetadata {
+            session_id: "s2".to_string(),
+            ..caller("alice")
+        };
+        assert!(
+            cache
+                .get_at(&cache.key("bank.balance", &input, &other_session), now)
+                .is_none()
+        );
+        let anonymous = CallMetadata {
+            session_id: "s1".to_string(),
+            ..Default::default()
+        };
+        assert!(
+            cache
+                .get_at(&cache.key("bank.balance", &input, &anonymous), now)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_get_returns_unexpired_results_and_counts_hits_and_misses() {
+        let cache = ResultCache::new();
+        let key = cache.key("weather.forecast", &json!({}), &CallMetadata::default());
+        let now = Instant::now();
+
+        assert_eq!(cache.get_at(&key, now), None);
+        cache.insert_at(
+            key.clone(),
+            &json!({ "temp": 21 }),
+            Duration::from_mins(1),
+            now,
+        );
+        assert_eq!(
+            cache.get_at(&key, now + Duration::from_secs(59)),
+            Some(json!({ "temp": 21 }))
+        );
+        assert_eq!(cache.get_at(&key, now + Duration::from_mins(1)), None);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.insertions), (1, 2, 1));
+        assert_eq!(stats.entries, 0);
+        assert!((stats.hit_rate() - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_insert_evicts_least_recently_used_results_to_stay_within_limits() {
+        let cache = ResultCache::new().with_max_entries(2).with_max_bytes(20);
+        let key = |n: u32| cache.key("t.t", &json!(n), &CallMetadata::default());
+        let now = Instant::now();
+        let ttl = Duration::from_mins(1);
+
+        cache.insert_at(key(1), &json!("a"), ttl, now);
+        cache.insert_at(key(2), &json!("b"), ttl, now + Duration::from_secs(1));
+        assert!(
+            cache
+                .get_at(&key(1), now + Duration::from_secs(2))
+                .is_some()
+        );
+        cache.insert_at(key(3), &json!("c"), ttl, now + Duration::from_secs(3));
+
+        assert!(
+            cache
+                .get_at(&key(2), now + Duration::from_secs(4))
+                .is_none()
+        );
+        assert!(
+            cache
+                .get_at(&key(1), now + Duration::from_secs(4))
+                .is_some()
+        );
+        assert!(
+            cache
+                .get_at(&key(3), now + Duration::from_secs(4))
+                .is_some()
+        );
+
+        // Results larger than the whole cache are not stored.
+        cache.insert_at(key(4), &json!("x".repeat(30)), ttl, now);
+        assert!(cache.get_at(&key(4), now).is_none());
+
+        let stats = cache.stats();
+        assert_eq!(stats.evictions, 1);
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.bytes, 6);
+    }
+}
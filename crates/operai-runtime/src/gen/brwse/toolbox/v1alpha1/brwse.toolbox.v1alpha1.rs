@@ -29,6 +29,54 @@ pub struct Tool {
     /// List of tags for categorization.
     #[prost(string, repeated, tag="8")]
     pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// State of the tool's circuit breaker, if one watches it.
+    #[prost(enumeration="tool::CircuitState", tag="9")]
+    pub circuit_state: i32,
+    /// Whether calling the tool again with the same input has no further
+    /// effect. Failed calls to idempotent tools are retried by the runtime.
+    #[prost(bool, tag="10")]
+    pub idempotent: bool,
+}
+/// Nested message and enum types in `Tool`.
+pub mod tool {
+    /// State of a tool's circuit breaker.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum CircuitState {
+        /// No circuit breaker watches the tool.
+        Unspecified = 0,
+        /// Calls run normally.
+        Closed = 1,
+        /// Calls fail with UNAVAILABLE without running the tool.
+        Open = 2,
+        /// A limited number of probe calls run to test whether the tool
+        /// recovered.
+        HalfOpen = 3,
+    }
+    impl CircuitState {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "CIRCUIT_STATE_UNSPECIFIED",
+                Self::Closed => "CLOSED",
+                Self::Open => "OPEN",
+                Self::HalfOpen => "HALF_OPEN",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "CIRCUIT_STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "CLOSED" => Some(Self::Closed),
+                "OPEN" => Some(Self::Open),
+                "HALF_OPEN" => Some(Self::HalfOpen),
+                _ => None,
+            }
+        }
+    }
 }
 /// Standard List request
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -115,12 +163,64 @@ pub struct CallToolRequest {
 /// Custom CallTool response
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CallToolResponse {
+    /// Policy effects evaluated for the call, in order. Only returned when the
+    /// request sets the x-policy-trace: true header.
+    #[prost(message, repeated, tag="4")]
+    pub policy_trace: ::prost::alloc::vec::Vec<PolicyTraceStep>,
+    /// Budgets the call counted against, after its cost was charged.
+    #[prost(message, repeated, tag="5")]
+    pub budgets: ::prost::alloc::vec::Vec<BudgetStatus>,
+    /// Times the tool was invoked for the call; more than 1 when failed attempts
+    /// of an idempotent tool were retried, and 0 when it did not run.
+    #[prost(uint32, tag="6")]
+    pub attempts: u32,
+    /// How the result cache served the call.
+    #[prost(enumeration="call_tool_response::CacheStatus", tag="7")]
+    pub cache: i32,
     /// Result of the tool invocation.
-    #[prost(oneof="call_tool_response::Result", tags="1, 2")]
+    #[prost(oneof="call_tool_response::Result", tags="1, 2, 3")]
     pub result: ::core::option::Option<call_tool_response::Result>,
 }
 /// Nested message and enum types in `CallToolResponse`.
 pub mod call_tool_response {
+    /// How the result cache served a call.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum CacheStatus {
+        /// The runtime has no result cache, or the tool's results are not cached.
+        Unspecified = 0,
+        /// The output was reused from an earlier call without running the tool.
+        Hit = 1,
+        /// No reusable output was cached, so the tool ran.
+        Miss = 2,
+        /// The request skipped the cache with the x-cache-mode: bypass header, so
+        /// the tool ran and its output replaced any cached one.
+        Bypass = 3,
+    }
+    impl CacheStatus {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "CACHE_STATUS_UNSPECIFIED",
+                Self::Hit => "HIT",
+                Self::Miss => "MISS",
+                Self::Bypass => "BYPASS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "CACHE_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "HIT" => Some(Self::Hit),
+                "MISS" => Some(Self::Miss),
+                "BYPASS" => Some(Self::Bypass),
+                _ => None,
+            }
+        }
+    }
     /// Result of the tool invocation.
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -130,363 +230,1843 @@ pub mod call_tool_response {
         /// Error message if the invocation failed.
         #[prost(string, tag="2")]
         Error(::prost::alloc::string::String),
+        /// The call is held until a person approves it. Only returned when the
+        /// request sets the x-approval-mode: defer header; retry the call with the
+        /// same session and input once the approval is granted.
+        #[prost(message, tag="3")]
+        PendingApproval(super::Approval),
+    }
+}
+/// Error details of a PERMISSION_DENIED CallTool status when a policy blocked
+/// the call, packed as an Any into the google.rpc.Status status details.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolicyDenial {
+    /// Name of the policy.
+    #[prost(string, tag="1")]
+    pub policy: ::prost::alloc::string::String,
+    /// Version of the policy.
+    #[prost(string, tag="2")]
+    pub policy_version: ::prost::alloc::string::String,
+    /// Position of the effect in the policy's effects, starting at 0.
+    #[prost(uint32, tag="3")]
+    pub effect_index: u32,
+    /// Tool pattern of the effect.
+    #[prost(string, tag="4")]
+    pub tool_pattern: ::prost::alloc::string::String,
+    /// The effect's condition expression.
+    #[prost(string, tag="5")]
+    pub condition: ::prost::alloc::string::String,
+    /// Whether the condition held: false for a failed guard, true for a deny
+    /// effect.
+    #[prost(bool, tag="6")]
+    pub condition_met: bool,
+    /// Policy effects evaluated before the denial, in order. Only set when the
+    /// request sets the x-policy-trace: true header.
+    #[prost(message, repeated, tag="7")]
+    pub trace: ::prost::alloc::vec::Vec<PolicyTraceStep>,
+}
+/// Error details of a RESOURCE_EXHAUSTED CallTool status when a cost budget
+/// blocked the call, packed as an Any into the google.rpc.Status status
+/// details.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetExceeded {
+    /// The exceeded budget, before the call.
+    #[prost(message, optional, tag="1")]
+    pub budget: ::core::option::Option<BudgetStatus>,
+    /// Static cost of the tool, or 0 if it declares none.
+    #[prost(double, tag="2")]
+    pub cost: f64,
+}
+/// Error details of a RESOURCE_EXHAUSTED CallTool status when a rate limit
+/// rejected the call, packed as an Any into the google.rpc.Status status
+/// details. The status also carries a retry-after header with the seconds to
+/// wait, rounded up.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RateLimited {
+    /// Name of the exceeded rate limit.
+    #[prost(string, tag="1")]
+    pub rate_limit: ::prost::alloc::string::String,
+    /// The empty bucket, such as "session=s1,tool=mail.send", or empty for a
+    /// bucket shared by all calls.
+    #[prost(string, tag="2")]
+    pub key: ::prost::alloc::string::String,
+    /// Seconds until the call would be allowed.
+    #[prost(double, tag="3")]
+    pub retry_after_seconds: f64,
+}
+/// How much of a cost budget has been spent.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetStatus {
+    /// Name of the budget.
+    #[prost(string, tag="1")]
+    pub budget: ::prost::alloc::string::String,
+    /// Scope the spend is shared in: "session", "caller" or "global".
+    #[prost(string, tag="2")]
+    pub scope: ::prost::alloc::string::String,
+    /// Maximum total cost.
+    #[prost(double, tag="3")]
+    pub limit: f64,
+    /// Cost spent in the current period.
+    #[prost(double, tag="4")]
+    pub spent: f64,
+    /// Cost left in the current period, never negative.
+    #[prost(double, tag="5")]
+    pub remaining: f64,
+}
+/// One policy effect evaluated for a call.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PolicyTraceStep {
+    /// Name of the policy.
+    #[prost(string, tag="1")]
+    pub policy: ::prost::alloc::string::String,
+    /// Version of the policy.
+    #[prost(string, tag="2")]
+    pub policy_version: ::prost::alloc::string::String,
+    /// Position of the effect in the policy's effects, starting at 0.
+    #[prost(uint32, tag="3")]
+    pub effect_index: u32,
+    /// Stage the effect was evaluated in: "before" or "after".
+    #[prost(string, tag="4")]
+    pub stage: ::prost::alloc::string::String,
+    /// Tool pattern of the effect.
+    #[prost(string, tag="5")]
+    pub tool_pattern: ::prost::alloc::string::String,
+    /// The effect's condition expression.
+    #[prost(string, tag="6")]
+    pub condition: ::prost::alloc::string::String,
+    /// Whether the condition held.
+    #[prost(bool, tag="7")]
+    pub condition_met: bool,
+    /// What the effect did.
+    #[prost(enumeration="policy_trace_step::Outcome", tag="8")]
+    pub outcome: i32,
+}
+/// Nested message and enum types in `PolicyTraceStep`.
+pub mod policy_trace_step {
+    /// What the effect did.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Outcome {
+        /// Unspecified outcome.
+        Unspecified = 0,
+        /// The condition did not hold and the effect did nothing.
+        Skipped = 1,
+        /// The condition held and the effect made no decision: a guard passed,
+        /// and any updates, rewrites or transforms were applied.
+        Applied = 2,
+        /// The effect allowed the call.
+        Allowed = 3,
+        /// The effect required approval for the call.
+        ApprovalRequired = 4,
+        /// The effect denied the call.
+        Denied = 5,
+        /// The condition did not hold and the guard blocked the call.
+        GuardFailed = 6,
+        /// The condition did not hold and the effect replaced the output with
+        /// its fail message.
+        OutputReplaced = 7,
+    }
+    impl Outcome {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "OUTCOME_UNSPECIFIED",
+                Self::Skipped => "SKIPPED",
+                Self::Applied => "APPLIED",
+                Self::Allowed => "ALLOWED",
+                Self::ApprovalRequired => "APPROVAL_REQUIRED",
+                Self::Denied => "DENIED",
+                Self::GuardFailed => "GUARD_FAILED",
+                Self::OutputReplaced => "OUTPUT_REPLACED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "OUTCOME_UNSPECIFIED" => Some(Self::Unspecified),
+                "SKIPPED" => Some(Self::Skipped),
+                "APPLIED" => Some(Self::Applied),
+                "ALLOWED" => Some(Self::Allowed),
+                "APPROVAL_REQUIRED" => Some(Self::ApprovalRequired),
+                "DENIED" => Some(Self::Denied),
+                "GUARD_FAILED" => Some(Self::GuardFailed),
+                "OUTPUT_REPLACED" => Some(Self::OutputReplaced),
+                _ => None,
+            }
+        }
+    }
+}
+/// Resource: Approval
+/// A tool call held by a require_approval policy effect until a person
+/// approves or denies it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Approval {
+    /// Resource name: "approvals/{approval_id}"
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// Resource name of the tool being called: "tools/{tool_id}"
+    #[prost(string, tag="2")]
+    pub tool: ::prost::alloc::string::String,
+    /// Tool input the approval applies to, after policy rewrites.
+    #[prost(message, optional, tag="3")]
+    pub input: ::core::option::Option<::prost_types::Struct>,
+    /// Session the call was made in.
+    #[prost(string, tag="4")]
+    pub session_id: ::prost::alloc::string::String,
+    /// Why the policy requires approval.
+    #[prost(string, tag="5")]
+    pub reason: ::prost::alloc::string::String,
+    /// Current state.
+    #[prost(enumeration="approval::State", tag="6")]
+    pub state: i32,
+    /// Creation time, in milliseconds since the Unix epoch.
+    #[prost(int64, tag="7")]
+    pub create_time_ms: i64,
+    /// Time the approval expires if still pending, in milliseconds since the
+    /// Unix epoch.
+    #[prost(int64, tag="8")]
+    pub expire_time_ms: i64,
+    /// Reason given by the person who denied the call.
+    #[prost(string, tag="9")]
+    pub deny_reason: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `Approval`.
+pub mod approval {
+    /// Lifecycle state of an approval.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum State {
+        /// Unspecified state.
+        Unspecified = 0,
+        /// Waiting for a decision.
+        Pending = 1,
+        /// Approved; the held call may run once.
+        Approved = 2,
+        /// Denied; the held call fails with permission denied.
+        Denied = 3,
+        /// No decision was made before the approval timed out.
+        Expired = 4,
     }
+    impl State {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "STATE_UNSPECIFIED",
+                Self::Pending => "PENDING",
+                Self::Approved => "APPROVED",
+                Self::Denied => "DENIED",
+                Self::Expired => "EXPIRED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "PENDING" => Some(Self::Pending),
+                "APPROVED" => Some(Self::Approved),
+                "DENIED" => Some(Self::Denied),
+                "EXPIRED" => Some(Self::Expired),
+                _ => None,
+            }
+        }
+    }
+}
+/// Custom EndSession request
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EndSessionRequest {
+    /// Session identifier previously sent as the x-session-id header.
+    #[prost(string, tag="1")]
+    pub session_id: ::prost::alloc::string::String,
+}
+/// Custom EndSession response
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EndSessionResponse {
+}
+/// Custom ListApprovals request
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListApprovalsRequest {
+}
+/// Custom ListApprovals response
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListApprovalsResponse {
+    /// Pending approvals, oldest first.
+    #[prost(message, repeated, tag="1")]
+    pub approvals: ::prost::alloc::vec::Vec<Approval>,
+}
+/// Custom ApproveCall request
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApproveCallRequest {
+    /// Resource name: "approvals/{approval_id}"
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Custom DenyCall request
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DenyCallRequest {
+    /// Resource name: "approvals/{approval_id}"
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// Optional reason reported to the caller.
+    #[prost(string, tag="2")]
+    pub reason: ::prost::alloc::string::String,
+}
+/// What a shadow-mode policy would have done for one tool.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ShadowReportEntry {
+    /// Name of the shadow policy.
+    #[prost(string, tag="1")]
+    pub policy: ::prost::alloc::string::String,
+    /// Resource name of the tool: "tools/{tool_id}"
+    #[prost(string, tag="2")]
+    pub tool: ::prost::alloc::string::String,
+    /// Evaluations in which the policy had an effect matching the tool.
+    #[prost(uint64, tag="3")]
+    pub evaluations: u64,
+    /// Calls the policy would have denied.
+    #[prost(uint64, tag="4")]
+    pub denials: u64,
+    /// Calls the policy would have held for approval.
+    #[prost(uint64, tag="5")]
+    pub approvals: u64,
+    /// Evaluations that would have changed session context.
+    #[prost(uint64, tag="6")]
+    pub context_updates: u64,
+    /// Calls whose input the policy would have rewritten.
+    #[prost(uint64, tag="7")]
+    pub input_rewrites: u64,
+    /// Results the policy would have reshaped or replaced with an error.
+    #[prost(uint64, tag="8")]
+    pub output_changes: u64,
+    /// Evaluations that failed with a CEL or rewrite error.
+    #[prost(uint64, tag="9")]
+    pub errors: u64,
+}
+/// Custom GetShadowReport request
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetShadowReportRequest {
+}
+/// Custom GetShadowReport response
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetShadowReportResponse {
+    /// Entries ordered by policy, then tool.
+    #[prost(message, repeated, tag="1")]
+    pub entries: ::prost::alloc::vec::Vec<ShadowReportEntry>,
+}
+/// Resource: Policy
+/// A policy registered with the server.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Policy {
+    /// Resource name: "policies/{policy_name}"
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// Version of the policy.
+    #[prost(string, tag="2")]
+    pub version: ::prost::alloc::string::String,
+    /// Evaluation priority; higher-priority policies are evaluated first.
+    #[prost(int32, tag="3")]
+    pub priority: i32,
+    /// Whether the policy is enforced or only observed: "enforce" or "shadow".
+    #[prost(string, tag="4")]
+    pub mode: ::prost::alloc::string::String,
+    /// The full policy as a JSON object, with the fields of a policy file.
+    #[prost(string, tag="5")]
+    pub definition: ::prost::alloc::string::String,
+}
+/// Standard List request
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListPoliciesRequest {
+}
+/// Standard List response
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPoliciesResponse {
+    /// Registered policies, in evaluation order.
+    #[prost(message, repeated, tag="1")]
+    pub policies: ::prost::alloc::vec::Vec<Policy>,
+}
+/// Standard Get request
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetPolicyRequest {
+    /// Resource name: "policies/{policy_name}"
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Custom Upsert request
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UpsertPolicyRequest {
+    /// The policy as a JSON object, with the fields of a policy file. A policy
+    /// with the same name is replaced.
+    #[prost(string, tag="1")]
+    pub definition: ::prost::alloc::string::String,
+}
+/// Standard Delete request
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeletePolicyRequest {
+    /// Resource name: "policies/{policy_name}"
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Standard Delete response
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeletePolicyResponse {
+}
+/// Custom GetSessionContext request
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetSessionContextRequest {
+    /// Session identifier previously sent as the x-session-id header.
+    #[prost(string, tag="1")]
+    pub session_id: ::prost::alloc::string::String,
+}
+/// Custom GetSessionContext response
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionContextResponse {
+    /// The session's policy context variables. Empty for an unknown session.
+    #[prost(message, optional, tag="1")]
+    pub context: ::core::option::Option<::prost_types::Struct>,
+}
+/// Custom ResetSession request
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ResetSessionRequest {
+    /// Session identifier previously sent as the x-session-id header.
+    #[prost(string, tag="1")]
+    pub session_id: ::prost::alloc::string::String,
+}
+/// Custom ResetSession response
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ResetSessionResponse {
 }
 /// Encoded file descriptor set for the `brwse.toolbox.v1alpha1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xd4, 0x2f, 0x0a, 0x24, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2f, 0x74, 0x6f, 0x6f, 0x6c, 0x62,
-    0x6f, 0x78, 0x2f, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2f, 0x74, 0x6f, 0x6f, 0x6c,
-    0x62, 0x6f, 0x78, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x16, 0x62, 0x72, 0x77, 0x73, 0x65,
-    0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61,
-    0x31, 0x1a, 0x1c, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62,
-    0x75, 0x66, 0x2f, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22,
-    0xab, 0x02, 0x0a, 0x04, 0x54, 0x6f, 0x6f, 0x6c, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x21, 0x0a, 0x0c,
-    0x64, 0x69, 0x73, 0x70, 0x6c, 0x61, 0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x0b, 0x64, 0x69, 0x73, 0x70, 0x6c, 0x61, 0x79, 0x4e, 0x61, 0x6d, 0x65, 0x12,
-    0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73,
-    0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b,
-    0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x3a, 0x0a, 0x0c, 0x69,
-    0x6e, 0x70, 0x75, 0x74, 0x5f, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x18, 0x05, 0x20, 0x01, 0x28,
-    0x0b, 0x32, 0x17, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-    0x62, 0x75, 0x66, 0x2e, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x52, 0x0b, 0x69, 0x6e, 0x70, 0x75,
-    0x74, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x3c, 0x0a, 0x0d, 0x6f, 0x75, 0x74, 0x70, 0x75,
-    0x74, 0x5f, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17,
-    0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66,
-    0x2e, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x52, 0x0c, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x53,
-    0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x22, 0x0a, 0x0c, 0x63, 0x61, 0x70, 0x61, 0x62, 0x69, 0x6c,
-    0x69, 0x74, 0x69, 0x65, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0c, 0x63, 0x61, 0x70,
-    0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x61, 0x67,
-    0x73, 0x18, 0x08, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x74, 0x61, 0x67, 0x73, 0x22, 0x4e, 0x0a,
-    0x10, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-    0x74, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x05, 0x52, 0x08, 0x70, 0x61, 0x67, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x1d,
-    0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x6f, 0x0a,
-    0x11, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-    0x73, 0x65, 0x12, 0x32, 0x0a, 0x05, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
-    0x0b, 0x32, 0x1c, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f,
-    0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x54, 0x6f, 0x6f, 0x6c, 0x52,
-    0x05, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x12, 0x26, 0x0a, 0x0f, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70,
-    0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
-    0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x98,
-    0x01, 0x0a, 0x12, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x27, 0x0a, 0x0f, 0x71, 0x75, 0x65, 0x72, 0x79, 0x5f, 0x65,
-    0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x18, 0x01, 0x20, 0x03, 0x28, 0x02, 0x52, 0x0e,
-    0x71, 0x75, 0x65, 0x72, 0x79, 0x45, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x12, 0x1d,
-    0x0a, 0x0a, 0x71, 0x75, 0x65, 0x72, 0x79, 0x5f, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x09, 0x71, 0x75, 0x65, 0x72, 0x79, 0x54, 0x65, 0x78, 0x74, 0x12, 0x1b, 0x0a,
-    0x09, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x05,
-    0x52, 0x08, 0x70, 0x61, 0x67, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61,
-    0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09,
-    0x70, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x69, 0x0a, 0x0c, 0x53, 0x65, 0x61,
-    0x72, 0x63, 0x68, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x30, 0x0a, 0x04, 0x74, 0x6f, 0x6f,
-    0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e,
-    0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31,
-    0x2e, 0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x04, 0x74, 0x6f, 0x6f, 0x6c, 0x12, 0x27, 0x0a, 0x0f, 0x72,
-    0x65, 0x6c, 0x65, 0x76, 0x61, 0x6e, 0x63, 0x65, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x18, 0x02,
-    0x20, 0x01, 0x28, 0x02, 0x52, 0x0e, 0x72, 0x65, 0x6c, 0x65, 0x76, 0x61, 0x6e, 0x63, 0x65, 0x53,
-    0x63, 0x6f, 0x72, 0x65, 0x22, 0x7d, 0x0a, 0x13, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x54, 0x6f,
-    0x6f, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3e, 0x0a, 0x07, 0x72,
-    0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x62,
-    0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61,
-    0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x52, 0x65, 0x73, 0x75,
-    0x6c, 0x74, 0x52, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x12, 0x26, 0x0a, 0x0f, 0x6e,
+    0x0a, 0x85, 0xca, 0x01, 0x0a, 0x24, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2f, 0x74, 0x6f, 0x6f, 0x6c,
+    0x62, 0x6f, 0x78, 0x2f, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2f, 0x74, 0x6f, 0x6f,
+    0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x16, 0x62, 0x72, 0x77, 0x73,
+    0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68,
+    0x61, 0x31, 0x1a, 0x1c, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x62, 0x75, 0x66, 0x2f, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x22, 0xef, 0x03, 0x0a, 0x04, 0x54, 0x6f, 0x6f, 0x6c, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d,
+    0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x21, 0x0a,
+    0x0c, 0x64, 0x69, 0x73, 0x70, 0x6c, 0x61, 0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x0b, 0x64, 0x69, 0x73, 0x70, 0x6c, 0x61, 0x79, 0x4e, 0x61, 0x6d, 0x65,
+    0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65,
+    0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x3a, 0x0a, 0x0c,
+    0x69, 0x6e, 0x70, 0x75, 0x74, 0x5f, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x62, 0x75, 0x66, 0x2e, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x52, 0x0b, 0x69, 0x6e, 0x70,
+    0x75, 0x74, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x3c, 0x0a, 0x0d, 0x6f, 0x75, 0x74, 0x70,
+    0x75, 0x74, 0x5f, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x17, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75,
+    0x66, 0x2e, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x52, 0x0c, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74,
+    0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x22, 0x0a, 0x0c, 0x63, 0x61, 0x70, 0x61, 0x62, 0x69,
+    0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0c, 0x63, 0x61,
+    0x70, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x61,
+    0x67, 0x73, 0x18, 0x08, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x74, 0x61, 0x67, 0x73, 0x12, 0x4e,
+    0x0a, 0x0d, 0x63, 0x69, 0x72, 0x63, 0x75, 0x69, 0x74, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18,
+    0x09, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x29, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f,
+    0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x54,
+    0x6f, 0x6f, 0x6c, 0x2e, 0x43, 0x69, 0x72, 0x63, 0x75, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x65,
+    0x52, 0x0c, 0x63, 0x69, 0x72, 0x63, 0x75, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x1e,
+    0x0a, 0x0a, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x0a, 0x20, 0x01,
+    0x28, 0x08, 0x52, 0x0a, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x74, 0x22, 0x52,
+    0x0a, 0x0c, 0x43, 0x69, 0x72, 0x63, 0x75, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x1d,
+    0x0a, 0x19, 0x43, 0x49, 0x52, 0x43, 0x55, 0x49, 0x54, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x45, 0x5f,
+    0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x0a, 0x0a,
+    0x06, 0x43, 0x4c, 0x4f, 0x53, 0x45, 0x44, 0x10, 0x01, 0x12, 0x08, 0x0a, 0x04, 0x4f, 0x50, 0x45,
+    0x4e, 0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09, 0x48, 0x41, 0x4c, 0x46, 0x5f, 0x4f, 0x50, 0x45, 0x4e,
+    0x10, 0x03, 0x22, 0x4e, 0x0a, 0x10, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73,
+    0x69, 0x7a, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x52, 0x08, 0x70, 0x61, 0x67, 0x65, 0x53,
+    0x69, 0x7a, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x22, 0x6f, 0x0a, 0x11, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x32, 0x0a, 0x05, 0x74, 0x6f, 0x6f, 0x6c, 0x73,
+    0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
+    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
+    0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x05, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x12, 0x26, 0x0a, 0x0f, 0x6e,
     0x65, 0x78, 0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02,
     0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50, 0x61, 0x67, 0x65, 0x54, 0x6f,
-    0x6b, 0x65, 0x6e, 0x22, 0x97, 0x01, 0x0a, 0x0e, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69,
-    0x61, 0x6c, 0x44, 0x61, 0x74, 0x61, 0x12, 0x4a, 0x0a, 0x06, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73,
-    0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x32, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
-    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
-    0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x44, 0x61, 0x74, 0x61, 0x2e, 0x56,
-    0x61, 0x6c, 0x75, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x06, 0x76, 0x61, 0x6c, 0x75,
-    0x65, 0x73, 0x1a, 0x39, 0x0a, 0x0b, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72,
-    0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03,
-    0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x54, 0x0a,
-    0x0f, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04,
-    0x6e, 0x61, 0x6d, 0x65, 0x12, 0x2d, 0x0a, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x18, 0x02, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f,
-    0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x52, 0x05, 0x69, 0x6e,
-    0x70, 0x75, 0x74, 0x22, 0x67, 0x0a, 0x10, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x31, 0x0a, 0x06, 0x6f, 0x75, 0x74, 0x70, 0x75,
-    0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65,
-    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74,
-    0x48, 0x00, 0x52, 0x06, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x12, 0x16, 0x0a, 0x05, 0x65, 0x72,
-    0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
-    0x6f, 0x72, 0x42, 0x08, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x32, 0xb2, 0x02, 0x0a,
-    0x07, 0x54, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x12, 0x60, 0x0a, 0x09, 0x4c, 0x69, 0x73, 0x74,
-    0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x12, 0x28, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f,
-    0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x4c,
-    0x69, 0x73, 0x74, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
-    0x29, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e,
-    0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6f,
-    0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x66, 0x0a, 0x0b, 0x53, 0x65,
-    0x61, 0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x12, 0x2a, 0x2e, 0x62, 0x72, 0x77, 0x73,
-    0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68,
-    0x61, 0x31, 0x2e, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2b, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f,
-    0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x53,
-    0x65, 0x61, 0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-    0x73, 0x65, 0x12, 0x5d, 0x0a, 0x08, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x12, 0x27,
+    0x6b, 0x65, 0x6e, 0x22, 0x98, 0x01, 0x0a, 0x12, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x54, 0x6f,
+    0x6f, 0x6c, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x27, 0x0a, 0x0f, 0x71, 0x75,
+    0x65, 0x72, 0x79, 0x5f, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x18, 0x01, 0x20,
+    0x03, 0x28, 0x02, 0x52, 0x0e, 0x71, 0x75, 0x65, 0x72, 0x79, 0x45, 0x6d, 0x62, 0x65, 0x64, 0x64,
+    0x69, 0x6e, 0x67, 0x12, 0x1d, 0x0a, 0x0a, 0x71, 0x75, 0x65, 0x72, 0x79, 0x5f, 0x74, 0x65, 0x78,
+    0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x71, 0x75, 0x65, 0x72, 0x79, 0x54, 0x65,
+    0x78, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x05, 0x52, 0x08, 0x70, 0x61, 0x67, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12,
+    0x1d, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x09, 0x70, 0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x69,
+    0x0a, 0x0c, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x30,
+    0x0a, 0x04, 0x74, 0x6f, 0x6f, 0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x62,
+    0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61,
+    0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x04, 0x74, 0x6f, 0x6f, 0x6c,
+    0x12, 0x27, 0x0a, 0x0f, 0x72, 0x65, 0x6c, 0x65, 0x76, 0x61, 0x6e, 0x63, 0x65, 0x5f, 0x73, 0x63,
+    0x6f, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x02, 0x52, 0x0e, 0x72, 0x65, 0x6c, 0x65, 0x76,
+    0x61, 0x6e, 0x63, 0x65, 0x53, 0x63, 0x6f, 0x72, 0x65, 0x22, 0x7d, 0x0a, 0x13, 0x53, 0x65, 0x61,
+    0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x3e, 0x0a, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x24, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f,
+    0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x53, 0x65, 0x61, 0x72, 0x63,
+    0x68, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x52, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73,
+    0x12, 0x26, 0x0a, 0x0f, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x70, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x6e, 0x65, 0x78, 0x74, 0x50,
+    0x61, 0x67, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x97, 0x01, 0x0a, 0x0e, 0x43, 0x72, 0x65,
+    0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x44, 0x61, 0x74, 0x61, 0x12, 0x4a, 0x0a, 0x06, 0x76,
+    0x61, 0x6c, 0x75, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x32, 0x2e, 0x62, 0x72,
+    0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c,
+    0x70, 0x68, 0x61, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x44,
+    0x61, 0x74, 0x61, 0x2e, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52,
+    0x06, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x1a, 0x39, 0x0a, 0x0b, 0x56, 0x61, 0x6c, 0x75, 0x65,
+    0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75,
+    0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02,
+    0x38, 0x01, 0x22, 0x54, 0x0a, 0x0f, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x2d, 0x0a, 0x05, 0x69, 0x6e, 0x70,
+    0x75, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c,
+    0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x53, 0x74, 0x72, 0x75, 0x63,
+    0x74, 0x52, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x22, 0xf6, 0x03, 0x0a, 0x10, 0x43, 0x61, 0x6c,
+    0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x31, 0x0a,
+    0x06, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e,
+    0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e,
+    0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x48, 0x00, 0x52, 0x06, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74,
+    0x12, 0x16, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48,
+    0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x4d, 0x0a, 0x10, 0x70, 0x65, 0x6e, 0x64,
+    0x69, 0x6e, 0x67, 0x5f, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x20, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62,
+    0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x41, 0x70, 0x70, 0x72,
+    0x6f, 0x76, 0x61, 0x6c, 0x48, 0x00, 0x52, 0x0f, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x41,
+    0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x12, 0x4a, 0x0a, 0x0c, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+    0x79, 0x5f, 0x74, 0x72, 0x61, 0x63, 0x65, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27, 0x2e,
+    0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31,
+    0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x54, 0x72, 0x61,
+    0x63, 0x65, 0x53, 0x74, 0x65, 0x70, 0x52, 0x0b, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x54, 0x72,
+    0x61, 0x63, 0x65, 0x12, 0x3e, 0x0a, 0x07, 0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x73, 0x18, 0x05,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f,
+    0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x42, 0x75,
+    0x64, 0x67, 0x65, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x07, 0x62, 0x75, 0x64, 0x67,
+    0x65, 0x74, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x18,
+    0x06, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x12,
+    0x4a, 0x0a, 0x05, 0x63, 0x61, 0x63, 0x68, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x34,
     0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76,
     0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c,
-    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x28, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x43, 0x61, 0x63, 0x68, 0x65, 0x53, 0x74,
+    0x61, 0x74, 0x75, 0x73, 0x52, 0x05, 0x63, 0x61, 0x63, 0x68, 0x65, 0x22, 0x4a, 0x0a, 0x0b, 0x43,
+    0x61, 0x63, 0x68, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x41,
+    0x43, 0x48, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45,
+    0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x07, 0x0a, 0x03, 0x48, 0x49, 0x54, 0x10,
+    0x01, 0x12, 0x08, 0x0a, 0x04, 0x4d, 0x49, 0x53, 0x53, 0x10, 0x02, 0x12, 0x0a, 0x0a, 0x06, 0x42,
+    0x59, 0x50, 0x41, 0x53, 0x53, 0x10, 0x03, 0x42, 0x08, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c,
+    0x74, 0x22, 0x95, 0x02, 0x0a, 0x0c, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x44, 0x65, 0x6e, 0x69,
+    0x61, 0x6c, 0x12, 0x16, 0x0a, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x25, 0x0a, 0x0e, 0x70, 0x6f,
+    0x6c, 0x69, 0x63, 0x79, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x0d, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x12, 0x21, 0x0a, 0x0c, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65,
+    0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0b, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x49,
+    0x6e, 0x64, 0x65, 0x78, 0x12, 0x21, 0x0a, 0x0c, 0x74, 0x6f, 0x6f, 0x6c, 0x5f, 0x70, 0x61, 0x74,
+    0x74, 0x65, 0x72, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x74, 0x6f, 0x6f, 0x6c,
+    0x50, 0x61, 0x74, 0x74, 0x65, 0x72, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x63, 0x6f, 0x6e, 0x64, 0x69,
+    0x74, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x63, 0x6f, 0x6e, 0x64,
+    0x69, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x23, 0x0a, 0x0d, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69,
+    0x6f, 0x6e, 0x5f, 0x6d, 0x65, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0c, 0x63, 0x6f,
+    0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x12, 0x3d, 0x0a, 0x05, 0x74, 0x72,
+    0x61, 0x63, 0x65, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x62, 0x72, 0x77, 0x73,
+    0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68,
+    0x61, 0x31, 0x2e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x54, 0x72, 0x61, 0x63, 0x65, 0x53, 0x74,
+    0x65, 0x70, 0x52, 0x05, 0x74, 0x72, 0x61, 0x63, 0x65, 0x22, 0x62, 0x0a, 0x0e, 0x42, 0x75, 0x64,
+    0x67, 0x65, 0x74, 0x45, 0x78, 0x63, 0x65, 0x65, 0x64, 0x65, 0x64, 0x12, 0x3c, 0x0a, 0x06, 0x62,
+    0x75, 0x64, 0x67, 0x65, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x62, 0x72,
+    0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c,
+    0x70, 0x68, 0x61, 0x31, 0x2e, 0x42, 0x75, 0x64, 0x67, 0x65, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75,
+    0x73, 0x52, 0x06, 0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x73,
+    0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x01, 0x52, 0x04, 0x63, 0x6f, 0x73, 0x74, 0x22, 0x6e, 0x0a,
+    0x0b, 0x52, 0x61, 0x74, 0x65, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x65, 0x64, 0x12, 0x1d, 0x0a, 0x0a,
+    0x72, 0x61, 0x74, 0x65, 0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x09, 0x72, 0x61, 0x74, 0x65, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x6b,
+    0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x2e, 0x0a,
+    0x13, 0x72, 0x65, 0x74, 0x72, 0x79, 0x5f, 0x61, 0x66, 0x74, 0x65, 0x72, 0x5f, 0x73, 0x65, 0x63,
+    0x6f, 0x6e, 0x64, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x01, 0x52, 0x11, 0x72, 0x65, 0x74, 0x72,
+    0x79, 0x41, 0x66, 0x74, 0x65, 0x72, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x22, 0x86, 0x01,
+    0x0a, 0x0c, 0x42, 0x75, 0x64, 0x67, 0x65, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x16,
+    0x0a, 0x06, 0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06,
+    0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x12, 0x14, 0x0a, 0x05,
+    0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x01, 0x52, 0x05, 0x6c, 0x69, 0x6d,
+    0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x70, 0x65, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x01, 0x52, 0x05, 0x73, 0x70, 0x65, 0x6e, 0x74, 0x12, 0x1c, 0x0a, 0x09, 0x72, 0x65, 0x6d, 0x61,
+    0x69, 0x6e, 0x69, 0x6e, 0x67, 0x18, 0x05, 0x20, 0x01, 0x28, 0x01, 0x52, 0x09, 0x72, 0x65, 0x6d,
+    0x61, 0x69, 0x6e, 0x69, 0x6e, 0x67, 0x22, 0xd0, 0x03, 0x0a, 0x0f, 0x50, 0x6f, 0x6c, 0x69, 0x63,
+    0x79, 0x54, 0x72, 0x61, 0x63, 0x65, 0x53, 0x74, 0x65, 0x70, 0x12, 0x16, 0x0a, 0x06, 0x70, 0x6f,
+    0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x70, 0x6f, 0x6c, 0x69,
+    0x63, 0x79, 0x12, 0x25, 0x0a, 0x0e, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x5f, 0x76, 0x65, 0x72,
+    0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x70, 0x6f, 0x6c, 0x69,
+    0x63, 0x79, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x21, 0x0a, 0x0c, 0x65, 0x66, 0x66,
+    0x65, 0x63, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52,
+    0x0b, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x14, 0x0a, 0x05,
+    0x73, 0x74, 0x61, 0x67, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x73, 0x74, 0x61,
+    0x67, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x74, 0x6f, 0x6f, 0x6c, 0x5f, 0x70, 0x61, 0x74, 0x74, 0x65,
+    0x72, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x74, 0x6f, 0x6f, 0x6c, 0x50, 0x61,
+    0x74, 0x74, 0x65, 0x72, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69,
+    0x6f, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x12, 0x23, 0x0a, 0x0d, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+    0x5f, 0x6d, 0x65, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0c, 0x63, 0x6f, 0x6e, 0x64,
+    0x69, 0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x12, 0x49, 0x0a, 0x07, 0x6f, 0x75, 0x74, 0x63,
+    0x6f, 0x6d, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2f, 0x2e, 0x62, 0x72, 0x77, 0x73,
+    0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68,
+    0x61, 0x31, 0x2e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x54, 0x72, 0x61, 0x63, 0x65, 0x53, 0x74,
+    0x65, 0x70, 0x2e, 0x4f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x52, 0x07, 0x6f, 0x75, 0x74, 0x63,
+    0x6f, 0x6d, 0x65, 0x22, 0x93, 0x01, 0x0a, 0x07, 0x4f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x12,
+    0x17, 0x0a, 0x13, 0x4f, 0x55, 0x54, 0x43, 0x4f, 0x4d, 0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45,
+    0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x4b, 0x49, 0x50,
+    0x50, 0x45, 0x44, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x41, 0x50, 0x50, 0x4c, 0x49, 0x45, 0x44,
+    0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x41, 0x4c, 0x4c, 0x4f, 0x57, 0x45, 0x44, 0x10, 0x03, 0x12,
+    0x15, 0x0a, 0x11, 0x41, 0x50, 0x50, 0x52, 0x4f, 0x56, 0x41, 0x4c, 0x5f, 0x52, 0x45, 0x51, 0x55,
+    0x49, 0x52, 0x45, 0x44, 0x10, 0x04, 0x12, 0x0a, 0x0a, 0x06, 0x44, 0x45, 0x4e, 0x49, 0x45, 0x44,
+    0x10, 0x05, 0x12, 0x10, 0x0a, 0x0c, 0x47, 0x55, 0x41, 0x52, 0x44, 0x5f, 0x46, 0x41, 0x49, 0x4c,
+    0x45, 0x44, 0x10, 0x06, 0x12, 0x13, 0x0a, 0x0f, 0x4f, 0x55, 0x54, 0x50, 0x55, 0x54, 0x5f, 0x52,
+    0x45, 0x50, 0x4c, 0x41, 0x43, 0x45, 0x44, 0x10, 0x07, 0x22, 0x97, 0x03, 0x0a, 0x08, 0x41, 0x70,
+    0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x6f,
+    0x6f, 0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x74, 0x6f, 0x6f, 0x6c, 0x12, 0x2d,
+    0x0a, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e,
+    0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e,
+    0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x52, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x12, 0x1d, 0x0a,
+    0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06,
+    0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x72, 0x65,
+    0x61, 0x73, 0x6f, 0x6e, 0x12, 0x3c, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x26, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c,
+    0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x41, 0x70, 0x70,
+    0x72, 0x6f, 0x76, 0x61, 0x6c, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x52, 0x05, 0x73, 0x74, 0x61,
+    0x74, 0x65, 0x12, 0x24, 0x0a, 0x0e, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x5f, 0x74, 0x69, 0x6d,
+    0x65, 0x5f, 0x6d, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0c, 0x63, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x54, 0x69, 0x6d, 0x65, 0x4d, 0x73, 0x12, 0x24, 0x0a, 0x0e, 0x65, 0x78, 0x70, 0x69,
+    0x72, 0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x5f, 0x6d, 0x73, 0x18, 0x08, 0x20, 0x01, 0x28, 0x03,
+    0x52, 0x0c, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x54, 0x69, 0x6d, 0x65, 0x4d, 0x73, 0x12, 0x1f,
+    0x0a, 0x0b, 0x64, 0x65, 0x6e, 0x79, 0x5f, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x09, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x0a, 0x64, 0x65, 0x6e, 0x79, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x22,
+    0x52, 0x0a, 0x05, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x15, 0x0a, 0x11, 0x53, 0x54, 0x41, 0x54,
+    0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12,
+    0x0b, 0x0a, 0x07, 0x50, 0x45, 0x4e, 0x44, 0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08,
+    0x41, 0x50, 0x50, 0x52, 0x4f, 0x56, 0x45, 0x44, 0x10, 0x02, 0x12, 0x0a, 0x0a, 0x06, 0x44, 0x45,
+    0x4e, 0x49, 0x45, 0x44, 0x10, 0x03, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x58, 0x50, 0x49, 0x52, 0x45,
+    0x44, 0x10, 0x04, 0x22, 0x32, 0x0a, 0x11, 0x45, 0x6e, 0x64, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0x14, 0x0a, 0x12, 0x45, 0x6e, 0x64, 0x53, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x16, 0x0a,
+    0x14, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x57, 0x0a, 0x15, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x70, 0x70,
+    0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3e,
+    0x0a, 0x09, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x20, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f,
+    0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x41, 0x70, 0x70, 0x72, 0x6f,
+    0x76, 0x61, 0x6c, 0x52, 0x09, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x22, 0x28,
+    0x0a, 0x12, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x22, 0x3d, 0x0a, 0x0f, 0x44, 0x65, 0x6e, 0x79,
+    0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e,
+    0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12,
+    0x16, 0x0a, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x22, 0xa8, 0x02, 0x0a, 0x11, 0x53, 0x68, 0x61, 0x64,
+    0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x16, 0x0a,
+    0x06, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x70,
+    0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x6f, 0x6f, 0x6c, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x04, 0x74, 0x6f, 0x6f, 0x6c, 0x12, 0x20, 0x0a, 0x0b, 0x65, 0x76, 0x61,
+    0x6c, 0x75, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b,
+    0x65, 0x76, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x64,
+    0x65, 0x6e, 0x69, 0x61, 0x6c, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x64, 0x65,
+    0x6e, 0x69, 0x61, 0x6c, 0x73, 0x12, 0x1c, 0x0a, 0x09, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61,
+    0x6c, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76,
+    0x61, 0x6c, 0x73, 0x12, 0x27, 0x0a, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x5f, 0x75,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0e, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x78, 0x74, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x73, 0x12, 0x25, 0x0a, 0x0e,
+    0x69, 0x6e, 0x70, 0x75, 0x74, 0x5f, 0x72, 0x65, 0x77, 0x72, 0x69, 0x74, 0x65, 0x73, 0x18, 0x07,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x0d, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x52, 0x65, 0x77, 0x72, 0x69,
+    0x74, 0x65, 0x73, 0x12, 0x25, 0x0a, 0x0e, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x5f, 0x63, 0x68,
+    0x61, 0x6e, 0x67, 0x65, 0x73, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0d, 0x6f, 0x75, 0x74,
+    0x70, 0x75, 0x74, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x65, 0x72,
+    0x72, 0x6f, 0x72, 0x73, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06, 0x65, 0x72, 0x72, 0x6f,
+    0x72, 0x73, 0x22, 0x18, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x53, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x52,
+    0x65, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x5e, 0x0a, 0x17,
+    0x47, 0x65, 0x74, 0x53, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x43, 0x0a, 0x07, 0x65, 0x6e, 0x74, 0x72, 0x69,
+    0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x29, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65,
+    0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61,
+    0x31, 0x2e, 0x53, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x45, 0x6e,
+    0x74, 0x72, 0x79, 0x52, 0x07, 0x65, 0x6e, 0x74, 0x72, 0x69, 0x65, 0x73, 0x22, 0x86, 0x01, 0x0a,
+    0x06, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74,
+    0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x05, 0x52, 0x08, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74,
+    0x79, 0x12, 0x12, 0x0a, 0x04, 0x6d, 0x6f, 0x64, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x04, 0x6d, 0x6f, 0x64, 0x65, 0x12, 0x1e, 0x0a, 0x0a, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x64, 0x65, 0x66, 0x69, 0x6e,
+    0x69, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x15, 0x0a, 0x13, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x6f, 0x6c,
+    0x69, 0x63, 0x69, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x52, 0x0a, 0x14,
+    0x4c, 0x69, 0x73, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3a, 0x0a, 0x08, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73,
+    0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
+    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
+    0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x08, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73,
+    0x22, 0x26, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x22, 0x35, 0x0a, 0x13, 0x55, 0x70, 0x73, 0x65,
+    0x72, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+    0x1e, 0x0a, 0x0a, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x0a, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x22,
+    0x29, 0x0a, 0x13, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x22, 0x16, 0x0a, 0x14, 0x44, 0x65,
+    0x6c, 0x65, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x22, 0x39, 0x0a, 0x18, 0x47, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
+    0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d,
+    0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0x4e, 0x0a,
+    0x19, 0x47, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65,
+    0x78, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x31, 0x0a, 0x07, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x6f,
+    0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x53, 0x74,
+    0x72, 0x75, 0x63, 0x74, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x34, 0x0a,
+    0x13, 0x52, 0x65, 0x73, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x49, 0x64, 0x22, 0x16, 0x0a, 0x14, 0x52, 0x65, 0x73, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x32, 0xb2, 0x02, 0x0a, 0x07,
+    0x54, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x12, 0x60, 0x0a, 0x09, 0x4c, 0x69, 0x73, 0x74, 0x54,
+    0x6f, 0x6f, 0x6c, 0x73, 0x12, 0x28, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f,
+    0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x4c, 0x69,
+    0x73, 0x74, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x29,
+    0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76,
+    0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6f, 0x6c,
+    0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x66, 0x0a, 0x0b, 0x53, 0x65, 0x61,
+    0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x12, 0x2a, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65,
+    0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61,
+    0x31, 0x2e, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x1a, 0x2b, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f,
+    0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x53, 0x65,
+    0x61, 0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x12, 0x5d, 0x0a, 0x08, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x12, 0x27, 0x2e,
+    0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31,
+    0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x28, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
+    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
+    0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x32, 0xf8, 0x08, 0x0a, 0x0c, 0x54, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x41, 0x64, 0x6d, 0x69,
+    0x6e, 0x12, 0x6c, 0x0a, 0x0d, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61,
+    0x6c, 0x73, 0x12, 0x2c, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62,
+    0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74,
+    0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x1a, 0x2d, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78,
+    0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x70,
+    0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x5b, 0x0a, 0x0b, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x2a,
+    0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76,
+    0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x43,
+    0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x62, 0x72, 0x77,
+    0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70,
+    0x68, 0x61, 0x31, 0x2e, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x12, 0x55, 0x0a, 0x08,
+    0x44, 0x65, 0x6e, 0x79, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x27, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65,
+    0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61,
+    0x31, 0x2e, 0x44, 0x65, 0x6e, 0x79, 0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x1a, 0x20, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f,
+    0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x41, 0x70, 0x70, 0x72, 0x6f,
+    0x76, 0x61, 0x6c, 0x12, 0x72, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x53, 0x68, 0x61, 0x64, 0x6f, 0x77,
+    0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x2e, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
+    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
+    0x47, 0x65, 0x74, 0x53, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
+    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
+    0x47, 0x65, 0x74, 0x53, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x69, 0x0a, 0x0c, 0x4c, 0x69, 0x73, 0x74, 0x50,
+    0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x12, 0x2b, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e,
     0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31,
-    0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-    0x65, 0x4a, 0xeb, 0x22, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x8e, 0x01, 0x01, 0x0a, 0x08, 0x0a,
-    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
-    0x1f, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x26, 0x0a, 0x51, 0x0a, 0x02,
-    0x04, 0x00, 0x12, 0x04, 0x08, 0x00, 0x21, 0x01, 0x1a, 0x45, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75,
-    0x72, 0x63, 0x65, 0x3a, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x0a, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65,
-    0x73, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72,
-    0x65, 0x64, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4f,
-    0x70, 0x65, 0x72, 0x61, 0x69, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x0a, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x08, 0x08, 0x0c, 0x0a, 0x79, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x12, 0x1a, 0x6c, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75,
-    0x72, 0x63, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x3a, 0x20, 0x22, 0x74, 0x6f, 0x6f, 0x6c, 0x73,
-    0x2f, 0x7b, 0x74, 0x6f, 0x6f, 0x6c, 0x5f, 0x69, 0x64, 0x7d, 0x22, 0x20, 0x77, 0x68, 0x65, 0x72,
-    0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x5f, 0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x22, 0x63, 0x72,
-    0x61, 0x74, 0x65, 0x2d, 0x6e, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x2d, 0x69, 0x64,
-    0x22, 0x0a, 0x20, 0x45, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x3a, 0x20, 0x22, 0x74, 0x6f, 0x6f,
-    0x6c, 0x73, 0x2f, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x2d, 0x77, 0x6f, 0x72, 0x6c, 0x64, 0x2e, 0x67,
-    0x72, 0x65, 0x65, 0x74, 0x22, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x0b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b,
-    0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0b, 0x10, 0x11,
-    0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x02, 0x1a, 0x1a, 0x32, 0x20,
-    0x48, 0x75, 0x6d, 0x61, 0x6e, 0x2d, 0x72, 0x65, 0x61, 0x64, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x64,
-    0x69, 0x73, 0x70, 0x6c, 0x61, 0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x28, 0x65, 0x2e, 0x67,
-    0x2e, 0x2c, 0x20, 0x22, 0x53, 0x61, 0x79, 0x20, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x21, 0x22, 0x29,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0e, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0e, 0x09, 0x15, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0e, 0x18, 0x19, 0x0a, 0x2b, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x02, 0x12, 0x03, 0x11, 0x02, 0x15, 0x1a, 0x1e, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x20,
-    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x63, 0x72, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
-    0x05, 0x12, 0x03, 0x11, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x11, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x11,
-    0x13, 0x14, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x14, 0x02, 0x19, 0x1a,
-    0x24, 0x20, 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66,
-    0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x64,
-    0x6f, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03,
-    0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x14, 0x09,
-    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x14, 0x17, 0x18, 0x0a,
-    0x49, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x17, 0x02, 0x2a, 0x1a, 0x3c, 0x20, 0x4a,
-    0x53, 0x4f, 0x4e, 0x20, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x2c, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73,
-    0x65, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75,
-    0x66, 0x20, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x04, 0x06, 0x12, 0x03, 0x17, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
-    0x01, 0x12, 0x03, 0x17, 0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12,
-    0x03, 0x17, 0x28, 0x29, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x1a, 0x02,
-    0x2b, 0x1a, 0x3d, 0x20, 0x4a, 0x53, 0x4f, 0x4e, 0x20, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20,
-    0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x2c, 0x20,
-    0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x70,
-    0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x20, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x06, 0x12, 0x03, 0x1a, 0x02, 0x18, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1a, 0x19, 0x26, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x1a, 0x29, 0x2a, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x06, 0x12, 0x03, 0x1d, 0x02, 0x23, 0x1a, 0x2a, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x6f,
-    0x66, 0x20, 0x63, 0x61, 0x70, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x20, 0x74,
-    0x68, 0x69, 0x73, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65,
-    0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x1d, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x05, 0x12, 0x03, 0x1d, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x1d, 0x12, 0x1e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x1d, 0x21, 0x22, 0x0a, 0x2f, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x07, 0x12, 0x03, 0x20, 0x02, 0x1b, 0x1a, 0x22, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20,
-    0x6f, 0x66, 0x20, 0x74, 0x61, 0x67, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x61, 0x74, 0x65,
-    0x67, 0x6f, 0x72, 0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x07, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x07, 0x05, 0x12, 0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07,
-    0x01, 0x12, 0x03, 0x20, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12,
-    0x03, 0x20, 0x19, 0x1a, 0x0a, 0x23, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x24, 0x00, 0x2a, 0x01,
-    0x1a, 0x17, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x4c, 0x69, 0x73, 0x74,
-    0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01,
-    0x12, 0x03, 0x24, 0x08, 0x18, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x26,
-    0x02, 0x16, 0x1a, 0x3e, 0x20, 0x4d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x20, 0x6e, 0x75, 0x6d,
-    0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x20, 0x74, 0x6f, 0x20,
-    0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x20, 0x28, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x3a,
-    0x20, 0x31, 0x30, 0x30, 0x2c, 0x20, 0x6d, 0x61, 0x78, 0x3a, 0x20, 0x31, 0x30, 0x30, 0x30, 0x29,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x26, 0x02, 0x07,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x26, 0x08, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x26, 0x14, 0x15, 0x0a, 0x42, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x29, 0x02, 0x18, 0x1a, 0x35, 0x20, 0x50, 0x61, 0x67, 0x69,
-    0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x72, 0x6f,
-    0x6d, 0x20, 0x61, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x20, 0x4c, 0x69, 0x73,
-    0x74, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x29, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x29, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x29, 0x16, 0x17, 0x0a, 0x24, 0x0a, 0x02, 0x04, 0x02,
-    0x12, 0x04, 0x2d, 0x00, 0x33, 0x01, 0x1a, 0x18, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72,
-    0x64, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x2d, 0x08, 0x19, 0x0a, 0x1d, 0x0a, 0x04,
-    0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x2f, 0x02, 0x1a, 0x1a, 0x10, 0x20, 0x4c, 0x69, 0x73, 0x74,
-    0x20, 0x6f, 0x66, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x2f, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x2f, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x2f, 0x18, 0x19, 0x0a, 0x32, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x32, 0x02, 0x1d,
-    0x1a, 0x25, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x6e, 0x65, 0x78, 0x74, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x65,
-    0x73, 0x75, 0x6c, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05,
-    0x12, 0x03, 0x32, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x32, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x32, 0x1b,
-    0x1c, 0x0a, 0x23, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x36, 0x00, 0x4a, 0x01, 0x1a, 0x17, 0x20,
-    0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x20, 0x72, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x36,
-    0x08, 0x1a, 0x0a, 0x82, 0x02, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x3c, 0x02, 0x25,
-    0x1a, 0xf4, 0x01, 0x20, 0x50, 0x72, 0x65, 0x2d, 0x63, 0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65, 0x64,
-    0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67,
-    0x20, 0x76, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6c,
-    0x69, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x69,
-    0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69,
-    0x6e, 0x67, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x73, 0x20, 0x75, 0x73,
-    0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x6d, 0x6f, 0x64,
-    0x65, 0x6c, 0x0a, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x75, 0x73, 0x65,
-    0x64, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x20, 0x74, 0x6f,
-    0x6f, 0x6c, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x73, 0x20, 0x28, 0x65,
-    0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x74, 0x65, 0x78, 0x74, 0x2d, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64,
-    0x69, 0x6e, 0x67, 0x2d, 0x33, 0x2d, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x29, 0x2e, 0x0a, 0x0a, 0x20,
-    0x49, 0x66, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x5f, 0x74, 0x65, 0x78, 0x74, 0x20, 0x69, 0x73,
-    0x20, 0x61, 0x6c, 0x73, 0x6f, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x2c, 0x20,
-    0x74, 0x68, 0x69, 0x73, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20, 0x69, 0x67,
-    0x6e, 0x6f, 0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04,
-    0x12, 0x03, 0x3c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03,
-    0x3c, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3c, 0x11,
-    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3c, 0x23, 0x24, 0x0a,
-    0x8e, 0x02, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x43, 0x02, 0x18, 0x1a, 0x80, 0x02,
-    0x20, 0x51, 0x75, 0x65, 0x72, 0x79, 0x20, 0x74, 0x65, 0x78, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x62,
-    0x65, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x65, 0x6d,
-    0x61, 0x6e, 0x74, 0x69, 0x63, 0x20, 0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x2e, 0x0a, 0x20, 0x57,
-    0x68, 0x65, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x67, 0x65,
-    0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64,
-    0x69, 0x6e, 0x67, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f,
-    0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65, 0x64, 0x0a, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64,
-    0x69, 0x6e, 0x67, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x6c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x75, 0x73,
-    0x65, 0x20, 0x69, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x61,
-    0x72, 0x63, 0x68, 0x2e, 0x0a, 0x0a, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x6f,
-    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2e, 0x20, 0x49, 0x66, 0x20, 0x70, 0x72, 0x6f, 0x76,
-    0x69, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x74, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x73, 0x20, 0x70,
-    0x72, 0x65, 0x63, 0x65, 0x64, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x71,
-    0x75, 0x65, 0x72, 0x79, 0x5f, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x43, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x43, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x43, 0x16, 0x17, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x03,
-    0x02, 0x02, 0x12, 0x03, 0x46, 0x02, 0x16, 0x1a, 0x3e, 0x20, 0x4d, 0x61, 0x78, 0x69, 0x6d, 0x75,
-    0x6d, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x75,
-    0x6c, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x20, 0x28, 0x64,
-    0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x3a, 0x20, 0x31, 0x30, 0x2c, 0x20, 0x6d, 0x61, 0x78, 0x3a,
-    0x20, 0x31, 0x30, 0x30, 0x29, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x46, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x46, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x46, 0x14,
-    0x15, 0x0a, 0x44, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x49, 0x02, 0x18, 0x1a, 0x37,
-    0x20, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x6b, 0x65,
-    0x6e, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75,
-    0x73, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x73,
-    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05,
-    0x12, 0x03, 0x49, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x49, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x49, 0x16,
-    0x17, 0x0a, 0x31, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x4d, 0x00, 0x53, 0x01, 0x1a, 0x25, 0x20,
-    0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x77, 0x69,
-    0x74, 0x68, 0x20, 0x72, 0x65, 0x6c, 0x65, 0x76, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x73, 0x63, 0x6f,
-    0x72, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x4d, 0x08, 0x14,
-    0x0a, 0x20, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x4f, 0x02, 0x10, 0x1a, 0x13, 0x20,
-    0x54, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x6f, 0x6c,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4f, 0x02, 0x06,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4f, 0x07, 0x0b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4f, 0x0e, 0x0f, 0x0a, 0x37, 0x0a, 0x04,
-    0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x52, 0x02, 0x1c, 0x1a, 0x2a, 0x20, 0x43, 0x6f, 0x73, 0x69,
-    0x6e, 0x65, 0x20, 0x73, 0x69, 0x6d, 0x69, 0x6c, 0x61, 0x72, 0x69, 0x74, 0x79, 0x20, 0x73, 0x63,
-    0x6f, 0x72, 0x65, 0x20, 0x62, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x20, 0x30, 0x20, 0x61, 0x6e,
-    0x64, 0x20, 0x31, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x52, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x52, 0x08,
-    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x52, 0x1a, 0x1b, 0x0a,
-    0x24, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x56, 0x00, 0x5c, 0x01, 0x1a, 0x18, 0x20, 0x43, 0x75,
-    0x73, 0x74, 0x6f, 0x6d, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x20, 0x72, 0x65, 0x73, 0x70,
-    0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x56, 0x08,
-    0x1b, 0x0a, 0x32, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x58, 0x02, 0x24, 0x1a, 0x25,
-    0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x20,
-    0x73, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x72, 0x65, 0x6c, 0x65, 0x76, 0x61,
-    0x6e, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x58, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x58, 0x0b,
-    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x58, 0x18, 0x1f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x58, 0x22, 0x23, 0x0a, 0x32, 0x0a,
-    0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x5b, 0x02, 0x1d, 0x1a, 0x25, 0x20, 0x54, 0x6f, 0x6b,
-    0x65, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x20,
-    0x70, 0x61, 0x67, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5b, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5b, 0x09, 0x18, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5b, 0x1b, 0x1c, 0x0a, 0x36, 0x0a, 0x02, 0x04,
-    0x06, 0x12, 0x04, 0x5f, 0x00, 0x62, 0x01, 0x1a, 0x2a, 0x20, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e,
-    0x74, 0x69, 0x61, 0x6c, 0x20, 0x64, 0x61, 0x74, 0x61, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20,
-    0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x63, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61,
-    0x6c, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x5f, 0x08, 0x16, 0x0a,
-    0x35, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x61, 0x02, 0x21, 0x1a, 0x28, 0x20, 0x4b,
-    0x65, 0x79, 0x2d, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x70, 0x61, 0x69, 0x72, 0x73, 0x20, 0x66,
-    0x6f, 0x72, 0x20, 0x63, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x20, 0x66, 0x69,
-    0x65, 0x6c, 0x64, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12,
-    0x03, 0x61, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x61,
-    0x16, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x61, 0x1f, 0x20,
-    0x0a, 0x25, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x65, 0x00, 0x6c, 0x01, 0x1a, 0x19, 0x20, 0x43,
-    0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x20, 0x72,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03,
-    0x65, 0x08, 0x17, 0x0a, 0x53, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x68, 0x02, 0x12,
-    0x1a, 0x46, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65,
-    0x3a, 0x20, 0x22, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2f, 0x7b, 0x74, 0x6f, 0x6f, 0x6c, 0x5f, 0x69,
-    0x64, 0x7d, 0x22, 0x0a, 0x20, 0x45, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x3a, 0x20, 0x22, 0x74,
-    0x6f, 0x6f, 0x6c, 0x73, 0x2f, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x2d, 0x77, 0x6f, 0x72, 0x6c, 0x64,
-    0x2e, 0x67, 0x72, 0x65, 0x65, 0x74, 0x22, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x68, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x68, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x68,
-    0x10, 0x11, 0x0a, 0x3d, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x6b, 0x02, 0x23, 0x1a,
-    0x30, 0x20, 0x49, 0x6e, 0x70, 0x75, 0x74, 0x20, 0x64, 0x61, 0x74, 0x61, 0x20, 0x66, 0x6f, 0x72,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20,
-    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x20, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x06, 0x12, 0x03, 0x6b, 0x02, 0x18, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6b, 0x19, 0x1e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6b, 0x21, 0x22, 0x0a, 0x26, 0x0a, 0x02, 0x04,
-    0x08, 0x12, 0x04, 0x6f, 0x00, 0x78, 0x01, 0x1a, 0x1a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d,
-    0x20, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-    0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x6f, 0x08, 0x18, 0x0a,
-    0x2e, 0x0a, 0x04, 0x04, 0x08, 0x08, 0x00, 0x12, 0x04, 0x71, 0x02, 0x77, 0x03, 0x1a, 0x20, 0x20,
-    0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f,
-    0x6f, 0x6c, 0x20, 0x69, 0x6e, 0x76, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x08, 0x00, 0x01, 0x12, 0x03, 0x71, 0x08, 0x0e, 0x0a, 0x36, 0x0a,
-    0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x73, 0x04, 0x26, 0x1a, 0x29, 0x20, 0x53, 0x75, 0x63,
-    0x63, 0x65, 0x73, 0x73, 0x66, 0x75, 0x6c, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x61,
-    0x73, 0x20, 0x61, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x20, 0x53, 0x74, 0x72,
-    0x75, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x73, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x73, 0x1b,
-    0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x73, 0x24, 0x25, 0x0a,
-    0x36, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x76, 0x04, 0x15, 0x1a, 0x29, 0x20, 0x45,
-    0x72, 0x72, 0x6f, 0x72, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x69, 0x66, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66,
-    0x61, 0x69, 0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05,
-    0x12, 0x03, 0x76, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x76, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x76, 0x13,
-    0x14, 0x0a, 0x26, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x05, 0x7b, 0x00, 0x8e, 0x01, 0x01, 0x1a, 0x19,
-    0x20, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x69, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x20,
-    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01,
-    0x12, 0x03, 0x7b, 0x08, 0x0f, 0x0a, 0x40, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x7e,
-    0x02, 0x3e, 0x1a, 0x33, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x61,
-    0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2e, 0x0a,
-    0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x6d,
-    0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x7e, 0x06, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
-    0x7e, 0x10, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7e, 0x2b,
-    0x3c, 0x0a, 0x4d, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0x82, 0x01, 0x02, 0x44, 0x1a,
+    0x2e, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x1a, 0x2c, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f,
+    0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x4c, 0x69,
+    0x73, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x12, 0x55, 0x0a, 0x09, 0x47, 0x65, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12,
+    0x28, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e,
+    0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x6f, 0x6c, 0x69,
+    0x63, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x62, 0x72, 0x77, 0x73,
+    0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68,
+    0x61, 0x31, 0x2e, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x5b, 0x0a, 0x0c, 0x55, 0x70, 0x73,
+    0x65, 0x72, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x2e, 0x62, 0x72, 0x77, 0x73,
+    0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68,
+    0x61, 0x31, 0x2e, 0x55, 0x70, 0x73, 0x65, 0x72, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
+    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
+    0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x69, 0x0a, 0x0c, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
+    0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x2b, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
+    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
+    0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x1a, 0x2c, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c,
+    0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x44, 0x65, 0x6c,
+    0x65, 0x74, 0x65, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x12, 0x78, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43,
+    0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x30, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74,
+    0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e,
+    0x47, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78,
+    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x31, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65,
+    0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61,
+    0x31, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74,
+    0x65, 0x78, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x69, 0x0a, 0x0c, 0x52,
+    0x65, 0x73, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x2b, 0x2e, 0x62, 0x72,
+    0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c,
+    0x70, 0x68, 0x61, 0x31, 0x2e, 0x52, 0x65, 0x73, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2c, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65,
+    0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61,
+    0x31, 0x2e, 0x52, 0x65, 0x73, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x63, 0x0a, 0x0a, 0x45, 0x6e, 0x64, 0x53, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x12, 0x29, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f,
+    0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x45, 0x6e,
+    0x64, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+    0x2a, 0x2e, 0x62, 0x72, 0x77, 0x73, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e,
+    0x76, 0x31, 0x61, 0x6c, 0x70, 0x68, 0x61, 0x31, 0x2e, 0x45, 0x6e, 0x64, 0x53, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x4a, 0xb2, 0x99, 0x01, 0x0a,
+    0x07, 0x12, 0x05, 0x00, 0x00, 0xd0, 0x04, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00,
+    0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x1f, 0x0a, 0x09, 0x0a, 0x02,
+    0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x26, 0x0a, 0x51, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x08,
+    0x00, 0x38, 0x01, 0x1a, 0x45, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x3a, 0x20,
+    0x54, 0x6f, 0x6f, 0x6c, 0x0a, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73,
+    0x20, 0x61, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f,
+    0x6f, 0x6c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x69,
+    0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
+    0x01, 0x12, 0x03, 0x08, 0x08, 0x0c, 0x0a, 0x32, 0x0a, 0x04, 0x04, 0x00, 0x04, 0x00, 0x12, 0x04,
+    0x0a, 0x02, 0x17, 0x03, 0x1a, 0x24, 0x20, 0x53, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x27, 0x73, 0x20, 0x63, 0x69, 0x72, 0x63, 0x75, 0x69, 0x74,
+    0x20, 0x62, 0x72, 0x65, 0x61, 0x6b, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x07, 0x13, 0x0a, 0x35, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x0c, 0x04, 0x22, 0x1a, 0x26, 0x20, 0x4e, 0x6f, 0x20, 0x63, 0x69, 0x72,
+    0x63, 0x75, 0x69, 0x74, 0x20, 0x62, 0x72, 0x65, 0x61, 0x6b, 0x65, 0x72, 0x20, 0x77, 0x61, 0x74,
+    0x63, 0x68, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x2e, 0x0a, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x1d, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0c, 0x20, 0x21, 0x0a,
+    0x24, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x04, 0x0f, 0x1a, 0x15,
+    0x20, 0x43, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x72, 0x75, 0x6e, 0x20, 0x6e, 0x6f, 0x72, 0x6d, 0x61,
+    0x6c, 0x6c, 0x79, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x0f, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x0f, 0x0d, 0x0e, 0x0a, 0x46, 0x0a, 0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x12,
+    0x03, 0x12, 0x04, 0x0d, 0x1a, 0x37, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x66, 0x61, 0x69,
+    0x6c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x55, 0x4e, 0x41, 0x56, 0x41, 0x49, 0x4c, 0x41, 0x42,
+    0x4c, 0x45, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x72, 0x75, 0x6e, 0x6e, 0x69,
+    0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x12, 0x04, 0x08, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x00, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x12, 0x0b, 0x0c, 0x0a, 0x59, 0x0a,
+    0x06, 0x04, 0x00, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x16, 0x04, 0x12, 0x1a, 0x4a, 0x20, 0x41,
+    0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x65, 0x64, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20,
+    0x6f, 0x66, 0x20, 0x70, 0x72, 0x6f, 0x62, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x72,
+    0x75, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x65, 0x73, 0x74, 0x20, 0x77, 0x68, 0x65, 0x74, 0x68,
+    0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x0a, 0x20, 0x72, 0x65, 0x63,
+    0x6f, 0x76, 0x65, 0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x16, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x04, 0x00,
+    0x02, 0x03, 0x02, 0x12, 0x03, 0x16, 0x10, 0x11, 0x0a, 0x79, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x1b, 0x02, 0x12, 0x1a, 0x6c, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65,
+    0x20, 0x6e, 0x61, 0x6d, 0x65, 0x3a, 0x20, 0x22, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2f, 0x7b, 0x74,
+    0x6f, 0x6f, 0x6c, 0x5f, 0x69, 0x64, 0x7d, 0x22, 0x20, 0x77, 0x68, 0x65, 0x72, 0x65, 0x20, 0x74,
+    0x6f, 0x6f, 0x6c, 0x5f, 0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x22, 0x63, 0x72, 0x61, 0x74, 0x65,
+    0x2d, 0x6e, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x6f, 0x6f, 0x6c, 0x2d, 0x69, 0x64, 0x22, 0x0a, 0x20,
+    0x45, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x3a, 0x20, 0x22, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2f,
+    0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x2d, 0x77, 0x6f, 0x72, 0x6c, 0x64, 0x2e, 0x67, 0x72, 0x65, 0x65,
+    0x74, 0x22, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1b, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x09, 0x0d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1b, 0x10, 0x11, 0x0a, 0x3f, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x1a, 0x1a, 0x32, 0x20, 0x48, 0x75, 0x6d,
+    0x61, 0x6e, 0x2d, 0x72, 0x65, 0x61, 0x64, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x64, 0x69, 0x73, 0x70,
+    0x6c, 0x61, 0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20,
+    0x22, 0x53, 0x61, 0x79, 0x20, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x21, 0x22, 0x29, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1e, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x1e, 0x18, 0x19, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02,
+    0x12, 0x03, 0x21, 0x02, 0x15, 0x1a, 0x1e, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x20, 0x76, 0x65, 0x72,
+    0x73, 0x69, 0x6f, 0x6e, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x72,
+    0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x21, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x21, 0x09,
+    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x21, 0x13, 0x14, 0x0a,
+    0x31, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x24, 0x02, 0x19, 0x1a, 0x24, 0x20, 0x44,
+    0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x77, 0x68,
+    0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x64, 0x6f, 0x65, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x24, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x24, 0x09, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x24, 0x17, 0x18, 0x0a, 0x49, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x27, 0x02, 0x2a, 0x1a, 0x3c, 0x20, 0x4a, 0x53, 0x4f, 0x4e,
+    0x20, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x69, 0x6e, 0x70, 0x75, 0x74, 0x2c, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74,
+    0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x20, 0x53,
+    0x74, 0x72, 0x75, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x06,
+    0x12, 0x03, 0x27, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03,
+    0x27, 0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x27, 0x28,
+    0x29, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x2a, 0x02, 0x2b, 0x1a, 0x3d,
+    0x20, 0x4a, 0x53, 0x4f, 0x4e, 0x20, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x2c, 0x20, 0x72, 0x65, 0x70,
+    0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x62, 0x75, 0x66, 0x20, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x05, 0x06, 0x12, 0x03, 0x2a, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x2a, 0x19, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x05, 0x03, 0x12, 0x03, 0x2a, 0x29, 0x2a, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12,
+    0x03, 0x2d, 0x02, 0x23, 0x1a, 0x2a, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x63,
+    0x61, 0x70, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x20, 0x74, 0x68, 0x69, 0x73,
+    0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x2d, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x06, 0x03, 0x12, 0x03, 0x2d, 0x21, 0x22, 0x0a, 0x2f, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07,
+    0x12, 0x03, 0x30, 0x02, 0x1b, 0x1a, 0x22, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x61, 0x67, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x61, 0x74, 0x65, 0x67, 0x6f, 0x72,
+    0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x07, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x05,
+    0x12, 0x03, 0x30, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03,
+    0x30, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x30, 0x19,
+    0x1a, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x33, 0x02, 0x21, 0x1a, 0x39,
+    0x20, 0x53, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f,
+    0x6f, 0x6c, 0x27, 0x73, 0x20, 0x63, 0x69, 0x72, 0x63, 0x75, 0x69, 0x74, 0x20, 0x62, 0x72, 0x65,
+    0x61, 0x6b, 0x65, 0x72, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x77, 0x61, 0x74,
+    0x63, 0x68, 0x65, 0x73, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x08, 0x06, 0x12, 0x03, 0x33, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01,
+    0x12, 0x03, 0x33, 0x0f, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03,
+    0x33, 0x1f, 0x20, 0x0a, 0x97, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x09, 0x12, 0x03, 0x37, 0x02,
+    0x17, 0x1a, 0x89, 0x01, 0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x63, 0x61, 0x6c,
+    0x6c, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x61, 0x67,
+    0x61, 0x69, 0x6e, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d,
+    0x65, 0x20, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x66,
+    0x75, 0x72, 0x74, 0x68, 0x65, 0x72, 0x0a, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x2e, 0x20,
+    0x46, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x74, 0x6f, 0x20,
+    0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x73,
+    0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x72, 0x75, 0x6e, 0x74, 0x69, 0x6d, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x09, 0x05, 0x12, 0x03, 0x37, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x09, 0x01, 0x12, 0x03, 0x37, 0x07, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x09, 0x03, 0x12, 0x03, 0x37, 0x14, 0x16, 0x0a, 0x23, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x3b,
+    0x00, 0x41, 0x01, 0x1a, 0x17, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x4c,
+    0x69, 0x73, 0x74, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x01, 0x01, 0x12, 0x03, 0x3b, 0x08, 0x18, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00,
+    0x12, 0x03, 0x3d, 0x02, 0x16, 0x1a, 0x3e, 0x20, 0x4d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x20,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x20,
+    0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x20, 0x28, 0x64, 0x65, 0x66, 0x61, 0x75,
+    0x6c, 0x74, 0x3a, 0x20, 0x31, 0x30, 0x30, 0x2c, 0x20, 0x6d, 0x61, 0x78, 0x3a, 0x20, 0x31, 0x30,
+    0x30, 0x30, 0x29, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x3d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3d, 0x08,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3d, 0x14, 0x15, 0x0a,
+    0x42, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x40, 0x02, 0x18, 0x1a, 0x35, 0x20, 0x50,
+    0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x20,
+    0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6f, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x40, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x40, 0x09, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x40, 0x16, 0x17, 0x0a, 0x24, 0x0a,
+    0x02, 0x04, 0x02, 0x12, 0x04, 0x44, 0x00, 0x4a, 0x01, 0x1a, 0x18, 0x20, 0x53, 0x74, 0x61, 0x6e,
+    0x64, 0x61, 0x72, 0x64, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x44, 0x08, 0x19, 0x0a,
+    0x1d, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x46, 0x02, 0x1a, 0x1a, 0x10, 0x20, 0x4c,
+    0x69, 0x73, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x46, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x46, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x46, 0x18, 0x19, 0x0a, 0x32, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x49, 0x02, 0x1d, 0x1a, 0x25, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x49, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x49, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x49, 0x1b, 0x1c, 0x0a, 0x23, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x4d, 0x00, 0x61, 0x01,
+    0x1a, 0x17, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68,
+    0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01,
+    0x12, 0x03, 0x4d, 0x08, 0x1a, 0x0a, 0x82, 0x02, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03,
+    0x53, 0x02, 0x25, 0x1a, 0xf4, 0x01, 0x20, 0x50, 0x72, 0x65, 0x2d, 0x63, 0x6f, 0x6d, 0x70, 0x75,
+    0x74, 0x65, 0x64, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64,
+    0x69, 0x6e, 0x67, 0x20, 0x76, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72,
+    0x61, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x73,
+    0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20,
+    0x6d, 0x6f, 0x64, 0x65, 0x6c, 0x0a, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20,
+    0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
+    0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x73,
+    0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x74, 0x65, 0x78, 0x74, 0x2d, 0x65, 0x6d, 0x62,
+    0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x2d, 0x33, 0x2d, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x29, 0x2e,
+    0x0a, 0x0a, 0x20, 0x49, 0x66, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x5f, 0x74, 0x65, 0x78, 0x74,
+    0x20, 0x69, 0x73, 0x20, 0x61, 0x6c, 0x73, 0x6f, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65,
+    0x64, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73,
+    0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x53, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x53, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x53, 0x11, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x53,
+    0x23, 0x24, 0x0a, 0x8e, 0x02, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x5a, 0x02, 0x18,
+    0x1a, 0x80, 0x02, 0x20, 0x51, 0x75, 0x65, 0x72, 0x79, 0x20, 0x74, 0x65, 0x78, 0x74, 0x20, 0x74,
+    0x6f, 0x20, 0x62, 0x65, 0x20, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x73, 0x65, 0x6d, 0x61, 0x6e, 0x74, 0x69, 0x63, 0x20, 0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x2e,
+    0x0a, 0x20, 0x57, 0x68, 0x65, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x2c,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x77, 0x69, 0x6c, 0x6c,
+    0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x6d, 0x62,
+    0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65, 0x64, 0x0a, 0x20, 0x65, 0x6d, 0x62,
+    0x65, 0x64, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x6c, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x75, 0x73, 0x65, 0x20, 0x69, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x2e, 0x0a, 0x0a, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x69,
+    0x73, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x2e, 0x20, 0x49, 0x66, 0x20, 0x70,
+    0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x74, 0x20, 0x74, 0x61, 0x6b, 0x65,
+    0x73, 0x20, 0x70, 0x72, 0x65, 0x63, 0x65, 0x64, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x6f, 0x76, 0x65,
+    0x72, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x5f, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x69, 0x6e,
+    0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5a, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5a, 0x09, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5a, 0x16, 0x17, 0x0a, 0x4b, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x5d, 0x02, 0x16, 0x1a, 0x3e, 0x20, 0x4d, 0x61, 0x78,
+    0x69, 0x6d, 0x75, 0x6d, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x72,
+    0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e,
+    0x20, 0x28, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x3a, 0x20, 0x31, 0x30, 0x2c, 0x20, 0x6d,
+    0x61, 0x78, 0x3a, 0x20, 0x31, 0x30, 0x30, 0x29, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x5d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x5d, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x5d, 0x14, 0x15, 0x0a, 0x44, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x60, 0x02,
+    0x18, 0x1a, 0x37, 0x20, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x70, 0x72, 0x65, 0x76,
+    0x69, 0x6f, 0x75, 0x73, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x54, 0x6f, 0x6f, 0x6c, 0x73,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x03, 0x05, 0x12, 0x03, 0x60, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x60, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x60, 0x16, 0x17, 0x0a, 0x31, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x64, 0x00, 0x6a, 0x01,
+    0x1a, 0x25, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x72, 0x65, 0x6c, 0x65, 0x76, 0x61, 0x6e, 0x63, 0x65, 0x20,
+    0x73, 0x63, 0x6f, 0x72, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03,
+    0x64, 0x08, 0x14, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x66, 0x02, 0x10,
+    0x1a, 0x13, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x65, 0x64, 0x20, 0x74,
+    0x6f, 0x6f, 0x6c, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03,
+    0x66, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x66, 0x07,
+    0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x66, 0x0e, 0x0f, 0x0a,
+    0x37, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x69, 0x02, 0x1c, 0x1a, 0x2a, 0x20, 0x43,
+    0x6f, 0x73, 0x69, 0x6e, 0x65, 0x20, 0x73, 0x69, 0x6d, 0x69, 0x6c, 0x61, 0x72, 0x69, 0x74, 0x79,
+    0x20, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x20, 0x62, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x20, 0x30,
+    0x20, 0x61, 0x6e, 0x64, 0x20, 0x31, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x69, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x69, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x69,
+    0x1a, 0x1b, 0x0a, 0x24, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x6d, 0x00, 0x73, 0x01, 0x1a, 0x18,
+    0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x20, 0x72,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12,
+    0x03, 0x6d, 0x08, 0x1b, 0x0a, 0x32, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x6f, 0x02,
+    0x24, 0x1a, 0x25, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c,
+    0x74, 0x73, 0x20, 0x73, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x72, 0x65, 0x6c,
+    0x65, 0x76, 0x61, 0x6e, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
+    0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12,
+    0x03, 0x6f, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6f,
+    0x18, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6f, 0x22, 0x23,
+    0x0a, 0x32, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x72, 0x02, 0x1d, 0x1a, 0x25, 0x20,
+    0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65,
+    0x78, 0x74, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c,
+    0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x72,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x72, 0x09, 0x18,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x72, 0x1b, 0x1c, 0x0a, 0x36,
+    0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x76, 0x00, 0x79, 0x01, 0x1a, 0x2a, 0x20, 0x43, 0x72, 0x65,
+    0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x20, 0x64, 0x61, 0x74, 0x61, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x61, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x63, 0x72, 0x65, 0x64, 0x65, 0x6e,
+    0x74, 0x69, 0x61, 0x6c, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x76,
+    0x08, 0x16, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x78, 0x02, 0x21, 0x1a,
+    0x28, 0x20, 0x4b, 0x65, 0x79, 0x2d, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x70, 0x61, 0x69, 0x72,
+    0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c,
+    0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x78, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x78, 0x16, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x78, 0x1f, 0x20, 0x0a, 0x26, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x05, 0x7c, 0x00, 0x83, 0x01, 0x01,
+    0x1a, 0x19, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f,
+    0x6f, 0x6c, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x07, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x17, 0x0a, 0x53, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12,
+    0x03, 0x7f, 0x02, 0x12, 0x1a, 0x46, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20,
+    0x6e, 0x61, 0x6d, 0x65, 0x3a, 0x20, 0x22, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2f, 0x7b, 0x74, 0x6f,
+    0x6f, 0x6c, 0x5f, 0x69, 0x64, 0x7d, 0x22, 0x0a, 0x20, 0x45, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65,
+    0x3a, 0x20, 0x22, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2f, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x2d, 0x77,
+    0x6f, 0x72, 0x6c, 0x64, 0x2e, 0x67, 0x72, 0x65, 0x65, 0x74, 0x22, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x7f, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x7f, 0x10, 0x11, 0x0a, 0x3e, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x04,
+    0x82, 0x01, 0x02, 0x23, 0x1a, 0x30, 0x20, 0x49, 0x6e, 0x70, 0x75, 0x74, 0x20, 0x64, 0x61, 0x74,
+    0x61, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x2c, 0x20,
+    0x61, 0x73, 0x20, 0x61, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x20, 0x53, 0x74,
+    0x72, 0x75, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x06, 0x12,
+    0x04, 0x82, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0x82, 0x01, 0x19, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x04, 0x82,
+    0x01, 0x21, 0x22, 0x0a, 0x28, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x06, 0x86, 0x01, 0x00, 0xb2, 0x01,
+    0x01, 0x1a, 0x1a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x54,
+    0x6f, 0x6f, 0x6c, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x08, 0x01, 0x12, 0x04, 0x86, 0x01, 0x08, 0x18, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x08,
+    0x04, 0x00, 0x12, 0x06, 0x88, 0x01, 0x02, 0x95, 0x01, 0x03, 0x1a, 0x25, 0x20, 0x48, 0x6f, 0x77,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x63, 0x61, 0x63, 0x68,
+    0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64, 0x20, 0x61, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x04, 0x00, 0x01, 0x12, 0x04, 0x88, 0x01, 0x07, 0x12,
+    0x0a, 0x58, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x8a, 0x01, 0x04, 0x21,
+    0x1a, 0x48, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x75, 0x6e, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x68,
+    0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x63, 0x61, 0x63,
+    0x68, 0x65, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x27,
+    0x73, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6e, 0x6f,
+    0x74, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x08,
+    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x08, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x8a, 0x01, 0x1f, 0x20, 0x0a, 0x56, 0x0a, 0x06,
+    0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x04, 0x0c, 0x1a, 0x46, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x72, 0x65,
+    0x75, 0x73, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x61, 0x72,
+    0x6c, 0x69, 0x65, 0x72, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75,
+    0x74, 0x20, 0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f,
+    0x6f, 0x6c, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x8d, 0x01, 0x04, 0x07, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x04, 0x8d, 0x01, 0x0a, 0x0b, 0x0a, 0x41, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x02,
+    0x12, 0x04, 0x90, 0x01, 0x04, 0x0d, 0x1a, 0x31, 0x20, 0x4e, 0x6f, 0x20, 0x72, 0x65, 0x75, 0x73,
+    0x61, 0x62, 0x6c, 0x65, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20,
+    0x63, 0x61, 0x63, 0x68, 0x65, 0x64, 0x2c, 0x20, 0x73, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x6f, 0x6f, 0x6c, 0x20, 0x72, 0x61, 0x6e, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x08, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x90, 0x01, 0x04, 0x08, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x08,
+    0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x90, 0x01, 0x0b, 0x0c, 0x0a, 0x8e, 0x01, 0x0a, 0x06,
+    0x04, 0x08, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x94, 0x01, 0x04, 0x0f, 0x1a, 0x7e, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x73, 0x6b, 0x69, 0x70, 0x70,
+    0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x78, 0x2d, 0x63, 0x61, 0x63, 0x68, 0x65, 0x2d, 0x6d, 0x6f,
+    0x64, 0x65, 0x3a, 0x20, 0x62, 0x79, 0x70, 0x61, 0x73, 0x73, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65,
+    0x72, 0x2c, 0x20, 0x73, 0x6f, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20,
+    0x72, 0x61, 0x6e, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x75, 0x74, 0x70,
+    0x75, 0x74, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x79, 0x20,
+    0x63, 0x61, 0x63, 0x68, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x08, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x94, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0x94, 0x01, 0x0d, 0x0e, 0x0a, 0x30,
+    0x0a, 0x04, 0x04, 0x08, 0x08, 0x00, 0x12, 0x06, 0x98, 0x01, 0x02, 0xa3, 0x01, 0x03, 0x1a, 0x20,
+    0x20, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x6f, 0x6f, 0x6c, 0x20, 0x69, 0x6e, 0x76, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x08, 0x00, 0x01, 0x12, 0x04, 0x98, 0x01, 0x08, 0x0e, 0x0a,
+    0x37, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x04, 0x9a, 0x01, 0x04, 0x26, 0x1a, 0x29, 0x20,
+    0x53, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x66, 0x75, 0x6c, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75,
+    0x74, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x20,
+    0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0x9a, 0x01, 0x04, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x9a, 0x01, 0x1b, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x9a, 0x01, 0x24, 0x25, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x04, 0x9d,
+    0x01, 0x04, 0x15, 0x1a, 0x29, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x6f, 0x63,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9d, 0x01, 0x13, 0x14, 0x0a, 0xd3, 0x01, 0x0a, 0x04,
+    0x04, 0x08, 0x02, 0x02, 0x12, 0x04, 0xa2, 0x01, 0x04, 0x22, 0x1a, 0xc4, 0x01, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x68, 0x65, 0x6c, 0x64, 0x20, 0x75,
+    0x6e, 0x74, 0x69, 0x6c, 0x20, 0x61, 0x20, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e, 0x20, 0x61, 0x70,
+    0x70, 0x72, 0x6f, 0x76, 0x65, 0x73, 0x20, 0x69, 0x74, 0x2e, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20,
+    0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x0a, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x73, 0x65, 0x74, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x78, 0x2d, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x2d, 0x6d,
+    0x6f, 0x64, 0x65, 0x3a, 0x20, 0x64, 0x65, 0x66, 0x65, 0x72, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65,
+    0x72, 0x3b, 0x20, 0x72, 0x65, 0x74, 0x72, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c,
+    0x6c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x61, 0x6d, 0x65,
+    0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x6e, 0x70,
+    0x75, 0x74, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x70, 0x70, 0x72,
+    0x6f, 0x76, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x67, 0x72, 0x61, 0x6e, 0x74, 0x65, 0x64, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa2, 0x01, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa2, 0x01, 0x0d, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa2, 0x01, 0x20, 0x21, 0x0a, 0x86,
+    0x01, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x2c, 0x1a, 0x78, 0x20,
+    0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x73, 0x20, 0x65,
+    0x76, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e,
+    0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x20, 0x77,
+    0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x20, 0x73, 0x65, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x78, 0x2d, 0x70, 0x6f, 0x6c, 0x69,
+    0x63, 0x79, 0x2d, 0x74, 0x72, 0x61, 0x63, 0x65, 0x3a, 0x20, 0x74, 0x72, 0x75, 0x65, 0x20, 0x68,
+    0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x04,
+    0x12, 0x04, 0xa7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x06, 0x12,
+    0x04, 0xa7, 0x01, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0xa7, 0x01, 0x1b, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa7,
+    0x01, 0x2a, 0x2b, 0x0a, 0x4d, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x04, 0xaa, 0x01, 0x02,
+    0x24, 0x1a, 0x3f, 0x20, 0x42, 0x75, 0x64, 0x67, 0x65, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x61, 0x6c, 0x6c, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x61, 0x67, 0x61,
+    0x69, 0x6e, 0x73, 0x74, 0x2c, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x69, 0x74, 0x73, 0x20,
+    0x63, 0x6f, 0x73, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x68, 0x61, 0x72, 0x67, 0x65, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12, 0x04, 0xaa, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x06, 0x12, 0x04, 0xaa, 0x01, 0x0b, 0x17,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x04, 0xaa, 0x01, 0x18, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x04, 0xaa, 0x01, 0x22, 0x23, 0x0a, 0x9a,
+    0x01, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x05, 0x12, 0x04, 0xae, 0x01, 0x02, 0x16, 0x1a, 0x8b, 0x01,
+    0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20,
+    0x77, 0x61, 0x73, 0x20, 0x69, 0x6e, 0x76, 0x6f, 0x6b, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x3b, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x74,
+    0x68, 0x61, 0x6e, 0x20, 0x31, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65,
+    0x64, 0x20, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x0a, 0x20, 0x6f, 0x66, 0x20, 0x61,
+    0x6e, 0x20, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x6f,
+    0x6c, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65, 0x64, 0x2c, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x30, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x64, 0x69,
+    0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x72, 0x75, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x05, 0x05, 0x12, 0x04, 0xae, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x05, 0x01, 0x12, 0x04, 0xae, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x05, 0x03, 0x12, 0x04, 0xae, 0x01, 0x14, 0x15, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x06,
+    0x12, 0x04, 0xb1, 0x01, 0x02, 0x18, 0x1a, 0x27, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x72, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x06, 0x12, 0x04, 0xb1, 0x01, 0x02, 0x0d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x0e, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x06, 0x03, 0x12, 0x04, 0xb1, 0x01, 0x16, 0x17, 0x0a, 0xa2, 0x01, 0x0a,
+    0x02, 0x04, 0x09, 0x12, 0x06, 0xb6, 0x01, 0x00, 0xcd, 0x01, 0x01, 0x1a, 0x93, 0x01, 0x20, 0x45,
+    0x72, 0x72, 0x6f, 0x72, 0x20, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x50, 0x45, 0x52, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x44, 0x45, 0x4e,
+    0x49, 0x45, 0x44, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x20, 0x73, 0x74, 0x61,
+    0x74, 0x75, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+    0x79, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x61, 0x6c, 0x6c, 0x2c, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x61,
+    0x6e, 0x20, 0x41, 0x6e, 0x79, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67,
+    0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x72, 0x70, 0x63, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73,
+    0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x04, 0xb6, 0x01, 0x08, 0x14, 0x0a, 0x23,
+    0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x14, 0x1a, 0x15, 0x20, 0x4e,
+    0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+    0x79, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb8, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb8, 0x01, 0x09,
+    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb8, 0x01, 0x12, 0x13,
+    0x0a, 0x26, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x1c, 0x1a, 0x18,
+    0x20, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xbb, 0x01, 0x09, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0xbb, 0x01, 0x1a, 0x1b, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x04, 0xbe,
+    0x01, 0x02, 0x1a, 0x1a, 0x40, 0x20, 0x50, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x69, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x27, 0x73, 0x20, 0x65, 0x66, 0x66,
+    0x65, 0x63, 0x74, 0x73, 0x2c, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61,
+    0x74, 0x20, 0x30, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x04,
+    0xbe, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x04, 0xbe,
+    0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x04, 0xbe, 0x01,
+    0x18, 0x19, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x1a,
+    0x1a, 0x1d, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x20, 0x70, 0x61, 0x74, 0x74, 0x65, 0x72, 0x6e, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc1, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc1, 0x01, 0x18, 0x19, 0x0a, 0x32, 0x0a, 0x04,
+    0x04, 0x09, 0x02, 0x04, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x17, 0x1a, 0x24, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x27, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x20, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x05, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc4, 0x01, 0x09, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x04, 0xc4, 0x01, 0x15, 0x16, 0x0a, 0x5e, 0x0a,
+    0x04, 0x04, 0x09, 0x02, 0x05, 0x12, 0x04, 0xc8, 0x01, 0x02, 0x19, 0x1a, 0x50, 0x20, 0x57, 0x68,
+    0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x20, 0x68, 0x65, 0x6c, 0x64, 0x3a, 0x20, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x67, 0x75, 0x61,
+    0x72, 0x64, 0x2c, 0x20, 0x74, 0x72, 0x75, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x64,
+    0x65, 0x6e, 0x79, 0x0a, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x05, 0x05, 0x12, 0x04, 0xc8, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x05, 0x01, 0x12, 0x04, 0xc8, 0x01, 0x07, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x05, 0x03, 0x12, 0x04, 0xc8, 0x01, 0x17, 0x18, 0x0a, 0x86, 0x01, 0x0a, 0x04, 0x04,
+    0x09, 0x02, 0x06, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x25, 0x1a, 0x78, 0x20, 0x50, 0x6f, 0x6c, 0x69,
+    0x63, 0x79, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x73, 0x20, 0x65, 0x76, 0x61, 0x6c, 0x75,
+    0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x64, 0x65, 0x6e, 0x69, 0x61, 0x6c, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x2e, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x0a, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x73, 0x65, 0x74,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x78, 0x2d, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x2d, 0x74,
+    0x72, 0x61, 0x63, 0x65, 0x3a, 0x20, 0x74, 0x72, 0x75, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x04, 0x12, 0x04, 0xcc, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x06, 0x12, 0x04, 0xcc, 0x01, 0x0b,
+    0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x1b, 0x20,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x06, 0x03, 0x12, 0x04, 0xcc, 0x01, 0x23, 0x24, 0x0a,
+    0xa9, 0x01, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x06, 0xd2, 0x01, 0x00, 0xd8, 0x01, 0x01, 0x1a, 0x9a,
+    0x01, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x20, 0x52, 0x45, 0x53, 0x4f, 0x55, 0x52, 0x43, 0x45, 0x5f, 0x45, 0x58,
+    0x48, 0x41, 0x55, 0x53, 0x54, 0x45, 0x44, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c,
+    0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x63,
+    0x6f, 0x73, 0x74, 0x20, 0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x0a, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2c, 0x20, 0x70, 0x61,
+    0x63, 0x6b, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x41, 0x6e, 0x79, 0x20, 0x69,
+    0x6e, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x72,
+    0x70, 0x63, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
+    0x0a, 0x20, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x0a, 0x01, 0x12, 0x04, 0xd2, 0x01, 0x08, 0x16, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00,
+    0x12, 0x04, 0xd4, 0x01, 0x02, 0x1a, 0x1a, 0x27, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x78, 0x63,
+    0x65, 0x65, 0x64, 0x65, 0x64, 0x20, 0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x2c, 0x20, 0x62, 0x65,
+    0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd4, 0x01, 0x0f, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd4, 0x01, 0x18, 0x19, 0x0a, 0x42, 0x0a, 0x04,
+    0x04, 0x0a, 0x02, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x12, 0x1a, 0x34, 0x20, 0x53, 0x74, 0x61,
+    0x74, 0x69, 0x63, 0x20, 0x63, 0x6f, 0x73, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x6f, 0x6f, 0x6c, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x30, 0x20, 0x69, 0x66, 0x20, 0x69, 0x74,
+    0x20, 0x64, 0x65, 0x63, 0x6c, 0x61, 0x72, 0x65, 0x73, 0x20, 0x6e, 0x6f, 0x6e, 0x65, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x09, 0x0d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd7, 0x01, 0x10, 0x11, 0x0a, 0xfd, 0x01,
+    0x0a, 0x02, 0x04, 0x0b, 0x12, 0x06, 0xde, 0x01, 0x00, 0xe8, 0x01, 0x01, 0x1a, 0xee, 0x01, 0x20,
+    0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x20, 0x6f, 0x66,
+    0x20, 0x61, 0x20, 0x52, 0x45, 0x53, 0x4f, 0x55, 0x52, 0x43, 0x45, 0x5f, 0x45, 0x58, 0x48, 0x41,
+    0x55, 0x53, 0x54, 0x45, 0x44, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x54, 0x6f, 0x6f, 0x6c, 0x20, 0x73,
+    0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x72, 0x61, 0x74,
+    0x65, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x0a, 0x20, 0x72, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x65,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2c, 0x20, 0x70, 0x61, 0x63, 0x6b,
+    0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x41, 0x6e, 0x79, 0x20, 0x69, 0x6e, 0x74,
+    0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x72, 0x70, 0x63,
+    0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x0a, 0x20,
+    0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61,
+    0x74, 0x75, 0x73, 0x20, 0x61, 0x6c, 0x73, 0x6f, 0x20, 0x63, 0x61, 0x72, 0x72, 0x69, 0x65, 0x73,
+    0x20, 0x61, 0x20, 0x72, 0x65, 0x74, 0x72, 0x79, 0x2d, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x68,
+    0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x77, 0x61, 0x69, 0x74, 0x2c,
+    0x20, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x75, 0x70, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x0b, 0x01, 0x12, 0x04, 0xde, 0x01, 0x08, 0x13, 0x0a, 0x30, 0x0a, 0x04, 0x04, 0x0b,
+    0x02, 0x00, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x18, 0x1a, 0x22, 0x20, 0x4e, 0x61, 0x6d, 0x65, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x63, 0x65, 0x65, 0x64, 0x65, 0x64, 0x20,
+    0x72, 0x61, 0x74, 0x65, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe0, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xe0, 0x01, 0x16, 0x17, 0x0a, 0x72, 0x0a, 0x04, 0x04, 0x0b, 0x02,
+    0x01, 0x12, 0x04, 0xe4, 0x01, 0x02, 0x11, 0x1a, 0x64, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x62, 0x75, 0x63, 0x6b, 0x65, 0x74, 0x2c, 0x20, 0x73, 0x75, 0x63, 0x68,
+    0x20, 0x61, 0x73, 0x20, 0x22, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x3d, 0x73, 0x31, 0x2c,
+    0x74, 0x6f, 0x6f, 0x6c, 0x3d, 0x6d, 0x61, 0x69, 0x6c, 0x2e, 0x73, 0x65, 0x6e, 0x64, 0x22, 0x2c,
+    0x20, 0x6f, 0x72, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x0a,
+    0x20, 0x62, 0x75, 0x63, 0x6b, 0x65, 0x74, 0x20, 0x73, 0x68, 0x61, 0x72, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xe4, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe4, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe4, 0x01, 0x0f, 0x10, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x0b,
+    0x02, 0x02, 0x12, 0x04, 0xe7, 0x01, 0x02, 0x21, 0x1a, 0x2a, 0x20, 0x53, 0x65, 0x63, 0x6f, 0x6e,
+    0x64, 0x73, 0x20, 0x75, 0x6e, 0x74, 0x69, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c,
+    0x6c, 0x20, 0x77, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77,
+    0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x04, 0xe7,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe7, 0x01,
+    0x09, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe7, 0x01, 0x1f,
+    0x20, 0x0a, 0x39, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0xeb, 0x01, 0x00, 0xfa, 0x01, 0x01, 0x1a,
+    0x2b, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x6d, 0x75, 0x63, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20,
+    0x63, 0x6f, 0x73, 0x74, 0x20, 0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x20, 0x68, 0x61, 0x73, 0x20,
+    0x62, 0x65, 0x65, 0x6e, 0x20, 0x73, 0x70, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x0c, 0x01, 0x12, 0x04, 0xeb, 0x01, 0x08, 0x14, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x0c, 0x02,
+    0x00, 0x12, 0x04, 0xed, 0x01, 0x02, 0x14, 0x1a, 0x15, 0x20, 0x4e, 0x61, 0x6d, 0x65, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x04, 0xed, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xed, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xed, 0x01, 0x12, 0x13, 0x0a, 0x4e, 0x0a, 0x04, 0x04,
+    0x0c, 0x02, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x13, 0x1a, 0x40, 0x20, 0x53, 0x63, 0x6f, 0x70,
+    0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x20, 0x69, 0x73, 0x20, 0x73,
+    0x68, 0x61, 0x72, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x3a, 0x20, 0x22, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x22, 0x2c, 0x20, 0x22, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x22, 0x20, 0x6f, 0x72,
+    0x20, 0x22, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x22, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x01, 0x05, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xf0, 0x01, 0x11, 0x12, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x02,
+    0x12, 0x04, 0xf3, 0x01, 0x02, 0x13, 0x1a, 0x15, 0x20, 0x4d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d,
+    0x20, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x20, 0x63, 0x6f, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x02, 0x05, 0x12, 0x04, 0xf3, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x02, 0x03, 0x12, 0x04, 0xf3, 0x01, 0x11, 0x12, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x0c,
+    0x02, 0x03, 0x12, 0x04, 0xf6, 0x01, 0x02, 0x13, 0x1a, 0x23, 0x20, 0x43, 0x6f, 0x73, 0x74, 0x20,
+    0x73, 0x70, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72,
+    0x72, 0x65, 0x6e, 0x74, 0x20, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x03, 0x05, 0x12, 0x04, 0xf6, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf6, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x03, 0x03, 0x12, 0x04, 0xf6, 0x01, 0x11, 0x12, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x0c,
+    0x02, 0x04, 0x12, 0x04, 0xf9, 0x01, 0x02, 0x17, 0x1a, 0x32, 0x20, 0x43, 0x6f, 0x73, 0x74, 0x20,
+    0x6c, 0x65, 0x66, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72,
+    0x65, 0x6e, 0x74, 0x20, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x2c, 0x20, 0x6e, 0x65, 0x76, 0x65,
+    0x72, 0x20, 0x6e, 0x65, 0x67, 0x61, 0x74, 0x69, 0x76, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x04, 0x05, 0x12, 0x04, 0xf9, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x04, 0x01, 0x12, 0x04, 0xf9, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0xf9, 0x01, 0x15, 0x16, 0x0a, 0x37, 0x0a, 0x02, 0x04, 0x0d, 0x12,
+    0x06, 0xfd, 0x01, 0x00, 0xb2, 0x02, 0x01, 0x1a, 0x29, 0x20, 0x4f, 0x6e, 0x65, 0x20, 0x70, 0x6f,
+    0x6c, 0x69, 0x63, 0x79, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x65, 0x76, 0x61, 0x6c,
+    0x75, 0x61, 0x74, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x63, 0x61, 0x6c, 0x6c,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x08, 0x17, 0x0a,
+    0x26, 0x0a, 0x04, 0x04, 0x0d, 0x04, 0x00, 0x12, 0x06, 0xff, 0x01, 0x02, 0x99, 0x02, 0x03, 0x1a,
+    0x16, 0x20, 0x57, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63,
+    0x74, 0x20, 0x64, 0x69, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x04, 0x00, 0x01,
+    0x12, 0x04, 0xff, 0x01, 0x07, 0x0e, 0x0a, 0x26, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x04, 0x81, 0x02, 0x04, 0x1c, 0x1a, 0x16, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69,
+    0x66, 0x69, 0x65, 0x64, 0x20, 0x6f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x2e, 0x0a, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x81, 0x02, 0x04, 0x17, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x81, 0x02, 0x1a, 0x1b,
+    0x0a, 0x48, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x84, 0x02, 0x04, 0x10,
+    0x1a, 0x38, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x64, 0x69, 0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x64, 0x69, 0x64,
+    0x20, 0x6e, 0x6f, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d,
+    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x02, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0d, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x84, 0x02, 0x0e, 0x0f, 0x0a, 0x8c, 0x01, 0x0a,
+    0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x88, 0x02, 0x04, 0x10, 0x1a, 0x7c, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x68, 0x65,
+    0x6c, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63,
+    0x74, 0x20, 0x6d, 0x61, 0x64, 0x65, 0x20, 0x6e, 0x6f, 0x20, 0x64, 0x65, 0x63, 0x69, 0x73, 0x69,
+    0x6f, 0x6e, 0x3a, 0x20, 0x61, 0x20, 0x67, 0x75, 0x61, 0x72, 0x64, 0x20, 0x70, 0x61, 0x73, 0x73,
+    0x65, 0x64, 0x2c, 0x0a, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x75, 0x70, 0x64,
+    0x61, 0x74, 0x65, 0x73, 0x2c, 0x20, 0x72, 0x65, 0x77, 0x72, 0x69, 0x74, 0x65, 0x73, 0x20, 0x6f,
+    0x72, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x6f, 0x72, 0x6d, 0x73, 0x20, 0x77, 0x65, 0x72,
+    0x65, 0x20, 0x61, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0d, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x88, 0x02, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x0d, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x88, 0x02, 0x0e, 0x0f, 0x0a, 0x2e, 0x0a,
+    0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x8b, 0x02, 0x04, 0x10, 0x1a, 0x1e, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77,
+    0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x8b, 0x02, 0x04, 0x0b, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0x8b, 0x02, 0x0e, 0x0f, 0x0a,
+    0x3c, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0x8e, 0x02, 0x04, 0x1a, 0x1a,
+    0x2c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x72, 0x65, 0x71,
+    0x75, 0x69, 0x72, 0x65, 0x64, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0x8e, 0x02, 0x04, 0x15, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0x8e, 0x02, 0x18, 0x19, 0x0a,
+    0x2d, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0x91, 0x02, 0x04, 0x0f, 0x1a,
+    0x1d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x64, 0x65, 0x6e,
+    0x69, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0x91, 0x02, 0x04, 0x0a, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0x91, 0x02, 0x0d, 0x0e,
+    0x0a, 0x4c, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x06, 0x12, 0x04, 0x94, 0x02, 0x04, 0x15,
+    0x1a, 0x3c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x64, 0x69, 0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x75, 0x61, 0x72, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0x94, 0x02, 0x04, 0x10, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x04, 0x94, 0x02, 0x13, 0x14,
+    0x0a, 0x67, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x07, 0x12, 0x04, 0x98, 0x02, 0x04, 0x18,
+    0x1a, 0x57, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x64, 0x69, 0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x72, 0x65, 0x70,
+    0x6c, 0x61, 0x63, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x0a, 0x20, 0x69, 0x74, 0x73, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x20,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04,
+    0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0x98, 0x02, 0x04, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d,
+    0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x04, 0x98, 0x02, 0x16, 0x17, 0x0a, 0x23, 0x0a, 0x04, 0x04,
+    0x0d, 0x02, 0x00, 0x12, 0x04, 0x9c, 0x02, 0x02, 0x14, 0x1a, 0x15, 0x20, 0x4e, 0x61, 0x6d, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x04, 0x9c, 0x02, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9c, 0x02, 0x09, 0x0f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9c, 0x02, 0x12, 0x13, 0x0a, 0x26, 0x0a,
+    0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x04, 0x9f, 0x02, 0x02, 0x1c, 0x1a, 0x18, 0x20, 0x56, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c,
+    0x69, 0x63, 0x79, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12, 0x04,
+    0x9f, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9f,
+    0x02, 0x09, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9f, 0x02,
+    0x1a, 0x1b, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x04, 0xa2, 0x02, 0x02, 0x1a,
+    0x1a, 0x40, 0x20, 0x50, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x27, 0x73, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74,
+    0x73, 0x2c, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x74, 0x20, 0x30,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa2, 0x02, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa2, 0x02, 0x09, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa2, 0x02, 0x18, 0x19, 0x0a,
+    0x47, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x03, 0x12, 0x04, 0xa5, 0x02, 0x02, 0x13, 0x1a, 0x39, 0x20,
+    0x53, 0x74, 0x61, 0x67, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74,
+    0x20, 0x77, 0x61, 0x73, 0x20, 0x65, 0x76, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x65, 0x64, 0x20, 0x69,
+    0x6e, 0x3a, 0x20, 0x22, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x22, 0x20, 0x6f, 0x72, 0x20, 0x22,
+    0x61, 0x66, 0x74, 0x65, 0x72, 0x22, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03,
+    0x05, 0x12, 0x04, 0xa5, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x01,
+    0x12, 0x04, 0xa5, 0x02, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x03, 0x12,
+    0x04, 0xa5, 0x02, 0x11, 0x12, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x04, 0x12, 0x04, 0xa8,
+    0x02, 0x02, 0x1a, 0x1a, 0x1d, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x20, 0x70, 0x61, 0x74, 0x74, 0x65,
+    0x72, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x05, 0x12, 0x04, 0xa8, 0x02, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa8, 0x02, 0x09, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x03, 0x12, 0x04, 0xa8, 0x02, 0x18, 0x19, 0x0a,
+    0x32, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x05, 0x12, 0x04, 0xab, 0x02, 0x02, 0x17, 0x1a, 0x24, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x27, 0x73, 0x20, 0x63, 0x6f, 0x6e,
+    0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x65, 0x78, 0x70, 0x72, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x05, 0x12, 0x04, 0xab, 0x02,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x01, 0x12, 0x04, 0xab, 0x02, 0x09,
+    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x05, 0x03, 0x12, 0x04, 0xab, 0x02, 0x15, 0x16,
+    0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x06, 0x12, 0x04, 0xae, 0x02, 0x02, 0x19, 0x1a, 0x1d,
+    0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e,
+    0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x68, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x06, 0x05, 0x12, 0x04, 0xae, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x06, 0x01, 0x12, 0x04, 0xae, 0x02, 0x07, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x06, 0x03, 0x12, 0x04, 0xae, 0x02, 0x17, 0x18, 0x0a, 0x24, 0x0a, 0x04, 0x04, 0x0d,
+    0x02, 0x07, 0x12, 0x04, 0xb1, 0x02, 0x02, 0x16, 0x1a, 0x16, 0x20, 0x57, 0x68, 0x61, 0x74, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x64, 0x69, 0x64, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x06, 0x12, 0x04, 0xb1, 0x02, 0x02, 0x09, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x01, 0x12, 0x04, 0xb1, 0x02, 0x0a, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x03, 0x12, 0x04, 0xb1, 0x02, 0x14, 0x15, 0x0a, 0x7f, 0x0a,
+    0x02, 0x04, 0x0e, 0x12, 0x06, 0xb7, 0x02, 0x00, 0xe5, 0x02, 0x01, 0x1a, 0x71, 0x20, 0x52, 0x65,
+    0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x3a, 0x20, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c,
+    0x0a, 0x20, 0x41, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x68, 0x65,
+    0x6c, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x5f,
+    0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20,
+    0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x75, 0x6e, 0x74, 0x69, 0x6c, 0x20, 0x61, 0x20, 0x70,
+    0x65, 0x72, 0x73, 0x6f, 0x6e, 0x0a, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x73, 0x20,
+    0x6f, 0x72, 0x20, 0x64, 0x65, 0x6e, 0x69, 0x65, 0x73, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0xb7, 0x02, 0x08, 0x10, 0x0a, 0x31, 0x0a, 0x04, 0x04,
+    0x0e, 0x04, 0x00, 0x12, 0x06, 0xb9, 0x02, 0x02, 0xc8, 0x02, 0x03, 0x1a, 0x21, 0x20, 0x4c, 0x69,
+    0x66, 0x65, 0x63, 0x79, 0x63, 0x6c, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x61, 0x6e, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x04, 0x00, 0x01, 0x12, 0x04, 0xb9, 0x02, 0x07, 0x0c, 0x0a, 0x24, 0x0a,
+    0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xbb, 0x02, 0x04, 0x1a, 0x1a, 0x14, 0x20,
+    0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xbb, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12,
+    0x04, 0xbb, 0x02, 0x18, 0x19, 0x0a, 0x29, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x12,
+    0x04, 0xbe, 0x02, 0x04, 0x10, 0x1a, 0x19, 0x20, 0x57, 0x61, 0x69, 0x74, 0x69, 0x6e, 0x67, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x0a,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xbe, 0x02, 0x04,
+    0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xbe, 0x02,
+    0x0e, 0x0f, 0x0a, 0x37, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xc1, 0x02,
+    0x04, 0x11, 0x1a, 0x27, 0x20, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x3b, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x68, 0x65, 0x6c, 0x64, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x6d, 0x61, 0x79,
+    0x20, 0x72, 0x75, 0x6e, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0e, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc1, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xc1, 0x02, 0x0f, 0x10, 0x0a, 0x45, 0x0a,
+    0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xc4, 0x02, 0x04, 0x0f, 0x1a, 0x35, 0x20,
+    0x44, 0x65, 0x6e, 0x69, 0x65, 0x64, 0x3b, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x65, 0x6c, 0x64,
+    0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x20, 0x70, 0x65, 0x72, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x64, 0x65, 0x6e, 0x69,
+    0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0xc4, 0x02, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x02,
+    0x12, 0x04, 0xc4, 0x02, 0x0d, 0x0e, 0x0a, 0x45, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04,
+    0x12, 0x04, 0xc7, 0x02, 0x04, 0x10, 0x1a, 0x35, 0x20, 0x4e, 0x6f, 0x20, 0x64, 0x65, 0x63, 0x69,
+    0x73, 0x69, 0x6f, 0x6e, 0x20, 0x77, 0x61, 0x73, 0x20, 0x6d, 0x61, 0x64, 0x65, 0x20, 0x62, 0x65,
+    0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61,
+    0x6c, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x64, 0x20, 0x6f, 0x75, 0x74, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc7, 0x02, 0x04, 0x0b, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xc7, 0x02, 0x0e, 0x0f, 0x0a,
+    0x38, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x12, 0x1a, 0x2a, 0x20,
+    0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x3a, 0x20, 0x22,
+    0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x2f, 0x7b, 0x61, 0x70, 0x70, 0x72, 0x6f,
+    0x76, 0x61, 0x6c, 0x5f, 0x69, 0x64, 0x7d, 0x22, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x00, 0x05, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xcb, 0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xcb, 0x02, 0x10, 0x11, 0x0a, 0x49, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x04,
+    0xce, 0x02, 0x02, 0x12, 0x1a, 0x3b, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20,
+    0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c,
+    0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x64, 0x3a, 0x20, 0x22,
+    0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2f, 0x7b, 0x74, 0x6f, 0x6f, 0x6c, 0x5f, 0x69, 0x64, 0x7d, 0x22,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x05, 0x12, 0x04, 0xce, 0x02, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0xce, 0x02, 0x09, 0x0d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0xce, 0x02, 0x10, 0x11, 0x0a, 0x4a,
+    0x0a, 0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0xd1, 0x02, 0x02, 0x23, 0x1a, 0x3c, 0x20, 0x54,
+    0x6f, 0x6f, 0x6c, 0x20, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x70,
+    0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x20, 0x61, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x73, 0x20, 0x74,
+    0x6f, 0x2c, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20,
+    0x72, 0x65, 0x77, 0x72, 0x69, 0x74, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x02, 0x06, 0x12, 0x04, 0xd1, 0x02, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xd1, 0x02, 0x19, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xd1, 0x02, 0x21, 0x22, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x03, 0x12,
+    0x04, 0xd4, 0x02, 0x02, 0x18, 0x1a, 0x1f, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x77, 0x61, 0x73, 0x20, 0x6d, 0x61, 0x64,
+    0x65, 0x20, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x05, 0x12,
+    0x04, 0xd4, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0xd4, 0x02, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x03, 0x12, 0x04, 0xd4,
+    0x02, 0x16, 0x17, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12, 0x04, 0xd7, 0x02, 0x02,
+    0x14, 0x1a, 0x23, 0x20, 0x57, 0x68, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69,
+    0x63, 0x79, 0x20, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x73, 0x20, 0x61, 0x70, 0x70, 0x72,
+    0x6f, 0x76, 0x61, 0x6c, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x05, 0x12,
+    0x04, 0xd7, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x01, 0x12, 0x04,
+    0xd7, 0x02, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x03, 0x12, 0x04, 0xd7,
+    0x02, 0x12, 0x13, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x05, 0x12, 0x04, 0xda, 0x02, 0x02,
+    0x12, 0x1a, 0x10, 0x20, 0x43, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x06, 0x12, 0x04, 0xda, 0x02,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x01, 0x12, 0x04, 0xda, 0x02, 0x08,
+    0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x03, 0x12, 0x04, 0xda, 0x02, 0x10, 0x11,
+    0x0a, 0x44, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x06, 0x12, 0x04, 0xdd, 0x02, 0x02, 0x1b, 0x1a, 0x36,
+    0x20, 0x43, 0x72, 0x65, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x2c, 0x20,
+    0x69, 0x6e, 0x20, 0x6d, 0x69, 0x6c, 0x6c, 0x69, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20,
+    0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x55, 0x6e, 0x69, 0x78, 0x20, 0x65,
+    0x70, 0x6f, 0x63, 0x68, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x05, 0x12,
+    0x04, 0xdd, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x01, 0x12, 0x04,
+    0xdd, 0x02, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x03, 0x12, 0x04, 0xdd,
+    0x02, 0x19, 0x1a, 0x0a, 0x62, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x07, 0x12, 0x04, 0xe1, 0x02, 0x02,
+    0x1b, 0x1a, 0x54, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x70, 0x70,
+    0x72, 0x6f, 0x76, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x20, 0x69, 0x66,
+    0x20, 0x73, 0x74, 0x69, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x2c, 0x20,
+    0x69, 0x6e, 0x20, 0x6d, 0x69, 0x6c, 0x6c, 0x69, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20,
+    0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x55, 0x6e, 0x69, 0x78, 0x20,
+    0x65, 0x70, 0x6f, 0x63, 0x68, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x07, 0x05,
+    0x12, 0x04, 0xe1, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x07, 0x01, 0x12,
+    0x04, 0xe1, 0x02, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x07, 0x03, 0x12, 0x04,
+    0xe1, 0x02, 0x19, 0x1a, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x08, 0x12, 0x04, 0xe4, 0x02,
+    0x02, 0x19, 0x1a, 0x31, 0x20, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x20, 0x67, 0x69, 0x76, 0x65,
+    0x6e, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e, 0x20,
+    0x77, 0x68, 0x6f, 0x20, 0x64, 0x65, 0x6e, 0x69, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x05, 0x12, 0x04,
+    0xe4, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x01, 0x12, 0x04, 0xe4,
+    0x02, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x03, 0x12, 0x04, 0xe4, 0x02,
+    0x17, 0x18, 0x0a, 0x29, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x06, 0xe8, 0x02, 0x00, 0xeb, 0x02, 0x01,
+    0x1a, 0x1b, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x45, 0x6e, 0x64, 0x53, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0xe8, 0x02, 0x08, 0x19, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x0f,
+    0x02, 0x00, 0x12, 0x04, 0xea, 0x02, 0x02, 0x18, 0x1a, 0x40, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x70, 0x72,
+    0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x78, 0x2d, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2d, 0x69,
+    0x64, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xea, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xea, 0x02, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xea, 0x02, 0x16, 0x17, 0x0a, 0x28, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x04, 0xee,
+    0x02, 0x00, 0x1d, 0x1a, 0x1c, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x45, 0x6e, 0x64,
+    0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0xee, 0x02, 0x08, 0x1a, 0x0a, 0x2a,
+    0x0a, 0x02, 0x04, 0x11, 0x12, 0x04, 0xf1, 0x02, 0x00, 0x1f, 0x1a, 0x1e, 0x20, 0x43, 0x75, 0x73,
+    0x74, 0x6f, 0x6d, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c,
+    0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11,
+    0x01, 0x12, 0x04, 0xf1, 0x02, 0x08, 0x1c, 0x0a, 0x2d, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0xf4,
+    0x02, 0x00, 0xf7, 0x02, 0x01, 0x1a, 0x1f, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x4c,
+    0x69, 0x73, 0x74, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x20, 0x72, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04, 0xf4,
+    0x02, 0x08, 0x1d, 0x0a, 0x30, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0xf6, 0x02, 0x02,
+    0x22, 0x1a, 0x22, 0x20, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x70, 0x70, 0x72,
+    0x6f, 0x76, 0x61, 0x6c, 0x73, 0x2c, 0x20, 0x6f, 0x6c, 0x64, 0x65, 0x73, 0x74, 0x20, 0x66, 0x69,
+    0x72, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0xf6, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x06, 0x12, 0x04, 0xf6,
+    0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf6, 0x02,
+    0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf6, 0x02, 0x20,
+    0x21, 0x0a, 0x2a, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0xfa, 0x02, 0x00, 0xfd, 0x02, 0x01, 0x1a,
+    0x1c, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65,
+    0x43, 0x61, 0x6c, 0x6c, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0xfa, 0x02, 0x08, 0x1a, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x13,
+    0x02, 0x00, 0x12, 0x04, 0xfc, 0x02, 0x02, 0x12, 0x1a, 0x2a, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75,
+    0x72, 0x63, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x3a, 0x20, 0x22, 0x61, 0x70, 0x70, 0x72, 0x6f,
+    0x76, 0x61, 0x6c, 0x73, 0x2f, 0x7b, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x5f, 0x69,
+    0x64, 0x7d, 0x22, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0xfc,
+    0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfc, 0x02,
+    0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfc, 0x02, 0x10,
+    0x11, 0x0a, 0x27, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0x80, 0x03, 0x00, 0x86, 0x03, 0x01, 0x1a,
+    0x19, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x44, 0x65, 0x6e, 0x79, 0x43, 0x61, 0x6c,
+    0x6c, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14,
+    0x01, 0x12, 0x04, 0x80, 0x03, 0x08, 0x17, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12,
+    0x04, 0x82, 0x03, 0x02, 0x12, 0x1a, 0x2a, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65,
+    0x20, 0x6e, 0x61, 0x6d, 0x65, 0x3a, 0x20, 0x22, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c,
+    0x73, 0x2f, 0x7b, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x5f, 0x69, 0x64, 0x7d, 0x22,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x04, 0x82, 0x03, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0x82, 0x03, 0x09, 0x0d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0x82, 0x03, 0x10, 0x11, 0x0a, 0x37,
+    0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0x85, 0x03, 0x02, 0x14, 0x1a, 0x29, 0x20, 0x4f,
+    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x20, 0x72,
+    0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x61, 0x6c, 0x6c, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0x85, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x85, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x85, 0x03, 0x12, 0x13, 0x0a, 0x47, 0x0a, 0x02, 0x04, 0x15, 0x12, 0x06, 0x89, 0x03, 0x00, 0xa4,
+    0x03, 0x01, 0x1a, 0x39, 0x20, 0x57, 0x68, 0x61, 0x74, 0x20, 0x61, 0x20, 0x73, 0x68, 0x61, 0x64,
+    0x6f, 0x77, 0x2d, 0x6d, 0x6f, 0x64, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x77,
+    0x6f, 0x75, 0x6c, 0x64, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x64, 0x6f, 0x6e, 0x65, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x15, 0x01, 0x12, 0x04, 0x89, 0x03, 0x08, 0x19, 0x0a, 0x2a, 0x0a, 0x04, 0x04, 0x15,
+    0x02, 0x00, 0x12, 0x04, 0x8b, 0x03, 0x02, 0x14, 0x1a, 0x1c, 0x20, 0x4e, 0x61, 0x6d, 0x65, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x20, 0x70, 0x6f,
+    0x6c, 0x69, 0x63, 0x79, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0x8b, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x8b, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8b,
+    0x03, 0x12, 0x13, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x01, 0x12, 0x04, 0x8e, 0x03, 0x02,
+    0x12, 0x1a, 0x2e, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20, 0x6e, 0x61, 0x6d,
+    0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x3a, 0x20, 0x22,
+    0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2f, 0x7b, 0x74, 0x6f, 0x6f, 0x6c, 0x5f, 0x69, 0x64, 0x7d, 0x22,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8e, 0x03, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8e, 0x03, 0x09, 0x0d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8e, 0x03, 0x10, 0x11, 0x0a, 0x50,
+    0x0a, 0x04, 0x04, 0x15, 0x02, 0x02, 0x12, 0x04, 0x91, 0x03, 0x02, 0x19, 0x1a, 0x42, 0x20, 0x45,
+    0x76, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68,
+    0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x68,
+    0x61, 0x64, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x6d, 0x61, 0x74,
+    0x63, 0x68, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x05, 0x12, 0x04, 0x91, 0x03, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x01, 0x12, 0x04, 0x91, 0x03, 0x09, 0x14, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x03, 0x12, 0x04, 0x91, 0x03, 0x17, 0x18, 0x0a, 0x33, 0x0a,
+    0x04, 0x04, 0x15, 0x02, 0x03, 0x12, 0x04, 0x94, 0x03, 0x02, 0x15, 0x1a, 0x25, 0x20, 0x43, 0x61,
+    0x6c, 0x6c, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x77,
+    0x6f, 0x75, 0x6c, 0x64, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x64, 0x65, 0x6e, 0x69, 0x65, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x05, 0x12, 0x04, 0x94, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x01, 0x12, 0x04, 0x94, 0x03, 0x09, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x03, 0x12, 0x04, 0x94, 0x03, 0x13, 0x14, 0x0a,
+    0x3e, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x04, 0x12, 0x04, 0x97, 0x03, 0x02, 0x17, 0x1a, 0x30, 0x20,
+    0x43, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+    0x20, 0x77, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x68, 0x65, 0x6c, 0x64,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x05, 0x12, 0x04, 0x97, 0x03, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x01, 0x12, 0x04, 0x97, 0x03, 0x09, 0x12, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x15, 0x02, 0x04, 0x03, 0x12, 0x04, 0x97, 0x03, 0x15, 0x16, 0x0a, 0x44, 0x0a, 0x04,
+    0x04, 0x15, 0x02, 0x05, 0x12, 0x04, 0x9a, 0x03, 0x02, 0x1d, 0x1a, 0x36, 0x20, 0x45, 0x76, 0x61,
+    0x6c, 0x75, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x6f,
+    0x75, 0x6c, 0x64, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64,
+    0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x05, 0x05, 0x12, 0x04, 0x9a, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x05, 0x01, 0x12, 0x04, 0x9a, 0x03, 0x09, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x05, 0x03, 0x12, 0x04, 0x9a, 0x03, 0x1b, 0x1c, 0x0a,
+    0x42, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x06, 0x12, 0x04, 0x9d, 0x03, 0x02, 0x1c, 0x1a, 0x34, 0x20,
+    0x43, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x69, 0x6e, 0x70, 0x75,
+    0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x77, 0x6f, 0x75,
+    0x6c, 0x64, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x72, 0x65, 0x77, 0x72, 0x69, 0x74, 0x74, 0x65,
+    0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x06, 0x05, 0x12, 0x04, 0x9d, 0x03,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x06, 0x01, 0x12, 0x04, 0x9d, 0x03, 0x09,
+    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x06, 0x03, 0x12, 0x04, 0x9d, 0x03, 0x1a, 0x1b,
+    0x0a, 0x51, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x07, 0x12, 0x04, 0xa0, 0x03, 0x02, 0x1c, 0x1a, 0x43,
+    0x20, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c,
+    0x69, 0x63, 0x79, 0x20, 0x77, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x72,
+    0x65, 0x73, 0x68, 0x61, 0x70, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61,
+    0x63, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x72, 0x72, 0x6f,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x07, 0x05, 0x12, 0x04, 0xa0, 0x03,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x07, 0x01, 0x12, 0x04, 0xa0, 0x03, 0x09,
+    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x07, 0x03, 0x12, 0x04, 0xa0, 0x03, 0x1a, 0x1b,
+    0x0a, 0x44, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x08, 0x12, 0x04, 0xa3, 0x03, 0x02, 0x14, 0x1a, 0x36,
+    0x20, 0x45, 0x76, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20,
+    0x43, 0x45, 0x4c, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65, 0x77, 0x72, 0x69, 0x74, 0x65, 0x20, 0x65,
+    0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x08, 0x05, 0x12,
+    0x04, 0xa3, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x08, 0x01, 0x12, 0x04,
+    0xa3, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x08, 0x03, 0x12, 0x04, 0xa3,
+    0x03, 0x12, 0x13, 0x0a, 0x2c, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x04, 0xa7, 0x03, 0x00, 0x21, 0x1a,
+    0x20, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x47, 0x65, 0x74, 0x53, 0x68, 0x61, 0x64,
+    0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xa7, 0x03, 0x08, 0x1e, 0x0a, 0x2f,
+    0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xaa, 0x03, 0x00, 0xad, 0x03, 0x01, 0x1a, 0x21, 0x20, 0x43,
+    0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x47, 0x65, 0x74, 0x53, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x52,
+    0x65, 0x70, 0x6f, 0x72, 0x74, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12, 0x04, 0xaa, 0x03, 0x08, 0x1f, 0x0a, 0x35, 0x0a, 0x04,
+    0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0xac, 0x03, 0x02, 0x29, 0x1a, 0x27, 0x20, 0x45, 0x6e, 0x74,
+    0x72, 0x69, 0x65, 0x73, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
+    0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x6f, 0x6f,
+    0x6c, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x04, 0x12, 0x04, 0xac, 0x03,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x06, 0x12, 0x04, 0xac, 0x03, 0x0b,
+    0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0xac, 0x03, 0x1d, 0x24,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xac, 0x03, 0x27, 0x28, 0x0a,
+    0x46, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xb1, 0x03, 0x00, 0xc0, 0x03, 0x01, 0x1a, 0x38, 0x20,
+    0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x3a, 0x20, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+    0x0a, 0x20, 0x41, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73,
+    0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x65, 0x72, 0x76, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12, 0x04,
+    0xb1, 0x03, 0x08, 0x0e, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x00, 0x12, 0x04, 0xb3, 0x03,
+    0x02, 0x12, 0x1a, 0x29, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20, 0x6e, 0x61,
+    0x6d, 0x65, 0x3a, 0x20, 0x22, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x2f, 0x7b, 0x70,
+    0x6f, 0x6c, 0x69, 0x63, 0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x7d, 0x22, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x18, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb3, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb3, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb3, 0x03, 0x10, 0x11, 0x0a, 0x26, 0x0a, 0x04, 0x04, 0x18,
+    0x02, 0x01, 0x12, 0x04, 0xb6, 0x03, 0x02, 0x15, 0x1a, 0x18, 0x20, 0x56, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb6, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb6, 0x03, 0x09, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb6, 0x03, 0x13, 0x14, 0x0a,
+    0x52, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x02, 0x12, 0x04, 0xb9, 0x03, 0x02, 0x15, 0x1a, 0x44, 0x20,
+    0x45, 0x76, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72,
+    0x69, 0x74, 0x79, 0x3b, 0x20, 0x68, 0x69, 0x67, 0x68, 0x65, 0x72, 0x2d, 0x70, 0x72, 0x69, 0x6f,
+    0x72, 0x69, 0x74, 0x79, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x20, 0x61, 0x72,
+    0x65, 0x20, 0x65, 0x76, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x65, 0x64, 0x20, 0x66, 0x69, 0x72, 0x73,
+    0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x05, 0x12, 0x04, 0xb9, 0x03,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb9, 0x03, 0x08,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb9, 0x03, 0x13, 0x14,
+    0x0a, 0x57, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x03, 0x12, 0x04, 0xbc, 0x03, 0x02, 0x12, 0x1a, 0x49,
+    0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c,
+    0x69, 0x63, 0x79, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x64, 0x20,
+    0x6f, 0x72, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x6f, 0x62, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64,
+    0x3a, 0x20, 0x22, 0x65, 0x6e, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x22, 0x20, 0x6f, 0x72, 0x20, 0x22,
+    0x73, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x22, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
+    0x03, 0x05, 0x12, 0x04, 0xbc, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03,
+    0x01, 0x12, 0x04, 0xbc, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x03,
+    0x12, 0x04, 0xbc, 0x03, 0x10, 0x11, 0x0a, 0x53, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x04, 0x12, 0x04,
+    0xbf, 0x03, 0x02, 0x18, 0x1a, 0x45, 0x20, 0x54, 0x68, 0x65, 0x20, 0x66, 0x75, 0x6c, 0x6c, 0x20,
+    0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x4a, 0x53, 0x4f, 0x4e,
+    0x20, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x2c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x70, 0x6f,
+    0x6c, 0x69, 0x63, 0x79, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x04, 0x05, 0x12, 0x04, 0xbf, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0xbf, 0x03, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0xbf, 0x03, 0x16, 0x17, 0x0a, 0x23, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x04,
+    0xc3, 0x03, 0x00, 0x1e, 0x1a, 0x17, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20,
+    0x4c, 0x69, 0x73, 0x74, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x19, 0x01, 0x12, 0x04, 0xc3, 0x03, 0x08, 0x1b, 0x0a, 0x26, 0x0a, 0x02, 0x04, 0x1a,
+    0x12, 0x06, 0xc6, 0x03, 0x00, 0xc9, 0x03, 0x01, 0x1a, 0x18, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64,
+    0x61, 0x72, 0x64, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0xc6, 0x03, 0x08, 0x1c, 0x0a,
+    0x39, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0xc8, 0x03, 0x02, 0x1f, 0x1a, 0x2b, 0x20,
+    0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+    0x69, 0x65, 0x73, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x65, 0x76, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0xc8, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xc8, 0x03, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xc8, 0x03, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xc8, 0x03, 0x1d, 0x1e, 0x0a, 0x24, 0x0a, 0x02, 0x04, 0x1b, 0x12, 0x06, 0xcc, 0x03,
+    0x00, 0xcf, 0x03, 0x01, 0x1a, 0x16, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20,
+    0x47, 0x65, 0x74, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x1b, 0x01, 0x12, 0x04, 0xcc, 0x03, 0x08, 0x18, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x1b, 0x02,
+    0x00, 0x12, 0x04, 0xce, 0x03, 0x02, 0x12, 0x1a, 0x29, 0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72,
+    0x63, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x3a, 0x20, 0x22, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69,
+    0x65, 0x73, 0x2f, 0x7b, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x7d,
+    0x22, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xce, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xce, 0x03, 0x09, 0x0d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12, 0x04, 0xce, 0x03, 0x10, 0x11, 0x0a,
+    0x25, 0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0xd2, 0x03, 0x00, 0xd6, 0x03, 0x01, 0x1a, 0x17, 0x20,
+    0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x55, 0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x72, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0xd2,
+    0x03, 0x08, 0x1b, 0x0a, 0x78, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x00, 0x12, 0x04, 0xd5, 0x03, 0x02,
+    0x18, 0x1a, 0x6a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x61,
+    0x73, 0x20, 0x61, 0x20, 0x4a, 0x53, 0x4f, 0x4e, 0x20, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x2c,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73,
+    0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x66, 0x69, 0x6c,
+    0x65, 0x2e, 0x20, 0x41, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x0a, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20,
+    0x69, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1c, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd5, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd5, 0x03, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd5, 0x03, 0x16, 0x17, 0x0a, 0x27, 0x0a, 0x02, 0x04, 0x1d,
+    0x12, 0x06, 0xd9, 0x03, 0x00, 0xdc, 0x03, 0x01, 0x1a, 0x19, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64,
+    0x61, 0x72, 0x64, 0x20, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0xd9, 0x03, 0x08, 0x1b,
+    0x0a, 0x37, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0xdb, 0x03, 0x02, 0x12, 0x1a, 0x29,
+    0x20, 0x52, 0x65, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x3a, 0x20,
+    0x22, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x2f, 0x7b, 0x70, 0x6f, 0x6c, 0x69, 0x63,
+    0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x7d, 0x22, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02,
+    0x00, 0x05, 0x12, 0x04, 0xdb, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xdb, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xdb, 0x03, 0x10, 0x11, 0x0a, 0x26, 0x0a, 0x02, 0x04, 0x1e, 0x12, 0x04, 0xdf, 0x03,
+    0x00, 0x1f, 0x1a, 0x1a, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x44, 0x65,
+    0x6c, 0x65, 0x74, 0x65, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04, 0xdf, 0x03, 0x08, 0x1c, 0x0a, 0x30, 0x0a, 0x02, 0x04,
+    0x1f, 0x12, 0x06, 0xe2, 0x03, 0x00, 0xe5, 0x03, 0x01, 0x1a, 0x22, 0x20, 0x43, 0x75, 0x73, 0x74,
+    0x6f, 0x6d, 0x20, 0x47, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e,
+    0x74, 0x65, 0x78, 0x74, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x1f, 0x01, 0x12, 0x04, 0xe2, 0x03, 0x08, 0x20, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x1f,
+    0x02, 0x00, 0x12, 0x04, 0xe4, 0x03, 0x02, 0x18, 0x1a, 0x40, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x70, 0x72,
+    0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x78, 0x2d, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2d, 0x69,
+    0x64, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xe4, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xe4, 0x03, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xe4, 0x03, 0x16, 0x17, 0x0a, 0x31, 0x0a, 0x02, 0x04, 0x20, 0x12, 0x06, 0xe8,
+    0x03, 0x00, 0xeb, 0x03, 0x01, 0x1a, 0x23, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x47,
+    0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
+    0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x20,
+    0x01, 0x12, 0x04, 0xe8, 0x03, 0x08, 0x21, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x00, 0x12,
+    0x04, 0xea, 0x03, 0x02, 0x25, 0x1a, 0x47, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x27, 0x73, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x78, 0x74, 0x20, 0x76, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x2e, 0x20,
+    0x45, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x20, 0x75, 0x6e, 0x6b,
+    0x6e, 0x6f, 0x77, 0x6e, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x06, 0x12, 0x04, 0xea, 0x03, 0x02, 0x18, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x20, 0x02, 0x00, 0x01, 0x12, 0x04, 0xea, 0x03, 0x19, 0x20, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x20, 0x02, 0x00, 0x03, 0x12, 0x04, 0xea, 0x03, 0x23, 0x24, 0x0a, 0x2b, 0x0a, 0x02, 0x04,
+    0x21, 0x12, 0x06, 0xee, 0x03, 0x00, 0xf1, 0x03, 0x01, 0x1a, 0x1d, 0x20, 0x43, 0x75, 0x73, 0x74,
+    0x6f, 0x6d, 0x20, 0x52, 0x65, 0x73, 0x65, 0x74, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x21, 0x01, 0x12,
+    0x04, 0xee, 0x03, 0x08, 0x1b, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x00, 0x12, 0x04, 0xf0,
+    0x03, 0x02, 0x18, 0x1a, 0x40, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x64,
+    0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75,
+    0x73, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x78, 0x2d, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2d, 0x69, 0x64, 0x20, 0x68, 0x65, 0x61,
+    0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x05, 0x12, 0x04,
+    0xf0, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf0,
+    0x03, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf0, 0x03,
+    0x16, 0x17, 0x0a, 0x2a, 0x0a, 0x02, 0x04, 0x22, 0x12, 0x04, 0xf4, 0x03, 0x00, 0x1f, 0x1a, 0x1e,
+    0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x52, 0x65, 0x73, 0x65, 0x74, 0x53, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x22, 0x01, 0x12, 0x04, 0xf4, 0x03, 0x08, 0x1c, 0x0a, 0x27, 0x0a, 0x02, 0x06,
+    0x00, 0x12, 0x06, 0xf7, 0x03, 0x00, 0x93, 0x04, 0x01, 0x1a, 0x19, 0x20, 0x4f, 0x70, 0x65, 0x72,
+    0x61, 0x69, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x04, 0xf7, 0x03, 0x08,
+    0x0f, 0x0a, 0x41, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x04, 0xfa, 0x03, 0x02, 0x3e, 0x1a,
+    0x33, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x61, 0x76, 0x61, 0x69,
+    0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x73, 0x2e, 0x0a, 0x20, 0x53, 0x74,
+    0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x74, 0x68,
+    0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfa,
+    0x03, 0x06, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xfa, 0x03,
+    0x10, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfa, 0x03, 0x2b,
+    0x3c, 0x0a, 0x4d, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0xfe, 0x03, 0x02, 0x44, 0x1a,
     0x3f, 0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x73,
     0x20, 0x62, 0x79, 0x20, 0x73, 0x65, 0x6d, 0x61, 0x6e, 0x74, 0x69, 0x63, 0x20, 0x73, 0x69, 0x6d,
     0x69, 0x6c, 0x61, 0x72, 0x69, 0x74, 0x79, 0x2e, 0x0a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d,
     0x20, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a,
-    0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x82, 0x01, 0x06, 0x11, 0x0a,
-    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x82, 0x01, 0x12, 0x24, 0x0a, 0x0d,
-    0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0x82, 0x01, 0x2f, 0x42, 0x0a, 0xa2, 0x03,
-    0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12, 0x04, 0x8d, 0x01, 0x02, 0x3b, 0x1a, 0x93, 0x03, 0x20,
+    0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfe, 0x03, 0x06, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xfe, 0x03, 0x12, 0x24, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfe, 0x03, 0x2f, 0x42, 0x0a, 0x94, 0x07,
+    0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12, 0x04, 0x92, 0x04, 0x02, 0x3b, 0x1a, 0x85, 0x07, 0x20,
     0x43, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x77, 0x69, 0x74,
     0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x70, 0x75,
     0x74, 0x2e, 0x0a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x54,
@@ -512,10 +2092,173 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x45, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x3a, 0x20, 0x78, 0x2d, 0x63, 0x72, 0x65, 0x64, 0x65,
     0x6e, 0x74, 0x69, 0x61, 0x6c, 0x2d, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x3a, 0x20, 0x65, 0x79,
     0x4a, 0x32, 0x59, 0x57, 0x78, 0x31, 0x5a, 0x58, 0x4d, 0x69, 0x4f, 0x6e, 0x73, 0x69, 0x2e, 0x2e,
-    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x06,
-    0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x8d, 0x01, 0x0f, 0x1e,
-    0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8d, 0x01, 0x29, 0x39, 0x62,
-    0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x2e, 0x0a, 0x20, 0x20, 0x20, 0x78, 0x2d, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x2d,
+    0x6d, 0x6f, 0x64, 0x65, 0x3a, 0x20, 0x22, 0x64, 0x65, 0x66, 0x65, 0x72, 0x22, 0x20, 0x74, 0x6f,
+    0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x5f,
+    0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x65, 0x61, 0x64,
+    0x20, 0x6f, 0x66, 0x20, 0x77, 0x61, 0x69, 0x74, 0x69, 0x6e, 0x67, 0x0a, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x72, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x73, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x2e,
+    0x0a, 0x20, 0x20, 0x20, 0x78, 0x2d, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x2d, 0x74, 0x72, 0x61,
+    0x63, 0x65, 0x3a, 0x20, 0x22, 0x74, 0x72, 0x75, 0x65, 0x22, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65,
+    0x74, 0x75, 0x72, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x61, 0x6c, 0x75, 0x61, 0x74,
+    0x65, 0x64, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74,
+    0x73, 0x20, 0x69, 0x6e, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x5f, 0x74,
+    0x72, 0x61, 0x63, 0x65, 0x20, 0x28, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x44, 0x65, 0x6e, 0x69, 0x61, 0x6c, 0x20, 0x64, 0x65, 0x74,
+    0x61, 0x69, 0x6c, 0x73, 0x29, 0x2e, 0x0a, 0x20, 0x20, 0x20, 0x78, 0x2d, 0x63, 0x61, 0x63, 0x68,
+    0x65, 0x2d, 0x6d, 0x6f, 0x64, 0x65, 0x3a, 0x20, 0x22, 0x62, 0x79, 0x70, 0x61, 0x73, 0x73, 0x22,
+    0x20, 0x74, 0x6f, 0x20, 0x72, 0x75, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6f, 0x6c,
+    0x20, 0x65, 0x76, 0x65, 0x6e, 0x20, 0x69, 0x66, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x75, 0x74,
+    0x70, 0x75, 0x74, 0x20, 0x69, 0x73, 0x20, 0x63, 0x61, 0x63, 0x68, 0x65, 0x64, 0x2c, 0x0a, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61,
+    0x63, 0x68, 0x65, 0x64, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x2e, 0x0a, 0x0a, 0x20, 0x41,
+    0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x62, 0x79,
+    0x20, 0x61, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x73, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x50, 0x45, 0x52, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4f, 0x4e, 0x5f,
+    0x44, 0x45, 0x4e, 0x49, 0x45, 0x44, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x61, 0x0a, 0x20, 0x50, 0x6f,
+    0x6c, 0x69, 0x63, 0x79, 0x44, 0x65, 0x6e, 0x69, 0x61, 0x6c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x72, 0x70, 0x63, 0x2e, 0x53, 0x74, 0x61,
+    0x74, 0x75, 0x73, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x64, 0x65, 0x74, 0x61, 0x69,
+    0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x92,
+    0x04, 0x06, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x92, 0x04,
+    0x0f, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x04, 0x92, 0x04, 0x29,
+    0x39, 0x0a, 0xfb, 0x02, 0x0a, 0x02, 0x06, 0x01, 0x12, 0x06, 0x9c, 0x04, 0x00, 0xd0, 0x04, 0x01,
+    0x1a, 0xec, 0x02, 0x20, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x69, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x62,
+    0x6f, 0x78, 0x20, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x69, 0x73, 0x74, 0x72, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x20, 0x4c, 0x65, 0x74,
+    0x73, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x73, 0x20, 0x64, 0x65, 0x63, 0x69,
+    0x64, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x68, 0x65, 0x6c, 0x64, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x2c, 0x20, 0x6d, 0x61, 0x6e, 0x61,
+    0x67, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x2c, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x69, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x74, 0x2c, 0x0a, 0x20, 0x72, 0x65, 0x73, 0x65, 0x74,
+    0x20, 0x6f, 0x72, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69,
+    0x63, 0x79, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x73, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x75, 0x74, 0x68, 0x65,
+    0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x65, 0x0a, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x73,
+    0x2c, 0x20, 0x73, 0x6f, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x73, 0x20, 0x64, 0x6f, 0x20,
+    0x6e, 0x6f, 0x74, 0x20, 0x65, 0x78, 0x70, 0x6f, 0x73, 0x65, 0x20, 0x69, 0x74, 0x20, 0x61, 0x6c,
+    0x6f, 0x6e, 0x67, 0x73, 0x69, 0x64, 0x65, 0x20, 0x54, 0x6f, 0x6f, 0x6c, 0x62, 0x6f, 0x78, 0x3a,
+    0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64, 0x20, 0x6f, 0x6e,
+    0x20, 0x69, 0x74, 0x73, 0x0a, 0x20, 0x6f, 0x77, 0x6e, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x65, 0x6e, 0x61,
+    0x62, 0x6c, 0x65, 0x64, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x6f, 0x6e,
+    0x6c, 0x79, 0x20, 0x62, 0x65, 0x20, 0x72, 0x65, 0x61, 0x63, 0x68, 0x61, 0x62, 0x6c, 0x65, 0x0a,
+    0x20, 0x62, 0x79, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x73, 0x2e, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x06, 0x01, 0x01, 0x12, 0x04, 0x9c, 0x04, 0x08, 0x14, 0x0a, 0x4b, 0x0a, 0x04,
+    0x06, 0x01, 0x02, 0x00, 0x12, 0x04, 0x9f, 0x04, 0x02, 0x4a, 0x1a, 0x3d, 0x20, 0x4c, 0x69, 0x73,
+    0x74, 0x73, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x77, 0x61,
+    0x69, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76,
+    0x61, 0x6c, 0x2e, 0x0a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x4c, 0x69, 0x73, 0x74,
+    0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x9f, 0x04, 0x06, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x00,
+    0x02, 0x12, 0x04, 0x9f, 0x04, 0x14, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0x9f, 0x04, 0x33, 0x48, 0x0a, 0x45, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x01, 0x12, 0x04,
+    0xa3, 0x04, 0x02, 0x39, 0x1a, 0x37, 0x20, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x73, 0x20,
+    0x61, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x6f, 0x6c, 0x20, 0x63,
+    0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x41, 0x70, 0x70,
+    0x72, 0x6f, 0x76, 0x65, 0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x01, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa3, 0x04, 0x06, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x06, 0x01, 0x02, 0x01, 0x02, 0x12, 0x04, 0xa3, 0x04, 0x12, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x06,
+    0x01, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa3, 0x04, 0x2f, 0x37, 0x0a, 0x40, 0x0a, 0x04, 0x06, 0x01,
+    0x02, 0x02, 0x12, 0x04, 0xa7, 0x04, 0x02, 0x33, 0x1a, 0x32, 0x20, 0x44, 0x65, 0x6e, 0x69, 0x65,
+    0x73, 0x20, 0x61, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x6f, 0x6c,
+    0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x44,
+    0x65, 0x6e, 0x79, 0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x06, 0x01, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa7, 0x04, 0x06, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x06,
+    0x01, 0x02, 0x02, 0x02, 0x12, 0x04, 0xa7, 0x04, 0x0f, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xa7, 0x04, 0x29, 0x31, 0x0a, 0x86, 0x01, 0x0a, 0x04, 0x06, 0x01,
+    0x02, 0x03, 0x12, 0x04, 0xac, 0x04, 0x02, 0x50, 0x1a, 0x78, 0x20, 0x52, 0x65, 0x70, 0x6f, 0x72,
+    0x74, 0x73, 0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x73, 0x68, 0x61, 0x64, 0x6f, 0x77, 0x2d, 0x6d,
+    0x6f, 0x64, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x20, 0x77, 0x6f, 0x75,
+    0x6c, 0x64, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x64, 0x6f, 0x6e, 0x65, 0x2c, 0x20, 0x70, 0x65,
+    0x72, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x6f, 0x6f,
+    0x6c, 0x2c, 0x0a, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x72, 0x76, 0x65, 0x72, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x20, 0x43,
+    0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x47, 0x65, 0x74, 0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x03, 0x01, 0x12, 0x04, 0xac, 0x04, 0x06,
+    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x03, 0x02, 0x12, 0x04, 0xac, 0x04, 0x16, 0x2c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x03, 0x03, 0x12, 0x04, 0xac, 0x04, 0x37, 0x4e, 0x0a,
+    0x45, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x04, 0x12, 0x04, 0xb0, 0x04, 0x02, 0x47, 0x1a, 0x37, 0x20,
+    0x4c, 0x69, 0x73, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74,
+    0x65, 0x72, 0x65, 0x64, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x69, 0x65, 0x73, 0x2e, 0x0a, 0x20,
+    0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x4c, 0x69, 0x73, 0x74, 0x20, 0x6d, 0x65,
+    0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x04, 0x01, 0x12,
+    0x04, 0xb0, 0x04, 0x06, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x04, 0x02, 0x12, 0x04,
+    0xb0, 0x04, 0x13, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x04, 0x03, 0x12, 0x04, 0xb0,
+    0x04, 0x31, 0x45, 0x0a, 0x3f, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x05, 0x12, 0x04, 0xb4, 0x04, 0x02,
+    0x33, 0x1a, 0x31, 0x20, 0x47, 0x65, 0x74, 0x73, 0x20, 0x61, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73,
+    0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x2e, 0x0a, 0x20, 0x53,
+    0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x47, 0x65, 0x74, 0x20, 0x6d, 0x65, 0x74, 0x68,
+    0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x05, 0x01, 0x12, 0x04, 0xb4,
+    0x04, 0x06, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x05, 0x02, 0x12, 0x04, 0xb4, 0x04,
+    0x10, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x05, 0x03, 0x12, 0x04, 0xb4, 0x04, 0x2b,
+    0x31, 0x0a, 0x8e, 0x02, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x06, 0x12, 0x04, 0xbc, 0x04, 0x02, 0x39,
+    0x1a, 0xff, 0x01, 0x20, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x73, 0x20, 0x61, 0x20,
+    0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x2c, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x69, 0x6e,
+    0x67, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x2e, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x0a, 0x20, 0x69, 0x73, 0x20, 0x76, 0x61,
+    0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x64, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x73, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x20, 0x63, 0x61, 0x6c, 0x6c,
+    0x2e, 0x0a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x55, 0x70, 0x73, 0x65, 0x72, 0x74,
+    0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x20, 0x43, 0x68, 0x61, 0x6e, 0x67,
+    0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x77, 0x72, 0x69, 0x74, 0x74,
+    0x65, 0x6e, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70,
+    0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x3a, 0x20, 0x61,
+    0x20, 0x72, 0x65, 0x6c, 0x6f, 0x61, 0x64, 0x65, 0x64, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79,
+    0x0a, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x06, 0x01, 0x12, 0x04, 0xbc, 0x04, 0x06,
+    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x06, 0x02, 0x12, 0x04, 0xbc, 0x04, 0x13, 0x26,
+    0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x06, 0x03, 0x12, 0x04, 0xbc, 0x04, 0x31, 0x37, 0x0a,
+    0x3e, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x07, 0x12, 0x04, 0xc0, 0x04, 0x02, 0x47, 0x1a, 0x30, 0x20,
+    0x55, 0x6e, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x73, 0x20, 0x61, 0x20, 0x70, 0x6f,
+    0x6c, 0x69, 0x63, 0x79, 0x2e, 0x0a, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20,
+    0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x07, 0x01, 0x12, 0x04, 0xc0, 0x04, 0x06, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x01, 0x02, 0x07, 0x02, 0x12, 0x04, 0xc0, 0x04, 0x13, 0x26, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x01, 0x02, 0x07, 0x03, 0x12, 0x04, 0xc0, 0x04, 0x31, 0x45, 0x0a, 0x49, 0x0a, 0x04,
+    0x06, 0x01, 0x02, 0x08, 0x12, 0x04, 0xc4, 0x04, 0x02, 0x56, 0x1a, 0x3b, 0x20, 0x47, 0x65, 0x74,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x78, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x2e, 0x0a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x47, 0x65, 0x74, 0x20, 0x6d,
+    0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x08, 0x01,
+    0x12, 0x04, 0xc4, 0x04, 0x06, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x08, 0x02, 0x12,
+    0x04, 0xc4, 0x04, 0x18, 0x30, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x08, 0x03, 0x12, 0x04,
+    0xc4, 0x04, 0x3b, 0x54, 0x0a, 0x62, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x09, 0x12, 0x04, 0xc8, 0x04,
+    0x02, 0x47, 0x1a, 0x54, 0x20, 0x43, 0x6c, 0x65, 0x61, 0x72, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x20, 0x6f,
+    0x66, 0x20, 0x61, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x6b, 0x65, 0x65,
+    0x70, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x74, 0x73, 0x20, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79,
+    0x2e, 0x0a, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x52, 0x65, 0x73, 0x65, 0x74, 0x20,
+    0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x09,
+    0x01, 0x12, 0x04, 0xc8, 0x04, 0x06, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x09, 0x02,
+    0x12, 0x04, 0xc8, 0x04, 0x13, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x09, 0x03, 0x12,
+    0x04, 0xc8, 0x04, 0x31, 0x45, 0x0a, 0xdc, 0x01, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x0a, 0x12, 0x04,
+    0xcf, 0x04, 0x02, 0x41, 0x1a, 0xcd, 0x01, 0x20, 0x44, 0x69, 0x73, 0x63, 0x61, 0x72, 0x64, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20,
+    0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x69, 0x74, 0x73, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x78, 0x74, 0x2c, 0x20, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x0a, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2d, 0x73, 0x63, 0x6f, 0x70, 0x65,
+    0x20, 0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x20, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x2e, 0x0a, 0x20,
+    0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x45, 0x6e, 0x64, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x20, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x2e, 0x0a, 0x0a, 0x20, 0x43, 0x61, 0x6c, 0x6c,
+    0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x64, 0x2e, 0x20,
+    0x45, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x20, 0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77,
+    0x6e, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x65,
+    0x64, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xcf,
+    0x04, 0x06, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x0a, 0x02, 0x12, 0x04, 0xcf, 0x04,
+    0x11, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x04, 0xcf, 0x04, 0x2d,
+    0x3f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("brwse.toolbox.v1alpha1.tonic.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
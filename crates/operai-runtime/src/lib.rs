@@ -9,6 +9,7 @@
 //! - **Remote Access**: gRPC and MCP transports for serving tools over the
 //!   network
 //! - **Policy Enforcement**: Pre- and post-execution policy checks
+//! - **Result Caching**: Opt-in reuse of results of read-only tools
 //!
 //! # Architecture
 //!
@@ -38,10 +39,13 @@
 //!
 //! // List available tools
 //! let tools = runtime
-//!     .list_tools(operai_runtime::proto::ListToolsRequest {
-//!         page_size: 100,
-//!         page_token: String::new(),
-//!     })
+//!     .list_tools(
+//!         operai_runtime::proto::ListToolsRequest {
+//!             page_size: 100,
+//!             page_token: String::new(),
+//!         },
+//!         operai_runtime::CallMetadata::default(),
+//!     )
 //!     .await?;
 //! # Ok(())
 //! # }
@@ -57,13 +61,39 @@
 )]
 pub mod proto {
     include!("gen/brwse/toolbox/v1alpha1/brwse.toolbox.v1alpha1.rs");
+
+    /// Names messages sent as `google.rpc.Status` details, so that they can
+    /// be packed into and unpacked from a `google.protobuf.Any`.
+    macro_rules! impl_detail_names {
+        ($($message:ident),* $(,)?) => {$(
+            impl ::prost::Name for $message {
+                const NAME: &'static str = stringify!($message);
+                const PACKAGE: &'static str = "brwse.toolbox.v1alpha1";
+
+                fn type_url() -> String {
+                    format!("type.googleapis.com/{}", <Self as ::prost::Name>::full_name())
+                }
+            }
+        )*};
+    }
+
+    impl_detail_names!(PolicyDenial, RateLimited, BudgetExceeded);
 }
 
+/// Queue of tool calls held for human approval.
+mod approval;
+
 /// Builder module for constructing configured runtime instances.
 ///
 /// See [`RuntimeBuilder`] for the main builder API.
 pub mod builder;
 
+/// Cache of the results of tool calls.
+///
+/// Provides [`ResultCache`], which keeps successful results of tools with a
+/// time to live, and its [`CacheStats`].
+pub mod cache;
+
 /// Core runtime implementations for local and remote tool execution.
 ///
 /// Provides [`Runtime`] (enum abstracting local/remote), [`LocalRuntime`]
@@ -73,7 +103,7 @@ pub mod runtime;
 /// Semantic search functionality for tool discovery.
 ///
 /// Provides [`SearchEmbedder`] trait for generating vector embeddings from text
-/// queries.
+/// queries, and the offline [`HashingEmbedder`] implementation.
 pub mod search;
 
 /// Transport layer implementations for serving tools over network protocols.
@@ -82,8 +112,9 @@ pub mod search;
 pub mod transports;
 
 pub use builder::RuntimeBuilder;
+pub use cache::{CacheStats, ResultCache};
 pub use runtime::{CallMetadata, LocalRuntime, RemoteRuntime, Runtime};
-pub use search::{SearchEmbedFuture, SearchEmbedder};
-pub use transports::grpc::ToolboxService;
+pub use search::{HashingEmbedder, SearchEmbedFuture, SearchEmbedder};
+pub use transports::grpc::{ToolboxAdminService, ToolboxService};
 #[cfg(feature = "mcp")]
 pub use transports::mcp::McpService;
@@ -28,27 +28,64 @@
 //!
 //! # Policy Enforcement
 //!
-//! Local runtimes enforce policies through a
+//! Local runtimes first check the call against their
+//! [`RateLimiter`](operai_core::rate_limit::RateLimiter), then enforce
+//! policies through a
 //! [`PolicyStore`](operai_core::policy::session::PolicyStore):
 //! - Pre-call policies evaluate before tool execution and can deny requests
 //! - Post-call policies evaluate after tool execution and can observe results
 //! - Policies are evaluated per-session, enabling fine-grained access control
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
 use abi_stable::std_types::{RSlice, RStr};
+use backon::Retryable;
 use base64::prelude::*;
 use futures::FutureExt;
-use operai_abi::{CallContext, RuntimeContext, ToolResult};
-use operai_core::{PolicyError, ToolInfo, ToolRegistry, policy::session::PolicyStore};
+use operai_abi::{CallContext, CallResult, RuntimeContext, ToolResult};
+use operai_core::{
+    Policy, PolicyError, PolicyMode, ToolInfo, ToolRegistry,
+    admission::{AdmissionController, AdmissionEntry, AdmissionRejected},
+    circuit::{CircuitBreaker, CircuitBreakers, CircuitOpen, CircuitState},
+    policy::{
+        PolicyStage,
+        budget::{BudgetExceeded, BudgetStatus},
+        request::{CallerIdentity, PolicyRequest},
+        session::PolicyStore,
+        trace::{Denial, EffectOutcome, PolicyTrace, TraceStep},
+    },
+    rate_limit::{RateLimit, RateLimited, RateLimiter},
+    retry::RetryPolicy,
+};
+use prost::{Message, Name};
+use prost_types::Any;
 use rkyv::rancor::BoxedError;
-use tonic::{Request, Status, transport::Channel};
-use tracing::{Span, debug, error, info, instrument};
-
-use crate::proto::{
-    CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse, SearchResult,
-    SearchToolsRequest, SearchToolsResponse, Tool, call_tool_response,
-    toolbox_client::ToolboxClient,
+use tonic::{Code, Request, Status, codegen::Bytes, transport::Channel};
+use tracing::{Span, debug, error, info, instrument, warn};
+
+use crate::{
+    approval::{Admission, ApprovalQueue, CallKey},
+    cache::{CacheStats, ResultCache},
+    proto::{
+        Approval, ApproveCallRequest, BudgetExceeded as BudgetExceededProto,
+        BudgetStatus as BudgetStatusProto, CallToolRequest, CallToolResponse, DeletePolicyRequest,
+        DeletePolicyResponse, DenyCallRequest, EndSessionRequest, EndSessionResponse,
+        GetPolicyRequest, GetSessionContextRequest, GetSessionContextResponse,
+        GetShadowReportRequest, GetShadowReportResponse, ListApprovalsRequest,
+        ListApprovalsResponse, ListPoliciesRequest, ListPoliciesResponse, ListToolsRequest,
+        ListToolsResponse, Policy as PolicyProto, PolicyDenial, PolicyTraceStep,
+        RateLimited as RateLimitedProto, ResetSessionRequest, ResetSessionResponse, SearchResult,
+        SearchToolsRequest, SearchToolsResponse, ShadowReportEntry, Tool, UpsertPolicyRequest,
+        call_tool_response, policy_trace_step, tool::CircuitState as CircuitStateProto,
+        toolbox_client::ToolboxClient,
+    },
 };
 
 /// Metadata associated with a tool invocation request.
@@ -64,6 +101,19 @@ pub struct CallMetadata {
     /// Credentials keyed by provider name (e.g., "github", "slack").
     /// Each provider maps to a set of key-value credential pairs.
     pub credentials: HashMap<String, HashMap<String, String>>,
+    /// Return a pending approval instead of waiting when a policy requires
+    /// approval for the call.
+    pub defer_approval: bool,
+    /// The authenticated caller, if an authentication layer established one.
+    ///
+    /// Exposed to policies as `caller`; never forwarded to remote runtimes.
+    pub caller: Option<CallerIdentity>,
+    /// Return the policy effects evaluated for the call with the response,
+    /// or with the error details if a policy denies it.
+    pub trace_policies: bool,
+    /// Run the tool even if its output is cached, replacing the cached
+    /// output.
+    pub bypass_cache: bool,
 }
 
 /// Runtime that can execute tools either locally or remotely.
@@ -80,7 +130,8 @@ pub enum Runtime {
 }
 
 impl Runtime {
-    /// Lists all available tools with pagination support.
+    /// Lists the tools visible to the session and caller of `metadata`, with
+    /// pagination support.
     ///
     /// Delegates to the underlying local or remote runtime to retrieve the list
     /// of registered tools.
@@ -89,14 +140,19 @@ impl Runtime {
     ///
     /// Returns `Status` if the underlying runtime fails to retrieve the tool
     /// list.
-    pub async fn list_tools(&self, request: ListToolsRequest) -> Result<ListToolsResponse, Status> {
+    pub async fn list_tools(
+        &self,
+        request: ListToolsRequest,
+        metadata: CallMetadata,
+    ) -> Result<ListToolsResponse, Status> {
         match self {
-            Self::Local(runtime) => runtime.list_tools(request).await,
-            Self::Remote(runtime) => runtime.list_tools(request).await,
+            Self::Local(runtime) => runtime.list_tools(request, metadata).await,
+            Self::Remote(runtime) => runtime.list_tools(request, metadata).await,
         }
     }
 
-    /// Searches for tools using semantic similarity with an embedding vector.
+    /// Searches the tools visible to the session and caller of `metadata`
+    /// using semantic similarity with an embedding vector.
     ///
     /// Delegates to the underlying runtime to perform semantic search over tool
     /// descriptions and metadata.
@@ -107,10 +163,11 @@ impl Runtime {
     pub async fn search_tools(
         &self,
         request: SearchToolsRequest,
+        metadata: CallMetadata,
     ) -> Result<SearchToolsResponse, Status> {
         match self {
-            Self::Local(runtime) => runtime.search_tools(request).await,
-            Self::Remote(runtime) => runtime.search_tools(request).await,
+            Self::Local(runtime) => runtime.search_tools(request, metadata).await,
+            Self::Remote(runtime) => runtime.search_tools(request, metadata).await,
         }
     }
 
@@ -145,12 +202,13 @@ impl Runtime {
 ///
 /// 1. Extract tool ID from request
 /// 2. Retrieve tool handle from registry
-/// 3. Evaluate pre-call policies (may deny request)
-/// 4. Serialize credentials and call context
-/// 5. Invoke tool via FFI boundary
-/// 6. Catch panics from tool execution
-/// 7. Evaluate post-call policies with result
-/// 8. Return response or error
+/// 3. Evaluate pre-call policies (may deny request or rewrite input)
+/// 4. Hold the call for human approval if a policy requires it
+/// 5. Serialize credentials and call context
+/// 6. Invoke tool via FFI boundary
+/// 7. Catch panics from tool execution
+/// 8. Evaluate post-call policies with result (may reshape or withhold output)
+/// 9. Return response or error
 ///
 /// # Thread Safety
 ///
@@ -167,6 +225,18 @@ pub struct LocalRuntime {
     runtime_ctx: RuntimeContext,
     /// Optional embedder for semantic search.
     search_embedder: Option<Arc<dyn crate::search::SearchEmbedder>>,
+    /// Calls held for human approval.
+    approvals: Arc<ApprovalQueue>,
+    /// Token buckets of the rate limits checked before policies.
+    rate_limiter: Arc<RateLimiter>,
+    /// Concurrency limits and timeouts enforced once policies allow a call.
+    admission: Arc<AdmissionController>,
+    /// Circuits of the tools watched by circuit breakers.
+    circuit_breakers: Arc<CircuitBreakers>,
+    /// Retries of failed calls to idempotent tools.
+    retry_policy: RetryPolicy,
+    /// Cache of the results of tools with a cache TTL, if enabled.
+    cache: Option<Arc<ResultCache>>,
 }
 
 impl LocalRuntime {
@@ -188,9 +258,88 @@ impl LocalRuntime {
             policy_store,
             runtime_ctx,
             search_embedder: None,
+            approvals: Arc::new(ApprovalQueue::default()),
+            rate_limiter: Arc::new(RateLimiter::new(Vec::new())),
+            admission: Arc::new(AdmissionController::new()),
+            circuit_breakers: Arc::new(CircuitBreakers::new(Vec::new())),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
         }
     }
 
+    /// Sets the rate limits checked before policies are evaluated.
+    ///
+    /// Replaces the rate limiter, refilling all buckets.
+    #[must_use]
+    pub fn with_rate_limits(mut self, limits: Vec<RateLimit>) -> Self {
+        self.rate_limiter = Arc::new(RateLimiter::new(limits));
+        self
+    }
+
+    /// Sets the circuit breakers checked right before admission, after rate
+    /// limits, approvals and the response cache.
+    ///
+    /// Replaces the circuits, closing all of them.
+    #[must_use]
+    pub fn with_circuit_breakers(mut self, breakers: Vec<CircuitBreaker>) -> Self {
+        self.circuit_breakers = Arc::new(CircuitBreakers::new(breakers));
+        self
+    }
+
+    /// Returns the circuits of the tools watched by circuit breakers.
+    #[must_use]
+    pub fn circuit_breakers(&self) -> &Arc<CircuitBreakers> {
+        &self.circuit_breakers
+    }
+
+    /// Sets the concurrency limits and timeouts of tool calls.
+    ///
+    /// Replaces the admission controller, resetting its statistics.
+    #[must_use]
+    pub fn with_admission(mut self, admission: AdmissionController) -> Self {
+        self.admission = Arc::new(admission);
+        self
+    }
+
+    /// Returns the admission counts and queue wait times of the tools whose
+    /// calls are subject to a concurrency limit or timeout, ordered by tool.
+    #[must_use]
+    pub fn admission_stats(&self) -> Vec<AdmissionEntry> {
+        self.admission.stats()
+    }
+
+    /// Sets how failed calls to idempotent tools are retried (default: up to
+    /// 3 attempts, backing off from 100 milliseconds).
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Reuses the results of tools with a cache TTL (default: no caching).
+    #[must_use]
+    pub fn with_result_cache(mut self, cache: ResultCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Returns the hit and miss counts and size of the result cache, if
+    /// results are cached.
+    #[must_use]
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Sets how long a call held for approval waits for a decision, and how
+    /// long a decision stays claimable (default: 5 minutes).
+    ///
+    /// Replaces the approval queue, discarding any pending approvals.
+    #[must_use]
+    pub fn with_approval_timeout(mut self, timeout: Duration) -> Self {
+        self.approvals = Arc::new(ApprovalQueue::new(timeout));
+        self
+    }
+
     /// Sets the search embedder for semantic search functionality.
     #[must_use]
     pub fn with_search_embedder(
@@ -233,7 +382,11 @@ impl LocalRuntime {
         self.registry.drain().await;
     }
 
-    /// Lists all available tools with pagination support.
+    /// Lists the tools visible to the session and caller of `metadata`, with
+    /// pagination support.
+    ///
+    /// Tools hidden by `visibility` stage policy effects are left out before
+    /// paginating.
     ///
     /// # Pagination
     ///
@@ -244,9 +397,16 @@ impl LocalRuntime {
     ///
     /// # Errors
     ///
-    /// This function currently never returns an error.
-    #[instrument(skip(self, request), fields(page_size = request.page_size))]
-    pub async fn list_tools(&self, request: ListToolsRequest) -> Result<ListToolsResponse, Status> {
+    /// Returns `internal` if policy evaluation fails.
+    #[instrument(
+        skip(self, request, metadata),
+        fields(page_size = request.page_size, session_id = %metadata.session_id)
+    )]
+    pub async fn list_tools(
+        &self,
+        request: ListToolsRequest,
+        metadata: CallMetadata,
+    ) -> Result<ListToolsResponse, Status> {
         let page_size: usize = if request.page_size <= 0 {
             100
         } else {
@@ -255,14 +415,22 @@ impl LocalRuntime {
 
         let offset: usize = request.page_token.parse().unwrap_or(0);
 
-        let all_tools: Vec<_> = self.registry.list().collect();
+        let all_tools = self
+            .policy_store
+            .filter_visible(
+                &visibility_request(&metadata),
+                self.registry.list().collect(),
+                |info| info,
+            )
+            .await
+            .map_err(|e| Status::internal(format!("policy evaluation error: {e}")))?;
         let total = all_tools.len();
 
         let tools: Vec<Tool> = all_tools
             .into_iter()
             .skip(offset)
             .take(page_size)
-            .map(tool_info_to_proto)
+            .map(|info| self.tool_to_proto(info))
             .collect();
 
         let next_offset = offset + tools.len();
@@ -278,17 +446,23 @@ impl LocalRuntime {
         })
     }
 
-    /// Searches for tools using semantic similarity with an embedding vector.
+    /// Searches the tools visible to the session and caller of `metadata`
+    /// using semantic similarity with an embedding vector.
+    ///
+    /// Tools hidden by `visibility` stage policy effects are left out before
+    /// the best `page_size` matches are taken.
     ///
     /// # Errors
     ///
     /// Returns `Status::invalid_argument` if neither `query_embedding` nor
     /// `query_text` is provided. Returns `Status::invalid_argument` if
     /// `query_text` is provided but no search embedder is configured.
-    #[instrument(skip(self, request), fields(embedding_dims = tracing::field::Empty, query_type = tracing::field::Empty))]
+    /// Returns `internal` if policy evaluation fails.
+    #[instrument(skip(self, request, metadata), fields(embedding_dims = tracing::field::Empty, query_type = tracing::field::Empty))]
     pub async fn search_tools(
         &self,
         request: SearchToolsRequest,
+        metadata: CallMetadata,
     ) -> Result<SearchToolsResponse, Status> {
         // Determine which query method to use (query_embedding takes precedence)
         let embedding = if !request.query_embedding.is_empty() {
@@ -319,12 +493,28 @@ impl LocalRuntime {
 
         info!(embedding_dims = embedding.len(), "Searching tools");
 
-        let search_results = self.registry.search(&embedding, page_size);
+        // Hidden tools must not take up the page, so rank every tool when
+        // some may be hidden.
+        let limit = if self.policy_store.has_visibility_effects() {
+            self.registry.len()
+        } else {
+            page_size
+        };
+        let search_results = self
+            .policy_store
+            .filter_visible(
+                &visibility_request(&metadata),
+                self.registry.search(&embedding, limit),
+                |(info, _)| info,
+            )
+            .await
+            .map_err(|e| Status::internal(format!("policy evaluation error: {e}")))?;
 
         let results: Vec<SearchResult> = search_results
             .into_iter()
+            .take(page_size)
             .map(|(tool_info, score)| SearchResult {
-                tool: Some(tool_info_to_proto(tool_info)),
+                tool: Some(self.tool_to_proto(tool_info)),
                 relevance_score: score,
             })
             .collect();
@@ -346,19 +536,39 @@ impl LocalRuntime {
     /// # Execution Flow
     ///
     /// 1. Extract tool ID and retrieve handle from registry
-    /// 2. Acquire in-flight request guard
-    /// 3. Evaluate pre-call policies (may return permission denied)
-    /// 4. Serialize credentials and context for FFI
-    /// 5. Invoke tool through FFI boundary
-    /// 6. Catch and handle panics
-    /// 7. Evaluate post-call policies with result
-    /// 8. Return tool output or error
+    /// 2. Return the pending approval if the caller defers a call that is
+    ///    still held; otherwise check rate limits (may return resource
+    ///    exhausted)
+    /// 3. Evaluate pre-call policies (may return permission denied) and wait
+    ///    for approval if they require it, or claim the decision on a held
+    ///    call the caller deferred
+    /// 4. Look up the result cache if the tool's results are cached and the
+    ///    caller did not bypass it; on a hit, skip to step 8 at no cost
+    /// 5. Check the tool's circuit (may return unavailable), wait for a slot
+    ///    under the tool's and the server's concurrency limits (may return
+    ///    resource exhausted), then acquire in-flight request guard
+    /// 6. Serialize credentials and context for FFI
+    /// 7. Invoke tool through FFI boundary, within the tool's timeout, retrying
+    ///    failed attempts of idempotent tools with backoff
+    /// 8. Catch and handle panics, record the outcome in the tool's circuit,
+    ///    and cache successful output if the tool's results are cached
+    /// 9. Evaluate post-call policies with result, charging its cost to the
+    ///    applicable budgets
+    /// 10. Return tool output or error, with the state of those budgets, the
+    ///     number of attempts made and how the cache served the call
     ///
     /// # Errors
     ///
     /// - `invalid_argument`: Tool name format is invalid
     /// - `not_found`: Tool does not exist in registry
     /// - `permission_denied`: Pre-call policy rejected the request
+    /// - `resource_exhausted`: A rate limit is exceeded, a cost budget is
+    ///   spent, the tool's static cost would overrun it, or a concurrency limit
+    ///   is reached and its queue is full
+    /// - `unavailable`: The tool's circuit is open after repeated failures, or
+    ///   half-open with all its probe calls in flight
+    /// - `deadline_exceeded`: The call did not complete within the tool's
+    ///   timeout, counting the time spent waiting for a slot
     /// - `internal`: Policy evaluation error, serialization failure, or tool
     ///   panic
     #[instrument(
@@ -366,7 +576,9 @@ impl LocalRuntime {
         fields(
             request_id = %metadata.request_id,
             session_id = %metadata.session_id,
-            tool_id = tracing::field::Empty
+            tool_id = tracing::field::Empty,
+            attempts = tracing::field::Empty,
+            cache = tracing::field::Empty
         )
     )]
     pub async fn call_tool(
@@ -387,100 +599,556 @@ impl LocalRuntime {
 
         debug!("Invoking tool");
 
-        let inflight_guard = self.registry.start_request_guard();
-
         let input_value = if let Some(s) = request.input.as_ref() {
             struct_to_json_value(s)
         } else {
             serde_json::Value::Object(serde_json::Map::new())
         };
-        let input_json = serde_json::to_vec(&input_value).unwrap_or_else(|_| b"{}".to_vec());
 
-        self.policy_store
-            .evaluate_pre_effects(&metadata.session_id, tool_id, &input_value)
+        let trace = metadata.trace_policies.then(PolicyTrace::new);
+        let mut policy_request = PolicyRequest::new(&metadata.session_id, tool_id)
+            .with_tool_info(handle.info())
+            .with_request_id(&metadata.request_id)
+            .with_caller(metadata.caller.as_ref())
+            .with_credentials(metadata.credentials.keys().map(String::as_str))
+            .with_trace(trace.as_ref());
+
+        // A caller that deferred a call still held for approval gets the
+        // pending approval back when it checks on the call, without spending
+        // rate limit tokens or applying policy effects. Once the call is
+        // decided, its retry is rate limited and evaluated against the
+        // current policies like any other call before it claims the decision.
+        let approval_key = CallKey::new(
+            &metadata.session_id,
+            metadata.caller.as_ref(),
+            tool_id,
+            &input_value,
+        );
+        if metadata.defer_approval
+            && let Some(pending) = self.approvals.pending(&approval_key)
+        {
+            return Ok(pending_approval_response(pending, trace.as_ref()));
+        }
+
+        self.rate_limiter
+            .check(tool_id, &metadata.session_id, metadata.caller.as_ref())
+            .map_err(|limited| rate_limited_status(&limited))?;
+
+        // Policies may rewrite the input; the tool and post-effects see the
+        // rewritten value.
+        let pre = self
+            .policy_store
+            .evaluate_pre_effects(&policy_request, &input_value)
             .await
             .map_err(|e| match e {
-                PolicyError::GuardFailed(msg) => Status::permission_denied(msg),
+                PolicyError::GuardFailed(denial) => denial_status(&denial, trace.as_ref()),
+                PolicyError::BudgetExceeded(exceeded) => budget_exceeded_status(&exceeded),
                 _ => Status::internal(format!("policy evaluation error: {e}")),
             })?;
+        let input_value = pre.input;
+
+        if let Some(reason) = pre.approval {
+            let admission = self.approvals.submit(
+                &approval_key,
+                &input_value,
+                &reason,
+                metadata.defer_approval,
+            );
+            if let Some(pending) = self.await_approval(admission, &metadata).await? {
+                return Ok(pending_approval_response(pending, trace.as_ref()));
+            }
+        }
 
-        let user_creds_bin = rkyv::to_bytes::<BoxedError>(&metadata.credentials)
-            .map_err(|e| Status::internal(format!("failed to serialize credentials: {e}")))?;
-        let system_creds_bin = &handle.system_credentials;
-
-        let context = CallContext {
-            request_id: RStr::from_str(&metadata.request_id),
-            session_id: RStr::from_str(&metadata.session_id),
-            user_credentials: RSlice::from_slice(&user_creds_bin),
-            system_credentials: RSlice::from_slice(system_creds_bin),
-        };
-
-        let result =
-            std::panic::AssertUnwindSafe(handle.call(context, RSlice::from_slice(&input_json)))
-                .catch_unwind()
-                .await;
-
-        drop(inflight_guard);
-
-        let (rpc_result, policy_outcome_val, policy_outcome_err) = if let Ok(call_result) = result {
-            match call_result.result {
-                ToolResult::Ok => {
-                    let output_value: serde_json::Value =
-                        serde_json::from_slice(call_result.output.as_slice())
-                            .unwrap_or(serde_json::Value::Null);
-                    let output_struct = json_value_to_struct(&output_value).unwrap_or_default();
-
-                    (
-                        Ok(CallToolResponse {
-                            result: Some(call_tool_response::Result::Output(output_struct)),
-                        }),
-                        Some(output_value),
-                        None,
-                    )
-                }
-                ToolResult::Error => {
-                    let error_msg =
-                        String::from_utf8_lossy(call_result.output.as_slice()).to_string();
-                    error!(tool_id = %tool_id, error = %error_msg, "Tool invocation failed");
-
-                    (
-                        Ok(CallToolResponse {
-                            result: Some(call_tool_response::Result::Error(error_msg.clone())),
-                        }),
-                        None,
-                        Some(error_msg),
-                    )
-                }
-                other => {
-                    error!(tool_id = %tool_id, result = ?other, "Tool invocation failed");
-                    let msg = format!("tool error: {other:?}");
-                    (
-                        Ok(CallToolResponse {
-                            result: Some(call_tool_response::Result::Error(msg.clone())),
-                        }),
-                        None,
-                        Some(msg),
-                    )
-                }
+        // The cache is only consulted once policies allowed the call, so it
+        // never answers a call that would have been refused.
+        let cached_call = self.cache.as_ref().and_then(|cache| {
+            let ttl = cache.ttl(handle.info())?;
+            let key = cache.key(tool_id, &input_value, &metadata);
+            Some((cache, key, ttl))
+        });
+        let (cache_status, cached_output) = match &cached_call {
+            None => (call_tool_response::CacheStatus::Unspecified, None),
+            Some((cache, _, _)) if metadata.bypass_cache => {
+                cache.record_bypass();
+                (call_tool_response::CacheStatus::Bypass, None)
             }
+            Some((cache, key, _)) => match cache.get(key) {
+                Some(output) => (call_tool_response::CacheStatus::Hit, Some(output)),
+                None => (call_tool_response::CacheStatus::Miss, None),
+            },
+        };
+        if cached_call.is_some() {
+            Span::current().record("cache", cache_status.as_str_name());
+        }
+
+        let (outcome, attempts) = if let Some(output) = cached_output {
+            // A cached result costs nothing, and says nothing about the health
+            // of the tool, so it leaves the circuit untouched.
+            policy_request = policy_request.with_cost(Some(0.0));
+            (Ok(output), 0)
         } else {
-            let msg = "Tool execution panicked".to_string();
-            (Err(Status::internal(&msg)), None, Some(msg))
+            // Calls waiting for approval are not in flight, so they do not hold
+            // up draining, and their timeout starts once they are approved.
+            let timeout = self.admission.timeout(tool_id);
+            let start = Instant::now();
+            // The circuit is checked last, so that a probe of a half-open
+            // circuit is only taken by a call that is about to run.
+            let circuit = self
+                .circuit_breakers
+                .check(tool_id)
+                .map_err(|open| circuit_open_status(&open))?;
+            let permit = within(timeout, start, self.admission.admit(tool_id))
+                .await
+                .map_err(|timeout| self.timed_out_status(tool_id, timeout))?
+                .map_err(|rejected| admission_rejected_status(&rejected))?;
+            if !permit.queue_wait().is_zero() {
+                debug!(
+                    queue_wait_ms = permit.queue_wait().as_secs_f64() * 1000.0,
+                    "Tool call admitted after queueing"
+                );
+            }
+            let inflight_guard = self.registry.start_request_guard();
+
+            let input_json = serde_json::to_vec(&input_value).unwrap_or_else(|_| b"{}".to_vec());
+
+            let user_creds_bin = rkyv::to_bytes::<BoxedError>(&metadata.credentials)
+                .map_err(|e| Status::internal(format!("failed to serialize credentials: {e}")))?;
+            let system_creds_bin = &handle.system_credentials;
+
+            let context = CallContext {
+                request_id: RStr::from_str(&metadata.request_id),
+                session_id: RStr::from_str(&metadata.session_id),
+                user_credentials: RSlice::from_slice(&user_creds_bin),
+                system_credentials: RSlice::from_slice(system_creds_bin),
+            };
+
+            // Failed attempts of idempotent tools are retried within the
+            // call's timeout; a backoff that would outlast it ends the retries
+            // instead.
+            let retry = self.retry_policy.applies_to(handle.info());
+            let deadline = timeout.map(|timeout| start + timeout);
+            let attempts = AtomicU32::new(0);
+            let attempt = || {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                // The panic payload is dropped here, as it is never reported.
+                std::panic::AssertUnwindSafe(handle.call(context, RSlice::from_slice(&input_json)))
+                    .catch_unwind()
+                    .map(move |result| match result {
+                        Ok(call_result)
+                            if retry && RetryPolicy::is_retryable(call_result.result) =>
+                        {
+                            Err(call_result)
+                        }
+                        Ok(call_result) => Ok(Ok(call_result)),
+                        Err(_panic) => Ok(Err(())),
+                    })
+            };
+            let result = within(
+                timeout,
+                start,
+                attempt
+                    .retry(self.retry_policy.backoff())
+                    .adjust(|_: &CallResult, backoff: Option<Duration>| {
+                        backoff.filter(|backoff| {
+                            deadline.is_none_or(|deadline| Instant::now() + *backoff < deadline)
+                        })
+                    })
+                    .notify(|failed: &CallResult, backoff: Duration| {
+                        info!(
+                            attempt = attempts.load(Ordering::Relaxed),
+                            backoff_ms = backoff.as_secs_f64() * 1000.0,
+                            error = %String::from_utf8_lossy(failed.output.as_slice()),
+                            "Retrying failed tool call"
+                        );
+                    })
+                    // Boxed so the compiler can prove the retrying future
+                    // `Send`.
+                    .boxed(),
+            )
+            .await
+            .map(|result| result.unwrap_or_else(Ok));
+            let attempts = attempts.into_inner();
+            Span::current().record("attempts", attempts);
+
+            drop(inflight_guard);
+            drop(permit);
+
+            let result = match result {
+                Ok(result) => result,
+                Err(timeout) => {
+                    if let Some(circuit) = circuit {
+                        circuit.record(false);
+                    }
+                    let status = self.timed_out_status(tool_id, timeout);
+                    // Post-effects still record the failure, as for a panic.
+                    let _outcome = self
+                        .policy_store
+                        .evaluate_post_effects(&policy_request, &input_value, Err(status.message()))
+                        .await
+                        .map_err(|e| Status::internal(format!("policy effect error: {e}")))?;
+                    return Err(status);
+                }
+            };
+            if let Some(circuit) = circuit {
+                circuit.record(
+                    matches!(&result, Ok(call_result) if call_result.result == ToolResult::Ok),
+                );
+            }
+
+            let outcome = if let Ok(call_result) = result {
+                // Budgets are charged what the tool reported, if it did and
+                // the cost is valid; otherwise its static cost.
+                let cost = call_result.cost.into_option().filter(|&cost| {
+                    let valid = cost.is_finite() && cost >= 0.0;
+                    if !valid {
+                        warn!(tool_id = %tool_id, cost, "Ignoring invalid reported cost");
+                    }
+                    valid
+                });
+                policy_request = policy_request.with_cost(cost);
+                match call_result.result {
+                    ToolResult::Ok => Ok(serde_json::from_slice(call_result.output.as_slice())
+                        .unwrap_or(serde_json::Value::Null)),
+                    ToolResult::Error => {
+                        let error_msg =
+                            String::from_utf8_lossy(call_result.output.as_slice()).to_string();
+                        error!(tool_id = %tool_id, error = %error_msg, "Tool invocation failed");
+                        Err(error_msg)
+                    }
+                    other => {
+                        error!(tool_id = %tool_id, result = ?other, "Tool invocation failed");
+                        Err(format!("tool error: {other:?}"))
+                    }
+                }
+            } else {
+                let msg = "Tool execution panicked".to_string();
+                // Post-effects still record the failure, but a panic is always
+                // reported as an internal error.
+                let _outcome = self
+                    .policy_store
+                    .evaluate_post_effects(&policy_request, &input_value, Err(&msg))
+                    .await
+                    .map_err(|e| Status::internal(format!("policy effect error: {e}")))?;
+                return Err(Status::internal(msg));
+            };
+            (outcome, attempts)
         };
+        if let Some((cache, key, ttl)) = cached_call
+            && cache_status != call_tool_response::CacheStatus::Hit
+            && let Ok(output) = &outcome
+        {
+            cache.insert(key, output, ttl);
+        }
 
-        let policy_res_arg = match &policy_outcome_err {
-            Some(e) => Err(e.as_str()),
-            None => Ok(policy_outcome_val
-                .as_ref()
-                .unwrap_or(&serde_json::Value::Null)),
+        // Post-effects may transform, drop, or withhold parts of the output
+        // before the caller sees it.
+        let outcome = self
+            .policy_store
+            .evaluate_post_effects(
+                &policy_request,
+                &input_value,
+                outcome.as_ref().map_err(String::as_str),
+            )
+            .await
+            .map_err(|e| Status::internal(format!("policy effect error: {e}")))?;
+
+        let result = match outcome {
+            Ok(output_value) => call_tool_response::Result::Output(
+                json_value_to_struct(&output_value).unwrap_or_default(),
+            ),
+            Err(error_msg) => call_tool_response::Result::Error(error_msg),
+        };
+        let budgets = self
+            .policy_store
+            .budget_status(&policy_request)
+            .await
+            .map_err(|e| Status::internal(format!("policy effect error: {e}")))?;
+        Ok(CallToolResponse {
+            result: Some(result),
+            policy_trace: trace_to_proto(trace.as_ref()),
+            budgets: budgets.iter().map(budget_status_to_proto).collect(),
+            attempts,
+            cache: cache_status.into(),
+        })
+    }
+
+    /// Converts a [`ToolInfo`] to a protobuf `Tool` message with the state of
+    /// its circuit.
+    fn tool_to_proto(&self, info: &ToolInfo) -> Tool {
+        let state = self.circuit_breakers.status(&info.qualified_id).map_or(
+            CircuitStateProto::Unspecified,
+            |status| match status.state {
+                CircuitState::Closed => CircuitStateProto::Closed,
+                CircuitState::Open => CircuitStateProto::Open,
+                CircuitState::HalfOpen => CircuitStateProto::HalfOpen,
+            },
+        );
+        Tool {
+            circuit_state: state.into(),
+            ..tool_info_to_proto(info)
+        }
+    }
+
+    /// Counts a call that did not complete within `timeout` and builds its
+    /// `deadline_exceeded` status.
+    fn timed_out_status(&self, tool_id: &str, timeout: Duration) -> Status {
+        self.admission.record_timeout(tool_id);
+        info!(timeout_secs = timeout.as_secs_f64(), "Tool call timed out");
+        Status::deadline_exceeded(format!(
+            "Tool {tool_id} did not complete within {}s",
+            timeout.as_secs_f64()
+        ))
+    }
+
+    /// Holds a call until a person approves it.
+    ///
+    /// Returns `None` when the call may run, or the pending approval when
+    /// the caller asked to defer rather than wait.
+    async fn await_approval(
+        &self,
+        admission: Admission,
+        metadata: &CallMetadata,
+    ) -> Result<Option<Approval>, Status> {
+        let admission = match admission {
+            Admission::Pending(approval, _) if metadata.defer_approval => {
+                info!(approval = %approval.name, "Tool call awaiting approval");
+                return Ok(Some(approval));
+            }
+            Admission::Pending(approval, receiver) => {
+                info!(approval = %approval.name, "Waiting for tool call approval");
+                self.approvals
+                    .wait(&approval, receiver)
+                    .await
+                    .ok_or_else(|| {
+                        Status::deadline_exceeded(format!(
+                            "approval {} was not granted in time",
+                            approval.name
+                        ))
+                    })?
+            }
+            admission => admission,
         };
 
+        match admission {
+            Admission::Granted => Ok(None),
+            Admission::Denied(reason) => Err(Status::permission_denied(reason)),
+            Admission::Pending(approval, _) => Err(Status::deadline_exceeded(format!(
+                "approval {} was not granted in time",
+                approval.name
+            ))),
+        }
+    }
+
+    /// Ends a session, discarding its policy session state.
+    ///
+    /// Ending an unknown session succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `internal` if the session store fails.
+    #[instrument(skip(self, request), fields(session_id = %request.session_id))]
+    pub async fn end_session(
+        &self,
+        request: EndSessionRequest,
+    ) -> Result<EndSessionResponse, Status> {
         self.policy_store
-            .evaluate_post_effects(&metadata.session_id, tool_id, &input_value, policy_res_arg)
+            .end_session(&request.session_id)
             .await
-            .map_err(|e| Status::internal(format!("policy effect error: {e}")))?;
+            .map_err(|e| Status::internal(format!("failed to end session: {e}")))?;
+        Ok(EndSessionResponse {})
+    }
+
+    /// Lists tool calls waiting for approval, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// This method currently does not fail.
+    pub async fn list_approvals(
+        &self,
+        _request: ListApprovalsRequest,
+    ) -> Result<ListApprovalsResponse, Status> {
+        Ok(ListApprovalsResponse {
+            approvals: self.approvals.list(),
+        })
+    }
+
+    /// Approves a pending tool call, letting the next matching call run once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `not_found` if the approval is unknown or expired, and
+    /// `failed_precondition` if it was already decided.
+    #[instrument(skip(self, request), fields(approval = %request.name))]
+    pub async fn approve_call(&self, request: ApproveCallRequest) -> Result<Approval, Status> {
+        let approval = self
+            .approvals
+            .approve(&request.name)
+            .map_err(Status::from)?;
+        info!("Tool call approved");
+        Ok(approval)
+    }
+
+    /// Denies a pending tool call; the next matching call fails with
+    /// `permission_denied`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `not_found` if the approval is unknown or expired, and
+    /// `failed_precondition` if it was already decided.
+    #[instrument(skip(self, request), fields(approval = %request.name))]
+    pub async fn deny_call(&self, request: DenyCallRequest) -> Result<Approval, Status> {
+        let approval = self
+            .approvals
+            .deny(&request.name, &request.reason)
+            .map_err(Status::from)?;
+        info!("Tool call denied");
+        Ok(approval)
+    }
+
+    /// Reports what shadow-mode policies would have done, per policy and tool.
+    ///
+    /// # Errors
+    ///
+    /// This method currently does not fail.
+    pub async fn get_shadow_report(
+        &self,
+        _request: GetShadowReportRequest,
+    ) -> Result<GetShadowReportResponse, Status> {
+        let entries = self
+            .policy_store
+            .shadow_report()
+            .entries()
+            .into_iter()
+            .map(|entry| ShadowReportEntry {
+                policy: entry.policy,
+                tool: format!("tools/{}", entry.tool),
+                evaluations: entry.stats.evaluations,
+                denials: entry.stats.denials,
+                approvals: entry.stats.approvals,
+                context_updates: entry.stats.context_updates,
+                input_rewrites: entry.stats.input_rewrites,
+                output_changes: entry.stats.output_changes,
+                errors: entry.stats.errors,
+            })
+            .collect();
+        Ok(GetShadowReportResponse { entries })
+    }
+
+    /// Lists the registered policies, in evaluation order.
+    ///
+    /// # Errors
+    ///
+    /// This method currently does not fail.
+    pub async fn list_policies(
+        &self,
+        _request: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, Status> {
+        Ok(ListPoliciesResponse {
+            policies: self
+                .policy_store
+                .list()
+                .iter()
+                .map(policy_to_proto)
+                .collect(),
+        })
+    }
+
+    /// Gets a registered policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `invalid_argument` if the name is malformed and `not_found`
+    /// if no policy of that name is registered.
+    pub async fn get_policy(&self, request: GetPolicyRequest) -> Result<PolicyProto, Status> {
+        let name = extract_policy_name(&request.name)?;
+        self.policy_store
+            .get(name)
+            .map(|policy| policy_to_proto(&policy))
+            .ok_or_else(|| Status::not_found(format!("policy not found: {name}")))
+    }
+
+    /// Registers a policy, replacing any policy of the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `invalid_argument` if the definition is not a valid policy or
+    /// fails to compile.
+    #[instrument(skip_all)]
+    pub async fn upsert_policy(&self, request: UpsertPolicyRequest) -> Result<PolicyProto, Status> {
+        let policy: Policy = serde_json::from_str(&request.definition)
+            .map_err(|e| Status::invalid_argument(format!("invalid policy definition: {e}")))?;
+        let response = policy_to_proto(&policy);
+        self.policy_store
+            .register(policy)
+            .map_err(|e| Status::invalid_argument(format!("invalid policy: {e}")))?;
+        info!(name = %response.name, version = %response.version, "Policy upserted");
+        Ok(response)
+    }
+
+    /// Unregisters a policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `invalid_argument` if the name is malformed, `not_found` if no
+    /// policy of that name is registered, and `internal` if the policy store
+    /// fails.
+    #[instrument(skip(self, request), fields(policy = %request.name))]
+    pub async fn delete_policy(
+        &self,
+        request: DeletePolicyRequest,
+    ) -> Result<DeletePolicyResponse, Status> {
+        let name = extract_policy_name(&request.name)?;
+        let removed = self
+            .policy_store
+            .remove(name)
+            .map_err(|e| Status::internal(format!("failed to delete policy: {e}")))?;
+        if !removed {
+            return Err(Status::not_found(format!("policy not found: {name}")));
+        }
+        info!("Policy deleted");
+        Ok(DeletePolicyResponse {})
+    }
+
+    /// Gets the policy context of a session.
+    ///
+    /// # Errors
+    ///
+    /// Returns `internal` if the session store fails.
+    #[instrument(skip(self, request), fields(session_id = %request.session_id))]
+    pub async fn get_session_context(
+        &self,
+        request: GetSessionContextRequest,
+    ) -> Result<GetSessionContextResponse, Status> {
+        let context = self
+            .policy_store
+            .session_context(&request.session_id)
+            .await
+            .map_err(|e| Status::internal(format!("failed to load session: {e}")))?;
+        Ok(GetSessionContextResponse {
+            context: json_value_to_struct(&serde_json::Value::Object(
+                context.into_iter().collect(),
+            )),
+        })
+    }
 
-        rpc_result
+    /// Clears the policy context of a session, keeping its history.
+    ///
+    /// Resetting an unknown session succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `internal` if the session store fails.
+    #[instrument(skip(self, request), fields(session_id = %request.session_id))]
+    pub async fn reset_session(
+        &self,
+        request: ResetSessionRequest,
+    ) -> Result<ResetSessionResponse, Status> {
+        self.policy_store
+            .reset_session(&request.session_id)
+            .await
+            .map_err(|e| Status::internal(format!("failed to reset session: {e}")))?;
+        info!("Session context reset");
+        Ok(ResetSessionResponse {})
     }
 }
 
@@ -510,25 +1178,44 @@ impl RemoteRuntime {
         Self { client }
     }
 
-    /// Lists all available tools from the remote service.
+    /// Lists the tools visible to the session of `metadata` from the remote
+    /// service.
+    ///
+    /// Metadata is attached to the gRPC request as headers before sending.
     ///
     /// # Errors
     ///
-    /// Returns `Status` if the gRPC request fails.
-    pub async fn list_tools(&self, request: ListToolsRequest) -> Result<ListToolsResponse, Status> {
+    /// Returns `Status` if the gRPC request fails or if invalid metadata is
+    /// provided.
+    pub async fn list_tools(
+        &self,
+        request: ListToolsRequest,
+        metadata: CallMetadata,
+    ) -> Result<ListToolsResponse, Status> {
+        let mut request = Request::new(request);
+        apply_call_metadata(&mut request, &metadata)?;
+
         let response = self.client.clone().list_tools(request).await?.into_inner();
         Ok(response)
     }
 
-    /// Searches for tools using semantic similarity via the remote service.
+    /// Searches the tools visible to the session of `metadata` using
+    /// semantic similarity via the remote service.
+    ///
+    /// Metadata is attached to the gRPC request as headers before sending.
     ///
     /// # Errors
     ///
-    /// Returns `Status` if the gRPC request fails.
+    /// Returns `Status` if the gRPC request fails or if invalid metadata is
+    /// provided.
     pub async fn search_tools(
         &self,
         request: SearchToolsRequest,
+        metadata: CallMetadata,
     ) -> Result<SearchToolsResponse, Status> {
+        let mut request = Request::new(request);
+        apply_call_metadata(&mut request, &metadata)?;
+
         let response = self
             .client
             .clone()
@@ -574,9 +1261,254 @@ pub(crate) fn tool_info_to_proto(info: &ToolInfo) -> Tool {
         output_schema: json_str_to_struct(&info.output_schema),
         capabilities: info.capabilities.clone(),
         tags: info.tags.clone(),
+        circuit_state: CircuitStateProto::Unspecified.into(),
+        idempotent: info.idempotent,
+    }
+}
+
+/// Builds a status whose details are a `google.rpc.Status` carrying `details`
+/// as an `Any`, as gRPC's richer error model expects.
+pub(crate) fn status_with_details<T: Name>(
+    code: Code,
+    message: String,
+    details: &T,
+    metadata: tonic::metadata::MetadataMap,
+) -> Status {
+    let rpc_status = tonic_types::Status {
+        code: code.into(),
+        message: message.clone(),
+        details: vec![Any {
+            type_url: T::type_url(),
+            value: details.encode_to_vec(),
+        }],
+    };
+    Status::with_details_and_metadata(
+        code,
+        message,
+        Bytes::from(rpc_status.encode_to_vec()),
+        metadata,
+    )
+}
+
+/// Returns the `T` packed into the `google.rpc.Status` details of `status`,
+/// if there is one, such as the [`PolicyDenial`] of a denied call.
+#[must_use]
+pub fn status_details<T: Name + Default>(status: &Status) -> Option<T> {
+    tonic_types::Status::decode(status.details())
+        .ok()?
+        .details
+        .iter()
+        .find_map(|any| any.to_msg().ok())
+}
+
+/// Builds the `permission_denied` status for a call a policy blocked, with a
+/// [`PolicyDenial`] as its details, and logs the denial.
+fn denial_status(denial: &Denial, trace: Option<&PolicyTrace>) -> Status {
+    info!(
+        policy = %denial.policy,
+        policy_version = %denial.version,
+        effect = denial.effect,
+        tool_pattern = %denial.tool_pattern,
+        condition = %denial.condition,
+        condition_met = denial.condition_met,
+        reason = %denial.message,
+        "Tool call denied by policy"
+    );
+    let details = PolicyDenial {
+        policy: denial.policy.clone(),
+        policy_version: denial.version.clone(),
+        effect_index: u32::try_from(denial.effect).unwrap_or(u32::MAX),
+        tool_pattern: denial.tool_pattern.clone(),
+        condition: denial.condition.clone(),
+        condition_met: denial.condition_met,
+        trace: trace_to_proto(trace),
+    };
+    status_with_details(
+        Code::PermissionDenied,
+        denial.message.clone(),
+        &details,
+        tonic::metadata::MetadataMap::new(),
+    )
+}
+
+/// Builds the `resource_exhausted` status for a call a cost budget blocked,
+/// with a [`BudgetExceededProto`] as its details, and logs the denial.
+fn budget_exceeded_status(exceeded: &BudgetExceeded) -> Status {
+    info!(
+        budget = %exceeded.status.budget,
+        scope = %exceeded.status.scope,
+        limit = exceeded.status.limit,
+        spent = exceeded.status.spent,
+        cost = exceeded.cost,
+        "Tool call denied by budget"
+    );
+    let details = BudgetExceededProto {
+        budget: Some(budget_status_to_proto(&exceeded.status)),
+        cost: exceeded.cost,
+    };
+    status_with_details(
+        Code::ResourceExhausted,
+        exceeded.to_string(),
+        &details,
+        tonic::metadata::MetadataMap::new(),
+    )
+}
+
+/// Builds the `resource_exhausted` status for a call a rate limit rejected,
+/// with a [`RateLimitedProto`] as its details and a `retry-after` header in
+/// whole seconds, and logs the rejection.
+fn rate_limited_status(limited: &RateLimited) -> Status {
+    info!(
+        rate_limit = %limited.rate_limit,
+        key = %limited.key,
+        retry_after_secs = limited.retry_after.as_secs_f64(),
+        "Tool call rejected by rate limit"
+    );
+    let details = RateLimitedProto {
+        rate_limit: limited.rate_limit.clone(),
+        key: limited.key.clone(),
+        retry_after_seconds: limited.retry_after.as_secs_f64(),
+    };
+    let retry_after =
+        limited.retry_after.as_secs() + u64::from(limited.retry_after.subsec_nanos() > 0);
+    let mut metadata = tonic::metadata::MetadataMap::new();
+    metadata.insert("retry-after", retry_after.into());
+    status_with_details(
+        Code::ResourceExhausted,
+        limited.to_string(),
+        &details,
+        metadata,
+    )
+}
+
+/// Builds the `unavailable` status for a call rejected by a circuit breaker,
+/// with a `retry-after` header in whole seconds while the circuit is open,
+/// and logs the rejection.
+fn circuit_open_status(open: &CircuitOpen) -> Status {
+    info!(
+        breaker = %open.status.breaker,
+        state = %open.status.state,
+        "Tool call rejected by circuit breaker"
+    );
+    let mut status = Status::unavailable(open.to_string());
+    if let Some(retry_after) = open.status.retry_after {
+        let retry_after = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+        status
+            .metadata_mut()
+            .insert("retry-after", retry_after.into());
+    }
+    status
+}
+
+/// Builds the `resource_exhausted` status for a call rejected by admission
+/// control, and logs the rejection.
+fn admission_rejected_status(rejected: &AdmissionRejected) -> Status {
+    info!(
+        scope = ?rejected.scope,
+        max_concurrency = rejected.max_concurrency,
+        max_queue = rejected.max_queue,
+        "Tool call rejected by admission control"
+    );
+    Status::resource_exhausted(rejected.to_string())
+}
+
+/// Runs `future` to completion, or until `timeout` has passed since `start`,
+/// returning the timeout if it passed.
+async fn within<F: Future>(
+    timeout: Option<Duration>,
+    start: Instant,
+    future: F,
+) -> Result<F::Output, Duration> {
+    match timeout {
+        Some(timeout) => tokio::time::timeout_at((start + timeout).into(), future)
+            .await
+            .map_err(|_| timeout),
+        None => Ok(future.await),
+    }
+}
+
+/// Converts a [`BudgetStatus`] to a protobuf `BudgetStatus` message.
+fn budget_status_to_proto(status: &BudgetStatus) -> BudgetStatusProto {
+    BudgetStatusProto {
+        budget: status.budget.clone(),
+        scope: status.scope.to_string(),
+        limit: status.limit,
+        spent: status.spent,
+        remaining: status.remaining(),
+    }
+}
+
+/// Builds the response to a call held for approval that the caller defers.
+fn pending_approval_response(approval: Approval, trace: Option<&PolicyTrace>) -> CallToolResponse {
+    CallToolResponse {
+        result: Some(call_tool_response::Result::PendingApproval(approval)),
+        policy_trace: trace_to_proto(trace),
+        budgets: Vec::new(),
+        attempts: 0,
+        cache: call_tool_response::CacheStatus::Unspecified.into(),
+    }
+}
+
+/// Converts the steps of a policy trace to protobuf messages.
+fn trace_to_proto(trace: Option<&PolicyTrace>) -> Vec<PolicyTraceStep> {
+    trace.map_or_else(Vec::new, |trace| {
+        trace.steps().iter().map(trace_step_to_proto).collect()
+    })
+}
+
+/// Converts a [`TraceStep`] to a protobuf `PolicyTraceStep` message.
+fn trace_step_to_proto(step: &TraceStep) -> PolicyTraceStep {
+    use policy_trace_step::Outcome;
+
+    let outcome = match step.outcome {
+        EffectOutcome::Skipped => Outcome::Skipped,
+        EffectOutcome::Applied => Outcome::Applied,
+        EffectOutcome::Allowed => Outcome::Allowed,
+        EffectOutcome::ApprovalRequired => Outcome::ApprovalRequired,
+        EffectOutcome::Denied => Outcome::Denied,
+        EffectOutcome::GuardFailed => Outcome::GuardFailed,
+        EffectOutcome::OutputReplaced => Outcome::OutputReplaced,
+    };
+    PolicyTraceStep {
+        policy: step.policy.clone(),
+        policy_version: step.version.clone(),
+        effect_index: u32::try_from(step.effect).unwrap_or(u32::MAX),
+        stage: match step.stage {
+            PolicyStage::Before => "before",
+            PolicyStage::After => "after",
+            PolicyStage::Visibility => "visibility",
+        }
+        .to_string(),
+        tool_pattern: step.tool_pattern.clone(),
+        condition: step.condition.clone(),
+        condition_met: step.condition_met,
+        outcome: outcome.into(),
     }
 }
 
+/// Converts a [`Policy`] to a protobuf `Policy` message.
+fn policy_to_proto(policy: &Policy) -> PolicyProto {
+    PolicyProto {
+        name: format!("policies/{}", policy.name),
+        version: policy.version.clone(),
+        priority: policy.priority,
+        mode: match policy.mode {
+            PolicyMode::Enforce => "enforce",
+            PolicyMode::Shadow => "shadow",
+        }
+        .to_string(),
+        definition: serde_json::to_string(policy).unwrap_or_default(),
+    }
+}
+
+/// Extracts the policy name from a resource name by removing the
+/// "policies/" prefix.
+fn extract_policy_name(name: &str) -> Result<&str, Status> {
+    name.strip_prefix("policies/")
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| Status::invalid_argument("invalid policy name format"))
+}
+
 /// Extracts the tool ID from a tool name by removing the "tools/" prefix.
 ///
 /// Returns `None` if the name doesn't start with "tools/".
@@ -672,6 +1604,15 @@ fn normalize_endpoint(endpoint: &str) -> String {
     }
 }
 
+/// Builds the policy request used to decide which tools the session and
+/// caller of `metadata` may see. The tool is filled in per listed tool.
+pub(crate) fn visibility_request(metadata: &CallMetadata) -> PolicyRequest<'_> {
+    PolicyRequest::new(&metadata.session_id, "")
+        .with_request_id(&metadata.request_id)
+        .with_caller(metadata.caller.as_ref())
+        .with_credentials(metadata.credentials.keys().map(String::as_str))
+}
+
 /// Applies call metadata to a gRPC request as headers.
 ///
 /// Adds the following headers:
@@ -679,19 +1620,27 @@ fn normalize_endpoint(endpoint: &str) -> String {
 /// - `x-session-id`: Session identifier
 /// - `x-credential-{provider}`: Base64-encoded credential data for each
 ///   provider
+/// - `x-approval-mode`: `defer` if the caller defers approvals
+/// - `x-policy-trace`: `true` if the caller asks for a policy trace
 ///
 /// # Errors
 ///
 /// Returns `Status::invalid_argument` if header names or values are invalid.
 /// Returns `Status::internal` if credential serialization fails.
-fn apply_call_metadata(
-    request: &mut Request<CallToolRequest>,
-    metadata: &CallMetadata,
-) -> Result<(), Status> {
+fn apply_call_metadata<T>(request: &mut Request<T>, metadata: &CallMetadata) -> Result<(), Status> {
     let headers = request.metadata_mut();
 
     insert_header(headers, "x-request-id", &metadata.request_id)?;
     insert_header(headers, "x-session-id", &metadata.session_id)?;
+    if metadata.defer_approval {
+        insert_header(headers, "x-approval-mode", "defer")?;
+    }
+    if metadata.trace_policies {
+        insert_header(headers, "x-policy-trace", "true")?;
+    }
+    if metadata.bypass_cache {
+        insert_header(headers, "x-cache-mode", "bypass")?;
+    }
 
     for (provider, values) in &metadata.credentials {
         let json = serde_json::to_string(&CredentialData { values })
@@ -742,7 +1691,14 @@ mod tests {
         CallArgs, CallResult, InitArgs, RuntimeContext, TOOL_ABI_VERSION, ToolDescriptor, ToolMeta,
         ToolModule, ToolModuleRef, ToolResult, async_ffi::FfiFuture,
     };
-    use operai_core::policy::session::{InMemoryPolicySessionStore, PolicyStore};
+    use operai_core::{
+        Policy,
+        admission::ToolLimits,
+        policy::{
+            budget::Budget,
+            session::{InMemoryPolicySessionStore, PolicyStore},
+        },
+    };
 
     use super::*;
     use crate::proto::{CallToolRequest, call_tool_response};
@@ -751,31 +1707,104 @@ mod tests {
         FfiFuture::new(async { ToolResult::Ok })
     }
 
-    extern "C" fn static_tool_call(_args: CallArgs<'_>) -> FfiFuture<CallResult> {
-        let output = RVec::from_slice(br#"{"ok":true}"#);
-        FfiFuture::new(async { CallResult::ok(output) })
+    /// Calls made to a static tool, counted by the input's `fail_calls`.
+    static STATIC_TOOL_CALLS: AtomicU32 = AtomicU32::new(0);
+
+    /// Returns `ok: true` alongside the input the tool received, reporting
+    /// the input's `cost` if it has one, after sleeping for the input's
+    /// `sleep_ms` if it has one. Fails instead if the input has `fail: true`,
+    /// or while fewer calls than its `fail_calls` were made. The cost may be
+    /// a string such as `"NaN"`, which JSON numbers can't express.
+    extern "C" fn static_tool_call(args: CallArgs<'_>) -> FfiFuture<CallResult> {
+        let input: serde_json::Value =
+            serde_json::from_slice(args.input.as_slice()).unwrap_or_default();
+        let cost = input.get("cost").and_then(|cost| {
+            cost.as_f64()
+                .or_else(|| cost.as_str().and_then(|cost| cost.parse().ok()))
+        });
+        let sleep = input
+            .get("sleep_ms")
+            .and_then(serde_json::Value::as_f64)
+            .map(|sleep_ms| Duration::from_secs_f64(sleep_ms / 1000.0));
+        let failing = input
+            .get("fail_calls")
+            .and_then(serde_json::Value::as_f64)
+            .is_some_and(|fail_calls| {
+                f64::from(STATIC_TOOL_CALLS.fetch_add(1, Ordering::SeqCst)) < fail_calls
+            });
+        if failing || input.get("fail") == Some(&serde_json::Value::Bool(true)) {
+            return FfiFuture::new(async {
+                CallResult::error(ToolResult::Error, "upstream unavailable")
+            });
+        }
+        let output = serde_json::to_vec(&serde_json::json!({ "ok": true, "input": input }))
+            .expect("output should serialize");
+        FfiFuture::new(async move {
+            if let Some(sleep) = sleep {
+                tokio::time::sleep(sleep).await;
+            }
+            CallResult::ok(RVec::from(output)).with_cost(cost)
+        })
     }
 
     extern "C" fn static_tool_shutdown() {}
 
     fn static_tool_module_ref() -> ToolModuleRef {
-        let descriptor = ToolDescriptor {
+        static_module_ref("static-tool", "Static echo tool", &[])
+    }
+
+    fn static_module_ref(
+        crate_name: &'static str,
+        description: &'static str,
+        embedding: &'static [f32],
+    ) -> ToolModuleRef {
+        module_ref(crate_name, static_descriptor(description, embedding))
+    }
+
+    fn idempotent_tool_module_ref() -> ToolModuleRef {
+        module_ref(
+            "idempotent-tool",
+            ToolDescriptor {
+                idempotent: true,
+                ..static_descriptor("Static idempotent echo tool", &[])
+            },
+        )
+    }
+
+    fn cached_tool_module_ref() -> ToolModuleRef {
+        module_ref(
+            "cached-tool",
+            ToolDescriptor {
+                cache_ttl_secs: ROption::RSome(60),
+                ..static_descriptor("Static cached echo tool", &[])
+            },
+        )
+    }
+
+    fn static_descriptor(description: &'static str, embedding: &'static [f32]) -> ToolDescriptor {
+        ToolDescriptor {
             id: RStr::from_str("echo"),
             name: RStr::from_str("Echo"),
-            description: RStr::from_str("Static echo tool"),
+            description: RStr::from_str(description),
             input_schema: RStr::from_str(r#"{"type":"object"}"#),
             output_schema: RStr::from_str(r#"{"type":"object"}"#),
             credential_schema: ROption::RNone,
             capabilities: RSlice::from_slice(&[]),
             tags: RSlice::from_slice(&[]),
-            embedding: RSlice::from_slice(&[]),
-        };
+            embedding: RSlice::from_slice(embedding),
+            cost: ROption::RSome(1.0),
+            idempotent: false,
+            cache_ttl_secs: ROption::RNone,
+        }
+    }
+
+    fn module_ref(crate_name: &'static str, descriptor: ToolDescriptor) -> ToolModuleRef {
         let descriptors = Box::leak(Box::new([descriptor]));
 
         let module = ToolModule {
             meta: ToolMeta::new(
                 TOOL_ABI_VERSION,
-                RStr::from_str("static-tool"),
+                RStr::from_str(crate_name),
                 RStr::from_str("0.1.0"),
             ),
             descriptors: RSlice::from_slice(descriptors),
@@ -789,6 +1818,39 @@ mod tests {
         ToolModuleRef::from_prefix_ref(with_metadata.static_as_prefix())
     }
 
+    async fn runtime_with_modules(
+        modules: Vec<ToolModuleRef>,
+        policies: Vec<serde_json::Value>,
+    ) -> LocalRuntime {
+        let policy_store = PolicyStore::new(Arc::new(InMemoryPolicySessionStore::new()));
+        for policy in policies {
+            let policy: Policy = serde_json::from_value(policy).expect("policy should deserialize");
+            policy_store
+                .register(policy)
+                .expect("policy should register");
+        }
+        runtime_with_store(modules, policy_store).await
+    }
+
+    async fn runtime_with_store(
+        modules: Vec<ToolModuleRef>,
+        policy_store: PolicyStore,
+    ) -> LocalRuntime {
+        let mut registry = ToolRegistry::new();
+        let runtime_ctx = RuntimeContext::new();
+        for module in modules {
+            registry
+                .register_module(module, None, &runtime_ctx)
+                .await
+                .expect("static module should register");
+        }
+        LocalRuntime::with_context(Arc::new(registry), Arc::new(policy_store), runtime_ctx)
+    }
+
+    async fn static_runtime(policies: Vec<serde_json::Value>) -> LocalRuntime {
+        runtime_with_modules(vec![static_tool_module_ref()], policies).await
+    }
+
     #[tokio::test]
     async fn test_local_runtime_registers_static_tool() {
         let module = static_tool_module_ref();
@@ -826,4 +1888,1288 @@ mod tests {
             other => panic!("expected `ok` to be true, got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn test_search_tools_ranks_with_hashing_embedder() {
+        let embedder = crate::HashingEmbedder::default();
+        let modules = [
+            ("mailer", "Send an email message to a recipient"),
+            ("weather", "Get the weather forecast for a city"),
+            ("files", "List files in a directory"),
+        ]
+        .map(|(crate_name, description)| {
+            static_module_ref(
+                crate_name,
+                description,
+                Vec::leak(embedder.embed(description)),
+            )
+        });
+        let runtime = runtime_with_modules(modules.to_vec(), vec![])
+            .await
+            .with_search_embedder(Some(Arc::new(embedder)));
+
+        let response = runtime
+            .search_tools(
+                SearchToolsRequest {
+                    query_text: "email a message".to_string(),
+                    page_size: 3,
+                    ..Default::default()
+                },
+                CallMetadata::default(),
+            )
+            .await
+            .expect("search should succeed");
+
+        let names: Vec<_> = response
+            .results
+            .iter()
+            .filter_map(|result| result.tool.as_ref().map(|tool| tool.name.as_str()))
+            .collect();
+        assert_eq!(names.len(), 3);
+        assert_eq!(names[0], "tools/mailer.echo");
+    }
+
+    #[tokio::test]
+    async fn test_list_and_search_tools_hide_tools_by_visibility() {
+        let embedder = crate::HashingEmbedder::default();
+        let modules = [
+            ("mailer", "Send an email message to a recipient"),
+            ("weather", "Get the weather forecast for a city"),
+            ("files", "List files in a directory"),
+        ]
+        .map(|(crate_name, description)| {
+            static_module_ref(
+                crate_name,
+                description,
+                Vec::leak(embedder.embed(description)),
+            )
+        });
+        let policy = serde_json::json!({
+            "name": "mail-admins",
+            "version": "1",
+            "effects": [{
+                "tool": "mailer.*",
+                "stage": "visibility",
+                "when": "caller != null && \"admin\" in caller.roles",
+                "fail_message": "admins only"
+            }]
+        });
+        let runtime = runtime_with_modules(modules.to_vec(), vec![policy])
+            .await
+            .with_search_embedder(Some(Arc::new(embedder)));
+        let admin = CallMetadata {
+            caller: Some(CallerIdentity {
+                id: "ada".to_string(),
+                roles: vec!["admin".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let list = async |metadata: CallMetadata| {
+            let mut names = Vec::new();
+            let mut page_token = String::new();
+            loop {
+                let response = runtime
+                    .list_tools(
+                        ListToolsRequest {
+                            page_size: 1,
+                            page_token,
+                        },
+                        metadata.clone(),
+                    )
+                    .await
+                    .expect("list should succeed");
+                names.extend(response.tools.into_iter().map(|tool| tool.name));
+                if response.next_page_token.is_empty() {
+                    names.sort();
+                    return names;
+                }
+                page_token = response.next_page_token;
+            }
+        };
+        assert_eq!(
+            list(CallMetadata::default()).await,
+            ["tools/files.echo", "tools/weather.echo"]
+        );
+        assert_eq!(list(admin.clone()).await.len(), 3);
+
+        let search = async |metadata: CallMetadata| {
+            runtime
+                .search_tools(
+                    SearchToolsRequest {
+                        query_text: "email a message".to_string(),
+                        page_size: 2,
+                        ..Default::default()
+                    },
+                    metadata,
+                )
+                .await
+                .expect("search should succeed")
+                .results
+                .into_iter()
+                .filter_map(|result| result.tool.map(|tool| tool.name))
+                .collect::<Vec<_>>()
+        };
+        // Hidden tools do not take up the page.
+        let names = search(CallMetadata::default()).await;
+        assert_eq!(names.len(), 2);
+        assert!(!names.contains(&"tools/mailer.echo".to_string()));
+        assert_eq!(search(admin).await[0], "tools/mailer.echo");
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_applies_policy_input_rewrites() {
+        let runtime = static_runtime(vec![serde_json::json!({
+            "name": "safety",
+            "version": "1",
+            "effects": [{
+                "tool": "static-tool.*",
+                "stage": "before",
+                "when": "true",
+                "rewrite": {
+                    "dry_run": "true",
+                    "limit": "input.limit > 10 ? 10 : input.limit"
+                }
+            }]
+        })])
+        .await;
+
+        let response = runtime
+            .call_tool(
+                CallToolRequest {
+                    name: "tools/static-tool.echo".to_string(),
+                    input: json_value_to_struct(&serde_json::json!({ "limit": 500 })),
+                },
+                CallMetadata::default(),
+            )
+            .await
+            .expect("call_tool should succeed");
+
+        let Some(call_tool_response::Result::Output(output)) = response.result else {
+            panic!("expected output result");
+        };
+        assert_eq!(
+            struct_to_json_value(&output)["input"],
+            serde_json::json!({ "dry_run": true, "limit": 10.0 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_applies_policy_output_effects() {
+        let runtime = static_runtime(vec![serde_json::json!({
+            "name": "pii",
+            "version": "1",
+            "effects": [
+                {
+                    "tool": "static-tool.*",
+                    "stage": "after",
+                    "when": "success",
+                    "transform": { "input.email": "'***'" },
+                    "drop": ["input.ssn"]
+                },
+                {
+                    "tool": "static-tool.*",
+                    "stage": "after",
+                    "when": "!has(output.input.blocked)",
+                    "on_fail_replace_output": "output withheld by policy"
+                }
+            ]
+        })])
+        .await;
+        let call = |input: serde_json::Value| {
+            runtime.call_tool(
+                CallToolRequest {
+                    name: "tools/static-tool.echo".to_string(),
+                    input: json_value_to_struct(&input),
+                },
+                CallMetadata::default(),
+            )
+        };
+
+        let response = call(serde_json::json!({ "email": "a@example.com", "ssn": "1" }))
+            .await
+            .expect("call_tool should succeed");
+        let Some(call_tool_response::Result::Output(output)) = response.result else {
+            panic!("expected output result");
+        };
+        assert_eq!(
+            struct_to_json_value(&output)["input"],
+            serde_json::json!({ "email": "***" })
+        );
+
+        let response = call(serde_json::json!({ "blocked": true }))
+            .await
+            .expect("call_tool should succeed");
+        assert_eq!(
+            response.result,
+            Some(call_tool_response::Result::Error(
+                "output withheld by policy".to_string()
+            ))
+        );
+    }
+
+    async fn approval_runtime() -> LocalRuntime {
+        static_runtime(vec![serde_json::json!({
+            "name": "payments",
+            "version": "1",
+            "effects": [{
+                "tool": "static-tool.*",
+                "stage": "before",
+                "when": "input.amount > 100",
+                "effect": "require_approval",
+                "fail_message": "large payment"
+            }]
+        })])
+        .await
+    }
+
+    fn payment_request(amount: i64) -> CallToolRequest {
+        CallToolRequest {
+            name: "tools/static-tool.echo".to_string(),
+            input: json_value_to_struct(&serde_json::json!({ "amount": amount })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deferred_call_runs_once_after_approval() {
+        let runtime = approval_runtime().await;
+        let metadata = CallMetadata {
+            session_id: "s1".to_string(),
+            defer_approval: true,
+            ..Default::default()
+        };
+
+        let response = runtime
+            .call_tool(payment_request(5), metadata.clone())
+            .await
+            .expect("call_tool should succeed");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+
+        let response = runtime
+            .call_tool(payment_request(500), metadata.clone())
+            .await
+            .expect("call_tool should succeed");
+        let Some(call_tool_response::Result::PendingApproval(pending)) = response.result else {
+            panic!("expected pending approval");
+        };
+        assert_eq!(pending.tool, "tools/static-tool.echo");
+        assert_eq!(pending.session_id, "s1");
+        assert_eq!(pending.reason, "large payment");
+
+        let listed = runtime
+            .list_approvals(ListApprovalsRequest {})
+            .await
+            .expect("list_approvals should succeed");
+        assert_eq!(listed.approvals, vec![pending.clone()]);
+
+        runtime
+            .approve_call(ApproveCallRequest {
+                name: pending.name.clone(),
+            })
+            .await
+            .expect("approve_call should succeed");
+
+        let response = runtime
+            .call_tool(payment_request(500), metadata.clone())
+            .await
+            .expect("call_tool should succeed");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+
+        // The grant is used up; the next identical call needs a new approval.
+        let response = runtime
+            .call_tool(payment_request(500), metadata)
+            .await
+            .expect("call_tool should succeed");
+        let Some(call_tool_response::Result::PendingApproval(next)) = response.result else {
+            panic!("expected pending approval");
+        };
+        assert_ne!(next.name, pending.name);
+    }
+
+    #[tokio::test]
+    async fn test_polling_deferred_call_spends_no_rate_limit_tokens() {
+        let runtime = approval_runtime().await.with_rate_limits(vec![
+            RateLimit::new("payments", 2, Duration::from_hours(1)).with_tool("static-tool.*"),
+        ]);
+        let metadata = CallMetadata {
+            session_id: "s1".to_string(),
+            defer_approval: true,
+            ..Default::default()
+        };
+
+        let mut approval = None;
+        for _ in 0..3 {
+            let response = runtime
+                .call_tool(payment_request(500), metadata.clone())
+                .await
+                .expect("polling should not be rate limited");
+            let Some(call_tool_response::Result::PendingApproval(pending)) = response.result else {
+                panic!("expected pending approval");
+            };
+            approval = Some(pending);
+        }
+        runtime
+            .approve_call(ApproveCallRequest {
+                name: approval.expect("call should be held").name,
+            })
+            .await
+            .expect("approve_call should succeed");
+        let response = runtime
+            .call_tool(payment_request(500), metadata.clone())
+            .await
+            .expect("approved call should spend the second token");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+
+        let status = runtime
+            .call_tool(payment_request(5), metadata)
+            .await
+            .expect_err("the tokens should be spent");
+        assert_eq!(status.code(), Code::ResourceExhausted);
+    }
+
+    #[tokio::test]
+    async fn test_resumed_deferred_call_evaluates_current_pre_effects() {
+        let runtime = approval_runtime().await;
+        runtime
+            .upsert_policy(UpsertPolicyRequest {
+                definition: serde_json::json!({
+                    "name": "counter",
+                    "version": "1",
+                    "context": { "calls": 0 },
+                    "effects": [{
+                        "tool": "static-tool.*",
+                        "stage": "before",
+                        "when": "true",
+                        "set": { "calls": "context.calls + 1" }
+                    }]
+                })
+                .to_string(),
+            })
+            .await
+            .expect("upsert should succeed");
+        let metadata = CallMetadata {
+            session_id: "s1".to_string(),
+            defer_approval: true,
+            ..Default::default()
+        };
+
+        let mut approval = None;
+        for _ in 0..3 {
+            let response = runtime
+                .call_tool(payment_request(500), metadata.clone())
+                .await
+                .expect("call_tool should succeed");
+            let Some(call_tool_response::Result::PendingApproval(pending)) = response.result else {
+                panic!("expected pending approval");
+            };
+            approval = Some(pending);
+        }
+        runtime
+            .approve_call(ApproveCallRequest {
+                name: approval.expect("call should be held").name,
+            })
+            .await
+            .expect("approve_call should succeed");
+        // Holding the call and running it both apply the effects; checking on
+        // the held call does not.
+        let response = runtime
+            .call_tool(payment_request(500), metadata)
+            .await
+            .expect("call_tool should succeed");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+
+        let context = runtime
+            .get_session_context(GetSessionContextRequest {
+                session_id: "s1".to_string(),
+            })
+            .await
+            .expect("get session context should succeed")
+            .context
+            .expect("context should be set");
+        assert_eq!(
+            struct_to_json_value(&context),
+            serde_json::json!({ "calls": 2.0 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resumed_deferred_call_is_denied_by_current_policies() {
+        let runtime = approval_runtime().await;
+        let metadata = CallMetadata {
+            session_id: "s1".to_string(),
+            defer_approval: true,
+            ..Default::default()
+        };
+
+        let response = runtime
+            .call_tool(payment_request(500), metadata.clone())
+            .await
+            .expect("call_tool should succeed");
+        let Some(call_tool_response::Result::PendingApproval(pending)) = response.result else {
+            panic!("expected pending approval");
+        };
+        runtime
+            .approve_call(ApproveCallRequest { name: pending.name })
+            .await
+            .expect("approve_call should succeed");
+        runtime
+            .upsert_policy(UpsertPolicyRequest {
+                definition: serde_json::json!({
+                    "name": "freeze",
+                    "version": "1",
+                    "effects": [{
+                        "tool": "static-tool.*",
+                        "stage": "before",
+                        "when": "false",
+                        "fail_message": "payments are frozen"
+                    }]
+                })
+                .to_string(),
+            })
+            .await
+            .expect("upsert should succeed");
+
+        let status = runtime
+            .call_tool(payment_request(500), metadata)
+            .await
+            .expect_err("approved call should be denied by the new policy");
+        assert_eq!(status.code(), Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn test_waiting_call_runs_once_after_approval() {
+        let runtime = approval_runtime().await;
+        let call = {
+            let runtime = runtime.clone();
+            tokio::spawn(async move {
+                runtime
+                    .call_tool(payment_request(500), CallMetadata::default())
+                    .await
+            })
+        };
+
+        let pending = loop {
+            let listed = runtime
+                .list_approvals(ListApprovalsRequest {})
+                .await
+                .expect("list_approvals should succeed");
+            if let Some(approval) = listed.approvals.into_iter().next() {
+                break approval;
+            }
+            tokio::task::yield_now().await;
+        };
+        runtime
+            .approve_call(ApproveCallRequest {
+                name: pending.name.clone(),
+            })
+            .await
+            .expect("approve_call should succeed");
+        let response = call
+            .await
+            .expect("call task should not panic")
+            .expect("approved call should succeed");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+
+        // The waiting call used the grant; an identical call is held again.
+        let response = runtime
+            .call_tool(
+                payment_request(500),
+                CallMetadata {
+                    defer_approval: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("call_tool should succeed");
+        let Some(call_tool_response::Result::PendingApproval(next)) = response.result else {
+            panic!("expected pending approval");
+        };
+        assert_ne!(next.name, pending.name);
+    }
+
+    #[tokio::test]
+    async fn test_waiting_call_fails_when_denied() {
+        let runtime = approval_runtime().await;
+        let call = {
+            let runtime = runtime.clone();
+            tokio::spawn(async move {
+                runtime
+                    .call_tool(payment_request(500), CallMetadata::default())
+                    .await
+            })
+        };
+
+        let pending = loop {
+            let listed = runtime
+                .list_approvals(ListApprovalsRequest {})
+                .await
+                .expect("list_approvals should succeed");
+            if let Some(approval) = listed.approvals.into_iter().next() {
+                break approval;
+            }
+            tokio::task::yield_now().await;
+        };
+        runtime
+            .deny_call(DenyCallRequest {
+                name: pending.name,
+                reason: "over budget".to_string(),
+            })
+            .await
+            .expect("deny_call should succeed");
+
+        let status = call
+            .await
+            .expect("call task should not panic")
+            .expect_err("denied call should fail");
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(status.message(), "over budget");
+    }
+
+    #[tokio::test]
+    async fn test_waiting_call_times_out_without_decision() {
+        let runtime = approval_runtime()
+            .await
+            .with_approval_timeout(Duration::from_millis(20));
+
+        let status = runtime
+            .call_tool(payment_request(500), CallMetadata::default())
+            .await
+            .expect_err("undecided call should fail");
+        assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+
+        let listed = runtime
+            .list_approvals(ListApprovalsRequest {})
+            .await
+            .expect("list_approvals should succeed");
+        assert!(listed.approvals.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_shadow_policy_reports_denial_without_blocking_call() {
+        let runtime = static_runtime(vec![serde_json::json!({
+            "name": "payments",
+            "version": "1",
+            "mode": "shadow",
+            "effects": [{
+                "tool": "static-tool.*",
+                "stage": "before",
+                "when": "input.amount <= 100",
+                "fail_message": "large payment"
+            }]
+        })])
+        .await;
+
+        let response = runtime
+            .call_tool(payment_request(500), CallMetadata::default())
+            .await
+            .expect("shadow policy should not block the call");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+
+        let report = runtime
+            .get_shadow_report(GetShadowReportRequest {})
+            .await
+            .expect("get_shadow_report should succeed");
+        assert_eq!(
+            report.entries,
+            vec![ShadowReportEntry {
+                policy: "payments".to_string(),
+                tool: "tools/static-tool.echo".to_string(),
+                evaluations: 1,
+                denials: 1,
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_denied_call_returns_policy_denial_and_trace() {
+        let runtime = static_runtime(vec![serde_json::json!({
+            "name": "payments",
+            "version": "2",
+            "effects": [
+                {
+                    "tool": "static-tool.*",
+                    "stage": "before",
+                    "when": "input.amount > 1000",
+                    "effect": "require_approval"
+                },
+                {
+                    "tool": "static-tool.*",
+                    "stage": "before",
+                    "when": "input.amount <= 100",
+                    "fail_message": "large payment"
+                },
+                {
+                    "tool": "static-tool.*",
+                    "stage": "after",
+                    "when": "true"
+                }
+            ]
+        })])
+        .await;
+        let traced = || CallMetadata {
+            trace_policies: true,
+            ..CallMetadata::default()
+        };
+
+        let status = runtime
+            .call_tool(payment_request(500), traced())
+            .await
+            .expect_err("policy should deny the call");
+        assert_eq!(status.code(), Code::PermissionDenied);
+        assert_eq!(status.message(), "large payment");
+        // The details are a standard `google.rpc.Status`.
+        assert!(tonic_types::StatusExt::check_error_details(&status).is_ok());
+        let denial: PolicyDenial = status_details(&status).expect("details should decode");
+        assert_eq!(
+            (
+                denial.policy.as_str(),
+                denial.policy_version.as_str(),
+                denial.effect_index,
+                denial.tool_pattern.as_str(),
+                denial.condition.as_str(),
+                denial.condition_met,
+            ),
+            (
+                "payments",
+                "2",
+                1,
+                "static-tool.*",
+                "input.amount <= 100",
+                false
+            )
+        );
+        let outcomes = |steps: &[PolicyTraceStep]| -> Vec<(u32, policy_trace_step::Outcome)> {
+            steps
+                .iter()
+                .map(|step| (step.effect_index, step.outcome()))
+                .collect()
+        };
+        assert_eq!(
+            outcomes(&denial.trace),
+            [
+                (0, policy_trace_step::Outcome::Skipped),
+                (1, policy_trace_step::Outcome::GuardFailed),
+            ]
+        );
+
+        let response = runtime
+            .call_tool(payment_request(50), traced())
+            .await
+            .expect("call should succeed");
+        assert_eq!(
+            outcomes(&response.policy_trace),
+            [
+                (0, policy_trace_step::Outcome::Skipped),
+                (1, policy_trace_step::Outcome::Applied),
+                (2, policy_trace_step::Outcome::Applied),
+            ]
+        );
+        assert_eq!(response.policy_trace[2].stage, "after");
+
+        // Without the debug option the trace is omitted.
+        let status = runtime
+            .call_tool(payment_request(500), CallMetadata::default())
+            .await
+            .expect_err("policy should deny the call");
+        let denial: PolicyDenial = status_details(&status).expect("details should decode");
+        assert_eq!(denial.policy, "payments");
+        assert!(denial.trace.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_rejects_calls_over_rate_limit_with_retry_after() {
+        let runtime = static_runtime(vec![]).await.with_rate_limits(vec![
+            RateLimit::new("echo", 1, Duration::from_hours(1)).with_tool("static-tool.*"),
+        ]);
+        let session = |session_id: &str| CallMetadata {
+            session_id: session_id.to_string(),
+            ..Default::default()
+        };
+
+        runtime
+            .call_tool(payment_request(1), session("s1"))
+            .await
+            .expect("first call should succeed");
+        let status = runtime
+            .call_tool(payment_request(1), session("s1"))
+            .await
+            .expect_err("second call should be rate limited");
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(
+            status
+                .metadata()
+                .get("retry-after")
+                .and_then(|value| value.to_str().ok()),
+            Some("3600")
+        );
+        let details: RateLimitedProto = status_details(&status).expect("details should decode");
+        assert_eq!(details.rate_limit, "echo");
+        assert_eq!(details.key, "session=s1");
+        assert!(details.retry_after_seconds > 3599.0);
+
+        runtime
+            .call_tool(payment_request(1), session("s2"))
+            .await
+            .expect("other sessions have their own bucket");
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_fails_fast_while_circuit_is_open() {
+        let runtime = static_runtime(vec![]).await.with_circuit_breakers(vec![
+            CircuitBreaker::new("static")
+                .with_tool("static-tool.*")
+                .with_min_calls(2)
+                .with_open_for(Duration::from_hours(1)),
+        ]);
+        let request = |fail: bool| CallToolRequest {
+            name: "tools/static-tool.echo".to_string(),
+            input: json_value_to_struct(&serde_json::json!({ "fail": fail })),
+        };
+        let circuit_state = async || {
+            runtime
+                .list_tools(ListToolsRequest::default(), CallMetadata::default())
+                .await
+                .expect("list_tools should succeed")
+                .tools[0]
+                .circuit_state()
+        };
+        assert_eq!(circuit_state().await, CircuitStateProto::Closed);
+
+        for fail in [false, true] {
+            let response = runtime
+                .call_tool(request(fail), CallMetadata::default())
+                .await
+                .expect("call should run");
+            assert_eq!(
+                matches!(response.result, Some(call_tool_response::Result::Error(_))),
+                fail
+            );
+        }
+        assert_eq!(circuit_state().await, CircuitStateProto::Open);
+
+        let status = runtime
+            .call_tool(request(false), CallMetadata::default())
+            .await
+            .expect_err("open circuit should reject the call");
+        assert_eq!(status.code(), Code::Unavailable);
+        assert!(
+            status
+                .message()
+                .starts_with("Circuit of tool static-tool.echo is open")
+        );
+        assert_eq!(
+            status
+                .metadata()
+                .get("retry-after")
+                .and_then(|value| value.to_str().ok()),
+            Some("3600")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_call_held_for_approval_does_not_take_half_open_probe() {
+        let runtime = approval_runtime().await.with_circuit_breakers(vec![
+            CircuitBreaker::new("static")
+                .with_tool("static-tool.*")
+                .with_min_calls(1)
+                .with_open_for(Duration::from_millis(20))
+                .with_half_open_calls(1),
+        ]);
+        let request = |input: serde_json::Value| CallToolRequest {
+            name: "tools/static-tool.echo".to_string(),
+            input: json_value_to_struct(&input),
+        };
+        runtime
+            .call_tool(
+                request(serde_json::json!({ "amount": 1, "fail": true })),
+                CallMetadata::default(),
+            )
+            .await
+            .expect("call should run");
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        let held = {
+            let runtime = runtime.clone();
+            tokio::spawn(async move {
+                runtime
+                    .call_tool(payment_request(500), CallMetadata::default())
+                    .await
+            })
+        };
+        let pending = loop {
+            let listed = runtime
+                .list_approvals(ListApprovalsRequest {})
+                .await
+                .expect("list_approvals should succeed");
+            if let Some(approval) = listed.approvals.into_iter().next() {
+                break approval;
+            }
+            tokio::task::yield_now().await;
+        };
+
+        // The held call has not taken the half-open circuit's only probe.
+        let response = runtime
+            .call_tool(
+                request(serde_json::json!({ "amount": 1 })),
+                CallMetadata::default(),
+            )
+            .await
+            .expect("probe call should run");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+
+        runtime
+            .approve_call(ApproveCallRequest { name: pending.name })
+            .await
+            .expect("approve_call should succeed");
+        let response = held
+            .await
+            .expect("call task should not panic")
+            .expect("approved call should run");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_retries_failed_calls_of_idempotent_tools() {
+        let runtime = runtime_with_modules(
+            vec![static_tool_module_ref(), idempotent_tool_module_ref()],
+            vec![],
+        )
+        .await
+        .with_retry_policy(
+            RetryPolicy::new()
+                .with_max_attempts(3)
+                .with_initial_backoff(Duration::from_millis(1))
+                .with_jitter(false),
+        );
+        let call = async |tool: &str, fail_calls: u32| {
+            STATIC_TOOL_CALLS.store(0, Ordering::SeqCst);
+            runtime
+                .call_tool(
+                    CallToolRequest {
+                        name: format!("tools/{tool}.echo"),
+                        input: json_value_to_struct(
+                            &serde_json::json!({ "fail_calls": fail_calls }),
+                        ),
+                    },
+                    CallMetadata::default(),
+                )
+                .await
+                .expect("call should run")
+        };
+
+        let response = call("idempotent-tool", 2).await;
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+        assert_eq!(response.attempts, 3);
+
+        let response = call("idempotent-tool", 3).await;
+        assert_eq!(
+            response.result,
+            Some(call_tool_response::Result::Error(
+                "upstream unavailable".to_string()
+            ))
+        );
+        assert_eq!(response.attempts, 3);
+
+        let response = call("static-tool", 1).await;
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Error(_))
+        ));
+        assert_eq!(response.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_reuses_cached_results_of_tools_with_cache_ttl() {
+        let runtime = runtime_with_modules(
+            vec![static_tool_module_ref(), cached_tool_module_ref()],
+            vec![],
+        )
+        .await
+        .with_result_cache(ResultCache::new());
+        let call = async |tool: &str, input: serde_json::Value, bypass_cache: bool| {
+            runtime
+                .call_tool(
+                    CallToolRequest {
+                        name: format!("tools/{tool}.echo"),
+                        input: json_value_to_struct(&input),
+                    },
+                    CallMetadata {
+                        bypass_cache,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .expect("call should run")
+        };
+
+        let first = call("cached-tool", serde_json::json!({ "n": 1 }), false).await;
+        assert_eq!(first.cache(), call_tool_response::CacheStatus::Miss);
+        assert_eq!(first.attempts, 1);
+
+        let second = call("cached-tool", serde_json::json!({ "n": 1 }), false).await;
+        assert_eq!(second.cache(), call_tool_response::CacheStatus::Hit);
+        assert_eq!(second.attempts, 0);
+        assert_eq!(second.result, first.result);
+
+        let bypassed = call("cached-tool", serde_json::json!({ "n": 1 }), true).await;
+        assert_eq!(bypassed.cache(), call_tool_response::CacheStatus::Bypass);
+        assert_eq!(bypassed.attempts, 1);
+
+        let other = call("cached-tool", serde_json::json!({ "n": 2 }), false).await;
+        assert_eq!(other.cache(), call_tool_response::CacheStatus::Miss);
+
+        // Failed calls are not cached.
+        for _ in 0..2 {
+            let failed = call("cached-tool", serde_json::json!({ "fail": true }), false).await;
+            assert_eq!(failed.cache(), call_tool_response::CacheStatus::Miss);
+        }
+
+        let uncached = call("static-tool", serde_json::json!({ "n": 1 }), false).await;
+        assert_eq!(
+            uncached.cache(),
+            call_tool_response::CacheStatus::Unspecified
+        );
+        assert_eq!(uncached.attempts, 1);
+
+        let stats = runtime.cache_stats().expect("results should be cached");
+        assert_eq!((stats.hits, stats.misses, stats.bypasses), (1, 4, 1));
+        assert_eq!(stats.entries, 2);
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_enforces_concurrency_limit_and_timeout() {
+        let runtime = static_runtime(vec![]).await.with_admission(
+            AdmissionController::new().with_tool_limits(
+                "static-tool.echo",
+                ToolLimits::default()
+                    .with_timeout(Duration::from_millis(200))
+                    .with_max_concurrency(1),
+            ),
+        );
+        let sleep_request = |sleep_ms: u64| CallToolRequest {
+            name: "tools/static-tool.echo".to_string(),
+            input: json_value_to_struct(&serde_json::json!({ "sleep_ms": sleep_ms })),
+        };
+
+        let running = tokio::spawn({
+            let runtime = runtime.clone();
+            async move {
+                runtime
+                    .call_tool(sleep_request(100), CallMetadata::default())
+                    .await
+            }
+        });
+        while runtime.admission_stats().is_empty() {
+            tokio::task::yield_now().await;
+        }
+        let status = runtime
+            .call_tool(sleep_request(0), CallMetadata::default())
+            .await
+            .expect_err("the only slot is taken and there is no queue");
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(
+            status.message(),
+            "Tool static-tool.echo is at its limit of 1 concurrent calls with 0 queued"
+        );
+        running
+            .await
+            .expect("task should not panic")
+            .expect("first call should succeed");
+
+        let status = runtime
+            .call_tool(sleep_request(1000), CallMetadata::default())
+            .await
+            .expect_err("call should time out");
+        assert_eq!(status.code(), Code::DeadlineExceeded);
+        // The timed out call gave up its slot.
+        runtime
+            .call_tool(sleep_request(0), CallMetadata::default())
+            .await
+            .expect("slot should be free again");
+
+        let entries = runtime.admission_stats();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].stats.admitted, 3);
+        assert_eq!(entries[0].stats.rejected, 1);
+        assert_eq!(entries[0].stats.timed_out, 1);
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_charges_budgets_and_denies_once_spent() {
+        let policy_store = PolicyStore::new(Arc::new(InMemoryPolicySessionStore::new()))
+            .with_budgets(vec![Budget::new("credits", 3.0)]);
+        let runtime = runtime_with_store(vec![static_tool_module_ref()], policy_store).await;
+        let call = |input: serde_json::Value| CallToolRequest {
+            name: "tools/static-tool.echo".to_string(),
+            input: json_value_to_struct(&input),
+        };
+
+        // Without a reported cost the tool's static cost of 1 is charged.
+        let response = runtime
+            .call_tool(call(serde_json::json!({})), CallMetadata::default())
+            .await
+            .expect("call should succeed");
+        assert_eq!(
+            response.budgets,
+            [BudgetStatusProto {
+                budget: "credits".to_string(),
+                scope: "session".to_string(),
+                limit: 3.0,
+                spent: 1.0,
+                remaining: 2.0,
+            }]
+        );
+
+        // A reported cost replaces the static one, even if it overruns.
+        let response = runtime
+            .call_tool(
+                call(serde_json::json!({ "cost": 2.5 })),
+                CallMetadata::default(),
+            )
+            .await
+            .expect("call should succeed");
+        assert_eq!(
+            (response.budgets[0].spent, response.budgets[0].remaining),
+            (3.5, 0.0)
+        );
+
+        let status = runtime
+            .call_tool(call(serde_json::json!({})), CallMetadata::default())
+            .await
+            .expect_err("budget should deny the call");
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(
+            status.message(),
+            "Budget credits exceeded: 3.5 of 3 spent, call costs 1"
+        );
+        let exceeded: BudgetExceededProto = status_details(&status).expect("details should decode");
+        assert_eq!(
+            (exceeded.cost, exceeded.budget.map(|budget| budget.spent)),
+            (1.0, Some(3.5))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_charges_static_cost_for_invalid_reported_cost() {
+        let policy_store = PolicyStore::new(Arc::new(InMemoryPolicySessionStore::new()))
+            .with_budgets(vec![Budget::new("credits", 3.0)]);
+        let runtime = runtime_with_store(vec![static_tool_module_ref()], policy_store).await;
+
+        // NaN, infinity and negative costs are charged as the static cost
+        // of 1, so the budget still fills up and denies the next call.
+        for (cost, spent) in [("NaN", 1.0), ("inf", 2.0), ("-1", 3.0)] {
+            let response = runtime
+                .call_tool(
+                    CallToolRequest {
+                        name: "tools/static-tool.echo".to_string(),
+                        input: json_value_to_struct(&serde_json::json!({ "cost": cost })),
+                    },
+                    CallMetadata::default(),
+                )
+                .await
+                .expect("call should succeed");
+            assert_eq!((cost, response.budgets[0].spent), (cost, spent));
+        }
+        let status = runtime
+            .call_tool(
+                CallToolRequest {
+                    name: "tools/static-tool.echo".to_string(),
+                    input: json_value_to_struct(&serde_json::json!({})),
+                },
+                CallMetadata::default(),
+            )
+            .await
+            .expect_err("budget should deny the call");
+        assert_eq!(status.code(), Code::ResourceExhausted);
+    }
+
+    #[tokio::test]
+    async fn test_policy_management_rpcs() {
+        let runtime = static_runtime(vec![]).await;
+        let definition = |when: &str| {
+            serde_json::json!({
+                "name": "counter",
+                "version": "2",
+                "context": { "calls": 0 },
+                "effects": [{
+                    "tool": "static-tool.*",
+                    "stage": "after",
+                    "when": when,
+                    "set": { "calls": "context.calls + 1" }
+                }]
+            })
+            .to_string()
+        };
+
+        let status = runtime
+            .upsert_policy(UpsertPolicyRequest {
+                definition: definition("input."),
+            })
+            .await
+            .expect_err("invalid policy should be rejected");
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        let policy = runtime
+            .upsert_policy(UpsertPolicyRequest {
+                definition: definition("true"),
+            })
+            .await
+            .expect("upsert should succeed");
+        assert_eq!(
+            (
+                policy.name.as_str(),
+                policy.version.as_str(),
+                policy.mode.as_str()
+            ),
+            ("policies/counter", "2", "enforce")
+        );
+        let listed = runtime
+            .list_policies(ListPoliciesRequest {})
+            .await
+            .expect("list should succeed");
+        assert_eq!(listed.policies.len(), 1);
+        assert_eq!(listed.policies[0], policy);
+        let fetched = runtime
+            .get_policy(GetPolicyRequest {
+                name: "policies/counter".to_string(),
+            })
+            .await
+            .expect("get should succeed");
+        assert_eq!(fetched, policy);
+
+        let metadata = CallMetadata {
+            session_id: "s1".to_string(),
+            ..CallMetadata::default()
+        };
+        runtime
+            .call_tool(payment_request(10), metadata.clone())
+            .await
+            .expect("call should succeed");
+        let session = || GetSessionContextRequest {
+            session_id: "s1".to_string(),
+        };
+        let context = runtime
+            .get_session_context(session())
+            .await
+            .expect("get session context should succeed")
+            .context
+            .expect("context should be set");
+        assert_eq!(
+            struct_to_json_value(&context),
+            serde_json::json!({ "calls": 1.0 })
+        );
+
+        runtime
+            .reset_session(ResetSessionRequest {
+                session_id: "s1".to_string(),
+            })
+            .await
+            .expect("reset should succeed");
+        let context = runtime
+            .get_session_context(session())
+            .await
+            .expect("get session context should succeed")
+            .context
+            .expect("context should be set");
+        assert!(context.fields.is_empty());
+
+        runtime
+            .delete_policy(DeletePolicyRequest {
+                name: "policies/counter".to_string(),
+            })
+            .await
+            .expect("delete should succeed");
+        let status = runtime
+            .get_policy(GetPolicyRequest {
+                name: "policies/counter".to_string(),
+            })
+            .await
+            .expect_err("deleted policy should be gone");
+        assert_eq!(status.code(), Code::NotFound);
+        let status = runtime
+            .delete_policy(DeletePolicyRequest {
+                name: "counter".to_string(),
+            })
+            .await
+            .expect_err("malformed name should be rejected");
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_exposes_caller_and_tool_metadata_to_policies() {
+        let runtime = static_runtime(vec![serde_json::json!({
+            "name": "identity",
+            "version": "1",
+            "effects": [{
+                "tool": "static-tool.*",
+                "stage": "before",
+                "when": "caller != null && \"admin\" in caller.roles \
+                    && tool_info.crate == \"static-tool\" \
+                    && request.id == \"r1\" && credentials == [\"github\"]",
+                "fail_message": "admin required"
+            }]
+        })])
+        .await;
+
+        let metadata = CallMetadata {
+            request_id: "r1".to_string(),
+            credentials: HashMap::from([("github".to_string(), HashMap::new())]),
+            caller: Some(CallerIdentity {
+                id: "ada".to_string(),
+                roles: vec!["admin".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let response = runtime
+            .call_tool(payment_request(5), metadata.clone())
+            .await
+            .expect("admin call should succeed");
+        assert!(matches!(
+            response.result,
+            Some(call_tool_response::Result::Output(_))
+        ));
+
+        let status = runtime
+            .call_tool(
+                payment_request(5),
+                CallMetadata {
+                    caller: None,
+                    ..metadata
+                },
+            )
+            .await
+            .expect_err("anonymous call should be denied");
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(status.message(), "admin required");
+    }
 }
@@ -4,9 +4,16 @@
 //! embeddings from text queries. This enables semantic similarity search across
 //! the tool registry, allowing clients to discover relevant tools using natural
 //! language queries.
+//!
+//! [`HashingEmbedder`] is a built-in implementation that works fully offline
+//! and produces deterministic vectors, for air-gapped servers and tests.
 
 use futures::future::BoxFuture;
 
+mod hashing;
+
+pub use hashing::HashingEmbedder;
+
 /// Future type for embedding generation.
 ///
 /// Used by [`SearchEmbedder`] to asynchronously generate query embeddings
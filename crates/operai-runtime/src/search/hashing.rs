@@ -0,0 +1,245 @@
+//! Deterministic, dependency-free text embedder.
+//!
+//! [`HashingEmbedder`] turns text into a fixed-size vector using the hashing
+//! trick over word unigrams, word bigrams, and character trigrams. Each
+//! feature is hashed with FNV-1a into a signed bucket, term frequencies are
+//! dampened logarithmically, and the result is L2-normalized so cosine
+//! similarity reduces to a dot product.
+//!
+//! The output depends only on the input text and the configured dimension:
+//! no model download, network access, or random state is involved. This makes
+//! it suitable for air-gapped deployments and for tests that assert on search
+//! ranking. Tool embeddings and query embeddings must be produced with the
+//! same dimension to be comparable.
+
+use std::collections::HashMap;
+
+use super::{SearchEmbedFuture, SearchEmbedder};
+
+/// Feature weight for whole-word unigrams.
+const UNIGRAM_WEIGHT: f32 = 1.0;
+/// Feature weight for adjacent word pairs.
+const BIGRAM_WEIGHT: f32 = 0.5;
+/// Feature weight for character trigrams (spread across all trigrams of a
+/// word so long words do not dominate).
+const TRIGRAM_WEIGHT: f32 = 0.5;
+
+/// Common English and Rust words that carry little meaning for tool search.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "fn", "for", "from", "in", "is", "it", "let",
+    "mut", "of", "on", "or", "pub", "self", "the", "to", "use", "with",
+];
+
+/// Offline embedder based on feature hashing.
+///
+/// # Example
+///
+/// ```
+/// use operai_runtime::HashingEmbedder;
+///
+/// let embedder = HashingEmbedder::default();
+/// let a = embedder.embed("send an email to a user");
+/// let b = embedder.embed("send email");
+/// assert_eq!(a.len(), HashingEmbedder::DEFAULT_DIMENSIONS);
+/// assert_eq!(a, embedder.embed("send an email to a user"));
+/// assert!(a.iter().zip(&b).map(|(x, y)| x * y).sum::<f32>() > 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashingEmbedder {
+    dimensions: usize,
+}
+
+impl HashingEmbedder {
+    /// Dimension used by [`HashingEmbedder::default`].
+    pub const DEFAULT_DIMENSIONS: usize = 256;
+
+    /// Creates an embedder producing vectors of `dimensions` components.
+    ///
+    /// A dimension of zero is treated as one.
+    #[must_use]
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions: dimensions.max(1),
+        }
+    }
+
+    /// Returns the length of vectors produced by this embedder.
+    #[must_use]
+    pub const fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    /// Embeds `text` into an L2-normalized vector.
+    ///
+    /// Text without any indexable words yields the zero vector.
+    #[must_use]
+    pub fn embed(&self, text: &str) -> Vec<f32> {
+        let words = tokenize(text);
+
+        let mut features: HashMap<u64, f32> = HashMap::new();
+        for word in &words {
+            *features.entry(feature_hash(b'w', word)).or_default() += UNIGRAM_WEIGHT;
+
+            let padded: Vec<char> = std::iter::once('^')
+                .chain(word.chars())
+                .chain(std::iter::once('$'))
+                .collect();
+            let trigram_count = padded.len().saturating_sub(2);
+            if trigram_count > 0 {
+                #[expect(
+                    clippy::cast_precision_loss,
+                    reason = "word lengths are far below f32 precision limits"
+                )]
+                let weight = TRIGRAM_WEIGHT / trigram_count as f32;
+                for window in padded.windows(3) {
+                    let trigram: String = window.iter().collect();
+                    *features.entry(feature_hash(b'c', &trigram)).or_default() += weight;
+                }
+            }
+        }
+        for pair in words.windows(2) {
+            let bigram = format!("{} {}", pair[0], pair[1]);
+            *features.entry(feature_hash(b'b', &bigram)).or_default() += BIGRAM_WEIGHT;
+        }
+
+        let mut vector = vec![0.0_f32; self.dimensions];
+        for (hash, weight) in features {
+            // Sublinear term frequency keeps repeated tokens from drowning out
+            // the rest of the document.
+            let value = 1.0 + weight.ln_1p();
+            let index = usize::try_from(hash % self.dimensions as u64).unwrap_or(0);
+            if hash >> 63 == 0 {
+                vector[index] += value;
+            } else {
+                vector[index] -= value;
+            }
+        }
+
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for value in &mut vector {
+                *value /= norm;
+            }
+        }
+        vector
+    }
+}
+
+impl Default for HashingEmbedder {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DIMENSIONS)
+    }
+}
+
+impl SearchEmbedder for HashingEmbedder {
+    fn embed_query(&self, query: &str) -> SearchEmbedFuture<'_> {
+        let embedding = self.embed(query);
+        Box::pin(async move { Ok(embedding) })
+    }
+}
+
+/// Splits text into lowercase words on non-alphanumeric boundaries and
+/// `camelCase` transitions, dropping stop words and single characters.
+fn tokenize(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    let mut flush = |current: &mut String| {
+        if current.chars().count() > 1 && !STOP_WORDS.contains(&current.as_str()) {
+            words.push(std::mem::take(current));
+        } else {
+            current.clear();
+        }
+    };
+
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            if ch.is_uppercase() && prev_lower {
+                flush(&mut current);
+            }
+            prev_lower = ch.is_lowercase() || ch.is_numeric();
+            current.extend(ch.to_lowercase());
+        } else {
+            prev_lower = false;
+            flush(&mut current);
+        }
+    }
+    flush(&mut current);
+    words
+}
+
+/// FNV-1a hash of `text`, namespaced by feature kind so that e.g. a word and a
+/// trigram with the same spelling land in different buckets.
+fn feature_hash(kind: u8, text: &str) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET;
+    for byte in std::iter::once(kind).chain(text.bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_embed_is_deterministic_and_normalized() {
+        let embedder = HashingEmbedder::new(128);
+        let a = embedder.embed("Create a calendar event");
+        let b = embedder.embed("Create a calendar event");
+
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 128);
+        let norm: f32 = a.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_embed_empty_text_is_zero_vector() {
+        let embedder = HashingEmbedder::default();
+        let embedding = embedder.embed("  -- the a --");
+
+        assert_eq!(embedding.len(), HashingEmbedder::DEFAULT_DIMENSIONS);
+        assert!(embedding.iter().all(|v| *v == 0.0));
+    }
+
+    #[test]
+    fn test_related_text_ranks_above_unrelated_text() {
+        let embedder = HashingEmbedder::default();
+        let query = embedder.embed("send email message");
+        let email = embedder.embed("Send an email message to a recipient via SMTP");
+        let weather = embedder.embed("Get the current weather forecast for a city");
+
+        assert!(cosine(&query, &email) > cosine(&query, &weather));
+    }
+
+    #[test]
+    fn test_tokenize_splits_identifiers() {
+        assert_eq!(
+            tokenize("sendEmail create_issue HTTPRequest v2"),
+            vec!["send", "email", "create", "issue", "httprequest", "v2"]
+        );
+    }
+
+    #[test]
+    fn test_zero_dimensions_is_clamped() {
+        assert_eq!(HashingEmbedder::new(0).dimensions(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_embed_query_matches_embed() {
+        let embedder = HashingEmbedder::default();
+        let via_trait = embedder.embed_query("list files").await.unwrap();
+
+        assert_eq!(via_trait, embedder.embed("list files"));
+    }
+}
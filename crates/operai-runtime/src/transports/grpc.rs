@@ -4,12 +4,28 @@
 //! functionality over the network. It implements the `Toolbox` service trait,
 //! allowing clients to list, search, and call tools remotely.
 //!
+//! Operator actions, such as deciding calls held for approval, changing
+//! policies or ending sessions, are in the separate `ToolboxAdmin` service
+//! implemented by [`ToolboxAdminService`]. Agents calling tools must not be
+//! able to reach it, so servers register it on its own address rather than
+//! alongside `Toolbox`.
+//!
 //! # Metadata Extraction
 //!
 //! The service extracts metadata from gRPC request headers:
 //! - `x-request-id`: Request identifier for tracing
 //! - `x-session-id`: Session identifier for policy evaluation
 //! - `x-credential-*`: Base64-encoded JSON credentials for external services
+//! - `x-approval-mode`: `defer` to get a pending approval back instead of
+//!   waiting when a policy requires approval
+//! - `x-policy-trace`: `true` to get the evaluated policy effects back with the
+//!   response, or with the error details of a denied call
+//! - `x-cache-mode`: `bypass` to run the tool even if its output is cached
+//!
+//! The service does not authenticate callers. An interceptor or tower layer in
+//! front of it can insert a
+//! [`CallerIdentity`](operai_core::policy::request::CallerIdentity) into the
+//! request extensions, which is then passed to policies as `caller`.
 //!
 //! # Credentials Format
 //!
@@ -20,14 +36,23 @@
 use std::{collections::HashMap, sync::Arc};
 
 use base64::prelude::*;
-use operai_core::{ToolRegistry, policy::session::PolicyStore};
+use operai_core::{
+    ToolRegistry,
+    policy::{request::CallerIdentity, session::PolicyStore},
+};
 use tonic::{Request, Response, Status};
 use tracing::{instrument, warn};
 
 use crate::{
     proto::{
-        CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse, SearchToolsRequest,
-        SearchToolsResponse, toolbox_server::Toolbox,
+        Approval, ApproveCallRequest, CallToolRequest, CallToolResponse, DeletePolicyRequest,
+        DeletePolicyResponse, DenyCallRequest, EndSessionRequest, EndSessionResponse,
+        GetPolicyRequest, GetSessionContextRequest, GetSessionContextResponse,
+        GetShadowReportRequest, GetShadowReportResponse, ListApprovalsRequest,
+        ListApprovalsResponse, ListPoliciesRequest, ListPoliciesResponse, ListToolsRequest,
+        ListToolsResponse, Policy, ResetSessionRequest, ResetSessionResponse, SearchToolsRequest,
+        SearchToolsResponse, UpsertPolicyRequest, toolbox_admin_server::ToolboxAdmin,
+        toolbox_server::Toolbox,
     },
     runtime::{CallMetadata, LocalRuntime},
 };
@@ -95,6 +120,51 @@ impl ToolboxService {
         (get("x-request-id"), get("x-session-id"))
     }
 
+    /// Builds the call metadata of a request from its headers and the caller
+    /// identity in its extensions.
+    fn call_metadata<T>(request: &Request<T>) -> CallMetadata {
+        let (request_id, session_id) = Self::extract_metadata(request);
+        CallMetadata {
+            request_id,
+            session_id,
+            credentials: Self::extract_credentials(request),
+            defer_approval: Self::defers_approval(request),
+            caller: request.extensions().get::<CallerIdentity>().cloned(),
+            trace_policies: Self::traces_policies(request),
+            bypass_cache: Self::bypasses_cache(request),
+        }
+    }
+
+    /// Returns whether the request asks for a pending approval instead of
+    /// waiting, via the `x-approval-mode: defer` header.
+    fn defers_approval<T>(request: &Request<T>) -> bool {
+        request
+            .metadata()
+            .get("x-approval-mode")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.eq_ignore_ascii_case("defer"))
+    }
+
+    /// Returns whether the request asks for a policy evaluation trace, via
+    /// the `x-policy-trace: true` header.
+    fn traces_policies<T>(request: &Request<T>) -> bool {
+        request
+            .metadata()
+            .get("x-policy-trace")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+    }
+
+    /// Returns whether the request asks to skip the result cache, via the
+    /// `x-cache-mode: bypass` header.
+    fn bypasses_cache<T>(request: &Request<T>) -> bool {
+        request
+            .metadata()
+            .get("x-cache-mode")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.eq_ignore_ascii_case("bypass"))
+    }
+
     /// Extracts user credentials from gRPC request metadata headers.
     ///
     /// Credentials are passed via headers with the format
@@ -154,7 +224,8 @@ impl ToolboxService {
 /// - Calling tools with input validation and policy enforcement
 #[tonic::async_trait]
 impl Toolbox for ToolboxService {
-    /// Lists all available tools in the registry.
+    /// Lists the tools in the registry visible to the request's session and
+    /// caller.
     ///
     /// Supports pagination via `page_size` and `page_token` parameters.
     /// Returns tools in a deterministic order with a `next_page_token` for
@@ -164,11 +235,16 @@ impl Toolbox for ToolboxService {
         &self,
         request: Request<ListToolsRequest>,
     ) -> Result<Response<ListToolsResponse>, Status> {
-        let response = self.runtime.list_tools(request.into_inner()).await?;
+        let metadata = Self::call_metadata(&request);
+        let response = self
+            .runtime
+            .list_tools(request.into_inner(), metadata)
+            .await?;
         Ok(Response::new(response))
     }
 
-    /// Searches for tools by semantic similarity using an embedding vector.
+    /// Searches the tools visible to the request's session and caller by
+    /// semantic similarity using an embedding vector.
     ///
     /// The `query_embedding` must match the dimensionality of tool embeddings
     /// in the registry. Returns tools ranked by cosine similarity.
@@ -177,14 +253,19 @@ impl Toolbox for ToolboxService {
         &self,
         request: Request<SearchToolsRequest>,
     ) -> Result<Response<SearchToolsResponse>, Status> {
-        let response = self.runtime.search_tools(request.into_inner()).await?;
+        let metadata = Self::call_metadata(&request);
+        let response = self
+            .runtime
+            .search_tools(request.into_inner(), metadata)
+            .await?;
         Ok(Response::new(response))
     }
 
     /// Invokes a tool with the provided input and metadata.
     ///
     /// This method:
-    /// 1. Extracts request metadata (`request_id`, `session_id`)
+    /// 1. Extracts request metadata (`request_id`, `session_id`) and the caller
+    ///    identity from request extensions
     /// 2. Parses user credentials from headers
     /// 3. Validates the tool name format
     /// 4. Enforces pre-call policy evaluation
@@ -200,13 +281,7 @@ impl Toolbox for ToolboxService {
         &self,
         request: Request<CallToolRequest>,
     ) -> Result<Response<CallToolResponse>, Status> {
-        let (request_id, session_id) = Self::extract_metadata(&request);
-        let user_creds = Self::extract_credentials(&request);
-        let metadata = CallMetadata {
-            request_id,
-            session_id,
-            credentials: user_creds,
-        };
+        let metadata = Self::call_metadata(&request);
 
         let response = self
             .runtime
@@ -217,6 +292,152 @@ impl Toolbox for ToolboxService {
     }
 }
 
+/// gRPC service implementation for the `ToolboxAdmin` API.
+///
+/// This service wraps the same [`LocalRuntime`] as the [`ToolboxService`] it
+/// administers. It does not authenticate callers, so it should only be served
+/// on an address that operators, and not agents, can reach.
+pub struct ToolboxAdminService {
+    runtime: LocalRuntime,
+}
+
+impl ToolboxAdminService {
+    /// Creates a new `ToolboxAdminService` administering `runtime`.
+    #[must_use]
+    pub fn from_runtime(runtime: LocalRuntime) -> Self {
+        Self { runtime }
+    }
+
+    /// Returns a reference to the underlying runtime.
+    #[must_use]
+    pub fn runtime(&self) -> &LocalRuntime {
+        &self.runtime
+    }
+}
+
+/// gRPC service implementation for the `ToolboxAdmin` protocol.
+///
+/// This trait implementation handles the operator actions:
+/// - Listing, approving, and denying calls held for approval
+/// - Reporting what shadow-mode policies would have done
+/// - Listing, getting, registering, and unregistering policies
+/// - Getting and clearing the policy context of sessions, and ending them
+#[tonic::async_trait]
+impl ToolboxAdmin for ToolboxAdminService {
+    /// Lists tool calls waiting for approval.
+    #[instrument(skip(self, request))]
+    async fn list_approvals(
+        &self,
+        request: Request<ListApprovalsRequest>,
+    ) -> Result<Response<ListApprovalsResponse>, Status> {
+        let response = self.runtime.list_approvals(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Approves a pending tool call.
+    #[instrument(skip(self, request))]
+    async fn approve_call(
+        &self,
+        request: Request<ApproveCallRequest>,
+    ) -> Result<Response<Approval>, Status> {
+        let response = self.runtime.approve_call(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Denies a pending tool call.
+    #[instrument(skip(self, request))]
+    async fn deny_call(
+        &self,
+        request: Request<DenyCallRequest>,
+    ) -> Result<Response<Approval>, Status> {
+        let response = self.runtime.deny_call(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Reports what shadow-mode policies would have done.
+    #[instrument(skip(self, request))]
+    async fn get_shadow_report(
+        &self,
+        request: Request<GetShadowReportRequest>,
+    ) -> Result<Response<GetShadowReportResponse>, Status> {
+        let response = self.runtime.get_shadow_report(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Lists the registered policies.
+    #[instrument(skip(self, request))]
+    async fn list_policies(
+        &self,
+        request: Request<ListPoliciesRequest>,
+    ) -> Result<Response<ListPoliciesResponse>, Status> {
+        let response = self.runtime.list_policies(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Gets a registered policy.
+    #[instrument(skip(self, request))]
+    async fn get_policy(
+        &self,
+        request: Request<GetPolicyRequest>,
+    ) -> Result<Response<Policy>, Status> {
+        let response = self.runtime.get_policy(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Registers or replaces a policy.
+    #[instrument(skip(self, request))]
+    async fn upsert_policy(
+        &self,
+        request: Request<UpsertPolicyRequest>,
+    ) -> Result<Response<Policy>, Status> {
+        let response = self.runtime.upsert_policy(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Unregisters a policy.
+    #[instrument(skip(self, request))]
+    async fn delete_policy(
+        &self,
+        request: Request<DeletePolicyRequest>,
+    ) -> Result<Response<DeletePolicyResponse>, Status> {
+        let response = self.runtime.delete_policy(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Gets the policy context of a session.
+    #[instrument(skip(self, request))]
+    async fn get_session_context(
+        &self,
+        request: Request<GetSessionContextRequest>,
+    ) -> Result<Response<GetSessionContextResponse>, Status> {
+        let response = self
+            .runtime
+            .get_session_context(request.into_inner())
+            .await?;
+        Ok(Response::new(response))
+    }
+
+    /// Clears the policy context of a session.
+    #[instrument(skip(self, request))]
+    async fn reset_session(
+        &self,
+        request: Request<ResetSessionRequest>,
+    ) -> Result<Response<ResetSessionResponse>, Status> {
+        let response = self.runtime.reset_session(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    /// Discards the policy session state for the given session.
+    #[instrument(skip(self, request))]
+    async fn end_session(
+        &self,
+        request: Request<EndSessionRequest>,
+    ) -> Result<Response<EndSessionResponse>, Status> {
+        let response = self.runtime.end_session(request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     //! Integration tests for the gRPC transport layer.
@@ -232,6 +453,9 @@ mod tests {
     };
 
     use operai_abi::RuntimeContext;
+    use operai_core::policy::session::{
+        InMemoryPolicySessionStore, PolicySession, PolicySessionStore,
+    };
     use tonic::Code;
 
     use super::*;
@@ -659,6 +883,9 @@ mod tests {
             call_tool_response::Result::Error(message) => {
                 panic!("expected output, got error: {message}");
             }
+            call_tool_response::Result::PendingApproval(approval) => {
+                panic!("expected output, got pending approval: {}", approval.name);
+            }
         }
 
         assert_eq!(registry.inflight_count(), 0);
@@ -702,9 +929,89 @@ mod tests {
             call_tool_response::Result::Error(message) => {
                 panic!("expected output, got error: {message}");
             }
+            call_tool_response::Result::PendingApproval(approval) => {
+                panic!("expected output, got pending approval: {}", approval.name);
+            }
         }
     }
 
+    #[tokio::test]
+    async fn test_call_tool_passes_caller_identity_from_extensions_to_policies() {
+        // Arrange
+        let (service, _registry) = service_with_hello_world_registry().await;
+        let policy: operai_core::Policy = serde_json::from_value(serde_json::json!({
+            "name": "callers",
+            "version": "1",
+            "effects": [{
+                "tool": "hello-world.*",
+                "stage": "before",
+                "when": "caller != null && caller.id == \"ada\"",
+                "fail_message": "unknown caller"
+            }]
+        }))
+        .expect("policy should deserialize");
+        service
+            .runtime
+            .policy_store()
+            .register(policy)
+            .expect("policy should register");
+        let request = || {
+            Request::new(CallToolRequest {
+                name: "tools/hello-world.greet".to_string(),
+                input: Some(prost_types::Struct {
+                    fields: [("name".to_string(), make_string_value("Test"))]
+                        .into_iter()
+                        .collect(),
+                }),
+            })
+        };
+        let mut authenticated = request();
+        authenticated.extensions_mut().insert(CallerIdentity {
+            id: "ada".to_string(),
+            ..Default::default()
+        });
+
+        // Act
+        let allowed = <ToolboxService as Toolbox>::call_tool(&service, authenticated).await;
+        let denied = <ToolboxService as Toolbox>::call_tool(&service, request()).await;
+
+        // Assert
+        assert!(allowed.is_ok(), "authenticated call should succeed");
+        let status = denied.expect_err("anonymous call should be denied");
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(status.message(), "unknown caller");
+    }
+
+    #[tokio::test]
+    async fn test_end_session_discards_session_state() {
+        // Arrange
+        let session_store = Arc::new(InMemoryPolicySessionStore::new());
+        let policy_store = Arc::new(PolicyStore::new(
+            Arc::clone(&session_store) as Arc<dyn PolicySessionStore>
+        ));
+        let service = ToolboxAdminService::from_runtime(LocalRuntime::new(
+            Arc::new(ToolRegistry::new()),
+            policy_store,
+        ));
+        session_store
+            .save("sess-1", &PolicySession::default())
+            .await
+            .expect("session should save");
+
+        // Act
+        <ToolboxAdminService as ToolboxAdmin>::end_session(
+            &service,
+            Request::new(EndSessionRequest {
+                session_id: "sess-1".to_string(),
+            }),
+        )
+        .await
+        .expect("end_session should succeed");
+
+        // Assert
+        assert!(session_store.is_empty().expect("store should be readable"));
+    }
+
     #[tokio::test]
     async fn test_call_tool_with_missing_input_returns_error_result() {
         // Arrange
@@ -731,6 +1038,9 @@ mod tests {
             call_tool_response::Result::Error(message) => {
                 assert!(!message.is_empty());
             }
+            call_tool_response::Result::PendingApproval(approval) => {
+                panic!("expected error, got pending approval: {}", approval.name);
+            }
         }
 
         assert_eq!(registry.inflight_count(), 0);
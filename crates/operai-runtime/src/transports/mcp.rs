@@ -19,14 +19,71 @@
 //! `McpService` implements the `rmcp::ServerHandler` trait, bridging
 //! the MCP protocol with the local tool runtime. It handles:
 //!
-//! - Tool discovery and metadata conversion
+//! - Tool discovery and metadata conversion, hiding tools that `visibility`
+//!   stage policy effects deny to the session or caller
 //! - Request routing between standard and search modes
 //! - Session extraction from HTTP headers for policy enforcement
+//! - Passing on a
+//!   [`CallerIdentity`](operai_core::policy::request::CallerIdentity) that an
+//!   HTTP authentication layer inserted into the request extensions
 //! - Error translation between gRPC and MCP error formats
+//!
+//! # Session Lifecycle
+//!
+//! Each MCP session served over HTTP gets its own handler. Policy session
+//! state is kept until it is evicted by idle TTL or ended explicitly. With
+//! [`McpService::end_sessions_on_close`], the policy session recorded for an
+//! MCP session is ended when that session closes instead.
+//!
+//! # Approvals
+//!
+//! Calls that a policy holds for human approval do not block the MCP request.
+//! The tool returns a structured error with `status: "awaiting_approval"` and
+//! the approval's name; the client retries the same call in the same session
+//! once the approval is granted.
+//!
+//! # Denials
+//!
+//! A call blocked by a policy fails with an `invalid_request` error whose
+//! `data` names the policy and effect responsible (`policy`,
+//! `policy_version`, `effect_index`, `tool_pattern`, `condition`,
+//! `condition_met`).
+//!
+//! A call blocked by a cost budget fails with an `invalid_request` error
+//! whose `data` describes the budget (`budget`, `scope`, `limit`, `spent`,
+//! `remaining`, `cost`).
+//!
+//! A call rejected by a rate limit fails with an `invalid_request` error
+//! whose `data` names the limit and says when to retry (`rate_limit`, `key`,
+//! `retry_after_seconds`).
+//!
+//! # Budgets
+//!
+//! Results of calls that counted against cost budgets list what is left of
+//! them under the `budgets` key of the result's `_meta`.
+//!
+//! # Retries
+//!
+//! Results of calls to idempotent tools that were retried after failing give
+//! the number of attempts made under the `attempts` key of the result's
+//! `_meta`.
+//!
+//! # Caching
+//!
+//! Results of calls to tools whose results are cached say whether the output
+//! was reused (`hit`), computed (`miss`), or computed because the request set
+//! the `x-cache-mode: bypass` HTTP header (`bypass`) under the `cache` key of
+//! the result's `_meta`.
+
+use std::{
+    borrow::Cow,
+    sync::{Arc, OnceLock},
+};
 
-use std::{borrow::Cow, sync::Arc};
-
-use operai_core::{ToolInfo, ToolRegistry, policy::session::PolicyStore};
+use operai_core::{
+    ToolInfo, ToolRegistry,
+    policy::{request::CallerIdentity, session::PolicyStore},
+};
 use rmcp::{
     ErrorData, RoleServer,
     handler::server::ServerHandler,
@@ -44,10 +101,17 @@ use rmcp::{
 };
 use serde::de::DeserializeOwned;
 use tonic::Code;
+use tracing::warn;
 
 use crate::{
-    proto::{CallToolRequest, ListToolsRequest, SearchToolsRequest, call_tool_response},
-    runtime::{CallMetadata, LocalRuntime, json_value_to_struct, struct_to_json_value},
+    proto::{
+        BudgetExceeded, BudgetStatus, CallToolRequest, ListToolsRequest, PolicyDenial, RateLimited,
+        SearchToolsRequest, call_tool_response,
+    },
+    runtime::{
+        CallMetadata, LocalRuntime, json_value_to_struct, status_details, struct_to_json_value,
+        visibility_request,
+    },
     search::SearchEmbedder,
 };
 
@@ -92,6 +156,8 @@ pub struct McpService {
     info: ServerInfo,
     search_mode: bool,
     search_embedder: Option<Arc<dyn SearchEmbedder>>,
+    end_sessions_on_close: bool,
+    session_cleanup: Option<Arc<SessionCleanup>>,
 }
 
 impl McpService {
@@ -119,6 +185,8 @@ impl McpService {
             info,
             search_mode: false,
             search_embedder: None,
+            end_sessions_on_close: false,
+            session_cleanup: None,
         }
     }
 
@@ -143,6 +211,18 @@ impl McpService {
         self
     }
 
+    /// End the policy session of an MCP session when the MCP session closes.
+    ///
+    /// Disabled by default. The policy session ID is supplied by the client,
+    /// so only enable this with stateful HTTP sessions whose IDs are not
+    /// shared with other transports; in stateless mode every request's
+    /// handler is dropped after the request and would end the session.
+    #[must_use]
+    pub fn end_sessions_on_close(mut self, enabled: bool) -> Self {
+        self.end_sessions_on_close = enabled;
+        self
+    }
+
     /// Returns whether search mode is enabled.
     #[must_use]
     pub fn is_searchable(&self) -> bool {
@@ -174,13 +254,70 @@ impl McpService {
     ///
     /// Returns an Axum-compatible service with custom server settings for
     /// timeouts, limits, and other HTTP transport parameters.
+    ///
+    /// Every MCP session gets its own handler. If
+    /// [`end_sessions_on_close`](Self::end_sessions_on_close) is enabled, the
+    /// handler ends the session's policy state when the session closes.
     #[must_use]
     pub fn streamable_http_service_with_config(
         &self,
         config: StreamableHttpServerConfig,
     ) -> StreamableHttpService<Self, LocalSessionManager> {
         let service = self.clone();
-        StreamableHttpService::new(move || Ok(service.clone()), Arc::default(), config)
+        StreamableHttpService::new(
+            move || {
+                let mut service = service.clone();
+                if service.end_sessions_on_close {
+                    service.session_cleanup = Some(Arc::new(SessionCleanup::new(Arc::clone(
+                        service.runtime.policy_store(),
+                    ))));
+                }
+                Ok(service)
+            },
+            Arc::default(),
+            config,
+        )
+    }
+}
+
+/// Ends a policy session when the MCP session that used it closes.
+///
+/// Shared by the clones of a per-session handler; the session ID is recorded
+/// from the first tool call that carries one.
+struct SessionCleanup {
+    policy_store: Arc<PolicyStore>,
+    session_id: OnceLock<String>,
+}
+
+impl SessionCleanup {
+    fn new(policy_store: Arc<PolicyStore>) -> Self {
+        Self {
+            policy_store,
+            session_id: OnceLock::new(),
+        }
+    }
+
+    fn record(&self, session_id: &str) {
+        if !session_id.is_empty() {
+            let _ = self.session_id.get_or_init(|| session_id.to_string());
+        }
+    }
+}
+
+impl Drop for SessionCleanup {
+    fn drop(&mut self) {
+        let Some(session_id) = self.session_id.take() else {
+            return;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let policy_store = Arc::clone(&self.policy_store);
+        handle.spawn(async move {
+            if let Err(e) = policy_store.end_session(&session_id).await {
+                warn!(session_id = %session_id, error = %e, "Failed to end policy session");
+            }
+        });
     }
 }
 
@@ -192,22 +329,35 @@ impl ServerHandler for McpService {
 
     /// Lists available tools.
     ///
-    /// In standard mode, returns all tools from the registry.
+    /// In standard mode, returns the tools from the registry that the
+    /// session and caller may see.
     /// In search mode, returns the three meta-tools for search/list/call.
     fn list_tools(
         &self,
         _request: Option<PaginatedRequestParam>,
-        _context: RequestContext<RoleServer>,
+        context: RequestContext<RoleServer>,
     ) -> impl std::future::Future<Output = Result<ListToolsResult, ErrorData>> + Send + '_ {
         let registry = Arc::clone(self.runtime.registry());
+        let policy_store = Arc::clone(self.runtime.policy_store());
         let search_mode = self.search_mode;
         async move {
-            let tools = if search_mode {
-                search_mode_tools()
-            } else {
-                registry.list().map(tool_info_to_mcp).collect()
-            };
-            Ok(ListToolsResult::with_all_items(tools))
+            if search_mode {
+                return Ok(ListToolsResult::with_all_items(search_mode_tools()));
+            }
+            let metadata = call_metadata(&context);
+            let tools = policy_store
+                .filter_visible(
+                    &visibility_request(&metadata),
+                    registry.list().collect(),
+                    |info| info,
+                )
+                .await
+                .map_err(|e| {
+                    ErrorData::internal_error(format!("policy evaluation error: {e}"), None)
+                })?;
+            Ok(ListToolsResult::with_all_items(
+                tools.into_iter().map(tool_info_to_mcp).collect(),
+            ))
         }
     }
 
@@ -226,6 +376,11 @@ impl ServerHandler for McpService {
         let runtime = self.runtime.clone();
         let search_mode = self.search_mode;
         let search_embedder = self.search_embedder.clone();
+        if let Some(cleanup) = &self.session_cleanup
+            && let Some(session_id) = extract_session_id_from_extensions(&context.extensions)
+        {
+            cleanup.record(&session_id);
+        }
         async move {
             if search_mode {
                 return call_search_mode_tool(request, runtime, context, search_embedder).await;
@@ -237,12 +392,7 @@ impl ServerHandler for McpService {
                 .map(serde_json::Value::Object)
                 .and_then(|value| json_value_to_struct(&value));
 
-            let metadata = CallMetadata {
-                request_id: context.id.to_string(),
-                session_id: extract_session_id_from_extensions(&context.extensions)
-                    .unwrap_or_default(),
-                ..Default::default()
-            };
+            let metadata = call_metadata(&context);
 
             let response = runtime
                 .call_tool(
@@ -255,20 +405,42 @@ impl ServerHandler for McpService {
                 .await
                 .map_err(|status| status_to_error(&status))?;
 
-            match response.result {
+            let cache = response.cache();
+            let result = match response.result {
                 Some(call_tool_response::Result::Output(output)) => {
                     let value = struct_to_json_value(&output);
-                    Ok(CallToolResult::structured(value))
+                    CallToolResult::structured(value)
                 }
                 Some(call_tool_response::Result::Error(message)) => {
-                    Ok(CallToolResult::error(vec![Content::text(message)]))
+                    CallToolResult::error(vec![Content::text(message)])
                 }
-                None => Err(ErrorData::internal_error("missing tool response", None)),
-            }
+                Some(call_tool_response::Result::PendingApproval(approval)) => {
+                    CallToolResult::structured_error(approval_to_json(&approval))
+                }
+                None => return Err(ErrorData::internal_error("missing tool response", None)),
+            };
+            let result = with_budgets_meta(result, &response.budgets);
+            let result = with_attempts_meta(result, response.attempts);
+            Ok(with_cache_meta(result, cache))
         }
     }
 }
 
+/// Builds the metadata of a tool call or listing from the MCP session and
+/// the caller identity in the request extensions.
+///
+/// Calls held for approval return immediately rather than blocking.
+fn call_metadata(context: &RequestContext<RoleServer>) -> CallMetadata {
+    CallMetadata {
+        request_id: context.id.to_string(),
+        session_id: extract_session_id_from_extensions(&context.extensions).unwrap_or_default(),
+        defer_approval: true,
+        caller: extract_caller_from_extensions(&context.extensions),
+        bypass_cache: bypasses_cache(&context.extensions),
+        ..Default::default()
+    }
+}
+
 /// Handles tool invocations in search mode.
 ///
 /// Routes calls to the appropriate meta-tool:
@@ -281,20 +453,19 @@ async fn call_search_mode_tool(
     context: RequestContext<RoleServer>,
     search_embedder: Option<Arc<dyn SearchEmbedder>>,
 ) -> Result<CallToolResult, ErrorData> {
-    let metadata = CallMetadata {
-        request_id: context.id.to_string(),
-        session_id: extract_session_id_from_extensions(&context.extensions).unwrap_or_default(),
-        ..Default::default()
-    };
+    let metadata = call_metadata(&context);
 
     match request.name.as_ref() {
         SEARCH_TOOL_LIST => {
             let args = parse_args_or_default::<ListArgs>(request.arguments)?;
             let response = runtime
-                .list_tools(ListToolsRequest {
-                    page_size: args.page_size.unwrap_or(0),
-                    page_token: args.page_token.unwrap_or_default(),
-                })
+                .list_tools(
+                    ListToolsRequest {
+                        page_size: args.page_size.unwrap_or(0),
+                        page_token: args.page_token.unwrap_or_default(),
+                    },
+                    metadata,
+                )
                 .await
                 .map_err(|status| status_to_error(&status))?;
             Ok(CallToolResult::structured(list_tools_response_to_json(
@@ -313,12 +484,15 @@ async fn call_search_mode_tool(
                 ErrorData::internal_error(format!("failed to embed query: {err}"), None)
             })?;
             let response = runtime
-                .search_tools(SearchToolsRequest {
-                    query_embedding: embedding,
-                    query_text: String::new(),
-                    page_size: args.page_size.unwrap_or(0),
-                    page_token: args.page_token.unwrap_or_default(),
-                })
+                .search_tools(
+                    SearchToolsRequest {
+                        query_embedding: embedding,
+                        query_text: String::new(),
+                        page_size: args.page_size.unwrap_or(0),
+                        page_token: args.page_token.unwrap_or_default(),
+                    },
+                    metadata,
+                )
                 .await
                 .map_err(|status| status_to_error(&status))?;
             Ok(CallToolResult::structured(search_tools_response_to_json(
@@ -346,15 +520,17 @@ async fn call_search_mode_tool(
                 .await
                 .map_err(|status| status_to_error(&status))?;
             let value = call_tool_response_to_json(&response);
-            match response.result {
-                Some(call_tool_response::Result::Output(_)) => {
-                    Ok(CallToolResult::structured(value))
-                }
-                Some(call_tool_response::Result::Error(_)) => {
-                    Ok(CallToolResult::structured_error(value))
-                }
-                None => Err(ErrorData::internal_error("missing tool response", None)),
-            }
+            let result = match response.result {
+                Some(call_tool_response::Result::Output(_)) => CallToolResult::structured(value),
+                Some(
+                    call_tool_response::Result::Error(_)
+                    | call_tool_response::Result::PendingApproval(_),
+                ) => CallToolResult::structured_error(value),
+                None => return Err(ErrorData::internal_error("missing tool response", None)),
+            };
+            let result = with_budgets_meta(result, &response.budgets);
+            let result = with_attempts_meta(result, response.attempts);
+            Ok(with_cache_meta(result, response.cache()))
         }
         _ => Err(ErrorData::resource_not_found(
             format!("tool not found: {}", request.name),
@@ -702,10 +878,109 @@ fn call_tool_response_to_json(response: &crate::proto::CallToolResponse) -> serd
         Some(call_tool_response::Result::Error(message)) => {
             serde_json::json!({ "error": message })
         }
+        Some(call_tool_response::Result::PendingApproval(approval)) => approval_to_json(approval),
         None => serde_json::Value::Null,
     }
 }
 
+/// Converts a pending approval to the structured "awaiting approval" error
+/// returned to MCP clients.
+fn approval_to_json(approval: &crate::proto::Approval) -> serde_json::Value {
+    serde_json::json!({
+        "status": "awaiting_approval",
+        "approval": approval.name,
+        "tool": approval.tool,
+        "reason": approval.reason,
+        "expire_time_ms": approval.expire_time_ms,
+        "message": "This call requires human approval. Retry the same call once it is approved.",
+    })
+}
+
+/// Lists the state of the budgets a call counted against under the `budgets`
+/// key of the result's `_meta`, if there are any.
+fn with_budgets_meta(mut result: CallToolResult, budgets: &[BudgetStatus]) -> CallToolResult {
+    if !budgets.is_empty() {
+        let mut meta = result.meta.take().unwrap_or_default();
+        meta.0.insert(
+            "budgets".to_string(),
+            budgets.iter().map(budget_status_to_json).collect(),
+        );
+        result.meta = Some(meta);
+    }
+    result
+}
+
+/// Gives the number of attempts a call made under the `attempts` key of the
+/// result's `_meta`, if it was retried.
+fn with_attempts_meta(mut result: CallToolResult, attempts: u32) -> CallToolResult {
+    if attempts > 1 {
+        let mut meta = result.meta.take().unwrap_or_default();
+        meta.0.insert("attempts".to_string(), attempts.into());
+        result.meta = Some(meta);
+    }
+    result
+}
+
+/// Says how the result cache served a call under the `cache` key of the
+/// result's `_meta`, if the tool's results are cached.
+fn with_cache_meta(
+    mut result: CallToolResult,
+    cache: call_tool_response::CacheStatus,
+) -> CallToolResult {
+    let status = match cache {
+        call_tool_response::CacheStatus::Unspecified => return result,
+        call_tool_response::CacheStatus::Hit => "hit",
+        call_tool_response::CacheStatus::Miss => "miss",
+        call_tool_response::CacheStatus::Bypass => "bypass",
+    };
+    let mut meta = result.meta.take().unwrap_or_default();
+    meta.0.insert("cache".to_string(), status.into());
+    result.meta = Some(meta);
+    result
+}
+
+/// Converts the state of a budget to JSON.
+fn budget_status_to_json(status: &BudgetStatus) -> serde_json::Value {
+    serde_json::json!({
+        "budget": status.budget,
+        "scope": status.scope,
+        "limit": status.limit,
+        "spent": status.spent,
+        "remaining": status.remaining,
+    })
+}
+
+/// Converts the details of an exceeded budget to JSON.
+fn budget_exceeded_to_json(exceeded: &BudgetExceeded) -> serde_json::Value {
+    let mut value = exceeded
+        .budget
+        .as_ref()
+        .map_or_else(|| serde_json::json!({}), budget_status_to_json);
+    value["cost"] = exceeded.cost.into();
+    value
+}
+
+/// Converts the details of a rate-limited call to JSON.
+fn rate_limited_to_json(limited: &RateLimited) -> serde_json::Value {
+    serde_json::json!({
+        "rate_limit": limited.rate_limit,
+        "key": limited.key,
+        "retry_after_seconds": limited.retry_after_seconds,
+    })
+}
+
+/// Converts the details of a policy denial to JSON.
+fn policy_denial_to_json(denial: &PolicyDenial) -> serde_json::Value {
+    serde_json::json!({
+        "policy": denial.policy,
+        "policy_version": denial.policy_version,
+        "effect_index": denial.effect_index,
+        "tool_pattern": denial.tool_pattern,
+        "condition": denial.condition,
+        "condition_met": denial.condition_met,
+    })
+}
+
 /// Converts a protobuf Tool to JSON.
 fn proto_tool_to_json(tool: &crate::proto::Tool) -> serde_json::Value {
     let input_schema = tool
@@ -734,14 +1009,30 @@ fn proto_tool_to_json(tool: &crate::proto::Tool) -> serde_json::Value {
 /// Maps gRPC status codes to appropriate MCP error types:
 /// - `NotFound` → `resource_not_found`
 /// - `InvalidArgument` → `invalid_params`
-/// - `PermissionDenied`/`Unauthenticated` → `invalid_request`
+/// - `PermissionDenied`/`Unauthenticated` → `invalid_request`, with the
+///   [`PolicyDenial`] details as data when a policy blocked the call
+/// - `ResourceExhausted` → `invalid_request`, with the [`RateLimited`] details
+///   as data when the status has a `retry-after` header, or else the
+///   [`BudgetExceeded`] details
 /// - Others → `internal_error`
 fn status_to_error(status: &tonic::Status) -> ErrorData {
     let message = status.message().to_string();
     match status.code() {
         Code::NotFound => ErrorData::resource_not_found(message, None),
         Code::InvalidArgument => ErrorData::invalid_params(message, None),
-        Code::PermissionDenied | Code::Unauthenticated => ErrorData::invalid_request(message, None),
+        Code::PermissionDenied => {
+            let denial = status_details::<PolicyDenial>(status);
+            ErrorData::invalid_request(message, denial.as_ref().map(policy_denial_to_json))
+        }
+        Code::ResourceExhausted if status.metadata().contains_key("retry-after") => {
+            let limited = status_details::<RateLimited>(status);
+            ErrorData::invalid_request(message, limited.as_ref().map(rate_limited_to_json))
+        }
+        Code::ResourceExhausted => {
+            let exceeded = status_details::<BudgetExceeded>(status);
+            ErrorData::invalid_request(message, exceeded.as_ref().map(budget_exceeded_to_json))
+        }
+        Code::Unauthenticated => ErrorData::invalid_request(message, None),
         _ => ErrorData::internal_error(message, None),
     }
 }
@@ -759,6 +1050,23 @@ fn extract_session_id_from_extensions(extensions: &Extensions) -> Option<String>
         .map(ToString::to_string)
 }
 
+/// Returns the caller identity an HTTP authentication layer attached to the
+/// request, if any.
+fn extract_caller_from_extensions(extensions: &Extensions) -> Option<CallerIdentity> {
+    let parts = extensions.get::<http::request::Parts>()?;
+    parts.extensions.get::<CallerIdentity>().cloned()
+}
+
+/// Returns whether the HTTP request asks to skip the result cache, via the
+/// `x-cache-mode: bypass` header.
+fn bypasses_cache(extensions: &Extensions) -> bool {
+    extensions
+        .get::<http::request::Parts>()
+        .and_then(|parts| parts.headers.get("x-cache-mode"))
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case("bypass"))
+}
+
 /// Arguments for the `list_tool` meta-tool.
 #[derive(Debug, serde::Deserialize, Default)]
 struct ListArgs {
@@ -818,15 +1126,21 @@ mod tests {
         CallArgs, CallResult, InitArgs, RuntimeContext, TOOL_ABI_VERSION, ToolDescriptor, ToolMeta,
         ToolModule, ToolModuleRef, ToolResult, async_ffi::FfiFuture,
     };
-    use operai_core::policy::session::{InMemoryPolicySessionStore, PolicyStore};
+    use operai_core::{
+        Policy,
+        policy::session::{InMemoryPolicySessionStore, PolicySessionStore, PolicyStore},
+    };
     use rmcp::{
-        model::CallToolRequestParam, service::ServiceExt, transport::StreamableHttpClientTransport,
+        RoleClient,
+        model::CallToolRequestParam,
+        service::{RunningService, ServiceExt},
+        transport::StreamableHttpClientTransport,
     };
     use serde_json::Value;
     use tokio::sync::oneshot;
 
     use super::*;
-    use crate::SearchEmbedFuture;
+    use crate::{SearchEmbedFuture, runtime::status_with_details};
 
     struct TestEmbedder;
 
@@ -865,6 +1179,106 @@ mod tests {
         assert!(extract_session_id_from_extensions(&extensions).is_none());
     }
 
+    #[test]
+    fn test_rate_limit_errors_are_reported_as_json() {
+        let limited = RateLimited {
+            rate_limit: "mail".to_string(),
+            key: "caller=ada".to_string(),
+            retry_after_seconds: 1.5,
+        };
+        let mut metadata = tonic::metadata::MetadataMap::new();
+        metadata.insert("retry-after", 2.into());
+        let error = status_to_error(&status_with_details(
+            Code::ResourceExhausted,
+            "Rate limit mail exceeded".to_string(),
+            &limited,
+            metadata,
+        ));
+        assert_eq!(error.code, rmcp::model::ErrorCode::INVALID_REQUEST);
+        assert_eq!(
+            error.data,
+            Some(serde_json::json!({
+                "rate_limit": "mail",
+                "key": "caller=ada",
+                "retry_after_seconds": 1.5,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_budget_errors_and_status_are_reported_as_json() {
+        let status = BudgetStatus {
+            budget: "credits".to_string(),
+            scope: "caller".to_string(),
+            limit: 10.0,
+            spent: 9.5,
+            remaining: 0.5,
+        };
+        let exceeded = BudgetExceeded {
+            budget: Some(status.clone()),
+            cost: 1.0,
+        };
+        let error = status_to_error(&status_with_details(
+            Code::ResourceExhausted,
+            "Budget credits exceeded".to_string(),
+            &exceeded,
+            tonic::metadata::MetadataMap::new(),
+        ));
+        assert_eq!(error.code, rmcp::model::ErrorCode::INVALID_REQUEST);
+        assert_eq!(
+            error.data,
+            Some(serde_json::json!({
+                "budget": "credits",
+                "scope": "caller",
+                "limit": 10.0,
+                "spent": 9.5,
+                "remaining": 0.5,
+                "cost": 1.0,
+            }))
+        );
+
+        let result = with_budgets_meta(CallToolResult::success(Vec::new()), &[status]);
+        let meta = result.meta.expect("budgets should be in the meta");
+        assert_eq!(meta.0["budgets"][0]["remaining"], 0.5);
+        assert!(
+            with_budgets_meta(CallToolResult::success(Vec::new()), &[])
+                .meta
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_with_attempts_meta_only_reports_retried_calls() {
+        let result = with_attempts_meta(CallToolResult::success(Vec::new()), 3);
+        let meta = result.meta.expect("attempts should be in the meta");
+        assert_eq!(meta.0["attempts"], 3);
+        for attempts in [0, 1] {
+            assert!(
+                with_attempts_meta(CallToolResult::success(Vec::new()), attempts)
+                    .meta
+                    .is_none()
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_cache_meta_reports_cache_status_of_cached_tools() {
+        let result = with_cache_meta(
+            CallToolResult::success(Vec::new()),
+            call_tool_response::CacheStatus::Hit,
+        );
+        let meta = result.meta.expect("cache status should be in the meta");
+        assert_eq!(meta.0["cache"], "hit");
+        assert!(
+            with_cache_meta(
+                CallToolResult::success(Vec::new()),
+                call_tool_response::CacheStatus::Unspecified
+            )
+            .meta
+            .is_none()
+        );
+    }
+
     #[test]
     fn test_search_mode_tools_exposes_expected_names() {
         let tools = search_mode_tools();
@@ -897,6 +1311,9 @@ mod tests {
             capabilities: RSlice::from_slice(&[]),
             tags: RSlice::from_slice(&[]),
             embedding: RSlice::from_slice(embedding),
+            cost: ROption::RNone,
+            idempotent: false,
+            cache_ttl_secs: ROption::RNone,
         };
         let descriptors = Box::leak(Box::new([descriptor]));
 
@@ -924,41 +1341,79 @@ mod tests {
         }
     }
 
-    #[tokio::test]
-    async fn test_searchable_mcp_end_to_end() -> Result<()> {
-        let module = static_tool_module_ref();
+    async fn static_runtime(
+        session_store: Arc<dyn PolicySessionStore>,
+        policies: Vec<Value>,
+    ) -> Result<LocalRuntime> {
         let mut registry = ToolRegistry::new();
         let runtime_ctx = RuntimeContext::new();
         registry
-            .register_module(module, None, &runtime_ctx)
+            .register_module(static_tool_module_ref(), None, &runtime_ctx)
             .await
             .expect("static module should register");
 
-        let registry = Arc::new(registry);
-        let policy_store = Arc::new(PolicyStore::new(
-            Arc::new(InMemoryPolicySessionStore::new()),
-        ));
-        let runtime = LocalRuntime::with_context(Arc::clone(&registry), policy_store, runtime_ctx);
-
-        let service = McpService::from_runtime(runtime)
-            .searchable(true)
-            .with_search_embedder(Arc::new(TestEmbedder))
-            .streamable_http_service();
-        let router = axum::Router::new().nest_service("/mcp", service);
-
-        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
-        let addr = listener.local_addr()?;
-        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
-        let server = tokio::spawn(async move {
-            let _ = axum::serve(listener, router)
-                .with_graceful_shutdown(async {
-                    let _ = shutdown_rx.await;
-                })
-                .await;
-        });
+        let policy_store = Arc::new(PolicyStore::new(session_store));
+        for policy in policies {
+            policy_store.register(serde_json::from_value::<Policy>(policy)?)?;
+        }
+        Ok(LocalRuntime::with_context(
+            Arc::new(registry),
+            policy_store,
+            runtime_ctx,
+        ))
+    }
 
-        let uri = format!("http://{addr}/mcp");
-        let client = ().serve(StreamableHttpClientTransport::from_uri(uri)).await?;
+    /// Serves an [`McpService`] under `/mcp` on a local port.
+    struct TestServer {
+        uri: String,
+        shutdown_tx: oneshot::Sender<()>,
+        server: tokio::task::JoinHandle<()>,
+    }
+
+    impl TestServer {
+        async fn start(service: &McpService) -> Result<Self> {
+            let router =
+                axum::Router::new().nest_service("/mcp", service.streamable_http_service());
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+            let uri = format!("http://{}/mcp", listener.local_addr()?);
+            let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+            let server = tokio::spawn(async move {
+                let _ = axum::serve(listener, router)
+                    .with_graceful_shutdown(async {
+                        let _ = shutdown_rx.await;
+                    })
+                    .await;
+            });
+            Ok(Self {
+                uri,
+                shutdown_tx,
+                server,
+            })
+        }
+
+        async fn client(&self) -> Result<RunningService<RoleClient, ()>> {
+            Ok(
+                ().serve(StreamableHttpClientTransport::from_uri(self.uri.clone()))
+                    .await?,
+            )
+        }
+
+        async fn shutdown(self) {
+            let _ = self.shutdown_tx.send(());
+            let _ = self.server.await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_searchable_mcp_end_to_end() -> Result<()> {
+        let runtime = static_runtime(Arc::new(InMemoryPolicySessionStore::new()), vec![]).await?;
+        let server = TestServer::start(
+            &McpService::from_runtime(runtime)
+                .searchable(true)
+                .with_search_embedder(Arc::new(TestEmbedder)),
+        )
+        .await?;
+        let client = server.client().await?;
 
         let tools = client.list_all_tools().await?;
         let mut names: Vec<_> = tools.iter().map(|tool| tool.name.as_ref()).collect();
@@ -1039,8 +1494,138 @@ mod tests {
         assert!(ok_value, "expected ok to be true");
 
         client.cancel().await?;
-        let _ = shutdown_tx.send(());
-        let _ = server.await;
+        server.shutdown().await;
+
+        Ok(())
+    }
+
+    async fn counter_runtime(
+        session_store: &Arc<InMemoryPolicySessionStore>,
+    ) -> Result<LocalRuntime> {
+        let policy = serde_json::json!({
+            "name": "counter",
+            "version": "1",
+            "context": { "calls": 0 },
+            "effects": [{
+                "tool": "*",
+                "stage": "after",
+                "when": "true",
+                "set": { "calls": "context.calls + 1" }
+            }]
+        });
+        static_runtime(
+            Arc::clone(session_store) as Arc<dyn PolicySessionStore>,
+            vec![policy],
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_closing_mcp_session_ends_policy_session() -> Result<()> {
+        let session_store = Arc::new(InMemoryPolicySessionStore::new());
+        let runtime = counter_runtime(&session_store).await?;
+        let server =
+            TestServer::start(&McpService::from_runtime(runtime).end_sessions_on_close(true))
+                .await?;
+        let client = server.client().await?;
+        client
+            .call_tool(CallToolRequestParam {
+                name: "static-tool.echo".into(),
+                arguments: None,
+            })
+            .await?;
+        assert_eq!(session_store.len()?, 1);
+
+        client.cancel().await?;
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while !session_store.is_empty().expect("store should be readable") {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
+
+        server.shutdown().await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_closing_mcp_session_keeps_policy_session_by_default() -> Result<()> {
+        let session_store = Arc::new(InMemoryPolicySessionStore::new());
+        let runtime = counter_runtime(&session_store).await?;
+        let server = TestServer::start(&McpService::from_runtime(runtime)).await?;
+        let client = server.client().await?;
+        client
+            .call_tool(CallToolRequestParam {
+                name: "static-tool.echo".into(),
+                arguments: None,
+            })
+            .await?;
+        assert_eq!(session_store.len()?, 1);
+
+        client.cancel().await?;
+
+        // The closed session was handled before a call on a new session
+        // returns; both sessions keep their policy state.
+        let client = server.client().await?;
+        client
+            .call_tool(CallToolRequestParam {
+                name: "static-tool.echo".into(),
+                arguments: None,
+            })
+            .await?;
+        assert_eq!(session_store.len()?, 2);
+
+        client.cancel().await?;
+        server.shutdown().await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_call_awaiting_approval_returns_structured_error() -> Result<()> {
+        let policy = serde_json::json!({
+            "name": "review",
+            "version": "1",
+            "effects": [{
+                "tool": "static-tool.*",
+                "stage": "before",
+                "when": "true",
+                "effect": "require_approval",
+                "fail_message": "needs review"
+            }]
+        });
+        let runtime =
+            static_runtime(Arc::new(InMemoryPolicySessionStore::new()), vec![policy]).await?;
+        let server = TestServer::start(&McpService::from_runtime(runtime.clone())).await?;
+        let client = server.client().await?;
+        let call = || {
+            client.call_tool(CallToolRequestParam {
+                name: "static-tool.echo".into(),
+                arguments: None,
+            })
+        };
+
+        let held = call().await?;
+        assert_eq!(held.is_error, Some(true));
+        let held_struct = held
+            .structured_content
+            .expect("held call should return structured content");
+        assert_eq!(held_struct["status"], "awaiting_approval");
+        assert_eq!(held_struct["reason"], "needs review");
+        let name = held_struct["approval"]
+            .as_str()
+            .expect("approval name missing")
+            .to_string();
+
+        runtime
+            .approve_call(crate::proto::ApproveCallRequest { name })
+            .await?;
+        let approved = call().await?;
+        assert_ne!(approved.is_error, Some(true));
+
+        client.cancel().await?;
+        server.shutdown().await;
 
         Ok(())
     }
@@ -22,8 +22,17 @@
 //! into any type that implements `DeserializeOwned`. The deserialization uses
 //! JSON as an intermediate format, converting the `HashMap` to a JSON object
 //! and then to the target type.
+//!
+//! # Cost
+//!
+//! A tool whose cost depends on the call (e.g. on the tokens a paid API
+//! billed) reports it with [`Context::report_cost`]. The runtime counts it
+//! against its budgets instead of the tool's static `## Cost`.
 
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
 
 use operai_abi::CallContext;
 use rkyv::rancor::BoxedError;
@@ -34,13 +43,33 @@ use crate::credential::CredentialError;
 /// Execution context for a tool invocation.
 ///
 /// Contains metadata (request and session IDs) and credential stores for
-/// accessing system and user credentials during tool execution.
+/// accessing system and user credentials during tool execution. Clones of a
+/// context share the cost reported with [`report_cost`](Self::report_cost).
 #[derive(Debug, Clone)]
 pub struct Context {
     request_id: String,
     session_id: String,
     system_credentials: HashMap<String, HashMap<String, String>>,
     user_credentials: HashMap<String, HashMap<String, String>>,
+    cost: ReportedCost,
+}
+
+/// The cost reported for a call, shared between the context and the
+/// generated entrypoint that returns it to the runtime.
+#[doc(hidden)]
+#[derive(Debug, Clone, Default)]
+pub struct ReportedCost(Arc<Mutex<Option<f64>>>);
+
+impl ReportedCost {
+    /// Returns the reported cost, if any.
+    #[must_use]
+    pub fn get(&self) -> Option<f64> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn set(&self, cost: f64) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Some(cost);
+    }
 }
 
 impl Context {
@@ -81,6 +110,7 @@ impl Context {
             session_id,
             system_credentials,
             user_credentials,
+            cost: ReportedCost::default(),
         }
     }
 
@@ -96,6 +126,7 @@ impl Context {
             session_id: String::new(),
             system_credentials: HashMap::new(),
             user_credentials: HashMap::new(),
+            cost: ReportedCost::default(),
         }
     }
 
@@ -107,6 +138,7 @@ impl Context {
             session_id: session_id.to_string(),
             system_credentials: HashMap::new(),
             user_credentials: HashMap::new(),
+            cost: ReportedCost::default(),
         }
     }
 
@@ -154,6 +186,32 @@ impl Context {
         Self::get_credential(&self.user_credentials, name)
     }
 
+    /// Reports the cost of this call, replacing the tool's static cost.
+    ///
+    /// Reporting again replaces the previously reported cost. A cost that is
+    /// negative or not finite is ignored with a warning, leaving the
+    /// previously reported cost (or the static one) in place.
+    pub fn report_cost(&self, cost: f64) {
+        if !cost.is_finite() || cost < 0.0 {
+            tracing::warn!(cost, "Ignoring invalid reported cost");
+            return;
+        }
+        self.cost.set(cost);
+    }
+
+    /// Returns the cost reported for this call, if any.
+    #[must_use]
+    pub fn reported_cost(&self) -> Option<f64> {
+        self.cost.get()
+    }
+
+    /// Returns the shared slot holding the reported cost.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn __cost(&self) -> ReportedCost {
+        self.cost.clone()
+    }
+
     fn get_credential<T: DeserializeOwned>(
         store: &HashMap<String, HashMap<String, String>>,
         name: &str,
@@ -209,6 +267,32 @@ mod tests {
         assert!(ctx.session_id().is_empty());
     }
 
+    #[test]
+    fn test_reported_cost_is_shared_by_clones() {
+        let ctx = Context::empty();
+        assert_eq!(ctx.reported_cost(), None);
+
+        ctx.clone().report_cost(0.5);
+        ctx.report_cost(1.5);
+
+        assert_eq!(ctx.reported_cost(), Some(1.5));
+        assert_eq!(ctx.__cost().get(), Some(1.5));
+    }
+
+    #[test]
+    fn test_report_cost_ignores_invalid_costs() {
+        let ctx = Context::empty();
+        ctx.report_cost(f64::NAN);
+        ctx.report_cost(f64::INFINITY);
+        assert_eq!(ctx.reported_cost(), None);
+
+        ctx.report_cost(1.5);
+        ctx.report_cost(f64::NAN);
+        ctx.report_cost(f64::INFINITY);
+        ctx.report_cost(-1.0);
+        assert_eq!(ctx.reported_cost(), Some(1.5));
+    }
+
     #[test]
     fn test_context_with_metadata() {
         let ctx = Context::with_metadata("req-123", "sess-456");
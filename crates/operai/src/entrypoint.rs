@@ -86,6 +86,9 @@ pub struct Sealed(pub(crate) ());
 /// - `description` - Detailed description of what the tool does
 /// - `capabilities` - Optional list of capability strings the tool provides
 /// - `tags` - Optional list of tags for categorization and discovery
+/// - `cost` - Optional static cost of one call
+/// - `idempotent` - Whether the runtime may retry failed calls
+/// - `cache_ttl_secs` - Optional time a successful result may be reused
 /// - `input_schema_fn` - Function that returns JSON Schema for tool input
 /// - `output_schema_fn` - Function that returns JSON Schema for tool output
 /// - `handler` - Async function that handles tool invocations
@@ -102,6 +105,14 @@ pub struct ToolEntry {
     pub capabilities: &'static [&'static str],
     /// Optional list of tags for categorization and discovery
     pub tags: &'static [&'static str],
+    /// Optional static cost of one call, counted against runtime budgets
+    pub cost: Option<f64>,
+    /// Whether calling the tool again with the same input has no further
+    /// effect, letting the runtime retry failed calls
+    pub idempotent: bool,
+    /// Optional seconds a successful result may be reused for the same
+    /// input, when the runtime caches results
+    pub cache_ttl_secs: Option<u64>,
     /// Returns the JSON Schema for this tool's input
     pub input_schema_fn: fn() -> String,
     /// Returns the JSON Schema for this tool's output
@@ -209,6 +220,9 @@ mod tests {
             description: "Tool entrypoint test tool",
             capabilities: &[],
             tags: &["test"],
+            cost: None,
+            idempotent: false,
+            cache_ttl_secs: None,
             input_schema_fn: || "{\"type\":\"object\"}".to_string(),
             output_schema_fn: || "{\"type\":\"object\"}".to_string(),
             handler: test_tool_handler,
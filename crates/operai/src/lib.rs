@@ -29,12 +29,23 @@
 //! ///
 //! /// ## Tags
 //! /// - utility
+//! ///
+//! /// ## Cost
+//! /// - 0.01
+//! ///
+//! /// ## Idempotent
 //! #[tool]
 //! async fn my_tool(ctx: Context, input: MyInput) -> Result<MyOutput> {
 //!     Ok(MyOutput { /* ... */ })
 //! }
 //! ```
 //!
+//! An `## Idempotent` heading declares that calling the tool again with the
+//! same input has no further effect, so the runtime may retry failed calls.
+//! A `## Cache` section with one item gives the seconds for which a
+//! successful result may be reused for the same input, if the runtime caches
+//! results.
+//!
 //! The `Result` type is re-exported from `anyhow` and expands to `Result<T,
 //! anyhow::Error>`.
 //!
@@ -55,6 +66,8 @@
 //! - `request_id()`, `session_id()` - Request metadata
 //! - `system_credential(name)` - System-level credentials
 //! - `user_credential(name)` - User-provided credentials
+//! - `report_cost(cost)` - The cost of this call, replacing the tool's static
+//!   cost
 //!
 //! ## Credentials
 //!
@@ -226,6 +239,9 @@ macro_rules! generate_tool_entrypoint {
                                 capabilities: RSlice::from_slice(&capabilities[i]),
                                 tags: RSlice::from_slice(&tags[i]),
                                 embedding: RSlice::from_slice(EMBEDDING),
+                                cost: entry.cost.into(),
+                                idempotent: entry.idempotent,
+                                cache_ttl_secs: entry.cache_ttl_secs.into(),
                             }
                         })
                         .collect()
@@ -278,14 +294,16 @@ macro_rules! generate_tool_entrypoint {
                 };
 
                 let ctx = ::operai::__private::Context::__from_call_context(&args.context);
+                let cost = ctx.__cost();
                 let input_bytes = args.input.as_slice().to_vec();
                 let future = (handler)(ctx, input_bytes);
 
                 async move {
-                    match future.await {
+                    let result = match future.await {
                         Ok(output_bytes) => abi::CallResult::ok(RVec::from(output_bytes)),
                         Err(e) => abi::CallResult::error(abi::ToolResult::Error, &e.to_string()),
-                    }
+                    };
+                    result.with_cost(cost.get())
                 }
                 .into_ffi()
             }
@@ -350,6 +368,11 @@ mod tests {
         ///
         /// ## Tags
         /// - greeting
+        ///
+        /// ## Idempotent
+        ///
+        /// ## Cache
+        /// - 60
         #[tool]
         async fn greet(_ctx: Context, input: GreetInput) -> Result<GreetOutput> {
             Ok(GreetOutput {
@@ -373,6 +396,25 @@ mod tests {
             bail!("boom");
         }
 
+        #[derive(Debug, Deserialize, JsonSchema)]
+        struct ChargeInput {
+            amount: Option<f64>,
+        }
+
+        /// # Charge (ID: charge)
+        ///
+        /// Costs 0.5 unless it reports the amount.
+        ///
+        /// ## Cost
+        /// - 0.5
+        #[tool]
+        async fn charge(ctx: Context, input: ChargeInput) -> Result<()> {
+            if let Some(amount) = input.amount {
+                ctx.report_cost(amount);
+            }
+            Ok(())
+        }
+
         #[init]
         async fn setup() -> Result<()> {
             INIT_CALL_COUNT.fetch_add(1, Ordering::SeqCst);
@@ -610,6 +652,46 @@ mod tests {
         assert!(fail_tool.tags.as_slice().is_empty());
     }
 
+    #[test]
+    fn test_tool_descriptor_contains_static_cost() {
+        use operai_abi::abi_stable::std_types::ROption;
+
+        // Arrange
+        let module = test_tool_library::get_root_module();
+
+        // Act & Assert
+        assert_eq!(find_descriptor(&module, "charge").cost, ROption::RSome(0.5));
+        assert_eq!(find_descriptor(&module, "greet").cost, ROption::RNone);
+    }
+
+    #[test]
+    fn test_tool_descriptor_marks_idempotent_tools() {
+        // Arrange
+        let module = test_tool_library::get_root_module();
+
+        // Act & Assert
+        assert!(find_descriptor(&module, "greet").idempotent);
+        assert!(!find_descriptor(&module, "fail").idempotent);
+    }
+
+    #[test]
+    fn test_tool_descriptor_contains_cache_ttl() {
+        use operai_abi::abi_stable::std_types::ROption;
+
+        // Arrange
+        let module = test_tool_library::get_root_module();
+
+        // Act & Assert
+        assert_eq!(
+            find_descriptor(&module, "greet").cache_ttl_secs,
+            ROption::RSome(60)
+        );
+        assert_eq!(
+            find_descriptor(&module, "fail").cache_ttl_secs,
+            ROption::RNone
+        );
+    }
+
     #[test]
     fn test_all_registered_tools_are_discoverable() {
         // Arrange
@@ -654,6 +736,28 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_call_returns_reported_cost() {
+        use operai_abi::abi_stable::std_types::ROption;
+
+        // Arrange
+        let module = test_tool_library::get_root_module();
+
+        for (input, cost) in [
+            (r#"{"amount": 2.5}"#, ROption::RSome(2.5)),
+            ("{}", ROption::RNone),
+        ] {
+            let args = call_args("req-123", "sess-456", "charge", input.as_bytes());
+
+            // Act
+            let result = (module.call())(args).await;
+
+            // Assert
+            assert_eq!(result.result, operai_abi::ToolResult::Ok);
+            assert_eq!(result.cost, cost);
+        }
+    }
+
     #[tokio::test]
     async fn test_call_with_unknown_tool_id_returns_not_found() {
         // Arrange